@@ -0,0 +1,182 @@
+//! `clob bracket`: a take-profit limit order on the book and a stop watched
+//! locally, linked so that whichever executes first cancels the other. A
+//! partly filled take-profit shrinks what the stop still has to cover.
+
+use anyhow::{Result, bail};
+use polymarket_client_sdk::auth::Normal;
+use polymarket_client_sdk::auth::state::Authenticated;
+use polymarket_client_sdk::clob;
+use polymarket_client_sdk::clob::types::{OrderStatusType, Side};
+use polymarket_client_sdk::types::Decimal;
+
+use super::rest;
+use super::stop::{MAX_FIRE_FAILURES, StopOrder};
+use crate::auth::WalletSigner;
+use crate::commands::clob::CliSide;
+use crate::output::OutputFormat;
+use crate::output::algo::{print_bracket_finished, print_bracket_start, print_take_profit};
+use crate::poll::Poller;
+
+pub struct Bracket {
+    /// The stop leg; its size is the size of the whole bracket
+    pub stop: StopOrder,
+    pub take_profit: Decimal,
+}
+
+/// How the bracket ended.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    TakeProfit,
+    /// The stop fired and placed this many shares
+    Stopped(Decimal),
+    /// The take-profit order was canceled or expired elsewhere
+    Closed,
+    /// Ctrl+C before either leg executed
+    Cancelled,
+}
+
+/// Where the take-profit order stands.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TakeProfit {
+    pub order_id: String,
+    pub matched: Decimal,
+}
+
+impl Bracket {
+    /// The take-profit has to sit on the profitable side of the stop: above
+    /// it when the bracket sells, below it when it buys.
+    pub fn validate(&self) -> Result<()> {
+        let (take_profit, trigger) = (self.take_profit, self.stop.trigger);
+        match self.stop.side {
+            CliSide::Sell if take_profit <= trigger => {
+                bail!("--take-profit ({take_profit}) must be above --stop ({trigger}) when selling")
+            }
+            CliSide::Buy if take_profit >= trigger => {
+                bail!("--take-profit ({take_profit}) must be below --stop ({trigger}) when buying")
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+pub async fn run(
+    client: clob::Client<Authenticated<Normal>>,
+    signer: WalletSigner,
+    bracket: Bracket,
+    output: &OutputFormat,
+) -> Result<()> {
+    let stop = &bracket.stop;
+    let side = Side::from(stop.side);
+    print_bracket_start(&bracket, output);
+    let fill = rest(
+        &client,
+        &signer,
+        stop.token_id,
+        side,
+        bracket.take_profit,
+        stop.size,
+    )
+    .await?;
+    let mut take_profit = TakeProfit {
+        order_id: fill.order_id,
+        matched: fill.shares.min(stop.size),
+    };
+    print_take_profit(&bracket, &take_profit, output)?;
+
+    let mut poller = Poller::new(stop.interval);
+    let mut outcome = Outcome::Cancelled;
+    while poller.tick().await {
+        match client.order(&take_profit.order_id).await {
+            Ok(order) => {
+                if order.size_matched > take_profit.matched {
+                    take_profit.matched = order.size_matched;
+                    print_take_profit(&bracket, &take_profit, output)?;
+                }
+                if take_profit.matched >= stop.size {
+                    outcome = Outcome::TakeProfit;
+                    break;
+                }
+                if !matches!(order.status, OrderStatusType::Live) {
+                    outcome = Outcome::Closed;
+                    break;
+                }
+            }
+            Err(e) => eprintln!("Failed to check order {}: {e}", take_profit.order_id),
+        }
+        if !stop.check_and_report(&client, output).await? {
+            continue;
+        }
+
+        // Pull the take-profit first so the two legs can't both execute.
+        if let Err(e) = client.cancel_order(&take_profit.order_id).await {
+            bail!(
+                "Stop triggered but the take-profit order {} could not be canceled, so the stop \
+                 did not fire: {e}",
+                take_profit.order_id
+            );
+        }
+        if let Ok(order) = client.order(&take_profit.order_id).await {
+            take_profit.matched = take_profit.matched.max(order.size_matched);
+        }
+        let remaining = stop.size - take_profit.matched;
+        if remaining <= Decimal::ZERO {
+            outcome = Outcome::TakeProfit;
+            break;
+        }
+        let placed = stop
+            .fire_until_placed(&client, &signer, remaining, &mut poller, output)
+            .await?;
+        print_bracket_finished(
+            &bracket,
+            &take_profit,
+            &Outcome::Stopped(placed.shares),
+            output,
+        )?;
+        if placed.gave_up {
+            bail!("Gave up after {MAX_FIRE_FAILURES} failed attempts to place the stop order");
+        }
+        return Ok(());
+    }
+
+    // Without the monitor there is no stop, so don't leave the other leg behind.
+    if outcome == Outcome::Cancelled
+        && let Err(e) = client.cancel_order(&take_profit.order_id).await
+    {
+        eprintln!(
+            "Failed to cancel take-profit order {}: {e}",
+            take_profit.order_id
+        );
+    }
+    print_bracket_finished(&bracket, &take_profit, &outcome, output)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use polymarket_client_sdk::types::U256;
+    use rust_decimal_macros::dec;
+
+    fn bracket(side: CliSide, take_profit: Decimal) -> Bracket {
+        Bracket {
+            stop: StopOrder {
+                token_id: U256::from(1),
+                side,
+                trigger: dec!(0.35),
+                size: dec!(400),
+                limit: None,
+                interval: Duration::from_secs(5),
+            },
+            take_profit,
+        }
+    }
+
+    #[test]
+    fn take_profit_must_be_on_the_profitable_side_of_the_stop() {
+        assert!(bracket(CliSide::Sell, dec!(0.70)).validate().is_ok());
+        assert!(bracket(CliSide::Sell, dec!(0.30)).validate().is_err());
+        assert!(bracket(CliSide::Buy, dec!(0.30)).validate().is_ok());
+        assert!(bracket(CliSide::Buy, dec!(0.35)).validate().is_err());
+    }
+}
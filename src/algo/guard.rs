@@ -0,0 +1,209 @@
+//! `clob guard`: a dead man's switch for open orders. The CLOB has no
+//! heartbeat endpoint, so the guard makes an authenticated request every few
+//! seconds and cancels everything once too many fail in a row or the last
+//! good one is older than the TTL. Stopping the guard cancels them as well.
+
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, bail};
+use polymarket_client_sdk::auth::Normal;
+use polymarket_client_sdk::auth::state::Authenticated;
+use polymarket_client_sdk::clob;
+use polymarket_client_sdk::clob::types::request::OrdersRequest;
+use tokio::time::timeout;
+
+use crate::output::OutputFormat;
+use crate::output::algo::{print_guard_event, print_guard_finished, print_guard_start};
+use crate::poll::Poller;
+
+/// Attempts to cancel after Ctrl+C, when there is no one left to wait for.
+const MAX_CANCEL_ATTEMPTS: u32 = 3;
+
+pub struct Guard {
+    /// Longest time without a good heartbeat before orders are canceled
+    pub ttl: Duration,
+    /// Consecutive failed heartbeats before orders are canceled
+    pub misses: u32,
+    missed: u32,
+    last_ok: Instant,
+}
+
+/// Why the guard canceled the orders.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Trip {
+    Missed(u32),
+    /// No good heartbeat for this long
+    Expired(Duration),
+    /// Ctrl+C or SIGTERM
+    Stopped,
+}
+
+/// Something the guard did, reported as it happens.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GuardEvent {
+    /// A good heartbeat and the open orders it saw
+    Beat(u64),
+    Missed {
+        missed: u32,
+        error: String,
+    },
+    Tripped(Trip),
+    Canceled {
+        canceled: usize,
+        not_canceled: usize,
+    },
+    CancelFailed(String),
+}
+
+impl Guard {
+    pub fn new(ttl: Duration, misses: u32, now: Instant) -> Self {
+        Self {
+            ttl,
+            misses: misses.max(1),
+            missed: 0,
+            last_ok: now,
+        }
+    }
+
+    /// Spaces the heartbeats so that all the allowed misses fit in the TTL.
+    pub fn interval(&self) -> Duration {
+        (self.ttl / self.misses).max(Duration::from_secs(1))
+    }
+
+    /// Records a heartbeat, returning why the guard trips if it does.
+    fn record(&mut self, ok: bool, now: Instant) -> Option<Trip> {
+        if ok {
+            self.missed = 0;
+            self.last_ok = now;
+            return None;
+        }
+        self.missed += 1;
+        let since = now.saturating_duration_since(self.last_ok);
+        if self.missed >= self.misses {
+            Some(Trip::Missed(self.missed))
+        } else if since >= self.ttl {
+            Some(Trip::Expired(since))
+        } else {
+            None
+        }
+    }
+}
+
+pub async fn run(
+    client: clob::Client<Authenticated<Normal>>,
+    mut guard: Guard,
+    output: &OutputFormat,
+) -> Result<()> {
+    print_guard_start(&guard, output);
+    let request = OrdersRequest::builder().build();
+    let mut poller = Poller::new(guard.interval());
+    let trip = loop {
+        if !poller.tick().await {
+            break Trip::Stopped;
+        }
+        // A request that hangs is as good as a dropped connection.
+        let beat = match timeout(guard.interval(), client.orders(&request, None)).await {
+            Ok(Ok(page)) => Ok(page.count),
+            Ok(Err(e)) => Err(format!("{e:#}")),
+            Err(_) => Err("timed out".to_string()),
+        };
+        match beat {
+            Ok(open) => {
+                guard.record(true, Instant::now());
+                print_guard_event(&GuardEvent::Beat(open), output)?;
+            }
+            Err(error) => {
+                let trip = guard.record(false, Instant::now());
+                print_guard_event(
+                    &GuardEvent::Missed {
+                        missed: guard.missed,
+                        error,
+                    },
+                    output,
+                )?;
+                if let Some(trip) = trip {
+                    break trip;
+                }
+            }
+        }
+    };
+    print_guard_event(&GuardEvent::Tripped(trip.clone()), output)?;
+
+    // Once tripped, keep trying until connectivity is back; after Ctrl+C,
+    // give it a few quick attempts before leaving.
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        match client.cancel_all_orders().await {
+            Ok(result) => {
+                print_guard_event(
+                    &GuardEvent::Canceled {
+                        canceled: result.canceled.len(),
+                        not_canceled: result.not_canceled.len(),
+                    },
+                    output,
+                )?;
+                break;
+            }
+            Err(e) => print_guard_event(&GuardEvent::CancelFailed(format!("{e:#}")), output)?,
+        }
+        let retry = match trip {
+            Trip::Stopped if attempts < MAX_CANCEL_ATTEMPTS => {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                true
+            }
+            Trip::Stopped => false,
+            _ => poller.tick().await,
+        };
+        if !retry {
+            bail!("Could not cancel open orders; they may still be on the book");
+        }
+    }
+    print_guard_finished(&trip, output)?;
+    if trip != Trip::Stopped {
+        bail!("Guard tripped; all open orders were canceled");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heartbeats_fit_the_allowed_misses_into_the_ttl() {
+        let now = Instant::now();
+        assert_eq!(
+            Guard::new(Duration::from_secs(30), 3, now).interval(),
+            Duration::from_secs(10)
+        );
+        assert_eq!(
+            Guard::new(Duration::from_secs(2), 5, now).interval(),
+            Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn trips_after_consecutive_misses() {
+        let start = Instant::now();
+        let mut guard = Guard::new(Duration::from_secs(30), 3, start);
+        let at = |secs| start + Duration::from_secs(secs);
+        assert_eq!(guard.record(false, at(10)), None);
+        assert_eq!(guard.record(true, at(20)), None);
+        assert_eq!(guard.record(false, at(30)), None);
+        assert_eq!(guard.record(false, at(40)), None);
+        assert_eq!(guard.record(false, at(45)), Some(Trip::Missed(3)));
+    }
+
+    #[test]
+    fn trips_once_the_last_good_heartbeat_is_older_than_the_ttl() {
+        let start = Instant::now();
+        let mut guard = Guard::new(Duration::from_secs(30), 5, start);
+        // Slow failures can outlast the TTL before the misses add up.
+        assert_eq!(guard.record(false, start + Duration::from_secs(20)), None);
+        assert_eq!(
+            guard.record(false, start + Duration::from_secs(35)),
+            Some(Trip::Expired(Duration::from_secs(35)))
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! `clob iceberg`: shows only a small clip of a large limit order on the
+//! book, posting the next clip from the hidden remainder each time one
+//! fills. Fills arrive on the user channel; a slower poll of the live clip
+//! catches anything the stream misses.
+
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::Result;
+use futures::StreamExt as _;
+use polymarket_client_sdk::auth::Normal;
+use polymarket_client_sdk::auth::state::Authenticated;
+use polymarket_client_sdk::clob;
+use polymarket_client_sdk::clob::types::request::OrderBookSummaryRequest;
+use polymarket_client_sdk::clob::types::{OrderStatusType, Side};
+use polymarket_client_sdk::clob::ws::types::response::{OrderMessageType, WsMessage};
+use polymarket_client_sdk::types::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::{rest, save_state};
+use crate::auth::WalletSigner;
+use crate::commands::clob::{CliSide, parse_token_id};
+use crate::endpoints;
+use crate::output::OutputFormat;
+use crate::output::algo::{print_iceberg_event, print_iceberg_finished, print_iceberg_start};
+use crate::poll::Poller;
+
+/// Consecutive failures to post a clip before giving up.
+const MAX_POST_FAILURES: u32 = 3;
+
+/// One visible slice of the iceberg.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Clip {
+    pub order_id: String,
+    pub size: Decimal,
+    pub matched: Decimal,
+}
+
+/// Everything needed to resume a run; rewritten whenever a clip changes.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IcebergState {
+    pub token_id: String,
+    pub side: CliSide,
+    pub price: Decimal,
+    /// Shares shown on the book at a time
+    pub display: Decimal,
+    /// Shares to trade in total
+    pub total: Decimal,
+    /// Shares filled by clips that are no longer on the book
+    #[serde(default)]
+    pub retired: Decimal,
+    /// The clip currently on the book
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub live: Option<Clip>,
+}
+
+/// Something that happened to a clip, reported as it happens.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IcebergEvent {
+    Posted(Clip),
+    /// Partly filled and still on the book
+    Matched(Clip),
+    Filled(Clip),
+    /// Canceled or expired before it filled, e.g. from another session
+    Closed(Clip),
+    Failed(String),
+}
+
+impl IcebergState {
+    pub fn filled(&self) -> Decimal {
+        self.retired + self.live.as_ref().map_or(Decimal::ZERO, |c| c.matched)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.filled() >= self.total
+    }
+
+    fn next_clip_size(&self) -> Decimal {
+        self.display.min(self.total - self.filled())
+    }
+
+    /// Applies the live clip's latest matched size. A clip that is fully
+    /// matched, or no longer on the book, is retired.
+    fn update(&mut self, order_id: &str, matched: Decimal, on_book: bool) -> Option<IcebergEvent> {
+        let live = self.live.as_mut().filter(|c| c.order_id == order_id)?;
+        let progressed = matched > live.matched;
+        live.matched = live.matched.max(matched);
+        if live.matched < live.size && on_book {
+            return progressed.then(|| IcebergEvent::Matched(live.clone()));
+        }
+        let clip = self.live.take()?;
+        self.retired += clip.matched;
+        Some(if clip.matched >= clip.size {
+            IcebergEvent::Filled(clip)
+        } else {
+            IcebergEvent::Closed(clip)
+        })
+    }
+}
+
+pub async fn run(
+    client: clob::Client<Authenticated<Normal>>,
+    signer: WalletSigner,
+    mut state: IcebergState,
+    state_path: &Path,
+    poll: Duration,
+    output: &OutputFormat,
+) -> Result<()> {
+    let token_id = parse_token_id(&state.token_id)?;
+    let side = Side::from(state.side);
+    let request = OrderBookSummaryRequest::builder()
+        .token_id(token_id)
+        .build();
+    let market = client.order_book(&request).await?.market;
+    let ws = endpoints::clob_ws().authenticate(client.credentials().clone(), client.address())?;
+    let mut stream = Box::pin(ws.subscribe_user_events(vec![market])?);
+    let mut streaming = true;
+    print_iceberg_start(&state, state_path, output);
+
+    let mut poller = Poller::new(poll);
+    let mut failures = 0;
+    let report = |state: &IcebergState, event: &IcebergEvent| -> Result<()> {
+        save_state(state_path, state)?;
+        print_iceberg_event(state, event, output)
+    };
+    loop {
+        if state.live.is_none() {
+            if state.is_complete() || failures >= MAX_POST_FAILURES {
+                break;
+            }
+            let size = state.next_clip_size();
+            match rest(&client, &signer, token_id, side, state.price, size).await {
+                Ok(fill) => {
+                    failures = 0;
+                    let clip = Clip {
+                        order_id: fill.order_id,
+                        size,
+                        matched: fill.shares.min(size),
+                    };
+                    state.live = Some(clip.clone());
+                    report(&state, &IcebergEvent::Posted(clip))?;
+                }
+                Err(e) => {
+                    failures += 1;
+                    report(&state, &IcebergEvent::Failed(format!("{e:#}")))?;
+                }
+            }
+        }
+
+        let (order_id, matched, on_book) = tokio::select! {
+            msg = stream.next(), if streaming => match msg {
+                Some(Ok(WsMessage::Order(order))) => (
+                    order.id,
+                    order.size_matched.unwrap_or_default(),
+                    !matches!(order.msg_type, Some(OrderMessageType::Cancellation)),
+                ),
+                Some(Ok(_)) => continue,
+                Some(Err(e)) => {
+                    eprintln!("User channel error: {e}");
+                    continue;
+                }
+                None => {
+                    streaming = false;
+                    eprintln!("User channel closed; polling the live clip instead.");
+                    continue;
+                }
+            },
+            running = poller.tick() => {
+                if !running {
+                    break;
+                }
+                // Nothing to check; the next pass retries posting the clip.
+                let Some(live) = &state.live else { continue };
+                match client.order(&live.order_id).await {
+                    Ok(order) => (
+                        order.id,
+                        order.size_matched,
+                        matches!(order.status, OrderStatusType::Live),
+                    ),
+                    Err(e) => {
+                        eprintln!("Failed to check order {}: {e}", live.order_id);
+                        continue;
+                    }
+                }
+            }
+        };
+        if let Some(event) = state.update(&order_id, matched, on_book) {
+            report(&state, &event)?;
+            if matches!(event, IcebergEvent::Closed(_)) {
+                break;
+            }
+        }
+    }
+
+    // Never leave a clip behind on the book once the iceberg stops.
+    if let Some(live) = state.live.clone() {
+        if let Err(e) = client.cancel_order(&live.order_id).await {
+            eprintln!("Failed to cancel order {}: {e}", live.order_id);
+        }
+        let matched = match client.order(&live.order_id).await {
+            Ok(order) => order.size_matched,
+            Err(_) => live.matched,
+        };
+        if let Some(event) = state.update(&live.order_id, matched, false) {
+            report(&state, &event)?;
+        }
+    }
+    print_iceberg_finished(&state, state_path, output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn iceberg(live: Option<Clip>) -> IcebergState {
+        IcebergState {
+            token_id: "1".into(),
+            side: CliSide::Sell,
+            price: dec!(0.62),
+            display: dec!(50),
+            total: dec!(120),
+            retired: dec!(100),
+            live,
+        }
+    }
+
+    fn clip(matched: Decimal) -> Clip {
+        Clip {
+            order_id: "0xa".into(),
+            size: dec!(20),
+            matched,
+        }
+    }
+
+    #[test]
+    fn the_last_clip_only_shows_what_is_left() {
+        assert_eq!(iceberg(None).next_clip_size(), dec!(20));
+        let mut early = iceberg(None);
+        early.retired = Decimal::ZERO;
+        assert_eq!(early.next_clip_size(), dec!(50));
+    }
+
+    #[test]
+    fn partial_fills_keep_the_clip_and_full_fills_retire_it() {
+        let mut state = iceberg(Some(clip(dec!(0))));
+        assert_eq!(
+            state.update("0xa", dec!(5), true),
+            Some(IcebergEvent::Matched(clip(dec!(5))))
+        );
+        assert_eq!(state.update("0xa", dec!(5), true), None);
+        assert_eq!(state.filled(), dec!(105));
+        assert_eq!(
+            state.update("0xa", dec!(20), true),
+            Some(IcebergEvent::Filled(clip(dec!(20))))
+        );
+        assert!(state.live.is_none());
+        assert!(state.is_complete());
+    }
+
+    #[test]
+    fn a_clip_that_leaves_the_book_unfilled_is_closed() {
+        let mut state = iceberg(Some(clip(dec!(3))));
+        assert_eq!(state.update("0xother", dec!(20), false), None);
+        assert_eq!(
+            state.update("0xa", dec!(3), false),
+            Some(IcebergEvent::Closed(clip(dec!(3))))
+        );
+        assert_eq!(state.retired, dec!(103));
+    }
+}
@@ -0,0 +1,157 @@
+//! Managed orders the CLOB has no native type for, such as TWAP, icebergs,
+//! stops, trailing stops, and brackets, plus a guard that cancels everything
+//! when its heartbeats stop. Each runs in the foreground, places and tracks
+//! child orders, and reports every decision. Those with progress to lose keep a state
+//! file so a stopped run can be resumed.
+
+pub mod bracket;
+pub mod guard;
+pub mod iceberg;
+pub mod stop;
+pub mod trailing;
+pub mod twap;
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use chrono::Utc;
+use polymarket_client_sdk::auth::Normal;
+use polymarket_client_sdk::auth::state::Authenticated;
+use polymarket_client_sdk::clob;
+use polymarket_client_sdk::clob::types::response::PostOrderResponse;
+use polymarket_client_sdk::clob::types::{Amount, OrderType, Side};
+use polymarket_client_sdk::types::{Decimal, U256};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::auth::{self, WalletSigner};
+use crate::commands::clob::place_limit_order;
+use crate::config;
+use crate::output::write_atomic;
+
+/// What a child order actually traded.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Fill {
+    pub order_id: String,
+    pub shares: Decimal,
+    pub usdc: Decimal,
+}
+
+impl Fill {
+    /// Buys make USDC and take shares; sells make shares and take USDC.
+    fn from_response(side: Side, response: &PostOrderResponse) -> Result<Self> {
+        if !response.success {
+            bail!(
+                "Order rejected: {}",
+                response.error_msg.as_deref().unwrap_or("no reason given")
+            );
+        }
+        let (shares, usdc) = match side {
+            Side::Buy => (response.taking_amount, response.making_amount),
+            _ => (response.making_amount, response.taking_amount),
+        };
+        Ok(Self {
+            order_id: response.order_id.clone(),
+            shares,
+            usdc,
+        })
+    }
+}
+
+/// Takes up to `shares` from the book right away (fill-and-kill), paying no
+/// worse than `limit` when given. Whatever doesn't fill is canceled, so
+/// nothing is left resting.
+pub async fn take(
+    client: &clob::Client<Authenticated<Normal>>,
+    signer: &WalletSigner,
+    token_id: U256,
+    side: Side,
+    shares: Decimal,
+    limit: Option<Decimal>,
+) -> Result<Fill> {
+    let mut builder = client
+        .market_order()
+        .token_id(token_id)
+        .side(side)
+        .amount(Amount::shares(shares)?)
+        .order_type(OrderType::FAK);
+    if let Some(limit) = limit {
+        builder = builder.price(limit);
+    }
+    let order = auth::sign_order(client, signer, builder.build().await?).await?;
+    let response = client.post_order(order).await?;
+    Fill::from_response(side, &response)
+}
+
+/// Rests a GTC limit order on the book. The fill covers whatever matched the
+/// moment it was posted.
+pub async fn rest(
+    client: &clob::Client<Authenticated<Normal>>,
+    signer: &WalletSigner,
+    token_id: U256,
+    side: Side,
+    price: Decimal,
+    shares: Decimal,
+) -> Result<Fill> {
+    let response = place_limit_order(
+        client,
+        signer,
+        token_id,
+        side,
+        price,
+        shares,
+        OrderType::GTC,
+        false,
+        None,
+    )
+    .await?;
+    Fill::from_response(side, &response)
+}
+
+/// A fresh state file for a new run of `kind`, e.g. `twap-20250601T120000.json`.
+pub fn new_state_path(kind: &str) -> Result<PathBuf> {
+    let dir = config::algo_state_dir()?;
+    std::fs::create_dir_all(&dir).context("Failed to create the algo state directory")?;
+    Ok(dir.join(format!(
+        "{kind}-{}.json",
+        Utc::now().format("%Y%m%dT%H%M%S")
+    )))
+}
+
+pub fn load_state<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read state file {}", path.display()))?;
+    serde_json::from_str(&contents)
+        .with_context(|| format!("{} is not a valid state file", path.display()))
+}
+
+pub fn save_state(path: &Path, state: &impl Serialize) -> Result<()> {
+    write_atomic(path, &serde_json::to_string_pretty(state)?)
+        .with_context(|| format!("Failed to write state file {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use polymarket_client_sdk::clob::types::OrderStatusType;
+    use rust_decimal_macros::dec;
+
+    fn response(success: bool, making: Decimal, taking: Decimal) -> PostOrderResponse {
+        PostOrderResponse::builder()
+            .making_amount(making)
+            .taking_amount(taking)
+            .order_id("0x1")
+            .status(OrderStatusType::Matched)
+            .success(success)
+            .build()
+    }
+
+    #[test]
+    fn fill_reads_shares_from_the_side_that_received_them() {
+        let buy = Fill::from_response(Side::Buy, &response(true, dec!(4.5), dec!(10))).unwrap();
+        assert_eq!((buy.shares, buy.usdc), (dec!(10), dec!(4.5)));
+        let sell = Fill::from_response(Side::Sell, &response(true, dec!(10), dec!(5.5))).unwrap();
+        assert_eq!((sell.shares, sell.usdc), (dec!(10), dec!(5.5)));
+        assert!(Fill::from_response(Side::Buy, &response(false, dec!(0), dec!(0))).is_err());
+    }
+}
@@ -0,0 +1,217 @@
+//! `clob stop`: the CLOB has no stop orders, so this watches the book and
+//! fires an order once the price crosses the trigger. A check that fails is
+//! logged and tried again at the next interval; once triggered, the stop
+//! keeps firing until its size is placed.
+
+use std::time::Duration;
+
+use anyhow::{Result, bail};
+use polymarket_client_sdk::auth::Normal;
+use polymarket_client_sdk::auth::state::Authenticated;
+use polymarket_client_sdk::clob;
+use polymarket_client_sdk::clob::types::Side;
+use polymarket_client_sdk::clob::types::request::OrderBookSummaryRequest;
+use polymarket_client_sdk::types::{Decimal, U256};
+
+use super::{Fill, rest, take};
+use crate::auth::WalletSigner;
+use crate::commands::clob::CliSide;
+use crate::output::OutputFormat;
+use crate::output::algo::{print_stop_decision, print_stop_finished, print_stop_start};
+use crate::poll::Poller;
+
+/// Consecutive failures to place the order, once triggered, before giving up.
+pub const MAX_FIRE_FAILURES: u32 = 5;
+
+pub struct StopOrder {
+    pub token_id: U256,
+    pub side: CliSide,
+    pub trigger: Decimal,
+    /// Shares to trade once triggered
+    pub size: Decimal,
+    /// Rest a limit order at this price instead of taking from the book
+    pub limit: Option<Decimal>,
+    pub interval: Duration,
+}
+
+/// How much of a triggered stop made it onto the book.
+pub struct Placed {
+    pub shares: Decimal,
+    /// Stopped after too many failed attempts rather than on Ctrl+C
+    pub gave_up: bool,
+}
+
+/// What a check saw and what it did about it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Decision {
+    /// Not crossed; the price the stop watches, if the book had one
+    Waiting(Option<Decimal>),
+    Triggered(Decimal),
+    Fired(Fill),
+    FireFailed(String),
+    CheckFailed(String),
+}
+
+impl StopOrder {
+    /// A sell stop watches the best bid falling to the trigger; a buy stop
+    /// watches the best ask rising to it. Those are the prices the order
+    /// would trade at.
+    pub fn crossed(&self, best_bid: Option<Decimal>, best_ask: Option<Decimal>) -> Decision {
+        match self.side {
+            CliSide::Sell => match best_bid {
+                Some(bid) if bid <= self.trigger => Decision::Triggered(bid),
+                bid => Decision::Waiting(bid),
+            },
+            CliSide::Buy => match best_ask {
+                Some(ask) if ask >= self.trigger => Decision::Triggered(ask),
+                ask => Decision::Waiting(ask),
+            },
+        }
+    }
+
+    async fn check(&self, client: &clob::Client<Authenticated<Normal>>) -> Decision {
+        let request = OrderBookSummaryRequest::builder()
+            .token_id(self.token_id)
+            .build();
+        match client.order_book(&request).await {
+            Ok(book) => self.crossed(
+                book.bids.iter().map(|l| l.price).max(),
+                book.asks.iter().map(|l| l.price).min(),
+            ),
+            Err(e) => Decision::CheckFailed(format!("{e:#}")),
+        }
+    }
+
+    /// Checks the book once and reports the decision; true once triggered.
+    pub async fn check_and_report(
+        &self,
+        client: &clob::Client<Authenticated<Normal>>,
+        output: &OutputFormat,
+    ) -> Result<bool> {
+        let decision = self.check(client).await;
+        print_stop_decision(self, &decision, output)?;
+        Ok(matches!(decision, Decision::Triggered(_)))
+    }
+
+    async fn fire(
+        &self,
+        client: &clob::Client<Authenticated<Normal>>,
+        signer: &WalletSigner,
+        shares: Decimal,
+    ) -> Result<Fill> {
+        let side = Side::from(self.side);
+        match self.limit {
+            Some(price) => rest(client, signer, self.token_id, side, price, shares).await,
+            None => take(client, signer, self.token_id, side, shares, None).await,
+        }
+    }
+
+    /// Fires right away, then at each tick until `shares` are placed. Market
+    /// orders can fill partly, so each attempt takes what is still left.
+    pub async fn fire_until_placed(
+        &self,
+        client: &clob::Client<Authenticated<Normal>>,
+        signer: &WalletSigner,
+        shares: Decimal,
+        poller: &mut Poller,
+        output: &OutputFormat,
+    ) -> Result<Placed> {
+        let mut placed = Decimal::ZERO;
+        let mut failures = 0;
+        loop {
+            let decision = match self.fire(client, signer, shares - placed).await {
+                Ok(fill) => {
+                    failures = 0;
+                    // A resting limit order places the whole size, filled or not.
+                    placed = match self.limit {
+                        Some(_) => shares,
+                        None => placed + fill.shares,
+                    };
+                    Decision::Fired(fill)
+                }
+                Err(e) => {
+                    failures += 1;
+                    Decision::FireFailed(format!("{e:#}"))
+                }
+            };
+            print_stop_decision(self, &decision, output)?;
+            let gave_up = failures >= MAX_FIRE_FAILURES;
+            if placed >= shares || gave_up || !poller.tick().await {
+                return Ok(Placed {
+                    shares: placed,
+                    gave_up,
+                });
+            }
+        }
+    }
+}
+
+pub async fn run(
+    client: clob::Client<Authenticated<Normal>>,
+    signer: WalletSigner,
+    stop: StopOrder,
+    output: &OutputFormat,
+) -> Result<()> {
+    print_stop_start(&stop, output);
+    let mut poller = Poller::new(stop.interval);
+    let mut triggered = false;
+    while !triggered && poller.tick().await {
+        triggered = stop.check_and_report(&client, output).await?;
+    }
+    if !triggered {
+        return print_stop_finished(&stop, false, Decimal::ZERO, output);
+    }
+
+    let placed = stop
+        .fire_until_placed(&client, &signer, stop.size, &mut poller, output)
+        .await?;
+    print_stop_finished(&stop, true, placed.shares, output)?;
+    if placed.gave_up {
+        bail!("Gave up after {MAX_FIRE_FAILURES} failed attempts to place the stop order");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn stop(side: CliSide) -> StopOrder {
+        StopOrder {
+            token_id: U256::from(1),
+            side,
+            trigger: dec!(0.35),
+            size: dec!(400),
+            limit: None,
+            interval: Duration::from_secs(5),
+        }
+    }
+
+    #[test]
+    fn sell_stops_trigger_when_the_bid_falls_to_the_trigger() {
+        let sell = stop(CliSide::Sell);
+        assert_eq!(
+            sell.crossed(Some(dec!(0.36)), Some(dec!(0.30))),
+            Decision::Waiting(Some(dec!(0.36)))
+        );
+        assert_eq!(
+            sell.crossed(Some(dec!(0.35)), None),
+            Decision::Triggered(dec!(0.35))
+        );
+        assert_eq!(sell.crossed(None, Some(dec!(0.2))), Decision::Waiting(None));
+    }
+
+    #[test]
+    fn buy_stops_trigger_when_the_ask_rises_to_the_trigger() {
+        let buy = stop(CliSide::Buy);
+        assert_eq!(
+            buy.crossed(Some(dec!(0.40)), Some(dec!(0.34))),
+            Decision::Waiting(Some(dec!(0.34)))
+        );
+        assert_eq!(
+            buy.crossed(None, Some(dec!(0.37))),
+            Decision::Triggered(dec!(0.37))
+        );
+    }
+}
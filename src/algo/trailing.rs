@@ -0,0 +1,203 @@
+//! `clob trailing-stop`: a stop whose trigger follows the market at a fixed
+//! distance as it moves favorably, and never moves back. Prices come from
+//! the market channel, with a slower check of the book in case the stream
+//! misses an update or drops.
+
+use anyhow::{Result, bail};
+use futures::StreamExt as _;
+use polymarket_client_sdk::auth::Normal;
+use polymarket_client_sdk::auth::state::Authenticated;
+use polymarket_client_sdk::clob;
+use polymarket_client_sdk::clob::types::request::OrderBookSummaryRequest;
+use polymarket_client_sdk::types::Decimal;
+
+use super::stop::{Decision, MAX_FIRE_FAILURES, StopOrder};
+use crate::auth::WalletSigner;
+use crate::commands::clob::CliSide;
+use crate::endpoints;
+use crate::output::OutputFormat;
+use crate::output::algo::{print_stop_finished, print_trailing_event, print_trailing_start};
+use crate::poll::Poller;
+
+pub struct TrailingStop {
+    /// The stop that fires; its trigger is moved as the market moves
+    pub stop: StopOrder,
+    /// Distance between the best price seen and the trigger
+    pub trail: Decimal,
+    /// Best price seen: the highest bid for a sell, the lowest ask for a buy
+    pub extreme: Option<Decimal>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TrailEvent {
+    /// A new best price moved the trigger
+    Ratcheted {
+        price: Decimal,
+        trigger: Decimal,
+    },
+    Triggered(Decimal),
+}
+
+impl TrailingStop {
+    /// Takes the latest quote, ratcheting the trigger on a new best price or
+    /// reporting a retrace to it.
+    fn observe(
+        &mut self,
+        best_bid: Option<Decimal>,
+        best_ask: Option<Decimal>,
+    ) -> Option<TrailEvent> {
+        let side = self.stop.side;
+        let price = match side {
+            CliSide::Sell => best_bid?,
+            CliSide::Buy => best_ask?,
+        };
+        let favorable = self.extreme.is_none_or(|extreme| match side {
+            CliSide::Sell => price > extreme,
+            CliSide::Buy => price < extreme,
+        });
+        if favorable {
+            self.extreme = Some(price);
+            self.stop.trigger = match side {
+                CliSide::Sell => price - self.trail,
+                CliSide::Buy => price + self.trail,
+            };
+            return Some(TrailEvent::Ratcheted {
+                price,
+                trigger: self.stop.trigger,
+            });
+        }
+        match self.stop.crossed(best_bid, best_ask) {
+            Decision::Triggered(price) => Some(TrailEvent::Triggered(price)),
+            _ => None,
+        }
+    }
+}
+
+pub async fn run(
+    client: clob::Client<Authenticated<Normal>>,
+    signer: WalletSigner,
+    mut trailing: TrailingStop,
+    output: &OutputFormat,
+) -> Result<()> {
+    let token_id = trailing.stop.token_id;
+    print_trailing_start(&trailing, output);
+    let ws = endpoints::clob_ws();
+    let mut stream = Box::pin(ws.subscribe_prices(vec![token_id])?);
+    let mut streaming = true;
+    let (mut best_bid, mut best_ask) = (None, None);
+
+    let mut poller = Poller::new(trailing.stop.interval);
+    let mut triggered = false;
+    while !triggered {
+        tokio::select! {
+            msg = stream.next(), if streaming => match msg {
+                Some(Ok(change)) => {
+                    for entry in change.price_changes.iter().filter(|e| e.asset_id == token_id) {
+                        best_bid = entry.best_bid.or(best_bid);
+                        best_ask = entry.best_ask.or(best_ask);
+                    }
+                }
+                Some(Err(e)) => {
+                    eprintln!("Stream error: {e}");
+                    continue;
+                }
+                None => {
+                    streaming = false;
+                    eprintln!("Price stream closed; checking the book instead.");
+                    continue;
+                }
+            },
+            running = poller.tick() => {
+                if !running {
+                    break;
+                }
+                let request = OrderBookSummaryRequest::builder().token_id(token_id).build();
+                match client.order_book(&request).await {
+                    Ok(book) => {
+                        best_bid = book.bids.iter().map(|l| l.price).max();
+                        best_ask = book.asks.iter().map(|l| l.price).min();
+                    }
+                    Err(e) => {
+                        eprintln!("Failed to check the book: {e}");
+                        continue;
+                    }
+                }
+            }
+        }
+        if let Some(event) = trailing.observe(best_bid, best_ask) {
+            print_trailing_event(&trailing, &event, output)?;
+            triggered = matches!(event, TrailEvent::Triggered(_));
+        }
+    }
+    let stop = &trailing.stop;
+    if !triggered {
+        return print_stop_finished(stop, false, Decimal::ZERO, output);
+    }
+
+    let placed = stop
+        .fire_until_placed(&client, &signer, stop.size, &mut poller, output)
+        .await?;
+    print_stop_finished(stop, true, placed.shares, output)?;
+    if placed.gave_up {
+        bail!("Gave up after {MAX_FIRE_FAILURES} failed attempts to place the stop order");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use polymarket_client_sdk::types::U256;
+    use rust_decimal_macros::dec;
+
+    fn trailing(side: CliSide) -> TrailingStop {
+        TrailingStop {
+            stop: StopOrder {
+                token_id: U256::from(1),
+                side,
+                trigger: Decimal::ZERO,
+                size: dec!(300),
+                limit: None,
+                interval: Duration::from_secs(30),
+            },
+            trail: dec!(0.05),
+            extreme: None,
+        }
+    }
+
+    #[test]
+    fn sell_trigger_follows_the_bid_up_and_fires_on_a_retrace() {
+        let mut sell = trailing(CliSide::Sell);
+        assert_eq!(
+            sell.observe(Some(dec!(0.50)), None),
+            Some(TrailEvent::Ratcheted {
+                price: dec!(0.50),
+                trigger: dec!(0.45)
+            })
+        );
+        sell.observe(Some(dec!(0.58)), None);
+        assert_eq!(sell.stop.trigger, dec!(0.53));
+        // Falling back without reaching the trigger leaves it where it was.
+        assert_eq!(sell.observe(Some(dec!(0.55)), None), None);
+        assert_eq!(sell.stop.trigger, dec!(0.53));
+        assert_eq!(
+            sell.observe(Some(dec!(0.53)), None),
+            Some(TrailEvent::Triggered(dec!(0.53)))
+        );
+    }
+
+    #[test]
+    fn buy_trigger_follows_the_ask_down() {
+        let mut buy = trailing(CliSide::Buy);
+        buy.observe(None, Some(dec!(0.40)));
+        buy.observe(None, Some(dec!(0.32)));
+        assert_eq!(buy.stop.trigger, dec!(0.37));
+        assert_eq!(buy.observe(Some(dec!(0.9)), None), None);
+        assert_eq!(
+            buy.observe(None, Some(dec!(0.38))),
+            Some(TrailEvent::Triggered(dec!(0.38)))
+        );
+    }
+}
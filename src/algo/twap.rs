@@ -0,0 +1,212 @@
+//! `clob twap`: works a parent order by taking an equal share of what's left
+//! at a fixed interval, so it fills over the chosen duration instead of all
+//! at once. Shortfalls from thin books roll into later slices.
+
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use polymarket_client_sdk::auth::Normal;
+use polymarket_client_sdk::auth::state::Authenticated;
+use polymarket_client_sdk::clob;
+use polymarket_client_sdk::clob::types::Side;
+use polymarket_client_sdk::types::{Decimal, U256};
+use rust_decimal::RoundingStrategy;
+use serde::{Deserialize, Serialize};
+
+use super::{Fill, save_state, take};
+use crate::auth::WalletSigner;
+use crate::commands::clob::{CliSide, parse_token_id, resolve_limit_price};
+use crate::output::OutputFormat;
+use crate::output::algo::{print_twap_finished, print_twap_slice, print_twap_start};
+use crate::poll::Poller;
+use crate::price_expr::PriceExpr;
+
+/// A child order, successful or not.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Slice {
+    pub number: u32,
+    pub at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    pub shares: Decimal,
+    pub usdc: Decimal,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Everything needed to resume a run; rewritten after every slice.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TwapState {
+    pub token_id: String,
+    pub side: CliSide,
+    /// Shares to trade in total
+    pub total: Decimal,
+    pub slices: u32,
+    pub interval_secs: u64,
+    /// Worst price each slice accepts, as a `--price` expression
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    #[serde(default)]
+    pub history: Vec<Slice>,
+}
+
+impl TwapState {
+    pub fn filled(&self) -> Decimal {
+        self.history.iter().map(|s| s.shares).sum()
+    }
+
+    pub fn usdc(&self) -> Decimal {
+        self.history.iter().map(|s| s.usdc).sum()
+    }
+
+    pub fn slices_done(&self) -> u32 {
+        u32::try_from(self.history.len()).unwrap_or(u32::MAX)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.slices_done() >= self.slices || self.filled() >= self.total
+    }
+
+    /// An even share of what's left over the remaining slices; the last
+    /// slice takes the rest.
+    fn next_size(&self) -> Decimal {
+        let remaining = (self.total - self.filled()).max(Decimal::ZERO);
+        let slices_left = self.slices.saturating_sub(self.slices_done()).max(1);
+        if slices_left == 1 {
+            return remaining;
+        }
+        (remaining / Decimal::from(slices_left)).round_dp_with_strategy(2, RoundingStrategy::ToZero)
+    }
+}
+
+/// Resolves the slice's price limit against the live book, then takes.
+async fn place_slice(
+    client: &clob::Client<Authenticated<Normal>>,
+    signer: &WalletSigner,
+    token_id: U256,
+    side: CliSide,
+    shares: Decimal,
+    price: Option<PriceExpr>,
+) -> Result<Fill> {
+    let limit = match price {
+        Some(price) => Some(resolve_limit_price(client, token_id, side, price).await?),
+        None => None,
+    };
+    take(client, signer, token_id, Side::from(side), shares, limit).await
+}
+
+pub async fn run(
+    client: clob::Client<Authenticated<Normal>>,
+    signer: WalletSigner,
+    mut state: TwapState,
+    state_path: &Path,
+    output: &OutputFormat,
+) -> Result<()> {
+    let token_id = parse_token_id(&state.token_id)?;
+    let price: Option<PriceExpr> = state.price.as_deref().map(str::parse).transpose()?;
+    print_twap_start(&state, state_path, output);
+
+    let mut poller = Poller::new(Duration::from_secs(state.interval_secs.max(1)));
+    while !state.is_complete() && poller.tick().await {
+        let shares = state.next_size();
+        let number = state.slices_done() + 1;
+        let result = if shares.is_zero() {
+            Ok(Fill::default())
+        } else {
+            place_slice(&client, &signer, token_id, state.side, shares, price).await
+        };
+        let slice = match result {
+            Ok(fill) => Slice {
+                number,
+                at: Utc::now(),
+                order_id: Some(fill.order_id).filter(|id| !id.is_empty()),
+                shares: fill.shares,
+                usdc: fill.usdc,
+                error: None,
+            },
+            Err(e) => Slice {
+                number,
+                at: Utc::now(),
+                order_id: None,
+                shares: Decimal::ZERO,
+                usdc: Decimal::ZERO,
+                error: Some(format!("{e:#}")),
+            },
+        };
+        state.history.push(slice);
+        save_state(state_path, &state)?;
+        if let Some(slice) = state.history.last() {
+            print_twap_slice(&state, slice, output)?;
+        }
+    }
+
+    print_twap_finished(&state, state_path, output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn state(total: Decimal, slices: u32) -> TwapState {
+        TwapState {
+            token_id: "1".into(),
+            side: CliSide::Buy,
+            total,
+            slices,
+            interval_secs: 60,
+            price: None,
+            history: Vec::new(),
+        }
+    }
+
+    fn filled(number: u32, shares: Decimal) -> Slice {
+        Slice {
+            number,
+            at: DateTime::UNIX_EPOCH,
+            order_id: None,
+            shares,
+            usdc: shares / Decimal::TWO,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn slices_split_the_remainder_evenly_and_the_last_takes_the_rest() {
+        let mut twap = state(dec!(100), 3);
+        assert_eq!(twap.next_size(), dec!(33.33));
+        twap.history.push(filled(1, dec!(33.33)));
+        assert_eq!(twap.next_size(), dec!(33.33));
+        twap.history.push(filled(2, dec!(33.33)));
+        assert_eq!(twap.next_size(), dec!(33.34));
+    }
+
+    #[test]
+    fn shortfalls_roll_into_later_slices() {
+        let mut twap = state(dec!(100), 4);
+        twap.history.push(filled(1, dec!(10)));
+        assert_eq!(twap.next_size(), dec!(30));
+        assert!(!twap.is_complete());
+    }
+
+    #[test]
+    fn complete_when_filled_or_out_of_slices() {
+        let mut twap = state(dec!(10), 5);
+        twap.history.push(filled(1, dec!(10)));
+        assert!(twap.is_complete());
+        let mut twap = state(dec!(10), 1);
+        twap.history.push(filled(1, dec!(2)));
+        assert!(twap.is_complete());
+    }
+
+    #[test]
+    fn state_round_trips_through_json() {
+        let mut twap = state(dec!(100), 2);
+        twap.price = Some("mid+1t".into());
+        twap.history.push(filled(1, dec!(50)));
+        let json = serde_json::to_string(&twap).unwrap();
+        assert_eq!(serde_json::from_str::<TwapState>(&json).unwrap(), twap);
+    }
+}
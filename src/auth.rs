@@ -1,15 +1,154 @@
 use std::str::FromStr;
+use std::sync::Mutex;
+#[cfg(feature = "ledger")]
+use std::sync::OnceLock;
+use std::time::Duration;
 
-use alloy::providers::ProviderBuilder;
-use anyhow::{Context, Result};
+use alloy::network::EthereumWallet;
+use alloy::primitives::{B256, Signature};
+use alloy::providers::{Provider as _, ProviderBuilder};
+use alloy::rpc::client::RpcClient;
+#[cfg(feature = "ledger")]
+use alloy::signers::ledger::LedgerSigner;
+use alloy::signers::local::PrivateKeySigner;
+use alloy::transports::http::{Http, reqwest};
+use alloy::transports::layers::FallbackService;
+use anyhow::{Context, Result, anyhow, bail};
+use async_trait::async_trait;
 use polymarket_client_sdk::auth::state::Authenticated;
-use polymarket_client_sdk::auth::{LocalSigner, Normal, Signer as _};
-use polymarket_client_sdk::clob::types::SignatureType;
-use polymarket_client_sdk::{POLYGON, clob};
+use polymarket_client_sdk::auth::{
+    Credentials, ExposeSecret as _, LocalSigner, Normal, Signer, Uuid,
+};
+use polymarket_client_sdk::clob::types::{SignableOrder, SignatureType, SignedOrder};
+use polymarket_client_sdk::types::{Address, ChainId};
+use polymarket_client_sdk::{clob, derive_proxy_wallet, derive_safe_wallet};
 
 use crate::config;
+use crate::endpoints;
 
 pub const RPC_URL: &str = "https://polygon.drpc.org";
+pub const AMOY_RPC_URL: &str = "https://rpc-amoy.polygon.technology";
+
+/// The public RPC endpoint of `chain`.
+pub fn public_rpc_url(chain: config::Chain) -> &'static str {
+    match chain {
+        config::Chain::Polygon => RPC_URL,
+        config::Chain::Amoy => AMOY_RPC_URL,
+    }
+}
+
+/// How long an RPC endpoint gets to answer the health check.
+const RPC_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long an RPC endpoint gets to answer a request before it is retried
+/// on the next one.
+const RPC_REQUEST_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// The endpoints that passed the health check for each list of candidates,
+/// so it runs once per process.
+static HEALTHY_RPC: Mutex<Option<(Vec<String>, Vec<String>)>> = Mutex::new(None);
+
+/// The `configured` RPC endpoints in order, with `chain`'s public one as the
+/// last resort.
+fn rpc_urls(mut configured: Vec<String>, chain: config::Chain) -> Vec<String> {
+    let public = public_rpc_url(chain);
+    if !configured.iter().any(|u| u == public) {
+        configured.push(public.to_string());
+    }
+    configured
+}
+
+/// Checks that `url` answers within [`RPC_TIMEOUT`] and serves the selected
+/// chain.
+async fn check_rpc(url: &str) -> Result<()> {
+    let provider = ProviderBuilder::new()
+        .connect(url)
+        .await
+        .context("cannot connect")?;
+    let chain_id = tokio::time::timeout(RPC_TIMEOUT, provider.get_chain_id())
+        .await
+        .map_err(|_| anyhow!("timed out after {}s", RPC_TIMEOUT.as_secs()))?
+        .context("request failed")?;
+    let chain = config::chain();
+    if chain_id != chain.id() {
+        bail!("serves chain {chain_id}, not {chain:?}");
+    }
+    Ok(())
+}
+
+/// The configured RPC endpoints that pass [`check_rpc`], in order, warning
+/// on stderr about the ones left out. With only the public endpoint there
+/// is nothing to fail over to, so it is used unchecked.
+async fn healthy_rpc_urls() -> Result<Vec<String>> {
+    let urls = rpc_urls(config::rpc_urls(), config::chain());
+    if urls.len() == 1 {
+        return Ok(urls);
+    }
+    if let Some((checked, healthy)) = HEALTHY_RPC
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        && *checked == urls
+    {
+        return Ok(healthy.clone());
+    }
+
+    let checks = futures::future::join_all(urls.iter().map(|url| check_rpc(url))).await;
+    let mut healthy = Vec::new();
+    let mut failures = Vec::new();
+    for (url, check) in urls.iter().zip(checks) {
+        match check {
+            Ok(()) => healthy.push(url.clone()),
+            Err(e) if e.chain().count() > 1 => {
+                failures.push(format!("RPC {url} {e}: {}", e.root_cause()));
+            }
+            Err(e) => failures.push(format!("RPC {url} {e}")),
+        }
+    }
+    if healthy.is_empty() {
+        bail!(
+            "No Polygon RPC endpoint is reachable:\n  {}\nSet working ones with --rpc-url or `polymarket config set rpc_url`",
+            failures.join("\n  ")
+        );
+    }
+    if !failures.is_empty() {
+        eprintln!(
+            "Warning: {}; using {}",
+            failures.join("; "),
+            healthy.join(", ")
+        );
+    }
+    *HEALTHY_RPC.lock().unwrap_or_else(|e| e.into_inner()) = Some((urls, healthy.clone()));
+    Ok(healthy)
+}
+
+/// An RPC client over the healthy endpoints. A request that fails or times
+/// out on one endpoint is answered by another, so long-running commands
+/// survive an endpoint going down part way.
+async fn rpc_client() -> Result<RpcClient> {
+    let http = reqwest::Client::builder()
+        .timeout(RPC_REQUEST_TIMEOUT)
+        .build()
+        .context("Failed to set up the RPC client")?;
+    let transports = healthy_rpc_urls()
+        .await?
+        .iter()
+        .map(|url| {
+            let url = url
+                .parse()
+                .with_context(|| format!("Invalid RPC URL {url}"))?;
+            Ok(Http::with_client(http.clone(), url))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    // Reads race the endpoints; a transaction goes to one at a time so a
+    // failover can't report it as already known.
+    let sequential = ["eth_sendRawTransaction", "eth_sendRawTransactionSync"]
+        .map(String::from)
+        .into();
+    let count = transports.len();
+    let fallback = FallbackService::new_with_sequential_methods(transports, count, sequential);
+    Ok(RpcClient::new(fallback, false))
+}
 
 fn parse_signature_type(s: &str) -> SignatureType {
     match s {
@@ -19,14 +158,166 @@ fn parse_signature_type(s: &str) -> SignatureType {
     }
 }
 
-pub fn resolve_signer(
-    private_key: Option<&str>,
-) -> Result<impl polymarket_client_sdk::auth::Signer> {
-    let (key, _) = config::resolve_key(private_key);
-    let key = key.ok_or_else(|| anyhow::anyhow!("{}", config::NO_WALLET_MSG))?;
+/// The Ledger picked with `--signer`, connected once per process.
+#[cfg(feature = "ledger")]
+static LEDGER: OnceLock<LedgerSigner> = OnceLock::new();
+
+/// The derivation path in a `--signer` value: `ledger` for the first Ledger
+/// Live account, or `ledger:<path>`.
+fn parse_signer(spec: &str) -> Result<Option<&str>> {
+    match spec.split_once(':') {
+        None if spec == "ledger" => Ok(None),
+        Some(("ledger", path)) if !path.is_empty() => Ok(Some(path)),
+        _ => bail!("Unknown signer '{spec}'; use ledger or ledger:<derivation path>"),
+    }
+}
+
+/// Applies `--signer`: connects to the Ledger, whose address then stands in
+/// for the configured wallet's.
+pub async fn select_signer(spec: Option<&str>) -> Result<()> {
+    let Some(spec) = spec else {
+        return Ok(());
+    };
+    let path = parse_signer(spec)?;
+    config::select_hardware_signer(true);
+    #[cfg(feature = "ledger")]
+    {
+        let ledger = crate::ledger::connect(path, config::chain_id()).await?;
+        let _ = LEDGER.set(ledger);
+        Ok(())
+    }
+    #[cfg(not(feature = "ledger"))]
+    {
+        let _ = path;
+        bail!("This build has no Ledger support; rebuild it with `--features ledger`")
+    }
+}
+
+/// The wallet that signs: the configured key, or a Ledger picked with
+/// `--signer`.
+pub enum WalletSigner {
+    Local(PrivateKeySigner),
+    #[cfg(feature = "ledger")]
+    Ledger(&'static LedgerSigner),
+}
+
+#[async_trait]
+impl Signer for WalletSigner {
+    async fn sign_hash(&self, hash: &B256) -> alloy::signers::Result<Signature> {
+        match self {
+            Self::Local(signer) => signer.sign_hash(hash).await,
+            #[cfg(feature = "ledger")]
+            Self::Ledger(ledger) => ledger.sign_hash(hash).await,
+        }
+    }
+
+    fn address(&self) -> Address {
+        match self {
+            Self::Local(signer) => signer.address(),
+            #[cfg(feature = "ledger")]
+            Self::Ledger(ledger) => ledger.address(),
+        }
+    }
+
+    fn chain_id(&self) -> Option<ChainId> {
+        match self {
+            Self::Local(signer) => signer.chain_id(),
+            #[cfg(feature = "ledger")]
+            Self::Ledger(ledger) => ledger.chain_id(),
+        }
+    }
+
+    /// A Ledger keeps the chain it was connected for.
+    fn set_chain_id(&mut self, chain_id: Option<ChainId>) {
+        match self {
+            Self::Local(signer) => signer.set_chain_id(chain_id),
+            #[cfg(feature = "ledger")]
+            Self::Ledger(_) => {}
+        }
+    }
+}
+
+pub fn resolve_signer(private_key: Option<&str>) -> Result<WalletSigner> {
+    #[cfg(feature = "ledger")]
+    if let Some(ledger) = LEDGER.get() {
+        return Ok(WalletSigner::Ledger(ledger));
+    }
+    let key = config::signing_key(private_key)?;
     LocalSigner::from_str(&key)
         .context("Invalid private key")
-        .map(|s| s.with_chain_id(Some(POLYGON)))
+        .map(|s| WalletSigner::Local(s.with_chain_id(Some(config::chain_id()))))
+}
+
+/// Signs `order` for `client`. The SDK signs a bare hash, which a Ledger
+/// won't, so a Ledger signs the typed order instead.
+pub async fn sign_order(
+    client: &clob::Client<Authenticated<Normal>>,
+    signer: &WalletSigner,
+    order: SignableOrder,
+) -> Result<SignedOrder> {
+    match signer {
+        WalletSigner::Local(_) => Ok(client.sign(signer, order).await?),
+        #[cfg(feature = "ledger")]
+        WalletSigner::Ledger(ledger) => crate::ledger::sign_order(client, ledger, order).await,
+    }
+}
+
+/// New CLOB API credentials for `signer` under `nonce`.
+pub async fn create_api_key(signer: &WalletSigner, nonce: Option<u32>) -> Result<Credentials> {
+    match signer {
+        WalletSigner::Local(_) => Ok(endpoints::clob().create_api_key(signer, nonce).await?),
+        #[cfg(feature = "ledger")]
+        WalletSigner::Ledger(ledger) => crate::ledger::create_api_key(ledger, nonce).await,
+    }
+}
+
+/// The CLOB API credentials of `signer` under `nonce`, created if it has none.
+pub async fn create_or_derive_api_key(
+    signer: &WalletSigner,
+    nonce: Option<u32>,
+) -> Result<Credentials> {
+    match signer {
+        WalletSigner::Local(_) => Ok(endpoints::clob()
+            .create_or_derive_api_key(signer, nonce)
+            .await?),
+        #[cfg(feature = "ledger")]
+        WalletSigner::Ledger(ledger) => {
+            crate::ledger::create_or_derive_api_key(ledger, nonce).await
+        }
+    }
+}
+
+/// Address of the configured wallet, read without unlocking an encrypted key.
+pub fn resolve_address(private_key: Option<&str>) -> Result<Address> {
+    #[cfg(feature = "ledger")]
+    if let Some(ledger) = LEDGER.get() {
+        return Ok(ledger.address());
+    }
+    match config::resolve_address(private_key) {
+        (Some(address), _) => Ok(address),
+        (None, config::KeySource::None) => anyhow::bail!("{}", config::NO_WALLET_MSG),
+        (None, _) => anyhow::bail!("Invalid private key"),
+    }
+}
+
+/// Wallet that holds funds and positions on `chain` for the given signature
+/// type.
+fn funder_address(eoa: Address, sig_type: SignatureType, chain: ChainId) -> Address {
+    match sig_type {
+        SignatureType::Proxy => derive_proxy_wallet(eoa, chain),
+        SignatureType::GnosisSafe => derive_safe_wallet(eoa, chain),
+        _ => None,
+    }
+    .unwrap_or(eoa)
+}
+
+pub fn resolve_funder_address(
+    private_key: Option<&str>,
+    signature_type_flag: Option<&str>,
+) -> Result<Address> {
+    let eoa = resolve_address(private_key)?;
+    let sig_type = parse_signature_type(&config::resolve_signature_type(signature_type_flag));
+    Ok(funder_address(eoa, sig_type, config::chain_id()))
 }
 
 pub async fn authenticated_clob_client(
@@ -38,44 +329,197 @@ pub async fn authenticated_clob_client(
 }
 
 pub async fn authenticate_with_signer(
-    signer: &(impl polymarket_client_sdk::auth::Signer + Sync),
+    signer: &WalletSigner,
     signature_type_flag: Option<&str>,
 ) -> Result<clob::Client<Authenticated<Normal>>> {
     let sig_type = parse_signature_type(&config::resolve_signature_type(signature_type_flag));
+    if let Some(cached) = config::api_credentials(signer.address()).and_then(|c| to_credentials(&c))
+    {
+        let client = login(signer, sig_type, Some(cached)).await?;
+        // A key deleted or revoked elsewhere stays cached until the API
+        // refuses it; then it is dropped and a fresh one derived below.
+        match client.api_keys().await {
+            Err(e) if is_rejected(&e) => config::clear_api_credentials()?,
+            _ => return Ok(client),
+        }
+    }
 
-    clob::Client::default()
+    // The SDK's own login signs a bare hash, which a Ledger won't.
+    let credentials = match signer {
+        WalletSigner::Local(_) => None,
+        #[cfg(feature = "ledger")]
+        WalletSigner::Ledger(_) => Some(create_or_derive_api_key(signer, None).await?),
+    };
+    let client = login(signer, sig_type, credentials).await?;
+    // Caching only saves a round trip, so failing to is not an error.
+    let _ =
+        config::save_api_credentials(from_credentials(signer.address(), client.credentials(), 0));
+    Ok(client)
+}
+
+/// A CLOB client for `signer`, deriving its API key unless `credentials`
+/// are given.
+async fn login(
+    signer: &WalletSigner,
+    sig_type: SignatureType,
+    credentials: Option<Credentials>,
+) -> Result<clob::Client<Authenticated<Normal>>> {
+    let builder = endpoints::clob()
         .authentication_builder(signer)
-        .signature_type(sig_type)
-        .authenticate()
-        .await
-        .context("Failed to authenticate with Polymarket CLOB")
+        .signature_type(sig_type);
+    match credentials {
+        Some(credentials) => builder.credentials(credentials),
+        None => builder,
+    }
+    .authenticate()
+    .await
+    .context("Failed to authenticate with Polymarket CLOB")
+}
+
+/// Whether the CLOB API turned a request away for its credentials.
+fn is_rejected(error: &polymarket_client_sdk::error::Error) -> bool {
+    error
+        .downcast_ref::<polymarket_client_sdk::error::Status>()
+        .is_some_and(|status| matches!(status.status_code.as_u16(), 401 | 403))
+}
+
+/// SDK credentials from a cached entry, if it is complete and well-formed.
+pub fn to_credentials(cached: &config::ApiCredentials) -> Option<Credentials> {
+    Some(Credentials::new(
+        Uuid::parse_str(&cached.key).ok()?,
+        cached.secret.clone()?,
+        cached.passphrase.clone()?,
+    ))
+}
+
+/// A cache entry for `credentials` of `address`, created with `nonce`.
+pub fn from_credentials(
+    address: Address,
+    credentials: &Credentials,
+    nonce: u32,
+) -> config::ApiCredentials {
+    config::ApiCredentials {
+        address,
+        key: credentials.key().to_string(),
+        nonce,
+        secret: Some(credentials.secret().expose_secret().to_string()),
+        passphrase: Some(credentials.passphrase().expose_secret().to_string()),
+    }
 }
 
 pub async fn create_readonly_provider() -> Result<impl alloy::providers::Provider + Clone> {
+    Ok(ProviderBuilder::new().connect_client(rpc_client().await?))
+}
+
+/// Public endpoints for chains the Bridge API takes deposits from, used when
+/// `--source-rpc` isn't given.
+pub fn source_rpc_url(chain_id: ChainId) -> Option<&'static str> {
+    match chain_id {
+        1 => Some("https://eth.drpc.org"),
+        10 => Some("https://optimism.drpc.org"),
+        56 => Some("https://bsc.drpc.org"),
+        137 => Some(RPC_URL),
+        8453 => Some("https://base.drpc.org"),
+        42161 => Some("https://arbitrum.drpc.org"),
+        _ => None,
+    }
+}
+
+/// Read-only provider for another chain. Connecting makes no request, so
+/// the endpoint is only checked by [`check_source_rpc`].
+pub async fn create_source_readonly_provider(
+    url: &str,
+) -> Result<impl alloy::providers::Provider + Clone> {
     ProviderBuilder::new()
-        .connect(RPC_URL)
+        .connect(url)
         .await
-        .context("Failed to connect to Polygon RPC")
+        .with_context(|| format!("Failed to connect to {url}"))
 }
 
-pub async fn create_provider(
+/// Checks that `provider` answers and serves `chain_id`.
+pub async fn check_source_rpc<P: alloy::providers::Provider>(
+    provider: &P,
+    url: &str,
+    chain_id: ChainId,
+) -> Result<()> {
+    let served = tokio::time::timeout(RPC_TIMEOUT, provider.get_chain_id())
+        .await
+        .map_err(|_| anyhow!("{url} timed out after {}s", RPC_TIMEOUT.as_secs()))?
+        .with_context(|| format!("{url} did not answer"))?;
+    if served != chain_id {
+        bail!(
+            "{url} serves chain {served}, not {chain_id}; pass --source-rpc for chain {chain_id}"
+        );
+    }
+    Ok(())
+}
+
+/// Signing provider for another chain, for sending from the configured key.
+pub async fn create_source_provider(
     private_key: Option<&str>,
+    url: &str,
+    chain_id: ChainId,
 ) -> Result<impl alloy::providers::Provider + Clone> {
-    let (key, _) = config::resolve_key(private_key);
-    let key = key.ok_or_else(|| anyhow::anyhow!("{}", config::NO_WALLET_MSG))?;
+    let key = config::signing_key(private_key)?;
     let signer = LocalSigner::from_str(&key)
         .context("Invalid private key")?
-        .with_chain_id(Some(POLYGON));
+        .with_chain_id(Some(chain_id));
     ProviderBuilder::new()
         .wallet(signer)
-        .connect(RPC_URL)
+        .connect(url)
+        .await
+        .with_context(|| format!("Failed to connect to {url} with wallet"))
+}
+
+/// How an address holds funds on Polymarket.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WalletKind {
+    /// A plain key-controlled account.
+    Eoa,
+    /// A contract wallet: the proxy or Safe that Polymarket deploys per user.
+    Proxy,
+}
+
+/// Classifies `address` by whether it has contract code on Polygon.
+pub async fn wallet_kind(address: Address) -> Result<WalletKind> {
+    let provider = create_readonly_provider().await?;
+    let code = provider
+        .get_code_at(address)
         .await
-        .context("Failed to connect to Polygon RPC with wallet")
+        .context("Failed to look up wallet code on Polygon")?;
+    Ok(if code.is_empty() {
+        WalletKind::Eoa
+    } else {
+        WalletKind::Proxy
+    })
+}
+
+/// Wallets Polymarket would deploy for an EOA: the proxy used by email
+/// logins and the Safe used by browser wallets.
+pub fn derived_wallets(eoa: Address) -> (Option<Address>, Option<Address>) {
+    (
+        derive_proxy_wallet(eoa, config::chain_id()),
+        derive_safe_wallet(eoa, config::chain_id()),
+    )
+}
+
+pub async fn create_provider(
+    private_key: Option<&str>,
+) -> Result<impl alloy::providers::Provider + Clone> {
+    let wallet = match resolve_signer(private_key)? {
+        WalletSigner::Local(signer) => EthereumWallet::new(signer),
+        #[cfg(feature = "ledger")]
+        WalletSigner::Ledger(ledger) => EthereumWallet::new(ledger),
+    };
+    Ok(ProviderBuilder::new()
+        .wallet(wallet)
+        .connect_client(rpc_client().await?))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use polymarket_client_sdk::{AMOY, POLYGON};
 
     #[test]
     fn parse_signature_type_proxy() {
@@ -99,4 +543,54 @@ mod tests {
     fn parse_signature_type_unknown_defaults_to_eoa() {
         assert_eq!(parse_signature_type("unknown"), SignatureType::Eoa);
     }
+
+    #[test]
+    fn parse_signer_takes_an_optional_derivation_path() {
+        assert_eq!(parse_signer("ledger").unwrap(), None);
+        assert_eq!(
+            parse_signer("ledger:m/44'/60'/1'/0/0").unwrap(),
+            Some("m/44'/60'/1'/0/0")
+        );
+        assert!(parse_signer("ledger:").is_err());
+        assert!(parse_signer("trezor").is_err());
+    }
+
+    #[test]
+    fn rpc_urls_end_with_the_public_endpoint() {
+        let a = || "https://a.example".to_string();
+        assert_eq!(
+            rpc_urls(vec![a()], config::Chain::Polygon),
+            ["https://a.example", RPC_URL]
+        );
+        assert_eq!(
+            rpc_urls(vec![RPC_URL.into(), a()], config::Chain::Polygon),
+            [RPC_URL, "https://a.example"]
+        );
+        assert_eq!(
+            rpc_urls(vec![RPC_URL.into(), a()], config::Chain::Amoy),
+            [RPC_URL, "https://a.example", AMOY_RPC_URL]
+        );
+        assert_eq!(rpc_urls(Vec::new(), config::Chain::Amoy), [AMOY_RPC_URL]);
+    }
+
+    #[test]
+    fn funder_address_eoa_is_signer() {
+        let eoa = Address::repeat_byte(0x11);
+        assert_eq!(funder_address(eoa, SignatureType::Eoa, POLYGON), eoa);
+    }
+
+    #[test]
+    fn funder_address_proxy_is_derived() {
+        let eoa = Address::repeat_byte(0x11);
+        assert_eq!(
+            funder_address(eoa, SignatureType::Proxy, POLYGON),
+            derive_proxy_wallet(eoa, POLYGON).unwrap()
+        );
+    }
+
+    #[test]
+    fn amoy_has_no_proxy_so_funds_stay_with_the_signer() {
+        let eoa = Address::repeat_byte(0x11);
+        assert_eq!(funder_address(eoa, SignatureType::Proxy, AMOY), eoa);
+    }
 }
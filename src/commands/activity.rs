@@ -0,0 +1,130 @@
+use anyhow::Result;
+use clap::Args;
+use polymarket_client_sdk::data::types::ActivityType;
+use polymarket_client_sdk::data::types::request::ActivityRequest;
+
+use super::{describe_wallet, parse_address, parse_date_bound};
+use crate::auth;
+use crate::endpoints;
+use crate::output::data::print_activity;
+use crate::output::{OutputFormat, color};
+
+#[derive(Args)]
+pub struct ActivityArgs {
+    /// Wallet address to audit (default: your configured wallet)
+    #[arg(long)]
+    pub address: Option<String>,
+
+    /// Only these activity types (comma-separated)
+    #[arg(long = "type", value_delimiter = ',')]
+    pub types: Vec<ActivityKind>,
+
+    /// Only activity on or after this date (YYYY-MM-DD)
+    #[arg(long)]
+    pub since: Option<String>,
+
+    /// Only activity on or before this date (YYYY-MM-DD)
+    #[arg(long)]
+    pub until: Option<String>,
+
+    /// Max results
+    #[arg(long, default_value = "25")]
+    pub limit: i32,
+
+    /// Pagination offset
+    #[arg(long)]
+    pub offset: Option<i32>,
+}
+
+#[derive(Clone, Debug, clap::ValueEnum)]
+pub enum ActivityKind {
+    Trade,
+    Split,
+    Merge,
+    Redeem,
+    Reward,
+    Conversion,
+    Yield,
+    MakerRebate,
+}
+
+impl From<ActivityKind> for ActivityType {
+    fn from(kind: ActivityKind) -> Self {
+        match kind {
+            ActivityKind::Trade => ActivityType::Trade,
+            ActivityKind::Split => ActivityType::Split,
+            ActivityKind::Merge => ActivityType::Merge,
+            ActivityKind::Redeem => ActivityType::Redeem,
+            ActivityKind::Reward => ActivityType::Reward,
+            ActivityKind::Conversion => ActivityType::Conversion,
+            ActivityKind::Yield => ActivityType::Yield,
+            ActivityKind::MakerRebate => ActivityType::MakerRebate,
+        }
+    }
+}
+
+fn unix_seconds(date: Option<chrono::DateTime<chrono::Utc>>) -> Option<u64> {
+    date.and_then(|d| u64::try_from(d.timestamp()).ok())
+}
+
+pub async fn execute(
+    args: ActivityArgs,
+    output: OutputFormat,
+    private_key: Option<&str>,
+    signature_type: Option<&str>,
+) -> Result<()> {
+    let since = args
+        .since
+        .map(|s| parse_date_bound(&s, false))
+        .transpose()?;
+    let until = args.until.map(|s| parse_date_bound(&s, true)).transpose()?;
+    let user = match &args.address {
+        Some(address) => parse_address(address)?,
+        None => auth::resolve_funder_address(private_key, signature_type)?,
+    };
+
+    let request = ActivityRequest::builder()
+        .user(user)
+        .activity_types(args.types.into_iter().map(ActivityType::from).collect())
+        .limit(args.limit)?
+        .maybe_offset(args.offset)?
+        .maybe_start(unix_seconds(since))
+        .maybe_end(unix_seconds(until))
+        .build();
+    let activity = endpoints::data().activity(&request).await?;
+    if args.address.is_some() {
+        describe_wallet(user, &output).await;
+    }
+    print_activity(&activity, &output)?;
+
+    if matches!(output, OutputFormat::Table)
+        && i32::try_from(activity.len()).is_ok_and(|n| n == args.limit)
+    {
+        let next = args.offset.unwrap_or(0) + args.limit;
+        println!("{}", color::dim(format!("More results: --offset {next}")));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn activity_kind_maps_to_api_type() {
+        assert_eq!(
+            ActivityType::from(ActivityKind::Redeem),
+            ActivityType::Redeem
+        );
+    }
+
+    #[test]
+    fn unix_seconds_rejects_pre_epoch() {
+        let before_epoch = parse_date_bound("1969-12-31", false).ok();
+        assert_eq!(unix_seconds(before_epoch), None);
+        assert_eq!(
+            unix_seconds(parse_date_bound("1970-01-02", false).ok()),
+            Some(86_400)
+        );
+    }
+}
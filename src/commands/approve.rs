@@ -3,16 +3,19 @@
 
 use alloy::primitives::U256;
 use alloy::sol;
+use alloy::sol_types::SolCall as _;
 use anyhow::{Context, Result};
 use clap::{Args, Subcommand};
-use polymarket_client_sdk::types::{Address, address};
-use polymarket_client_sdk::{POLYGON, contract_config};
+use polymarket_client_sdk::contract_config;
+use polymarket_client_sdk::types::Address;
 
-use crate::auth;
-use crate::output::OutputFormat;
-use crate::output::approve::{ApprovalStatus, print_approval_status, print_tx_result};
-
-const USDC_ADDRESS: Address = address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
+use crate::output::approve::{
+    ApprovalStatus, approval_status_json, print_approval_status, print_tx_result,
+};
+use crate::output::dry_run::PlannedTx;
+use crate::output::{OutputFormat, print_json};
+use crate::tx::{self, GasArgs, TxOptions, WaitArgs};
+use crate::{auth, config};
 
 sol! {
     #[sol(rpc)]
@@ -42,18 +45,46 @@ pub enum ApproveCommand {
         address: Option<String>,
     },
     /// Approve all required contracts for trading (sends on-chain transactions)
-    Set,
+    Set {
+        /// USDC each contract may spend (default: unlimited)
+        #[arg(long)]
+        amount: Option<String>,
+        /// Only approve these contracts (default: the trading contracts)
+        #[arg(long, value_enum)]
+        contract: Option<ContractGroup>,
+    },
+    /// Zero out USDC allowances and outcome token approvals, e.g. before retiring a wallet
+    Revoke {
+        /// Which contracts to revoke
+        #[arg(long, value_enum, default_value_t = ContractGroup::All)]
+        contract: ContractGroup,
+    },
 }
 
-struct ApprovalTarget {
-    name: &'static str,
-    address: Address,
+/// Polymarket contracts grouped the way users think of them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum ContractGroup {
+    /// The CTF Exchange, for regular markets
+    Exchange,
+    /// The Neg Risk Exchange and Adapter, for multi-outcome events
+    NegRisk,
+    /// The Conditional Tokens contract, which takes USDC for splits
+    Ctf,
+    /// Every contract above
+    All,
 }
 
-fn approval_targets() -> Result<Vec<ApprovalTarget>> {
-    let config = contract_config(POLYGON, false).context("No contract config for Polygon")?;
-    let neg_risk_config =
-        contract_config(POLYGON, true).context("No neg-risk contract config for Polygon")?;
+pub(crate) struct ApprovalTarget {
+    pub name: &'static str,
+    pub address: Address,
+}
+
+/// Contracts that trade or settle on the user's behalf.
+pub(crate) fn approval_targets() -> Result<Vec<ApprovalTarget>> {
+    let config =
+        contract_config(config::chain_id(), false).context("No contract config for this chain")?;
+    let neg_risk_config = contract_config(config::chain_id(), true)
+        .context("No neg-risk contract config for this chain")?;
 
     let mut targets = vec![
         ApprovalTarget {
@@ -76,14 +107,66 @@ fn approval_targets() -> Result<Vec<ApprovalTarget>> {
     Ok(targets)
 }
 
+/// The contracts in `group`, with whether each can hold an outcome token
+/// approval. The Conditional Tokens contract holds the tokens itself, so only
+/// its USDC allowance means anything.
+fn group_targets(group: ContractGroup) -> Result<Vec<(ApprovalTarget, bool)>> {
+    let mut targets: Vec<(ApprovalTarget, bool)> = approval_targets()?
+        .into_iter()
+        .filter(|t| match group {
+            ContractGroup::Exchange => t.name == "CTF Exchange",
+            ContractGroup::NegRisk => t.name.starts_with("Neg Risk"),
+            ContractGroup::Ctf => false,
+            ContractGroup::All => true,
+        })
+        .map(|t| (t, true))
+        .collect();
+    if matches!(group, ContractGroup::Ctf | ContractGroup::All) {
+        let config = contract_config(config::chain_id(), false)
+            .context("No contract config for this chain")?;
+        targets.push((
+            ApprovalTarget {
+                name: "Conditional Tokens",
+                address: config.conditional_tokens,
+            },
+            false,
+        ));
+    }
+    Ok(targets)
+}
+
 pub async fn execute(
     args: ApproveArgs,
     output: OutputFormat,
     private_key: Option<&str>,
+    dry_run: bool,
+    confirm: bool,
+    gas: &GasArgs,
 ) -> Result<()> {
     match args.command {
         ApproveCommand::Check { address } => check(address.as_deref(), private_key, output).await,
-        ApproveCommand::Set => set(private_key, output).await,
+        ApproveCommand::Set { amount, contract } => {
+            let options = TxOptions {
+                private_key,
+                dry_run,
+                confirm,
+                gas,
+                wait: WaitArgs::default(),
+                simulate: false,
+            };
+            set(contract, amount.as_deref(), &options, output).await
+        }
+        ApproveCommand::Revoke { contract } => {
+            let options = TxOptions {
+                private_key,
+                dry_run,
+                confirm,
+                gas,
+                wait: WaitArgs::default(),
+                simulate: false,
+            };
+            revoke(contract, &options, output).await
+        }
     }
 }
 
@@ -95,31 +178,43 @@ async fn check(
     let owner: Address = if let Some(addr) = address_arg {
         super::parse_address(addr)?
     } else {
-        let signer = auth::resolve_signer(private_key)?;
-        polymarket_client_sdk::auth::Signer::address(&signer)
+        auth::resolve_address(private_key)?
     };
 
+    let statuses = statuses(owner, &group_targets(ContractGroup::All)?).await?;
+    print_approval_status(&statuses, &output)
+}
+
+/// What `owner` has granted each of `targets`, with RPC failures kept per
+/// cell so one bad read doesn't hide the rest.
+async fn statuses(
+    owner: Address,
+    targets: &[(ApprovalTarget, bool)],
+) -> Result<Vec<ApprovalStatus>> {
     let provider = auth::create_readonly_provider().await?;
-    let config = contract_config(POLYGON, false).context("No contract config for Polygon")?;
+    let config =
+        contract_config(config::chain_id(), false).context("No contract config for this chain")?;
 
-    let usdc = IERC20::new(USDC_ADDRESS, provider.clone());
+    let usdc = IERC20::new(config.collateral, provider.clone());
     let ctf = IERC1155::new(config.conditional_tokens, provider.clone());
 
-    let targets = approval_targets()?;
     let mut statuses = Vec::new();
 
-    for target in &targets {
+    for (target, takes_tokens) in targets {
         let (usdc_allowance, usdc_error) = match usdc.allowance(owner, target.address).call().await
         {
             Ok(val) => (val, None),
             Err(e) => (U256::ZERO, Some(e.to_string())),
         };
 
-        let (ctf_approved, ctf_error) =
+        let (ctf_approved, ctf_error) = if *takes_tokens {
             match ctf.isApprovedForAll(owner, target.address).call().await {
-                Ok(val) => (val, None),
-                Err(e) => (false, Some(e.to_string())),
-            };
+                Ok(val) => (Some(val), None),
+                Err(e) => (Some(false), Some(e.to_string())),
+            }
+        } else {
+            (None, None)
+        };
 
         statuses.push(ApprovalStatus {
             contract_name: target.name.to_string(),
@@ -131,84 +226,263 @@ async fn check(
         });
     }
 
-    print_approval_status(&statuses, &output)
+    Ok(statuses)
 }
 
-async fn set(private_key: Option<&str>, output: OutputFormat) -> Result<()> {
-    let provider = auth::create_provider(private_key).await?;
-    let config = contract_config(POLYGON, false).context("No contract config for Polygon")?;
+/// One approval `set` sends.
+struct Step {
+    tx: PlannedTx,
+    kind: &'static str,
+    label: String,
+    contract: &'static str,
+}
 
-    let usdc = IERC20::new(USDC_ADDRESS, provider.clone());
-    let ctf = IERC1155::new(config.conditional_tokens, provider.clone());
+/// The approvals `set` sends, in order: USDC then, where it applies, CTF for
+/// each contract.
+fn set_steps(from: Address, targets: &[(ApprovalTarget, bool)], amount: U256) -> Result<Vec<Step>> {
+    let config =
+        contract_config(config::chain_id(), false).context("No contract config for this chain")?;
+
+    let mut steps = Vec::new();
+    for (target, operator) in targets {
+        steps.push(Step {
+            tx: PlannedTx {
+                action: format!("USDC approve \u{2192} {}", target.name),
+                from: Some(from),
+                to: config.collateral,
+                calldata: IERC20::approveCall {
+                    spender: target.address,
+                    value: amount,
+                }
+                .abi_encode(),
+                value: U256::ZERO,
+            },
+            kind: "erc20",
+            label: format!("USDC \u{2192} {}", target.name),
+            contract: target.name,
+        });
+        if *operator {
+            steps.push(Step {
+                tx: PlannedTx {
+                    action: format!("CTF setApprovalForAll \u{2192} {}", target.name),
+                    from: Some(from),
+                    to: config.conditional_tokens,
+                    calldata: IERC1155::setApprovalForAllCall {
+                        operator: target.address,
+                        approved: true,
+                    }
+                    .abi_encode(),
+                    value: U256::ZERO,
+                },
+                kind: "erc1155",
+                label: format!("CTF  \u{2192} {}", target.name),
+                contract: target.name,
+            });
+        }
+    }
+    Ok(steps)
+}
 
-    let targets = approval_targets()?;
-    let total = targets.len() * 2;
+async fn set(
+    group: Option<ContractGroup>,
+    amount: Option<&str>,
+    options: &TxOptions<'_>,
+    output: OutputFormat,
+) -> Result<()> {
+    let from = auth::resolve_address(options.private_key)?;
+    let targets = match group {
+        Some(group) => group_targets(group)?,
+        None => approval_targets()?.into_iter().map(|t| (t, true)).collect(),
+    };
+    let allowance = amount
+        .map(super::ctf::parse_usdc_amount)
+        .transpose()?
+        .unwrap_or(U256::MAX);
+    let steps = set_steps(from, &targets, allowance)?;
+    let txs: Vec<PlannedTx> = steps.iter().map(|s| s.tx.clone()).collect();
+    let names: Vec<&str> = targets.iter().map(|(t, _)| t.name).collect();
+    let summary = [match amount {
+        Some(amount) => format!(
+            "Let {} spend up to {amount} USDC each and move your outcome tokens",
+            names.join(", ")
+        ),
+        None if group.is_none() => {
+            "Approve all trading contracts to move your USDC and outcome tokens".to_string()
+        }
+        None => format!("Let {} move your USDC and outcome tokens", names.join(", ")),
+    }];
+    let Some(gas) = tx::prepare(&txs, &summary, options, &output).await? else {
+        return Ok(());
+    };
+    let provider = auth::create_provider(options.private_key).await?;
+    let total = steps.len();
 
     if matches!(output, OutputFormat::Table) {
         println!("Approving contracts...\n");
     }
 
     let mut results: Vec<serde_json::Value> = Vec::new();
-    let mut step = 0;
-
-    for target in &targets {
-        step += 1;
-        let label = format!("USDC \u{2192} {}", target.name);
-        let tx_hash = usdc
-            .approve(target.address, U256::MAX)
-            .send()
-            .await
-            .context(format!("Failed to send USDC approval for {}", target.name))?
-            .watch()
-            .await
-            .context(format!(
-                "Failed to confirm USDC approval for {}",
-                target.name
-            ))?;
+    for (i, (step, gas)) in steps.iter().zip(&gas).enumerate() {
+        let tx_hash = tx::send(&provider, &step.tx, gas, options.wait).await?.hash;
 
         match output {
-            OutputFormat::Table => print_tx_result(step, total, &label, tx_hash),
-            OutputFormat::Json => results.push(serde_json::json!({
-                "step": step,
-                "type": "erc20",
-                "contract": target.name,
-                "tx_hash": format!("{tx_hash}"),
-            })),
+            OutputFormat::Table => print_tx_result(i + 1, total, &step.label, tx_hash),
+            OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                results.push(serde_json::json!({
+                    "step": i + 1,
+                    "type": step.kind,
+                    "contract": step.contract,
+                    "tx_hash": format!("{tx_hash}"),
+                }))
+            }
+        }
+    }
+
+    match output {
+        OutputFormat::Table => {
+            if amount.is_some() {
+                println!(
+                    "\nContracts approved. Raise the allowance with `approve set` when trades need more."
+                );
+            } else {
+                println!("\nAll contracts approved. You're ready to trade.");
+            }
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&results)?;
         }
+    }
 
-        step += 1;
-        let label = format!("CTF  \u{2192} {}", target.name);
-        let tx_hash = ctf
-            .setApprovalForAll(target.address, true)
-            .send()
-            .await
-            .context(format!("Failed to send CTF approval for {}", target.name))?
-            .watch()
-            .await
-            .context(format!(
-                "Failed to confirm CTF approval for {}",
-                target.name
-            ))?;
+    Ok(())
+}
 
-        match output {
-            OutputFormat::Table => print_tx_result(step, total, &label, tx_hash),
-            OutputFormat::Json => results.push(serde_json::json!({
-                "step": step,
-                "type": "erc1155",
-                "contract": target.name,
-                "tx_hash": format!("{tx_hash}"),
-            })),
+/// The revocations for `targets`: a zero allowance and, where it applies, a
+/// cleared operator approval for each.
+fn revoke_steps(from: Address, targets: &[(ApprovalTarget, bool)]) -> Result<Vec<Step>> {
+    let config =
+        contract_config(config::chain_id(), false).context("No contract config for this chain")?;
+    let mut steps = Vec::new();
+    for (target, operator) in targets {
+        steps.push(Step {
+            tx: PlannedTx {
+                action: format!("USDC revoke \u{2192} {}", target.name),
+                from: Some(from),
+                to: config.collateral,
+                calldata: IERC20::approveCall {
+                    spender: target.address,
+                    value: U256::ZERO,
+                }
+                .abi_encode(),
+                value: U256::ZERO,
+            },
+            kind: "erc20",
+            label: format!("USDC \u{2192} {}", target.name),
+            contract: target.name,
+        });
+        if *operator {
+            steps.push(Step {
+                tx: PlannedTx {
+                    action: format!("CTF setApprovalForAll(false) \u{2192} {}", target.name),
+                    from: Some(from),
+                    to: config.conditional_tokens,
+                    calldata: IERC1155::setApprovalForAllCall {
+                        operator: target.address,
+                        approved: false,
+                    }
+                    .abi_encode(),
+                    value: U256::ZERO,
+                },
+                kind: "erc1155",
+                label: format!("CTF  \u{2192} {}", target.name),
+                contract: target.name,
+            });
         }
     }
+    Ok(steps)
+}
+
+/// Whether `status` still lets its contract move anything.
+fn still_granted(status: &ApprovalStatus, kind: &str) -> bool {
+    match kind {
+        "erc20" => status.usdc_error.is_some() || status.usdc_allowance > U256::ZERO,
+        _ => status.ctf_error.is_some() || status.ctf_approved == Some(true),
+    }
+}
 
+async fn revoke(group: ContractGroup, options: &TxOptions<'_>, output: OutputFormat) -> Result<()> {
+    let from = auth::resolve_address(options.private_key)?;
+    let targets = group_targets(group)?;
+    let steps = revoke_steps(from, &targets)?;
+    if options.dry_run {
+        let txs: Vec<PlannedTx> = steps.iter().map(|s| s.tx.clone()).collect();
+        tx::prepare(&txs, &[], options, &output).await?;
+        return Ok(());
+    }
+    // Only pay for revocations that change something.
+    let before = statuses(from, &targets).await?;
+    let pending: Vec<Step> = steps
+        .into_iter()
+        .filter(|step| {
+            before
+                .iter()
+                .find(|s| s.contract_name == step.contract)
+                .is_some_and(|s| still_granted(s, step.kind))
+        })
+        .collect();
+
+    let mut results: Vec<serde_json::Value> = Vec::new();
+    if !pending.is_empty() {
+        let txs: Vec<PlannedTx> = pending.iter().map(|s| s.tx.clone()).collect();
+        let mut summary = vec![format!("Revoke these approvals from {from}:")];
+        summary.extend(pending.iter().map(|s| format!("  {}", s.label)));
+        let Some(gas) = tx::prepare(&txs, &summary, options, &output).await? else {
+            return Ok(());
+        };
+        let provider = auth::create_provider(options.private_key).await?;
+        if matches!(output, OutputFormat::Table) {
+            println!("Revoking approvals...\n");
+        }
+        let total = pending.len();
+        for (i, (step, gas)) in pending.iter().zip(&gas).enumerate() {
+            let tx_hash = tx::send(&provider, &step.tx, gas, options.wait).await?.hash;
+            match output {
+                OutputFormat::Table => print_tx_result(i + 1, total, &step.label, tx_hash),
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    results.push(serde_json::json!({
+                        "step": i + 1,
+                        "type": step.kind,
+                        "contract": step.contract,
+                        "tx_hash": format!("{tx_hash}"),
+                    }));
+                }
+            }
+        }
+    } else if matches!(output, OutputFormat::Table) {
+        println!("Nothing to revoke: no approvals are set for these contracts.");
+    }
+
+    // Check the chain again rather than trust the receipts.
+    let after = statuses(from, &targets).await?;
     match output {
         OutputFormat::Table => {
-            println!("\nAll contracts approved. You're ready to trade.");
+            println!("\nApprovals for {from} now:\n");
+            print_approval_status(&after, &output)?;
         }
-        OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(&results)?);
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({
+                "revoked": results,
+                "approvals": approval_status_json(&after),
+            }))?;
         }
     }
-
+    let remaining = after
+        .iter()
+        .filter(|s| still_granted(s, "erc20") || still_granted(s, "erc1155"))
+        .count();
+    anyhow::ensure!(
+        remaining == 0,
+        "Approvals remain for {remaining} contract{}; run `polymarket approve check`",
+        if remaining == 1 { "" } else { "s" }
+    );
     Ok(())
 }
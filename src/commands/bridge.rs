@@ -1,12 +1,42 @@
+#![allow(clippy::exhaustive_enums, reason = "Generated by sol! macro")]
+#![allow(clippy::exhaustive_structs, reason = "Generated by sol! macro")]
+
 use super::parse_address;
 use crate::output::OutputFormat;
-use crate::output::bridge::{print_deposit, print_status, print_supported_assets};
-use anyhow::Result;
+use crate::output::bridge::{
+    BridgeDeposit, Withdrawal, format_status, print_bridge_deposit, print_deposit, print_status,
+    print_supported_assets, print_withdraw,
+};
+use crate::output::dry_run::PlannedTx;
+use crate::tx::{self, GasArgs, TxOptions, WaitArgs};
+use crate::{auth, config, endpoints};
+use alloy::primitives::U256;
+use alloy::primitives::utils::{format_units, parse_units};
+use alloy::sol;
+use alloy::sol_types::SolCall as _;
+use anyhow::{Context, Result, bail};
 use clap::{Args, Subcommand};
 use polymarket_client_sdk::bridge::{
     self,
-    types::{DepositRequest, StatusRequest},
+    types::{
+        DepositRequest, DepositTransaction, DepositTransactionStatus, StatusRequest,
+        StatusResponse, SupportedAsset,
+    },
 };
+use polymarket_client_sdk::types::Address;
+use polymarket_client_sdk::{POLYGON, contract_config};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::io::{IsTerminal as _, Write as _};
+use std::time::{Duration, Instant};
+
+sol! {
+    #[sol(rpc)]
+    interface IERC20 {
+        function transfer(address to, uint256 value) external returns (bool);
+        function balanceOf(address account) external view returns (uint256);
+    }
+}
 
 #[derive(Args)]
 pub struct BridgeArgs {
@@ -16,10 +46,45 @@ pub struct BridgeArgs {
 
 #[derive(Subcommand)]
 pub enum BridgeCommand {
-    /// Get deposit addresses for a wallet (EVM, Solana, Bitcoin)
+    /// Get deposit addresses for a wallet (EVM, Solana, Bitcoin), or bridge funds in from another chain
     Deposit {
-        /// Polymarket wallet address (0x...)
-        address: String,
+        /// Polymarket wallet address (0x...; default: your wallet)
+        address: Option<String>,
+        /// Amount to bridge in, in the token's units (e.g. 100 or 0.5); sent from your key's address
+        #[arg(long, requires = "from_chain")]
+        amount: Option<String>,
+        /// Chain ID to send from (e.g. 1 for Ethereum); see `bridge supported-assets`
+        #[arg(long, requires = "amount")]
+        from_chain: Option<u64>,
+        /// Token to send, by symbol or contract address
+        #[arg(long, default_value = "USDC")]
+        token: String,
+        /// RPC endpoint for --from-chain (default: a public one for well-known chains)
+        #[arg(long, requires = "amount")]
+        source_rpc: Option<String>,
+        /// Wait until the bridge has delivered the funds to Polymarket
+        #[arg(long, requires = "amount")]
+        track: bool,
+        #[command(flatten)]
+        wait: WaitArgs,
+    },
+
+    /// Send USDC from your Polymarket wallet to an address on Polygon or another chain
+    Withdraw {
+        /// USDC to send (e.g. 25 or 25.5)
+        #[arg(long)]
+        amount: String,
+        /// Recipient address on the destination chain
+        #[arg(long)]
+        to: String,
+        /// Destination chain ID (default: Polygon); see `bridge supported-assets`
+        #[arg(long)]
+        to_chain: Option<u64>,
+        /// Token to receive on the destination chain; required with --to-chain
+        #[arg(long, requires = "to_chain")]
+        to_token: Option<String>,
+        #[command(flatten)]
+        wait: WaitArgs,
     },
 
     /// List supported chains and tokens for deposits
@@ -29,37 +94,584 @@ pub enum BridgeCommand {
     Status {
         /// Deposit address (EVM, Solana, or Bitcoin)
         address: String,
+        /// Keep checking until every transfer has completed or failed
+        #[arg(long)]
+        wait: bool,
+        /// Seconds --wait gives up after
+        #[arg(long, default_value_t = 1800, requires = "wait")]
+        timeout: u64,
     },
 }
 
-pub async fn execute(
+impl BridgeCommand {
+    pub fn is_read_only(&self) -> bool {
+        !matches!(
+            self,
+            Self::Withdraw { .. }
+                | Self::Deposit {
+                    amount: Some(_),
+                    ..
+                }
+        )
+    }
+
+    /// Read-only output that ends; `status --wait` keeps printing progress.
+    pub fn pageable(&self) -> bool {
+        self.is_read_only() && !matches!(self, Self::Status { wait: true, .. })
+    }
+}
+
+/// The Bridge API's stand-in address for a chain's native token.
+const NATIVE_TOKEN: Address =
+    alloy::primitives::address!("EeeeeEeeeEeEeeEeEeEeeEEEeeeeEeeeeeeeEEeE");
+
+/// How often tracking asks the Bridge API for news.
+const TRACK_POLL: Duration = Duration::from_secs(15);
+
+/// How long `bridge deposit --track` follows a transfer before leaving it
+/// to `bridge status --wait`.
+const TRACK_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// The asset on `chain` matching `token`, a symbol or contract address.
+fn find_asset<'a>(
+    assets: &'a [SupportedAsset],
+    chain: u64,
+    token: &str,
+) -> Result<&'a SupportedAsset> {
+    let on_chain: Vec<_> = assets.iter().filter(|a| a.chain_id == chain).collect();
+    anyhow::ensure!(
+        !on_chain.is_empty(),
+        "The bridge takes no deposits from chain {chain}; see `bridge supported-assets`"
+    );
+    on_chain
+        .iter()
+        .find(|a| {
+            a.token.symbol.eq_ignore_ascii_case(token)
+                || a.token.address.eq_ignore_ascii_case(token)
+        })
+        .copied()
+        .with_context(|| {
+            let symbols: Vec<_> = on_chain.iter().map(|a| a.token.symbol.as_str()).collect();
+            format!(
+                "The bridge doesn't take {token} on chain {chain}; it takes {}",
+                symbols.join(", ")
+            )
+        })
+}
+
+/// What gas is paid in on `chain`, going by the native token the bridge
+/// lists for it.
+fn gas_token(assets: &[SupportedAsset], chain: u64) -> String {
+    assets
+        .iter()
+        .find(|a| {
+            a.chain_id == chain
+                && a.token
+                    .address
+                    .parse::<Address>()
+                    .is_ok_and(|address| address == NATIVE_TOKEN)
+        })
+        .map_or_else(|| "native token".to_string(), |a| a.token.symbol.clone())
+}
+
+fn is_settled(status: &DepositTransactionStatus) -> bool {
+    matches!(
+        status,
+        DepositTransactionStatus::Completed | DepositTransactionStatus::Failed
+    )
+}
+
+/// Whether every transfer through a deposit address has completed or
+/// failed. An address with none yet is still waiting for its deposit.
+fn all_settled(response: &StatusResponse) -> bool {
+    !response.transactions.is_empty()
+        && response
+            .transactions
+            .iter()
+            .all(|tx| is_settled(&tx.status))
+}
+
+/// Polls the status of `address` until `done` says so or `timeout` passes.
+/// Returns the last status and whether `done` was reached.
+async fn track(
+    client: &bridge::Client,
+    address: &str,
+    timeout: Duration,
+    done: impl Fn(&StatusResponse) -> bool,
+) -> Result<(StatusResponse, bool)> {
+    let request = StatusRequest::builder().address(address).build();
+    let progress = std::io::stderr().is_terminal();
+    let started = Instant::now();
+    loop {
+        let response = client.status(&request).await?;
+        if done(&response) || started.elapsed() >= timeout {
+            if progress {
+                eprintln!();
+            }
+            let reached = done(&response);
+            return Ok((response, reached));
+        }
+        if progress {
+            let latest = response
+                .transactions
+                .last()
+                .map_or("Waiting for deposit", |tx| format_status(&tx.status));
+            eprint!(
+                "\r\x1b[2KBridge transfer through {address}: {latest} ({}s)",
+                started.elapsed().as_secs()
+            );
+            let _ = std::io::stderr().flush();
+        }
+        tokio::time::sleep(TRACK_POLL).await;
+    }
+}
+
+/// Body of the Bridge API's withdraw request.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WithdrawRequest {
+    address: Address,
+    to_chain_id: String,
+    to_token_address: String,
+    recipient_addr: String,
+}
+
+#[derive(Deserialize)]
+struct WithdrawResponse {
+    address: WithdrawAddress,
+    note: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct WithdrawAddress {
+    evm: Address,
+}
+
+/// Asks the Bridge API for an address on Polygon that forwards USDC sent to
+/// it on to `recipient` on another chain.
+async fn bridge_address(
     client: &bridge::Client,
+    funder: Address,
+    to_chain: u64,
+    to_token: &str,
+    recipient: &str,
+) -> Result<(Address, Option<String>)> {
+    let request = WithdrawRequest {
+        address: funder,
+        to_chain_id: to_chain.to_string(),
+        to_token_address: to_token.to_string(),
+        recipient_addr: recipient.to_string(),
+    };
+    let response = alloy::transports::http::reqwest::Client::new()
+        .post(format!("{}withdraw", client.host()))
+        .json(&request)
+        .send()
+        .await
+        .context("Failed to reach the Bridge API")?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        bail!("The Bridge API refused the withdrawal ({status}): {body}");
+    }
+    let response: WithdrawResponse = response
+        .json()
+        .await
+        .context("Unexpected response from the Bridge API")?;
+    Ok((response.address.evm, response.note))
+}
+
+/// The transaction that sends `amount` USDC from the wallet holding the
+/// user's funds; see [`tx::funder_call`].
+fn usdc_transfer(
+    funder: Address,
+    eoa: Address,
+    signature_type: &str,
+    to: Address,
+    amount: U256,
+) -> Result<PlannedTx> {
+    let usdc = contract_config(config::chain_id(), false)
+        .context("No contract config for this chain")?
+        .collateral;
+    let transfer = IERC20::transferCall { to, value: amount }.abi_encode();
+    tx::funder_call(
+        funder,
+        eoa,
+        signature_type,
+        format!("USDC transfer \u{2192} {to}"),
+        usdc,
+        transfer,
+    )
+}
+
+pub async fn execute(
     args: BridgeArgs,
     output: OutputFormat,
+    private_key: Option<&str>,
+    signature_type: Option<&str>,
+    dry_run: bool,
+    confirm: bool,
+    gas: &GasArgs,
 ) -> Result<()> {
+    let client = &endpoints::bridge();
     match args.command {
-        BridgeCommand::Deposit { address } => {
-            let request = DepositRequest::builder()
-                .address(parse_address(&address)?)
-                .build();
+        BridgeCommand::Deposit {
+            address,
+            amount: None,
+            ..
+        } => {
+            let wallet = match address {
+                Some(address) => parse_address(&address)?,
+                None => auth::resolve_funder_address(private_key, signature_type)?,
+            };
+            let request = DepositRequest::builder().address(wallet).build();
 
             let response = client.deposit(&request).await?;
             print_deposit(&response, &output)?;
         }
 
+        BridgeCommand::Deposit {
+            address,
+            amount: Some(amount),
+            from_chain,
+            token,
+            source_rpc,
+            track: follow,
+            wait,
+        } => {
+            let options = TxOptions {
+                private_key,
+                dry_run,
+                confirm,
+                gas,
+                wait,
+                simulate: true,
+            };
+            let chain = from_chain.context("Give --from-chain, the chain to send from")?;
+            let wallet = match address {
+                Some(address) => parse_address(&address)?,
+                None => auth::resolve_funder_address(private_key, signature_type)?,
+            };
+            let sender = auth::resolve_address(private_key)?;
+
+            // Quote: the bridge must take this token from this chain, and
+            // at least its minimum.
+            let assets = client.supported_assets().await?.supported_assets;
+            let asset = find_asset(&assets, chain, &token)?;
+            let symbol = asset.token.symbol.clone();
+            let token_address = asset
+                .token
+                .address
+                .parse::<Address>()
+                .ok()
+                .filter(|a| *a != NATIVE_TOKEN)
+                .with_context(|| {
+                    format!(
+                        "{symbol} on chain {chain} can't be sent from here; send it from any \
+                         wallet to the address `polymarket bridge deposit` gives"
+                    )
+                })?;
+            let decimals = asset.token.decimals;
+            let raw = parse_units(&amount, decimals)
+                .with_context(|| format!("Invalid amount: {amount}"))?
+                .get_absolute();
+            anyhow::ensure!(!raw.is_zero(), "Amount must be positive");
+            let minimum = asset.min_checkout_usd;
+            if symbol.to_ascii_uppercase().contains("USD")
+                && let Ok(value) = amount.trim().parse::<Decimal>()
+            {
+                anyhow::ensure!(
+                    value >= minimum,
+                    "The bridge takes at least ${minimum} per deposit; {amount} {symbol} is below it"
+                );
+            }
+
+            let deposit_address = client
+                .deposit(&DepositRequest::builder().address(wallet).build())
+                .await?
+                .address
+                .evm;
+            // A plain transfer to the deposit address is the whole bridge
+            // transaction, so there is nothing to approve first.
+            let call = PlannedTx {
+                action: format!("{symbol} transfer \u{2192} bridge deposit {deposit_address}"),
+                from: Some(sender),
+                to: token_address,
+                calldata: IERC20::transferCall {
+                    to: deposit_address,
+                    value: raw,
+                }
+                .abi_encode(),
+                value: U256::ZERO,
+            };
+
+            let url = source_rpc
+                .as_deref()
+                .or_else(|| auth::source_rpc_url(chain))
+                .with_context(|| {
+                    format!("No default RPC endpoint for chain {chain}; pass --source-rpc")
+                })?;
+            let source = auth::create_source_readonly_provider(url).await?;
+            if !dry_run {
+                auth::check_source_rpc(&source, url, chain).await?;
+                let held = IERC20::new(token_address, source.clone())
+                    .balanceOf(sender)
+                    .call()
+                    .await?;
+                if held < raw {
+                    bail!(
+                        "{sender} holds {} {symbol} on chain {chain}, less than the {amount} to bridge",
+                        format_units(held, decimals)?
+                    );
+                }
+            }
+
+            let summary = vec![
+                format!(
+                    "Bridge {amount} {symbol} from {sender} on {} (chain {chain})",
+                    asset.chain_name
+                ),
+                format!("Deposit address: {deposit_address}"),
+                format!("Arrives as USDC in {wallet} (minimum deposit ${minimum})"),
+            ];
+            let Some(gas) = tx::prepare_on(
+                &source,
+                &gas_token(&assets, chain),
+                std::slice::from_ref(&call),
+                &summary,
+                &options,
+                &output,
+            )
+            .await?
+            else {
+                return Ok(());
+            };
+            let provider = auth::create_source_provider(private_key, url, chain).await?;
+            let sent = tx::send(&provider, &call, &gas[0], options.wait).await?;
+
+            let ours = |tx: &DepositTransaction| {
+                tx.from_chain_id == chain
+                    && tx.from_amount_base_unit == raw
+                    && tx
+                        .from_token_address
+                        .eq_ignore_ascii_case(&asset.token.address)
+            };
+            let bridged = if follow {
+                let (response, _) = track(
+                    client,
+                    &deposit_address.to_string(),
+                    TRACK_TIMEOUT,
+                    |response| {
+                        response
+                            .transactions
+                            .iter()
+                            .any(|tx| ours(tx) && is_settled(&tx.status))
+                    },
+                )
+                .await?;
+                response.transactions.into_iter().rfind(|tx| ours(tx))
+            } else {
+                None
+            };
+            let failed = bridged
+                .as_ref()
+                .is_some_and(|tx| tx.status == DepositTransactionStatus::Failed);
+            print_bridge_deposit(
+                &BridgeDeposit {
+                    amount,
+                    symbol,
+                    from_chain: chain,
+                    from: sender,
+                    deposit_address,
+                    wallet,
+                    tx_hash: sent.hash,
+                    block: sent.block,
+                    bridged,
+                },
+                &output,
+            )?;
+            if failed {
+                bail!(
+                    "The bridge reported the transfer as failed; contact Polymarket support with the tx hash"
+                );
+            }
+        }
+
+        BridgeCommand::Withdraw {
+            amount,
+            to,
+            to_chain,
+            to_token,
+            wait,
+        } => {
+            let options = TxOptions {
+                private_key,
+                dry_run,
+                confirm,
+                gas,
+                wait,
+                simulate: true,
+            };
+            let raw = super::ctf::parse_usdc_amount(&amount)?;
+            let eoa = auth::resolve_address(private_key)?;
+            let funder = auth::resolve_funder_address(private_key, signature_type)?;
+            let signature_type = config::resolve_signature_type(signature_type);
+
+            let bridged = to_chain.filter(|c| *c != POLYGON);
+            let (destination, note) = match bridged {
+                None => (parse_address(&to)?, None),
+                Some(chain) => {
+                    let token = to_token.context(
+                        "Give --to-token, the token to receive; see `bridge supported-assets`",
+                    )?;
+                    bridge_address(client, funder, chain, &token, &to).await?
+                }
+            };
+            let call = usdc_transfer(funder, eoa, &signature_type, destination, raw)?;
+
+            tx::ensure_sendable(&signature_type, dry_run)?;
+            if !dry_run {
+                let provider = auth::create_readonly_provider().await?;
+                let usdc = IERC20::new(
+                    contract_config(config::chain_id(), false)
+                        .context("No contract config for this chain")?
+                        .collateral,
+                    provider,
+                );
+                let held = usdc.balanceOf(funder).call().await?;
+                if held < raw {
+                    bail!(
+                        "{funder} holds {} USDC, less than the {amount} to withdraw",
+                        super::wallet::format_usdc(held)
+                    );
+                }
+            }
+
+            let mut summary = vec![format!("Withdraw {amount} USDC from {funder} to {to}")];
+            if let Some(chain) = bridged {
+                summary.push(format!("Bridged to chain {chain} through {destination}"));
+            }
+            let Some(gas) =
+                tx::prepare(std::slice::from_ref(&call), &summary, &options, &output).await?
+            else {
+                return Ok(());
+            };
+            let provider = auth::create_provider(private_key).await?;
+            let sent = tx::send(&provider, &call, &gas[0], options.wait).await?;
+            let withdrawal = Withdrawal {
+                amount,
+                from: funder,
+                to,
+                to_chain: bridged.unwrap_or(POLYGON),
+                bridge_address: bridged.map(|_| destination),
+                note,
+                tx_hash: sent.hash,
+                block: sent.block,
+            };
+            print_withdraw(&withdrawal, &output)?;
+        }
+
         BridgeCommand::SupportedAssets => {
             let response = client.supported_assets().await?;
             print_supported_assets(&response, &output)?;
         }
 
-        BridgeCommand::Status { address } => {
+        BridgeCommand::Status {
+            address,
+            wait,
+            timeout,
+        } => {
             anyhow::ensure!(!address.trim().is_empty(), "Address cannot be empty");
-            let request = StatusRequest::builder().address(&address).build();
+            if wait {
+                let timeout = Duration::from_secs(timeout);
+                let (response, settled) = track(client, &address, timeout, all_settled).await?;
+                print_status(&response, &output)?;
+                anyhow::ensure!(
+                    settled,
+                    "Transfers through {address} are still in progress after {}s",
+                    timeout.as_secs()
+                );
+            } else {
+                let request = StatusRequest::builder().address(&address).build();
 
-            let response = client.status(&request).await?;
-            print_status(&response, &output)?;
+                let response = client.status(&request).await?;
+                print_status(&response, &output)?;
+            }
         }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assets() -> Vec<SupportedAsset> {
+        serde_json::from_value(serde_json::json!([
+            {
+                "chainId": "1",
+                "chainName": "Ethereum",
+                "token": {
+                    "name": "USD Coin",
+                    "symbol": "USDC",
+                    "address": "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+                    "decimals": 6
+                },
+                "minCheckoutUsd": 10
+            },
+            {
+                "chainId": "1",
+                "chainName": "Ethereum",
+                "token": {
+                    "name": "Ether",
+                    "symbol": "ETH",
+                    "address": "0xEeeeeEeeeEeEeeEeEeEeeEEEeeeeEeeeeeeeEEeE",
+                    "decimals": 18
+                },
+                "minCheckoutUsd": 10
+            }
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn assets_match_by_symbol_or_address_on_their_chain() {
+        let assets = assets();
+        assert_eq!(find_asset(&assets, 1, "usdc").unwrap().token.symbol, "USDC");
+        assert_eq!(
+            find_asset(&assets, 1, "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")
+                .unwrap()
+                .token
+                .symbol,
+            "USDC"
+        );
+        let err = find_asset(&assets, 1, "DAI").unwrap_err().to_string();
+        assert!(err.contains("USDC, ETH"), "{err}");
+        assert!(find_asset(&assets, 8453, "USDC").is_err());
+        assert_eq!(gas_token(&assets, 1), "ETH");
+        assert_eq!(gas_token(&assets, 8453), "native token");
+    }
+
+    #[test]
+    fn transfers_settle_once_all_complete_or_fail() {
+        let status = |states: &[&str]| -> StatusResponse {
+            let transactions: Vec<_> = states
+                .iter()
+                .map(|state| {
+                    serde_json::json!({
+                        "fromChainId": "1",
+                        "fromTokenAddress": "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+                        "fromAmountBaseUnit": "25000000",
+                        "toChainId": "137",
+                        "toTokenAddress": "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174",
+                        "status": state,
+                    })
+                })
+                .collect();
+            serde_json::from_value(serde_json::json!({ "transactions": transactions })).unwrap()
+        };
+        assert!(!all_settled(&status(&[])));
+        assert!(!all_settled(&status(&["COMPLETED", "PROCESSING"])));
+        assert!(all_settled(&status(&["COMPLETED", "FAILED"])));
+    }
+}
@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Args;
+use polymarket_client_sdk::gamma::{self, types::request::MarketsRequest};
+use polymarket_client_sdk::types::Decimal;
+
+use super::clob::run_ladder;
+use crate::tui::browse::{self, BrowseExit};
+use crate::tui::ladder::LadderConfig;
+
+#[derive(Args)]
+pub struct BrowseArgs {
+    /// Number of active markets to load (highest volume first)
+    #[arg(long, default_value = "200")]
+    pub limit: i32,
+}
+
+pub async fn execute(
+    client: &gamma::Client,
+    args: BrowseArgs,
+    private_key: Option<&str>,
+    signature_type: Option<&str>,
+) -> Result<()> {
+    let request = MarketsRequest::builder()
+        .limit(args.limit)
+        .closed(false)
+        .order("volume_num".into())
+        .ascending(false)
+        .build();
+    let markets = client.markets(&request).await?;
+
+    match browse::run(markets).await? {
+        BrowseExit::Quit => Ok(()),
+        BrowseExit::Ladder(token_id) => {
+            run_ladder(
+                LadderConfig {
+                    token_id,
+                    size: Decimal::TEN,
+                    interval: Duration::from_secs(1),
+                },
+                private_key,
+                signature_type,
+            )
+            .await
+        }
+    }
+}
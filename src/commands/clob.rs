@@ -1,38 +1,79 @@
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
-use anyhow::Result;
-use chrono::NaiveDate;
+use alloy::sol_types::{SolStruct as _, eip712_domain};
+use anyhow::{Context as _, Result};
+use chrono::{DateTime, Datelike as _, NaiveDate, Utc};
 use clap::{Args, Subcommand};
+use futures::{StreamExt as _, TryStreamExt as _};
+use polymarket_client_sdk::auth::state::Authenticated;
+use polymarket_client_sdk::auth::{Normal, Signer as _};
 use polymarket_client_sdk::clob;
+use polymarket_client_sdk::clob::types::response::{
+    NotificationResponse, OpenOrderResponse, OrderSummary, Page, PostOrderResponse,
+    TotalUserEarningResponse, TradeResponse, UserEarningResponse,
+};
 use polymarket_client_sdk::clob::types::{
-    Amount, AssetType, Interval, OrderType, Side, TimeRange,
+    Amount, AssetType, Interval, OrderStatusType, OrderType, Side, SignedOrder, TimeRange,
+    TradeStatusType,
     request::{
         BalanceAllowanceRequest, CancelMarketOrderRequest, DeleteNotificationsRequest,
         LastTradePriceRequest, MidpointRequest, OrderBookSummaryRequest, OrdersRequest,
         PriceHistoryRequest, PriceRequest, SpreadRequest, TradesRequest, UserRewardsEarningRequest,
     },
 };
-use polymarket_client_sdk::types::{Decimal, U256};
-
-use super::parse_condition_id;
-use crate::auth;
-use crate::output::OutputFormat;
+use polymarket_client_sdk::clob::ws::types::response::WsMessage;
+use polymarket_client_sdk::contract_config;
+use polymarket_client_sdk::data::types::request::PositionsRequest;
+use polymarket_client_sdk::types::{Address, B256, Decimal, U256};
+use rust_decimal::RoundingStrategy;
+use rust_decimal::prelude::{FromPrimitive as _, ToPrimitive as _};
+use serde::{Deserialize, Serialize};
+
+use super::pnl::fills_from_trade;
+use super::{
+    parse_condition_id, parse_time_bound, resolve_condition_arg, resolve_condition_list_arg,
+    resolve_token_arg, resolve_token_list_arg,
+};
+use crate::algo::bracket::Bracket;
+use crate::algo::guard::Guard;
+use crate::algo::trailing::TrailingStop;
+use crate::algo::{self, iceberg::IcebergState, stop::StopOrder, twap::TwapState};
+use crate::order_file::{self, OrderSpec};
+use crate::output::algo::format_interval;
 use crate::output::clob::{
-    print_account_status, print_api_keys, print_balance, print_batch_prices, print_cancel_result,
-    print_clob_market, print_clob_markets, print_create_api_key, print_current_rewards,
-    print_delete_api_key, print_earnings, print_fee_rate, print_geoblock, print_last_trade,
-    print_last_trades_prices, print_market_reward, print_midpoint, print_midpoints, print_neg_risk,
-    print_notifications, print_ok, print_order_book, print_order_books, print_order_detail,
-    print_order_scoring, print_orders, print_orders_scoring, print_post_order_result,
-    print_post_orders_result, print_price, print_price_history, print_reward_percentages,
-    print_rewards, print_server_time, print_simplified_markets, print_spread, print_spreads,
-    print_tick_size, print_trades, print_user_earnings_markets,
+    END_CURSOR, EarningsRange, FeePreview, RewardQuote, RewardSuggestion, TickerQuote,
+    TokenBalance, earnings_csv, from_raw_decimal, print_account_status, print_api_keys,
+    print_balance, print_balances, print_batch_prices, print_cancel_result, print_clob_market,
+    print_clob_markets, print_clob_tokens, print_create_api_key, print_current_rewards,
+    print_delete_api_key, print_earnings, print_earnings_range, print_earnings_summary,
+    print_fee_preview, print_fee_rate, print_geoblock, print_last_trade, print_last_trades_prices,
+    print_market_reward, print_midpoint, print_midpoints, print_neg_risk, print_notifications,
+    print_ok, print_order_book, print_order_books, print_order_detail, print_order_file_results,
+    print_order_scoring, print_order_update, print_orders, print_orders_scoring,
+    print_post_order_result, print_post_orders_result, print_price, print_price_chart,
+    print_price_history, print_price_ticker, print_replace_result, print_reward_percentages,
+    print_reward_suggestions, print_rewards, print_rotate_api_key, print_server_time,
+    print_simplified_markets, print_spread, print_spreads, print_tick_size, print_trade_update,
+    print_trades, print_user_earnings_markets,
 };
+use crate::output::dry_run::{DryRunOrder, net_of_fee, print_dry_run_orders, taker_fee};
+use crate::output::{OutputFormat, color, print_json, write_atomic};
+use crate::poll::Poller;
+use crate::price_expr::{BookQuote, PriceExpr, fit_to_tick, round_to_tick};
+use crate::tui::ladder::LadderConfig;
+use crate::{auth, config, endpoints, tui};
 
 #[derive(Args)]
 pub struct ClobArgs {
     #[command(subcommand)]
     pub command: ClobCommand,
+
+    /// Outcome to trade or quote when a token is given as a market slug or URL (e.g. yes)
+    #[arg(long, global = true)]
+    pub outcome: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -89,6 +130,12 @@ pub enum ClobCommand {
     Book {
         /// Token ID (numeric string)
         token_id: String,
+        /// Show only the best N levels on each side
+        #[arg(long, value_name = "N")]
+        depth: Option<usize>,
+        /// Merge levels into buckets this many ticks wide
+        #[arg(long, value_name = "TICKS", value_parser = clap::value_parser!(u32).range(1..))]
+        group: Option<u32>,
     },
 
     /// Get order books for multiple tokens
@@ -115,32 +162,34 @@ pub enum ClobCommand {
         condition_id: String,
     },
 
+    /// List each outcome of a market with its token ID and current price
+    Tokens {
+        /// Condition ID (0x-prefixed hex), market slug, or URL
+        condition_id: String,
+    },
+
     /// List CLOB markets
     Markets {
-        /// Pagination cursor
-        #[arg(long)]
-        cursor: Option<String>,
+        #[command(flatten)]
+        page: PageArgs,
     },
 
     /// List sampling markets (reward-eligible)
     SamplingMarkets {
-        /// Pagination cursor
-        #[arg(long)]
-        cursor: Option<String>,
+        #[command(flatten)]
+        page: PageArgs,
     },
 
     /// List simplified markets (reduced detail)
     SimplifiedMarkets {
-        /// Pagination cursor
-        #[arg(long)]
-        cursor: Option<String>,
+        #[command(flatten)]
+        page: PageArgs,
     },
 
     /// List simplified sampling markets
     SamplingSimpMarkets {
-        /// Pagination cursor
-        #[arg(long)]
-        cursor: Option<String>,
+        #[command(flatten)]
+        page: PageArgs,
     },
 
     /// Get tick size for a token
@@ -155,6 +204,21 @@ pub enum ClobCommand {
         token_id: String,
     },
 
+    /// Show the fee and net cost or proceeds of an order before placing it
+    FeePreview {
+        /// Token ID (numeric string)
+        token_id: String,
+        /// Side: buy or sell
+        #[arg(long)]
+        side: CliSide,
+        /// Limit price (e.g. 0.50)
+        #[arg(long)]
+        price: Decimal,
+        /// Size (number of shares, e.g. 10)
+        #[arg(long)]
+        size: Decimal,
+    },
+
     /// Check neg-risk status for a token
     NegRisk {
         /// Token ID (numeric string)
@@ -171,6 +235,12 @@ pub enum ClobCommand {
         /// Number of data points
         #[arg(long)]
         fidelity: Option<u32>,
+        /// Draw a line chart instead of the table (table output only)
+        #[arg(long)]
+        chart: bool,
+        /// Use a logarithmic price axis for --chart
+        #[arg(long, requires = "chart")]
+        log_scale: bool,
     },
 
     /// Get CLOB server time
@@ -187,9 +257,17 @@ pub enum ClobCommand {
         /// Filter by asset/token ID
         #[arg(long)]
         asset: Option<String>,
-        /// Pagination cursor
+        /// Only orders with this status
         #[arg(long)]
-        cursor: Option<String>,
+        status: Option<CliOrderStatus>,
+        /// Only buy or sell orders
+        #[arg(long)]
+        side: Option<CliSide>,
+        /// Order the table by price or by age, oldest first
+        #[arg(long)]
+        sort: Option<OrderSort>,
+        #[command(flatten)]
+        page: PageArgs,
     },
 
     /// Get a single order by ID (authenticated)
@@ -206,37 +284,268 @@ pub enum ClobCommand {
         /// Side: buy or sell
         #[arg(long)]
         side: CliSide,
-        /// Price (decimal, e.g. 0.50)
-        #[arg(long)]
+        /// Price: a decimal (0.50), or mid, bid, or ask from the live book with an
+        /// optional offset in price or ticks (mid-0.01, ask+1t)
+        #[arg(long, allow_hyphen_values = true)]
         price: String,
         /// Size (number of shares, e.g. 10)
         #[arg(long)]
         size: String,
-        /// Order type: GTC, FOK, GTD, FAK (default: GTC)
-        #[arg(long, default_value = "GTC")]
+        /// Order type: GTC, FOK, GTD, FAK (default: GTC, or `config set order_type`)
+        #[arg(long, value_enum, default_value_t = config::default_order_type())]
         order_type: CliOrderType,
         /// Post-only order
         #[arg(long)]
         post_only: bool,
+        /// Round a price between ticks to the next tick away from the spread
+        /// (down for buys, up for sells) instead of rejecting it
+        #[arg(long)]
+        round_to_tick: bool,
+        /// Send orders below the market's minimum size instead of rejecting
+        /// them, e.g. when the minimum shown is out of date
+        #[arg(long)]
+        force: bool,
+        #[command(flatten)]
+        expiry: ExpiryArgs,
     },
 
     /// Post multiple orders at once (authenticated)
     PostOrders {
         /// Token IDs (comma-separated, one per order)
-        #[arg(long)]
-        tokens: String,
+        #[arg(long, required_unless_present = "file")]
+        tokens: Option<String>,
         /// Side: buy or sell (same for all)
+        #[arg(long, required_unless_present_any = ["sides", "file"], conflicts_with = "sides")]
+        side: Option<CliSide>,
+        /// Sides (comma-separated, one per order), e.g. buy,sell
+        #[arg(long, value_delimiter = ',')]
+        sides: Vec<CliSide>,
+        /// Prices (comma-separated, one per order)
+        #[arg(long, required_unless_present = "file")]
+        prices: Option<String>,
+        /// Sizes (comma-separated, one per order)
+        #[arg(long, required_unless_present = "file")]
+        sizes: Option<String>,
+        /// Order type: GTC, FOK, GTD, FAK (default: GTC, or `config set order_type`)
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = config::default_order_type(),
+            conflicts_with = "order_types"
+        )]
+        order_type: CliOrderType,
+        /// Order types (comma-separated, one per order), e.g. GTC,GTD
+        #[arg(long, value_delimiter = ',')]
+        order_types: Vec<CliOrderType>,
+        #[command(flatten)]
+        expiry: ExpiryArgs,
+        /// Read orders from a JSON or CSV order file instead of the flags above
+        #[arg(
+            long,
+            value_name = "FILE",
+            conflicts_with_all = ["tokens", "side", "sides", "prices", "sizes", "order_types", "expires_at", "expires_in"]
+        )]
+        file: Option<PathBuf>,
+        /// Round a price between ticks to the next tick away from the spread
+        /// (down for buys, up for sells) instead of rejecting it
+        #[arg(long)]
+        round_to_tick: bool,
+        /// Send orders below the market's minimum size instead of rejecting
+        /// them, e.g. when the minimum shown is out of date
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Post a ladder of limit orders spread evenly across a price range (authenticated)
+    LadderOrders {
+        /// Token ID (numeric string)
+        #[arg(long)]
+        token: String,
+        /// Side: buy or sell
         #[arg(long)]
         side: CliSide,
-        /// Prices (comma-separated, one per order)
+        /// First price of the range
         #[arg(long)]
-        prices: String,
-        /// Sizes (comma-separated, one per order)
+        from: Decimal,
+        /// Last price of the range (inclusive)
+        #[arg(long)]
+        to: Decimal,
+        /// Number of orders
+        #[arg(long, default_value = "5")]
+        levels: usize,
+        /// Total shares across all levels
+        #[arg(long)]
+        total_size: Decimal,
+        /// Put more size further from the market (default: equal sizes)
         #[arg(long)]
-        sizes: String,
-        /// Order type: GTC, FOK, GTD, FAK (default: GTC)
-        #[arg(long, default_value = "GTC")]
+        skew: Option<LadderSkew>,
+        /// Order type: GTC, FOK, GTD, FAK (default: GTC, or `config set order_type`)
+        #[arg(long, value_enum, default_value_t = config::default_order_type())]
         order_type: CliOrderType,
+        /// Post-only orders
+        #[arg(long)]
+        post_only: bool,
+        /// Send orders below the market's minimum size instead of rejecting
+        /// them, e.g. when the minimum shown is out of date
+        #[arg(long)]
+        force: bool,
+        #[command(flatten)]
+        expiry: ExpiryArgs,
+    },
+
+    /// Work a large order over time: take an equal slice from the book at each
+    /// interval. Ctrl+C stops, and a stopped run can be resumed (authenticated)
+    Twap {
+        /// Token ID (numeric string)
+        #[arg(long, required_unless_present = "resume")]
+        token: Option<String>,
+        /// Side: buy or sell
+        #[arg(long, required_unless_present = "resume")]
+        side: Option<CliSide>,
+        /// Total shares to trade
+        #[arg(long, required_unless_present = "resume")]
+        total: Option<Decimal>,
+        /// How long to spread the order over, e.g. 45m, 2h, 1d
+        #[arg(long, required_unless_present = "resume")]
+        duration: Option<String>,
+        /// Number of child orders
+        #[arg(long, default_value = "12")]
+        slices: u32,
+        /// Worst price a slice accepts: a decimal or a book expression such as
+        /// ask+2t (default: whatever the book offers)
+        #[arg(long, allow_hyphen_values = true)]
+        price: Option<String>,
+        /// State file for this run (default: a new file in ~/.config/polymarket/algos)
+        #[arg(long, value_name = "FILE", conflicts_with = "resume")]
+        state: Option<PathBuf>,
+        /// Continue a stopped run from its state file
+        #[arg(
+            long,
+            value_name = "FILE",
+            conflicts_with_all = ["token", "side", "total", "duration", "price"]
+        )]
+        resume: Option<PathBuf>,
+    },
+
+    /// Show only part of a large limit order: keep a small visible order on
+    /// the book and post the next one from the hidden rest as it fills.
+    /// Ctrl+C cancels the visible order, and a stopped run can be resumed
+    /// (authenticated)
+    Iceberg {
+        /// Token ID (numeric string)
+        #[arg(long, required_unless_present = "resume")]
+        token: Option<String>,
+        /// Side: buy or sell
+        #[arg(long, required_unless_present = "resume")]
+        side: Option<CliSide>,
+        /// Limit price of every visible order
+        #[arg(long, required_unless_present = "resume")]
+        price: Option<Decimal>,
+        /// Shares visible on the book at a time
+        #[arg(long, required_unless_present = "resume")]
+        display: Option<Decimal>,
+        /// Total shares to trade
+        #[arg(long, required_unless_present = "resume")]
+        total: Option<Decimal>,
+        /// Seconds between checks of the visible order, in case a fill is
+        /// missed on the user channel
+        #[arg(long, default_value = "10")]
+        poll: u64,
+        /// State file for this run (default: a new file in ~/.config/polymarket/algos)
+        #[arg(long, value_name = "FILE", conflicts_with = "resume")]
+        state: Option<PathBuf>,
+        /// Continue a stopped run from its state file
+        #[arg(
+            long,
+            value_name = "FILE",
+            conflicts_with_all = ["token", "side", "price", "display", "total"]
+        )]
+        resume: Option<PathBuf>,
+    },
+
+    /// Sell (or buy) once the price crosses a trigger, since the CLOB has no
+    /// stop orders. Runs until it fires or Ctrl+C cancels it (authenticated)
+    Stop {
+        /// Token ID (numeric string)
+        #[arg(long)]
+        token: String,
+        /// Side: buy or sell. Sell stops fire when the best bid falls to the
+        /// trigger, buy stops when the best ask rises to it
+        #[arg(long)]
+        side: CliSide,
+        /// Price that fires the stop
+        #[arg(long)]
+        trigger: Decimal,
+        /// Shares to trade once triggered
+        #[arg(long)]
+        size: Decimal,
+        /// Post a limit order at this price instead of a market order
+        #[arg(long)]
+        limit: Option<Decimal>,
+        /// Seconds between price checks
+        #[arg(long, default_value = "5")]
+        interval: u64,
+    },
+
+    /// A stop whose trigger follows the price as it moves favorably and fires
+    /// when it retraces by the trail. Runs until it fires or Ctrl+C cancels
+    /// it (authenticated)
+    TrailingStop {
+        /// Token ID (numeric string)
+        #[arg(long)]
+        token: String,
+        /// Side: buy or sell. Sell stops trail the best bid, buy stops the best ask
+        #[arg(long)]
+        side: CliSide,
+        /// How far the price may retrace from its best before the stop fires
+        #[arg(long)]
+        trail: Decimal,
+        /// Shares to trade once triggered
+        #[arg(long)]
+        size: Decimal,
+        /// Seconds between checks of the book, in case the price stream misses
+        /// an update
+        #[arg(long, default_value = "30")]
+        interval: u64,
+    },
+
+    /// Exit a position with a take-profit limit order and a stop, linked so
+    /// whichever executes first cancels the other. Runs until one leg
+    /// executes or Ctrl+C cancels both (authenticated)
+    Bracket {
+        /// Token ID (numeric string)
+        #[arg(long)]
+        token: String,
+        /// Side of both legs: sell to exit a long position, buy to exit a short
+        #[arg(long)]
+        side: CliSide,
+        /// Shares to exit
+        #[arg(long)]
+        size: Decimal,
+        /// Limit price of the take-profit order
+        #[arg(long)]
+        take_profit: Decimal,
+        /// Price that fires the stop
+        #[arg(long)]
+        stop: Decimal,
+        /// Post the stop as a limit order at this price instead of a market order
+        #[arg(long)]
+        stop_limit: Option<Decimal>,
+        /// Seconds between checks
+        #[arg(long, default_value = "5")]
+        interval: u64,
+    },
+
+    /// Cancel all open orders if heartbeats to the CLOB stop getting
+    /// through. Runs until it trips or Ctrl+C stops it, canceling all open
+    /// orders either way (authenticated)
+    Guard {
+        /// Longest time without a good heartbeat, e.g. 30s or 2m
+        #[arg(long, default_value = "30s")]
+        ttl: String,
+        /// Consecutive missed heartbeats that trip the guard
+        #[arg(long, default_value = "3", value_parser = clap::value_parser!(u32).range(1..))]
+        misses: u32,
     },
 
     /// Create a market order (authenticated)
@@ -255,6 +564,30 @@ pub enum ClobCommand {
         order_type: CliOrderType,
     },
 
+    /// Cancel an order and immediately post a replacement with a new price or
+    /// size, keeping its token, side, order type, and expiration (authenticated)
+    Replace {
+        /// Order ID to replace
+        order_id: String,
+        /// New price: a decimal, or mid, bid, or ask with an offset (mid-0.01, ask+1t)
+        #[arg(long, allow_hyphen_values = true)]
+        price: String,
+        /// New size (default: the original order's unfilled size)
+        #[arg(long)]
+        size: Option<String>,
+        /// Post-only replacement
+        #[arg(long)]
+        post_only: bool,
+        /// Round a price between ticks to the next tick away from the spread
+        /// (down for buys, up for sells) instead of rejecting it
+        #[arg(long)]
+        round_to_tick: bool,
+        /// Send orders below the market's minimum size instead of rejecting
+        /// them, e.g. when the minimum shown is out of date
+        #[arg(long)]
+        force: bool,
+    },
+
     /// Cancel an order by ID (authenticated)
     Cancel {
         /// Order ID to cancel
@@ -288,9 +621,25 @@ pub enum ClobCommand {
         /// Filter by asset/token ID
         #[arg(long)]
         asset: Option<String>,
-        /// Pagination cursor
+        /// Only trades at or after this time: YYYY-MM-DD, RFC 3339, a Unix
+        /// timestamp, or an age like 7d
         #[arg(long)]
-        cursor: Option<String>,
+        after: Option<String>,
+        /// Only trades at or before this time (same formats as --after)
+        #[arg(long)]
+        before: Option<String>,
+        /// Only trades where you bought or sold. Applied to each page as it is
+        /// fetched, as are --status and --min-size; add --all to search every page
+        #[arg(long)]
+        side: Option<CliSide>,
+        /// Only trades with this settlement status
+        #[arg(long)]
+        status: Option<CliTradeStatus>,
+        /// Only trades where you traded at least this many shares
+        #[arg(long)]
+        min_size: Option<Decimal>,
+        #[command(flatten)]
+        page: PageArgs,
     },
 
     /// Get balance and allowance (authenticated)
@@ -303,6 +652,10 @@ pub enum ClobCommand {
         token: Option<String>,
     },
 
+    /// USDC balance and allowances plus the share balance of every token
+    /// with an open order or position, in USDC and share units (authenticated)
+    Balances,
+
     /// Refresh balance allowance on-chain (authenticated)
     UpdateBalance {
         /// Asset type: collateral or conditional
@@ -314,7 +667,18 @@ pub enum ClobCommand {
     },
 
     /// List notifications (authenticated)
-    Notifications,
+    Notifications {
+        /// Keep polling and print new notifications as they arrive (Ctrl+C to stop)
+        #[arg(long)]
+        follow: bool,
+        /// Seconds between polls
+        #[arg(long, default_value = "30", requires = "follow")]
+        interval: u64,
+        /// Shell command to run for each new notification, with
+        /// `POLYMARKET_NOTIFICATION_*` set
+        #[arg(long, value_name = "COMMAND", requires = "follow")]
+        exec: Option<String>,
+    },
 
     /// Delete notifications by IDs (authenticated)
     DeleteNotifications {
@@ -324,19 +688,27 @@ pub enum ClobCommand {
 
     /// List reward earnings (authenticated)
     Rewards {
-        /// Date (YYYY-MM-DD)
-        #[arg(long)]
-        date: String,
-        /// Pagination cursor
-        #[arg(long)]
-        cursor: Option<String>,
+        #[command(flatten)]
+        days: DayArgs,
+        #[command(flatten)]
+        page: PageArgs,
     },
 
-    /// Get total earnings for a date (authenticated)
+    /// Get total earnings for a date or range of dates (authenticated)
     Earnings {
-        /// Date (YYYY-MM-DD)
+        #[command(flatten)]
+        days: DayArgs,
+    },
+
+    /// Reward earnings for a calendar month, per day and per market, with an
+    /// optional CSV export (authenticated)
+    EarningsSummary {
+        /// Month (YYYY-MM); the current month runs up to today
         #[arg(long)]
-        date: String,
+        month: String,
+        /// Also write every day and market, with subtotals, to this CSV file
+        #[arg(long, value_name = "FILE")]
+        csv: Option<PathBuf>,
     },
 
     /// Get earnings with market reward config (authenticated)
@@ -354,18 +726,31 @@ pub enum ClobCommand {
 
     /// List current reward programs (authenticated)
     CurrentRewards {
-        /// Pagination cursor
-        #[arg(long)]
-        cursor: Option<String>,
+        #[command(flatten)]
+        page: PageArgs,
     },
 
     /// Get reward details for a market (authenticated)
     MarketReward {
         /// Market condition ID
         condition_id: String,
-        /// Pagination cursor
+        #[command(flatten)]
+        page: PageArgs,
+    },
+
+    /// Suggest bid and ask quotes that score for a market's liquidity rewards
+    /// while sitting as far from the midpoint as possible (authenticated)
+    RewardsSuggest {
+        /// Market condition ID
+        condition_id: String,
+        /// Shares per quote (default: the market's minimum for rewards)
         #[arg(long)]
-        cursor: Option<String>,
+        size: Option<Decimal>,
+        /// Share of the best possible score to aim for, from 0 to 1. Lower
+        /// values quote further from the midpoint, where fills are less
+        /// likely to be against informed traders
+        #[arg(long, default_value = "0.25")]
+        score: Decimal,
     },
 
     /// Check if an order is scoring rewards (authenticated)
@@ -389,16 +774,100 @@ pub enum ClobCommand {
     /// Create or derive an API key (authenticated)
     CreateApiKey,
 
+    /// Replace the API key with a new one and delete the old one (authenticated)
+    RotateApiKey,
+
     /// Check account status (authenticated)
     AccountStatus,
+
+    /// Keyboard-driven price ladder for quoting a token (authenticated, full-screen)
+    Ladder {
+        /// Token ID (numeric string)
+        token: String,
+        /// Order size in shares (adjust with +/- in the ladder)
+        #[arg(long, default_value = "10")]
+        size: String,
+        /// Refresh interval in milliseconds
+        #[arg(long, default_value = "1000")]
+        interval_ms: u64,
+    },
+
+    /// Live ticker of best bid/ask/mid for multiple tokens
+    WatchPrices {
+        /// Token IDs (comma-separated numeric strings)
+        #[arg(long)]
+        tokens: String,
+    },
+
+    /// Stream your order updates and trade fills in real time (authenticated)
+    WatchUser {
+        /// Only watch these market condition IDs (comma-separated, default: all)
+        #[arg(long)]
+        markets: Option<String>,
+    },
 }
 
-#[derive(Clone, Debug, clap::ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum CliSide {
     Buy,
     Sell,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum CliOrderStatus {
+    Live,
+    Matched,
+    #[value(alias = "canceled")]
+    Cancelled,
+}
+
+impl From<CliOrderStatus> for OrderStatusType {
+    fn from(s: CliOrderStatus) -> Self {
+        match s {
+            CliOrderStatus::Live => OrderStatusType::Live,
+            CliOrderStatus::Matched => OrderStatusType::Matched,
+            CliOrderStatus::Cancelled => OrderStatusType::Canceled,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum OrderSort {
+    Price,
+    Age,
+}
+
+impl OrderSort {
+    fn apply(self, orders: &mut [OpenOrderResponse]) {
+        match self {
+            Self::Price => orders.sort_by_key(|o| o.price),
+            Self::Age => orders.sort_by_key(|o| o.created_at),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum CliTradeStatus {
+    Matched,
+    Mined,
+    Confirmed,
+    Retrying,
+    Failed,
+}
+
+impl From<CliTradeStatus> for TradeStatusType {
+    fn from(s: CliTradeStatus) -> Self {
+        match s {
+            CliTradeStatus::Matched => TradeStatusType::Matched,
+            CliTradeStatus::Mined => TradeStatusType::Mined,
+            CliTradeStatus::Confirmed => TradeStatusType::Confirmed,
+            CliTradeStatus::Retrying => TradeStatusType::Retrying,
+            CliTradeStatus::Failed => TradeStatusType::Failed,
+        }
+    }
+}
+
 impl From<CliSide> for Side {
     fn from(s: CliSide) -> Self {
         match s {
@@ -408,6 +877,18 @@ impl From<CliSide> for Side {
     }
 }
 
+impl TryFrom<Side> for CliSide {
+    type Error = anyhow::Error;
+
+    fn try_from(s: Side) -> Result<Self> {
+        match s {
+            Side::Buy => Ok(CliSide::Buy),
+            Side::Sell => Ok(CliSide::Sell),
+            other => anyhow::bail!("Unsupported order side: {other}"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, clap::ValueEnum)]
 pub enum CliInterval {
     #[value(name = "1m")]
@@ -436,7 +917,8 @@ impl From<CliInterval> for Interval {
     }
 }
 
-#[derive(Clone, Debug, clap::ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
 pub enum CliOrderType {
     #[value(name = "GTC")]
     Gtc,
@@ -459,6 +941,20 @@ impl From<CliOrderType> for OrderType {
     }
 }
 
+impl TryFrom<OrderType> for CliOrderType {
+    type Error = anyhow::Error;
+
+    fn try_from(o: OrderType) -> Result<Self> {
+        match o {
+            OrderType::GTC => Ok(CliOrderType::Gtc),
+            OrderType::FOK => Ok(CliOrderType::Fok),
+            OrderType::GTD => Ok(CliOrderType::Gtd),
+            OrderType::FAK => Ok(CliOrderType::Fak),
+            other => anyhow::bail!("Unsupported order type: {other}"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, clap::ValueEnum)]
 pub enum CliAssetType {
     Collateral,
@@ -474,100 +970,1470 @@ impl From<CliAssetType> for AssetType {
     }
 }
 
-fn parse_token_id(s: &str) -> Result<U256> {
-    U256::from_str(s).map_err(|_| anyhow::anyhow!("Invalid token ID: {s}"))
+/// Authenticates and opens the full-screen price ladder.
+pub(crate) async fn run_ladder(
+    config: LadderConfig,
+    private_key: Option<&str>,
+    signature_type: Option<&str>,
+) -> Result<()> {
+    let signer = auth::resolve_signer(private_key)?;
+    let client = auth::authenticate_with_signer(&signer, signature_type).await?;
+    tui::ladder::run(client, signer, config).await
 }
 
-fn parse_token_ids(s: &str) -> Result<Vec<U256>> {
-    s.split(',').map(|t| parse_token_id(t.trim())).collect()
+/// Cursor pagination for list commands.
+#[derive(Args, Clone, Debug, Default)]
+pub struct PageArgs {
+    /// Pagination cursor
+    #[arg(long)]
+    pub cursor: Option<String>,
+    /// Follow cursors and fetch every page
+    #[arg(long)]
+    pub all: bool,
+    /// Stop following cursors after this many pages (implies --all)
+    #[arg(long, value_name = "N")]
+    pub max_pages: Option<u32>,
 }
 
-fn parse_date(s: &str) -> Result<NaiveDate> {
-    NaiveDate::parse_from_str(s, "%Y-%m-%d")
-        .map_err(|_| anyhow::anyhow!("Invalid date: expected YYYY-MM-DD format"))
+/// A single `--date`, or a `--from`/`--to` range of days.
+#[derive(Args, Clone, Debug)]
+pub struct DayArgs {
+    /// Date (YYYY-MM-DD)
+    #[arg(long, required_unless_present = "from", conflicts_with_all = ["from", "to"])]
+    pub date: Option<String>,
+    /// First day of a range (YYYY-MM-DD), summed per day and per market
+    #[arg(long)]
+    pub from: Option<String>,
+    /// Last day of the range, inclusive (YYYY-MM-DD, default: today)
+    #[arg(long, requires = "from")]
+    pub to: Option<String>,
 }
 
-pub async fn execute(
-    args: ClobArgs,
-    output: OutputFormat,
-    private_key: Option<&str>,
-    signature_type: Option<&str>,
-) -> Result<()> {
-    match args.command {
-        // Unauthenticated read commands
-        ClobCommand::Ok
-        | ClobCommand::Price { .. }
-        | ClobCommand::BatchPrices { .. }
-        | ClobCommand::Midpoint { .. }
-        | ClobCommand::Midpoints { .. }
-        | ClobCommand::Spread { .. }
-        | ClobCommand::Spreads { .. }
-        | ClobCommand::Book { .. }
-        | ClobCommand::Books { .. }
-        | ClobCommand::LastTrade { .. }
-        | ClobCommand::LastTrades { .. }
-        | ClobCommand::Market { .. }
-        | ClobCommand::Markets { .. }
-        | ClobCommand::SamplingMarkets { .. }
-        | ClobCommand::SimplifiedMarkets { .. }
-        | ClobCommand::SamplingSimpMarkets { .. }
-        | ClobCommand::TickSize { .. }
-        | ClobCommand::FeeRate { .. }
-        | ClobCommand::NegRisk { .. }
-        | ClobCommand::PriceHistory { .. }
-        | ClobCommand::Time
-        | ClobCommand::Geoblock => execute_read(args.command, &output).await,
+/// Days fetched at once for a `--from`/`--to` range.
+const CONCURRENT_DAYS: usize = 8;
 
-        // Authenticated trading commands
-        ClobCommand::Orders { .. }
-        | ClobCommand::Order { .. }
-        | ClobCommand::CreateOrder { .. }
-        | ClobCommand::PostOrders { .. }
-        | ClobCommand::MarketOrder { .. }
-        | ClobCommand::Cancel { .. }
-        | ClobCommand::CancelOrders { .. }
-        | ClobCommand::CancelAll
-        | ClobCommand::CancelMarket { .. }
-        | ClobCommand::Trades { .. }
-        | ClobCommand::Balance { .. }
-        | ClobCommand::UpdateBalance { .. }
-        | ClobCommand::Notifications
-        | ClobCommand::DeleteNotifications { .. } => {
-            execute_trade(args.command, &output, private_key, signature_type).await
-        }
+/// Nonces `clob rotate-api-key` tries for the new key.
+const ROTATE_NONCE_ATTEMPTS: u32 = 5;
 
-        // Authenticated reward commands
-        ClobCommand::Rewards { .. }
-        | ClobCommand::Earnings { .. }
-        | ClobCommand::EarningsMarkets { .. }
-        | ClobCommand::RewardPercentages
-        | ClobCommand::CurrentRewards { .. }
-        | ClobCommand::MarketReward { .. }
-        | ClobCommand::OrderScoring { .. }
-        | ClobCommand::OrdersScoring { .. } => {
-            execute_rewards(args.command, &output, private_key, signature_type).await
+#[derive(Debug, PartialEq)]
+enum Days {
+    One(NaiveDate),
+    /// Every day from `--from` to `--to`, oldest first
+    Range(Vec<NaiveDate>),
+}
+
+impl DayArgs {
+    fn resolve(&self, today: NaiveDate) -> Result<Days> {
+        if let Some(date) = &self.date {
+            return Ok(Days::One(parse_date(date)?));
         }
+        let from = parse_date(self.from.as_deref().unwrap_or_default())?;
+        let to = self
+            .to
+            .as_deref()
+            .map(parse_date)
+            .transpose()?
+            .unwrap_or(today);
+        anyhow::ensure!(from <= to, "--from {from} is after --to {to}");
+        Ok(Days::Range(
+            from.iter_days().take_while(|d| *d <= to).collect(),
+        ))
+    }
+}
 
-        // Account management commands
+/// Every day of a `YYYY-MM` month, stopping at today for the current month.
+fn month_days(month: &str, today: NaiveDate) -> Result<Vec<NaiveDate>> {
+    let first = NaiveDate::parse_from_str(&format!("{month}-01"), "%Y-%m-%d")
+        .map_err(|_| anyhow::anyhow!("Invalid month: {month}. Use YYYY-MM"))?;
+    anyhow::ensure!(first <= today, "{month} hasn't started yet");
+    Ok(first
+        .iter_days()
+        .take_while(|d| d.month() == first.month() && *d <= today)
+        .collect())
+}
+
+/// Sums earnings per day (every day in `days`, oldest first), per market
+/// (largest first), and per day and market.
+fn earnings_range(
+    days: &[NaiveDate],
+    entries: impl IntoIterator<Item = (NaiveDate, Option<B256>, Decimal)>,
+) -> EarningsRange {
+    let mut daily: Vec<(NaiveDate, Decimal)> = days.iter().map(|d| (*d, Decimal::ZERO)).collect();
+    let mut markets: HashMap<B256, Decimal> = HashMap::new();
+    let mut by_day: HashMap<(NaiveDate, B256), Decimal> = HashMap::new();
+    for (date, market, earnings) in entries {
+        if let Some((_, total)) = daily.iter_mut().find(|(d, _)| *d == date) {
+            *total += earnings;
+        }
+        if let Some(market) = market {
+            *markets.entry(market).or_default() += earnings;
+            *by_day.entry((date, market)).or_default() += earnings;
+        }
+    }
+    let mut markets: Vec<_> = markets.into_iter().collect();
+    markets.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    let mut entries: Vec<_> = by_day
+        .into_iter()
+        .map(|((date, market), earnings)| (date, market, earnings))
+        .collect();
+    entries.sort();
+    EarningsRange {
+        daily,
+        markets,
+        entries,
+    }
+}
+
+/// Fetches every page of per-market earnings for each day, several days at
+/// a time.
+async fn fetch_market_earnings(
+    client: &clob::Client<Authenticated<Normal>>,
+    days: &[NaiveDate],
+) -> Result<EarningsRange> {
+    let per_day: Vec<Vec<UserEarningResponse>> = futures::stream::iter(days.to_vec())
+        .map(|date| async move {
+            let mut earnings = Vec::new();
+            let mut cursor = None;
+            loop {
+                let page = client.earnings_for_user_for_day(date, cursor).await?;
+                earnings.extend(page.data);
+                if page.next_cursor == END_CURSOR {
+                    return Ok::<_, anyhow::Error>(earnings);
+                }
+                cursor = Some(page.next_cursor);
+            }
+        })
+        .buffered(CONCURRENT_DAYS)
+        .try_collect()
+        .await?;
+    Ok(earnings_range(
+        days,
+        per_day
+            .iter()
+            .flatten()
+            .map(|e| (e.date, Some(e.condition_id), e.earnings)),
+    ))
+}
+
+/// Pause between pages so `--all` stays well under the API's rate limits.
+const PAGE_PAUSE: Duration = Duration::from_millis(250);
+
+/// Fetches one page, or with `--all` follows cursors to the last page (or
+/// `--max-pages`). NDJSON is printed page by page as it arrives; other
+/// formats print every row as one listing.
+async fn fetch_pages<T, F, Fut>(
+    page: PageArgs,
+    output: &OutputFormat,
+    mut fetch: F,
+    print: impl Fn(&Page<T>, &OutputFormat) -> Result<()>,
+) -> Result<()>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = polymarket_client_sdk::Result<Page<T>>>,
+{
+    let mut result = fetch(page.cursor).await?;
+    if !page.all && page.max_pages.is_none() {
+        return print(&result, output);
+    }
+    let streaming = matches!(output, OutputFormat::Ndjson);
+    let max_pages = page.max_pages.unwrap_or(u32::MAX);
+    let mut data = Vec::new();
+    let mut pages = 1;
+    loop {
+        let next = result.next_cursor.clone();
+        let last = next == END_CURSOR || next.is_empty() || pages >= max_pages;
+        if streaming {
+            print(&result, output)?;
+        } else {
+            data.append(&mut result.data);
+        }
+        if last {
+            break;
+        }
+        tokio::time::sleep(PAGE_PAUSE).await;
+        result = fetch(Some(next)).await?;
+        pages += 1;
+    }
+    if streaming {
+        return Ok(());
+    }
+    let count = u64::try_from(data.len()).unwrap_or(u64::MAX);
+    let all = Page::builder()
+        .data(data)
+        .next_cursor(result.next_cursor)
+        .limit(result.limit)
+        .count(count)
+        .build();
+    print(&all, output)
+}
+
+/// One side of a book, best price first, optionally merged into `bucket`-wide
+/// price levels and cut to the best `depth`. Buckets round away from the
+/// spread, so a merged level never looks better than the orders in it.
+fn shape_levels(
+    levels: &[OrderSummary],
+    side: Side,
+    bucket: Option<Decimal>,
+    depth: Option<usize>,
+) -> Vec<OrderSummary> {
+    let bids = matches!(side, Side::Buy);
+    let mut shaped: Vec<OrderSummary> = Vec::new();
+    for level in levels {
+        let price = match bucket {
+            Some(b) if bids => (level.price / b).floor() * b,
+            Some(b) => (level.price / b).ceil() * b,
+            None => level.price,
+        };
+        match shaped.iter_mut().find(|l| l.price == price) {
+            Some(merged) => merged.size += level.size,
+            None => shaped.push(
+                OrderSummary::builder()
+                    .price(price.normalize())
+                    .size(level.size)
+                    .build(),
+            ),
+        }
+    }
+    if bids {
+        shaped.sort_by_key(|l| std::cmp::Reverse(l.price));
+    } else {
+        shaped.sort_by_key(|l| l.price);
+    }
+    shaped.truncate(depth.unwrap_or(usize::MAX));
+    shaped
+}
+
+/// A copy of `page` with only the rows `keep` accepts.
+fn filter_page<T: Clone>(page: &Page<T>, keep: impl Fn(&T) -> bool) -> Page<T> {
+    let data: Vec<T> = page.data.iter().filter(|row| keep(row)).cloned().collect();
+    Page::builder()
+        .count(u64::try_from(data.len()).unwrap_or(u64::MAX))
+        .data(data)
+        .next_cursor(page.next_cursor.clone())
+        .limit(page.limit)
+        .build()
+}
+
+/// Client-side `clob trades` filters. Side and size are ours: the trade's
+/// own when we took, our maker orders' when we made.
+struct TradeFilter {
+    side: Option<CliSide>,
+    status: Option<CliTradeStatus>,
+    min_size: Option<Decimal>,
+    funder: Address,
+}
+
+impl TradeFilter {
+    fn keeps(&self, trade: &TradeResponse) -> bool {
+        if self
+            .status
+            .is_some_and(|s| trade.status != TradeStatusType::from(s))
+        {
+            return false;
+        }
+        if self.side.is_none() && self.min_size.is_none() {
+            return true;
+        }
+        fills_from_trade(trade, self.funder).iter().any(|fill| {
+            self.side.is_none_or(|s| fill.side == Side::from(s))
+                && self.min_size.is_none_or(|min| fill.size >= min)
+        })
+    }
+}
+
+/// When a GTD order expires; only valid with `--order-type GTD`.
+#[derive(Args, Clone, Debug, Default)]
+pub struct ExpiryArgs {
+    /// GTD expiry as RFC 3339 (2025-06-01T12:00:00Z) or a Unix timestamp
+    #[arg(long, conflicts_with = "expires_in")]
+    pub expires_at: Option<String>,
+    /// GTD expiry relative to now, e.g. 90s, 45m, 2h30m, 1d
+    #[arg(long)]
+    pub expires_in: Option<String>,
+}
+
+/// The CLOB only honors a GTD expiration that is at least this far out, and
+/// treats the order as expired this long before the timestamp.
+const GTD_SECURITY_THRESHOLD: i64 = 60;
+
+/// Parses durations like `90s`, `45m`, `2h30m`, or `1d12h`.
+pub(crate) fn parse_duration(s: &str) -> Result<chrono::Duration> {
+    let invalid = || anyhow::anyhow!("Invalid duration: {s} (expected e.g. 90s, 45m, 2h30m, 1d)");
+    let mut total = chrono::Duration::zero();
+    let mut digits = String::new();
+    for c in s.trim().chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let n: i64 = digits.parse().map_err(|_| invalid())?;
+        digits.clear();
+        total += match c.to_ascii_lowercase() {
+            'd' => chrono::Duration::days(n),
+            'h' => chrono::Duration::hours(n),
+            'm' => chrono::Duration::minutes(n),
+            's' => chrono::Duration::seconds(n),
+            _ => return Err(invalid()),
+        };
+    }
+    if !digits.is_empty() || total <= chrono::Duration::zero() {
+        return Err(invalid());
+    }
+    Ok(total)
+}
+
+impl ExpiryArgs {
+    /// The expiration to put on the order, checked against `order_type`.
+    /// The CLOB's one-minute security threshold is added so the order stays
+    /// live until the requested time.
+    fn resolve(
+        &self,
+        order_type: CliOrderType,
+        now: DateTime<Utc>,
+    ) -> Result<Option<DateTime<Utc>>> {
+        let requested = match (&self.expires_at, &self.expires_in) {
+            (Some(at), _) => Some(match at.trim().parse::<i64>() {
+                Ok(ts) => DateTime::from_timestamp(ts, 0)
+                    .ok_or_else(|| anyhow::anyhow!("Invalid timestamp: {at}"))?,
+                Err(_) => DateTime::parse_from_rfc3339(at.trim())
+                    .map_err(|_| {
+                        anyhow::anyhow!(
+                            "Invalid --expires-at: {at} (expected RFC 3339 or a Unix timestamp)"
+                        )
+                    })?
+                    .with_timezone(&Utc),
+            }),
+            (None, Some(within)) => Some(now + parse_duration(within)?),
+            (None, None) => None,
+        };
+        match (order_type, requested) {
+            (CliOrderType::Gtd, None) => {
+                anyhow::bail!("GTD orders need --expires-at or --expires-in")
+            }
+            (CliOrderType::Gtd, Some(at)) if at <= now => {
+                anyhow::bail!("Expiration {} is in the past", at.to_rfc3339())
+            }
+            (CliOrderType::Gtd, Some(at)) => {
+                Ok(Some(at + chrono::Duration::seconds(GTD_SECURITY_THRESHOLD)))
+            }
+            (_, Some(_)) => anyhow::bail!("--expires-at and --expires-in require --order-type GTD"),
+            (_, None) => Ok(None),
+        }
+    }
+}
+
+/// One value per order: either the shared `--flag` repeated, or the
+/// comma-separated `--flags` list, which must match the number of orders.
+fn per_order<T: Copy>(shared: Option<T>, list: &[T], orders: usize, name: &str) -> Result<Vec<T>> {
+    match (list, shared) {
+        ([], Some(value)) => Ok(vec![value; orders]),
+        ([], None) => anyhow::bail!("No {name} given"),
+        (list, _) if list.len() == orders => Ok(list.to_vec()),
+        (list, _) => anyhow::bail!(
+            "Got {} {name} for {orders} orders; give one per order",
+            list.len()
+        ),
+    }
+}
+
+/// How `ladder-orders` spreads its total size over the levels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum LadderSkew {
+    /// Size grows by a constant step away from the market
+    Linear,
+    /// Size doubles at each level away from the market
+    Geometric,
+}
+
+/// Prices and sizes for a ladder of `levels` orders from `from` to `to`.
+/// Prices round to the tick away from the market (down for buys, up for
+/// sells); sizes round to hundredths, with any remainder on the level
+/// furthest from the market.
+fn ladder_levels(
+    side: CliSide,
+    (from, to): (Decimal, Decimal),
+    levels: usize,
+    total_size: Decimal,
+    skew: Option<LadderSkew>,
+    tick: Decimal,
+) -> Result<Vec<(Decimal, Decimal)>> {
+    anyhow::ensure!(levels > 0, "--levels must be at least 1");
+    anyhow::ensure!(total_size > Decimal::ZERO, "--total-size must be positive");
+    let steps = Decimal::from(levels.saturating_sub(1).max(1));
+    let mut prices = Vec::with_capacity(levels);
+    for i in 0..levels {
+        let exact = from + (to - from) * Decimal::from(i) / steps;
+        let price = round_to_tick(exact, tick, Side::from(side));
+        anyhow::ensure!(
+            price >= tick && price <= Decimal::ONE - tick,
+            "Ladder price {price} is outside the tradable range {tick} to {}",
+            Decimal::ONE - tick
+        );
+        anyhow::ensure!(
+            prices.last() != Some(&price),
+            "{levels} levels don't fit between {from} and {to} at tick size {tick}"
+        );
+        prices.push(price);
+    }
+
+    // Rank 0 is the level nearest the market: the highest bid or lowest ask.
+    let rank = |price: Decimal| {
+        prices
+            .iter()
+            .filter(|&&p| match side {
+                CliSide::Buy => p > price,
+                CliSide::Sell => p < price,
+            })
+            .count()
+    };
+    let weights: Vec<Decimal> = prices
+        .iter()
+        .map(|&p| match skew {
+            None => Decimal::ONE,
+            Some(LadderSkew::Linear) => Decimal::from(rank(p) + 1),
+            Some(LadderSkew::Geometric) => Decimal::from(1u64 << rank(p).min(62)),
+        })
+        .collect();
+    let total_weight: Decimal = weights.iter().sum();
+    let mut sizes: Vec<Decimal> = weights
+        .iter()
+        .map(|w| {
+            (total_size * w / total_weight).round_dp_with_strategy(2, RoundingStrategy::ToZero)
+        })
+        .collect();
+    let furthest = (0..levels)
+        .max_by_key(|&i| rank(prices[i]))
+        .unwrap_or_default();
+    let allocated: Decimal = sizes.iter().sum();
+    sizes[furthest] += total_size - allocated;
+    anyhow::ensure!(
+        sizes.iter().all(|s| *s > Decimal::ZERO),
+        "--total-size {total_size} is too small to split over {levels} levels"
+    );
+    Ok(prices.into_iter().zip(sizes).collect())
+}
+
+/// Quotes that score for liquidity rewards. An order `s` from the midpoint
+/// scores `((v - s) / v)²` per share of what an order at the midpoint would,
+/// where `v` is the max spread, so each side sits as far out as `score`
+/// allows, rounded toward the midpoint, without crossing the book.
+fn reward_quotes(
+    quote: &BookQuote,
+    max_spread: Decimal,
+    size: Decimal,
+    score: Decimal,
+) -> Result<(Decimal, Vec<RewardQuote>)> {
+    let (Some(bid), Some(ask)) = (quote.best_bid, quote.best_ask) else {
+        anyhow::bail!("The book needs both bids and asks to find the midpoint");
+    };
+    let midpoint = (bid + ask) / Decimal::TWO;
+    let tick = quote.tick_size;
+    let reach = Decimal::ONE
+        - score
+            .to_f64()
+            .and_then(|s| Decimal::from_f64(s.sqrt()))
+            .unwrap_or(Decimal::ONE);
+    let distance = max_spread * reach;
+    let prices = [
+        (
+            Side::Buy,
+            round_to_tick(midpoint - distance, tick, Side::Sell).min(ask - tick),
+        ),
+        (
+            Side::Sell,
+            round_to_tick(midpoint + distance, tick, Side::Buy).max(bid + tick),
+        ),
+    ];
+    let mut quotes = Vec::with_capacity(2);
+    for (side, price) in prices {
+        let from_mid = (price - midpoint).abs();
+        if from_mid >= max_spread || price < tick || price > Decimal::ONE - tick {
+            continue;
+        }
+        let share = (max_spread - from_mid) / max_spread;
+        quotes.push(RewardQuote {
+            side,
+            price,
+            size,
+            distance: from_mid,
+            score: (share * share).round_dp(4),
+        });
+    }
+    anyhow::ensure!(
+        !quotes.is_empty(),
+        "No price within the max spread of {max_spread} around {midpoint} is on the tick grid"
+    );
+    Ok((midpoint, quotes))
+}
+
+/// Notifications carry no ID of their own, so they are told apart by what
+/// they report.
+fn notification_key(n: &NotificationResponse) -> String {
+    let p = &n.payload;
+    format!(
+        "{}:{}:{}:{}",
+        n.r#type, p.order_id, p.trade_id, p.matched_size
+    )
+}
+
+/// What an `--exec` hook sees of a notification.
+fn notification_env(n: &NotificationResponse) -> Vec<(&'static str, String)> {
+    let p = &n.payload;
+    vec![
+        ("POLYMARKET_NOTIFICATION_TYPE", n.r#type.to_string()),
+        ("POLYMARKET_NOTIFICATION_QUESTION", p.question.clone()),
+        ("POLYMARKET_NOTIFICATION_OUTCOME", p.outcome.clone()),
+        ("POLYMARKET_NOTIFICATION_SIDE", p.side.to_string()),
+        ("POLYMARKET_NOTIFICATION_PRICE", p.price.to_string()),
+        ("POLYMARKET_NOTIFICATION_SIZE", p.matched_size.to_string()),
+        ("POLYMARKET_NOTIFICATION_ORDER_ID", p.order_id.clone()),
+        ("POLYMARKET_NOTIFICATION_TRADE_ID", p.trade_id.clone()),
+        ("POLYMARKET_NOTIFICATION_MARKET_SLUG", p.market_slug.clone()),
+    ]
+}
+
+/// A validated limit order, ready to be built and signed.
+#[derive(Debug)]
+struct LimitOrder {
+    token_id: U256,
+    side: CliSide,
+    price: Decimal,
+    size: Decimal,
+    order_type: CliOrderType,
+    post_only: bool,
+    expiration: Option<DateTime<Utc>>,
+}
+
+/// The CLOB accepts at most this many orders per batch request.
+const MAX_BATCH_ORDERS: usize = 15;
+
+/// Checks every order in a file before anything is signed, reporting all
+/// problems at once rather than stopping at the first.
+fn prepare_file_orders(specs: &[OrderSpec], now: DateTime<Utc>) -> Result<Vec<LimitOrder>> {
+    let mut orders = Vec::with_capacity(specs.len());
+    let mut problems = Vec::new();
+    for (i, spec) in specs.iter().enumerate() {
+        let prepared = (|| -> Result<LimitOrder> {
+            let token_id = parse_token_id(spec.token.trim())?;
+            if spec.price <= Decimal::ZERO || spec.price >= Decimal::ONE {
+                anyhow::bail!("price {} must be between 0 and 1", spec.price);
+            }
+            if spec.size <= Decimal::ZERO {
+                anyhow::bail!("size {} must be greater than 0", spec.size);
+            }
+            match (spec.order_type, spec.expiration) {
+                (CliOrderType::Gtd, None) => anyhow::bail!("GTD orders need an expiration"),
+                (CliOrderType::Gtd, _) | (_, None) => {}
+                (_, Some(_)) => anyhow::bail!("only GTD orders may have an expiration"),
+            }
+            let expiry = ExpiryArgs {
+                expires_at: spec.expiration.map(|ts| ts.to_string()),
+                expires_in: None,
+            };
+            Ok(LimitOrder {
+                token_id,
+                side: spec.side,
+                price: spec.price,
+                size: spec.size,
+                order_type: spec.order_type,
+                post_only: false,
+                expiration: expiry.resolve(spec.order_type, now)?,
+            })
+        })();
+        match prepared {
+            Ok(order) => orders.push(order),
+            Err(e) => problems.push(format!("  order {}: {e}", i + 1)),
+        }
+    }
+    if !problems.is_empty() {
+        anyhow::bail!(
+            "{} of {} orders are invalid; nothing was signed:\n{}",
+            problems.len(),
+            specs.len(),
+            problems.join("\n")
+        );
+    }
+    Ok(orders)
+}
+
+/// The price an order should be signed at, or why it can't be.
+fn check_order(
+    order: &LimitOrder,
+    tick: Decimal,
+    min_size: Decimal,
+    round: bool,
+    force: bool,
+) -> Result<Decimal> {
+    let price = fit_to_tick(order.price, tick, Side::from(order.side), round)?;
+    anyhow::ensure!(
+        force || order.size >= min_size,
+        "Size {} is below the market's minimum order size of {min_size}; pass --force to send \
+         it anyway",
+        order.size
+    );
+    Ok(price)
+}
+
+/// Checks each order against its market before anything is signed: the
+/// price must sit on the tick grid (or with `round` is moved onto it), and
+/// unless `force` is set the size must meet the market's minimum.
+async fn preflight_orders(
+    client: &clob::Client<Authenticated<Normal>>,
+    orders: &mut [LimitOrder],
+    round: bool,
+    force: bool,
+) -> Result<()> {
+    let mut markets: HashMap<U256, (Decimal, Decimal)> = HashMap::new();
+    let mut problems = Vec::new();
+    for (i, order) in orders.iter_mut().enumerate() {
+        let (tick, min_size) = match markets.get(&order.token_id) {
+            Some(&limits) => limits,
+            None => {
+                let request = OrderBookSummaryRequest::builder()
+                    .token_id(order.token_id)
+                    .build();
+                let book = client.order_book(&request).await?;
+                let limits = (Decimal::from(book.tick_size), book.min_order_size);
+                markets.insert(order.token_id, limits);
+                limits
+            }
+        };
+        match check_order(order, tick, min_size, round, force) {
+            Ok(price) => order.price = price,
+            Err(e) => problems.push((i, e)),
+        }
+    }
+    match problems.as_slice() {
+        [] => Ok(()),
+        [(_, e)] if orders.len() == 1 => Err(anyhow::anyhow!("{e}")),
+        _ => anyhow::bail!(
+            "{} of {} orders failed preflight checks; nothing was signed:\n{}",
+            problems.len(),
+            orders.len(),
+            problems
+                .iter()
+                .map(|(i, e)| format!("  order {}: {e}", i + 1))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ),
+    }
+}
+
+async fn sign_limit_orders(
+    client: &clob::Client<Authenticated<Normal>>,
+    signer: &auth::WalletSigner,
+    orders: &[LimitOrder],
+) -> Result<Vec<SignedOrder>> {
+    let mut signed_orders = Vec::with_capacity(orders.len());
+    for order in orders {
+        let mut builder = client
+            .limit_order()
+            .token_id(order.token_id)
+            .side(Side::from(order.side))
+            .price(order.price)
+            .size(order.size)
+            .order_type(OrderType::from(order.order_type))
+            .post_only(order.post_only);
+        if let Some(expiration) = order.expiration {
+            builder = builder.expiration(expiration);
+        }
+        signed_orders.push(auth::sign_order(client, signer, builder.build().await?).await?);
+    }
+    Ok(signed_orders)
+}
+
+/// Converts a 6-decimal on-chain amount (USDC or shares) to a decimal.
+fn from_raw_amount(raw: U256) -> Result<Decimal> {
+    let raw = Decimal::from_str(&raw.to_string())
+        .map_err(|_| anyhow::anyhow!("Amount {raw} is out of range"))?;
+    Ok(raw / Decimal::from(1_000_000))
+}
+
+/// Describes a signed order for `--dry-run` and confirmation, including the
+/// EIP-712 hash the exchange would know it by.
+async fn describe_order(
+    client: &clob::Client<Authenticated<Normal>>,
+    signed: &SignedOrder,
+) -> Result<DryRunOrder> {
+    let order = &signed.order;
+    let neg_risk = client.neg_risk(order.tokenId).await?.neg_risk;
+    let exchange = contract_config(config::chain_id(), neg_risk)
+        .ok_or_else(|| anyhow::anyhow!("No exchange contract configured for this chain"))?
+        .exchange;
+    let domain = eip712_domain! {
+        name: "Polymarket CTF Exchange",
+        version: "1",
+        chain_id: config::chain_id(),
+        verifying_contract: exchange,
+    };
+
+    // Buys pay USDC for shares; sells pay shares for USDC.
+    let is_buy = order.side == 0;
+    let (notional, size) = if is_buy {
+        (
+            from_raw_amount(order.makerAmount)?,
+            from_raw_amount(order.takerAmount)?,
+        )
+    } else {
+        (
+            from_raw_amount(order.takerAmount)?,
+            from_raw_amount(order.makerAmount)?,
+        )
+    };
+    let price = if size.is_zero() {
+        Decimal::ZERO
+    } else {
+        (notional / size).round_dp(6)
+    };
+    let fee_rate = Decimal::from_str(&order.feeRateBps.to_string()).unwrap_or_default();
+
+    Ok(DryRunOrder {
+        hash: order.eip712_signing_hash(&domain),
+        token_id: order.tokenId,
+        side: if is_buy { "BUY" } else { "SELL" }.into(),
+        order_type: signed.order_type.to_string(),
+        price,
+        size,
+        notional,
+        fee_rate_bps: order.feeRateBps,
+        taker_fee: taker_fee(fee_rate, price, size),
+        expiration: order.expiration,
+        maker: order.maker,
+        signer: order.signer,
+        payload: serde_json::to_value(signed)?,
+    })
+}
+
+/// Asks before posting, describing each order by market question and outcome.
+async fn confirm_orders(
+    client: &clob::Client<Authenticated<Normal>>,
+    signed_orders: &[SignedOrder],
+) -> Result<()> {
+    let mut orders = Vec::with_capacity(signed_orders.len());
+    for signed in signed_orders {
+        orders.push(describe_order(client, signed).await?);
+    }
+    // Small orders may be set to go through without asking.
+    let total: Decimal = orders.iter().map(|o| o.notional).sum();
+    if config::confirm_above().is_some_and(|threshold| total < threshold) {
+        return Ok(());
+    }
+    let labels = super::token_labels(orders.iter().map(|o| o.token_id).collect()).await;
+    let mut summary: Vec<String> = orders
+        .iter()
+        .map(|o| {
+            let market = labels
+                .get(&o.token_id)
+                .cloned()
+                .unwrap_or_else(|| format!("token {}", o.token_id));
+            let net = net_of_fee(o.side == "BUY", o.notional, o.taker_fee);
+            let net_label = if o.side == "BUY" { "cost" } else { "proceeds" };
+            format!(
+                "{} {} {market} @ {} (${}, {}; fee up to ${}, net {net_label} ${net})",
+                o.side, o.size, o.price, o.notional, o.order_type, o.taker_fee
+            )
+        })
+        .collect();
+    if orders.len() > 1 {
+        let fees: Decimal = orders.iter().map(|o| o.taker_fee).sum();
+        summary.push(format!(
+            "{} orders, ${total} total, fees up to ${fees}",
+            orders.len()
+        ));
+    }
+    super::confirm_submit(&summary)
+}
+
+/// Posts signed orders in batches the CLOB accepts, returning results in the
+/// same order. With `--dry-run`, prints them instead and returns None; with
+/// `confirm`, asks first.
+async fn submit_orders(
+    client: &clob::Client<Authenticated<Normal>>,
+    mut signed_orders: Vec<SignedOrder>,
+    dry_run: bool,
+    confirm: bool,
+    output: &OutputFormat,
+) -> Result<Option<Vec<PostOrderResponse>>> {
+    if dry_run {
+        let mut report = Vec::with_capacity(signed_orders.len());
+        for signed in &signed_orders {
+            report.push(describe_order(client, signed).await?);
+        }
+        print_dry_run_orders(&report, output)?;
+        return Ok(None);
+    }
+    if confirm {
+        confirm_orders(client, &signed_orders).await?;
+    }
+    let mut results = Vec::with_capacity(signed_orders.len());
+    while !signed_orders.is_empty() {
+        let rest = signed_orders.split_off(signed_orders.len().min(MAX_BATCH_ORDERS));
+        results.extend(client.post_orders(signed_orders).await?);
+        signed_orders = rest;
+    }
+    Ok(Some(results))
+}
+
+/// Turns a `--price` expression into a limit price, fetching the book only
+/// when the price is relative to it.
+pub(crate) async fn resolve_limit_price(
+    client: &clob::Client<Authenticated<Normal>>,
+    token_id: U256,
+    side: CliSide,
+    price: PriceExpr,
+) -> Result<Decimal> {
+    if let PriceExpr::Fixed(price) = price {
+        return Ok(price);
+    }
+    let request = OrderBookSummaryRequest::builder()
+        .token_id(token_id)
+        .build();
+    let book = client.order_book(&request).await?;
+    let quote = BookQuote {
+        best_bid: book.bids.iter().map(|l| l.price).max(),
+        best_ask: book.asks.iter().map(|l| l.price).min(),
+        tick_size: book.tick_size.into(),
+    };
+    price.resolve(Side::from(side), &quote)
+}
+
+/// Builds, signs, and posts a single limit order.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn place_limit_order(
+    client: &clob::Client<Authenticated<Normal>>,
+    signer: &auth::WalletSigner,
+    token_id: U256,
+    side: Side,
+    price: Decimal,
+    size: Decimal,
+    order_type: OrderType,
+    post_only: bool,
+    expiration: Option<DateTime<Utc>>,
+) -> Result<PostOrderResponse> {
+    let mut builder = client
+        .limit_order()
+        .token_id(token_id)
+        .side(side)
+        .price(price)
+        .size(size)
+        .order_type(order_type)
+        .post_only(post_only);
+    if let Some(expiration) = expiration {
+        builder = builder.expiration(expiration);
+    }
+    let order = builder.build().await?;
+    let order = auth::sign_order(client, signer, order).await?;
+    Ok(client.post_order(order).await?)
+}
+
+pub(crate) fn parse_token_id(s: &str) -> Result<U256> {
+    U256::from_str(s).map_err(|_| anyhow::anyhow!("Invalid token ID: {s}"))
+}
+
+pub(crate) fn parse_token_ids(s: &str) -> Result<Vec<U256>> {
+    s.split(',').map(|t| parse_token_id(t.trim())).collect()
+}
+
+fn parse_condition_ids(s: &str) -> Result<Vec<B256>> {
+    s.split(',').map(|c| parse_condition_id(c.trim())).collect()
+}
+
+fn parse_date(s: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .map_err(|_| anyhow::anyhow!("Invalid date: expected YYYY-MM-DD format"))
+}
+
+impl ClobCommand {
+    /// Read-only queries that can be re-run with `--watch`.
+    pub(crate) fn watchable(&self) -> bool {
+        matches!(
+            self,
+            Self::Ok
+                | Self::Price { .. }
+                | Self::BatchPrices { .. }
+                | Self::Midpoint { .. }
+                | Self::Midpoints { .. }
+                | Self::Spread { .. }
+                | Self::Spreads { .. }
+                | Self::Book { .. }
+                | Self::Books { .. }
+                | Self::LastTrade { .. }
+                | Self::LastTrades { .. }
+                | Self::Market { .. }
+                | Self::Tokens { .. }
+                | Self::Markets { .. }
+                | Self::SamplingMarkets { .. }
+                | Self::SimplifiedMarkets { .. }
+                | Self::SamplingSimpMarkets { .. }
+                | Self::TickSize { .. }
+                | Self::FeeRate { .. }
+                | Self::FeePreview { .. }
+                | Self::NegRisk { .. }
+                | Self::PriceHistory { .. }
+                | Self::Time
+                | Self::Orders { .. }
+                | Self::Order { .. }
+                | Self::Trades { .. }
+                | Self::Balance { .. }
+                | Self::Balances
+                | Self::Notifications { follow: false, .. }
+                | Self::Rewards { .. }
+                | Self::Earnings { .. }
+                | Self::EarningsSummary { .. }
+                | Self::EarningsMarkets { .. }
+                | Self::RewardPercentages
+                | Self::CurrentRewards { .. }
+                | Self::MarketReward { .. }
+                | Self::OrderScoring { .. }
+                | Self::OrdersScoring { .. }
+                | Self::AccountStatus
+        )
+    }
+
+    /// Replaces market slugs and polymarket.com URLs in token and condition ID
+    /// arguments with the IDs they stand for, so the handlers only see IDs.
+    async fn resolve_references(&mut self, outcome: Option<&str>) -> Result<()> {
+        match self {
+            Self::Price { token_id, .. }
+            | Self::Midpoint { token_id }
+            | Self::Spread { token_id, .. }
+            | Self::Book { token_id, .. }
+            | Self::LastTrade { token_id }
+            | Self::TickSize { token_id }
+            | Self::FeeRate { token_id }
+            | Self::FeePreview { token_id, .. }
+            | Self::NegRisk { token_id }
+            | Self::PriceHistory { token_id, .. }
+            | Self::CreateOrder {
+                token: token_id, ..
+            }
+            | Self::MarketOrder {
+                token: token_id, ..
+            }
+            | Self::LadderOrders {
+                token: token_id, ..
+            }
+            | Self::Stop {
+                token: token_id, ..
+            }
+            | Self::Bracket {
+                token: token_id, ..
+            }
+            | Self::TrailingStop {
+                token: token_id, ..
+            }
+            | Self::Ladder {
+                token: token_id, ..
+            } => *token_id = resolve_token_arg(token_id, outcome).await?,
+            Self::BatchPrices { token_ids, .. }
+            | Self::Midpoints { token_ids }
+            | Self::Spreads { token_ids }
+            | Self::Books { token_ids }
+            | Self::LastTrades { token_ids }
+            | Self::PostOrders {
+                tokens: Some(token_ids),
+                ..
+            }
+            | Self::WatchPrices {
+                tokens: token_ids, ..
+            } => *token_ids = resolve_token_list_arg(token_ids, outcome).await?,
+            Self::Market { condition_id }
+            | Self::Tokens { condition_id }
+            | Self::MarketReward { condition_id, .. }
+            | Self::RewardsSuggest { condition_id, .. } => {
+                *condition_id = resolve_condition_arg(condition_id).await?;
+            }
+            Self::Orders { market, asset, .. }
+            | Self::CancelMarket { market, asset }
+            | Self::Trades { market, asset, .. } => {
+                if let Some(market) = market {
+                    *market = resolve_condition_arg(market).await?;
+                }
+                if let Some(asset) = asset {
+                    *asset = resolve_token_arg(asset, outcome).await?;
+                }
+            }
+            Self::Balance { token, .. }
+            | Self::UpdateBalance { token, .. }
+            | Self::Twap { token, .. }
+            | Self::Iceberg { token, .. } => {
+                if let Some(token) = token {
+                    *token = resolve_token_arg(token, outcome).await?;
+                }
+            }
+            Self::WatchUser {
+                markets: Some(markets),
+                ..
+            } => *markets = resolve_condition_list_arg(markets).await?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Commands that change account state but can't preview it, so they must
+    /// not run under `--dry-run`.
+    pub(crate) fn rejects_dry_run(&self) -> bool {
+        matches!(
+            self,
+            Self::Cancel { .. }
+                | Self::CancelOrders { .. }
+                | Self::CancelAll
+                | Self::CancelMarket { .. }
+                | Self::UpdateBalance { .. }
+                | Self::DeleteNotifications { .. }
+                | Self::CreateApiKey
+                | Self::DeleteApiKey
+                | Self::RotateApiKey
+                | Self::Ladder { .. }
+                | Self::Twap { .. }
+                | Self::Iceberg { .. }
+                | Self::Stop { .. }
+                | Self::Bracket { .. }
+                | Self::TrailingStop { .. }
+                | Self::Guard { .. }
+        )
+    }
+
+    /// Streams and the ladder redraw the terminal, and order placement may
+    /// prompt for confirmation, so they must not be paged.
+    pub(crate) fn pageable(&self) -> bool {
+        !matches!(
+            self,
+            Self::WatchPrices { .. }
+                | Self::WatchUser { .. }
+                | Self::Notifications { follow: true, .. }
+                | Self::Ladder { .. }
+                | Self::Twap { .. }
+                | Self::Iceberg { .. }
+                | Self::Stop { .. }
+                | Self::Bracket { .. }
+                | Self::TrailingStop { .. }
+                | Self::Guard { .. }
+                | Self::CreateOrder { .. }
+                | Self::PostOrders { .. }
+                | Self::LadderOrders { .. }
+                | Self::MarketOrder { .. }
+                | Self::Replace { .. }
+        )
+    }
+}
+
+pub async fn execute(
+    mut args: ClobArgs,
+    output: OutputFormat,
+    private_key: Option<&str>,
+    signature_type: Option<&str>,
+    dry_run: bool,
+    confirm: bool,
+) -> Result<()> {
+    args.command
+        .resolve_references(args.outcome.as_deref())
+        .await?;
+    match args.command {
+        // Unauthenticated read commands
+        ClobCommand::Ok
+        | ClobCommand::Price { .. }
+        | ClobCommand::BatchPrices { .. }
+        | ClobCommand::Midpoint { .. }
+        | ClobCommand::Midpoints { .. }
+        | ClobCommand::Spread { .. }
+        | ClobCommand::Spreads { .. }
+        | ClobCommand::Book { .. }
+        | ClobCommand::Books { .. }
+        | ClobCommand::LastTrade { .. }
+        | ClobCommand::LastTrades { .. }
+        | ClobCommand::Market { .. }
+        | ClobCommand::Tokens { .. }
+        | ClobCommand::Markets { .. }
+        | ClobCommand::SamplingMarkets { .. }
+        | ClobCommand::SimplifiedMarkets { .. }
+        | ClobCommand::SamplingSimpMarkets { .. }
+        | ClobCommand::TickSize { .. }
+        | ClobCommand::FeeRate { .. }
+        | ClobCommand::FeePreview { .. }
+        | ClobCommand::NegRisk { .. }
+        | ClobCommand::PriceHistory { .. }
+        | ClobCommand::Time
+        | ClobCommand::Geoblock => execute_read(args.command, &output).await,
+
+        // Authenticated trading commands
+        ClobCommand::Orders { .. }
+        | ClobCommand::Order { .. }
+        | ClobCommand::CreateOrder { .. }
+        | ClobCommand::PostOrders { .. }
+        | ClobCommand::LadderOrders { .. }
+        | ClobCommand::MarketOrder { .. }
+        | ClobCommand::Replace { .. }
+        | ClobCommand::Cancel { .. }
+        | ClobCommand::CancelOrders { .. }
+        | ClobCommand::CancelAll
+        | ClobCommand::CancelMarket { .. }
+        | ClobCommand::Trades { .. }
+        | ClobCommand::Balance { .. }
+        | ClobCommand::Balances
+        | ClobCommand::UpdateBalance { .. }
+        | ClobCommand::Notifications { .. }
+        | ClobCommand::DeleteNotifications { .. } => {
+            execute_trade(
+                args.command,
+                &output,
+                private_key,
+                signature_type,
+                dry_run,
+                confirm,
+            )
+            .await
+        }
+
+        // Authenticated reward commands
+        ClobCommand::Rewards { .. }
+        | ClobCommand::Earnings { .. }
+        | ClobCommand::EarningsSummary { .. }
+        | ClobCommand::EarningsMarkets { .. }
+        | ClobCommand::RewardPercentages
+        | ClobCommand::CurrentRewards { .. }
+        | ClobCommand::MarketReward { .. }
+        | ClobCommand::RewardsSuggest { .. }
+        | ClobCommand::OrderScoring { .. }
+        | ClobCommand::OrdersScoring { .. } => {
+            execute_rewards(args.command, &output, private_key, signature_type).await
+        }
+
+        // Account management commands
         ClobCommand::ApiKeys
         | ClobCommand::DeleteApiKey
         | ClobCommand::CreateApiKey
+        | ClobCommand::RotateApiKey
         | ClobCommand::AccountStatus => {
             execute_account(args.command, &output, private_key, signature_type).await
         }
+
+        // Streaming commands
+        ClobCommand::WatchPrices { .. } | ClobCommand::WatchUser { .. } => {
+            execute_stream(args.command, &output, private_key, signature_type).await
+        }
+
+        // Managed orders that run in the foreground
+        ClobCommand::Twap { .. }
+        | ClobCommand::Iceberg { .. }
+        | ClobCommand::Stop { .. }
+        | ClobCommand::Bracket { .. }
+        | ClobCommand::TrailingStop { .. }
+        | ClobCommand::Guard { .. } => {
+            execute_managed(args.command, &output, private_key, signature_type, confirm).await
+        }
+
+        // Full-screen interactive commands
+        ClobCommand::Ladder {
+            token,
+            size,
+            interval_ms,
+        } => {
+            let size =
+                Decimal::from_str(&size).map_err(|_| anyhow::anyhow!("Invalid size: {size}"))?;
+            run_ladder(
+                LadderConfig {
+                    token_id: parse_token_id(&token)?,
+                    size,
+                    interval: Duration::from_millis(interval_ms.max(250)),
+                },
+                private_key,
+                signature_type,
+            )
+            .await
+        }
+    }
+}
+
+async fn execute_managed(
+    command: ClobCommand,
+    output: &OutputFormat,
+    private_key: Option<&str>,
+    signature_type: Option<&str>,
+    confirm: bool,
+) -> Result<()> {
+    match command {
+        ClobCommand::Twap {
+            resume: Some(path), ..
+        } => {
+            let state: TwapState = algo::load_state(&path)?;
+            anyhow::ensure!(
+                !state.is_complete(),
+                "This TWAP already finished: filled {} of {} shares",
+                state.filled(),
+                state.total
+            );
+            if confirm {
+                super::confirm_submit(&[format!(
+                    "Resume TWAP: {} {} more shares over {} slices",
+                    Side::from(state.side),
+                    state.total - state.filled(),
+                    state.slices - state.slices_done()
+                )])?;
+            }
+            let signer = auth::resolve_signer(private_key)?;
+            let client = auth::authenticate_with_signer(&signer, signature_type).await?;
+            algo::twap::run(client, signer, state, &path, output).await
+        }
+
+        ClobCommand::Twap {
+            token,
+            side,
+            total,
+            duration,
+            slices,
+            price,
+            state,
+            resume: None,
+        } => {
+            let (Some(token), Some(side), Some(total), Some(duration)) =
+                (token, side, total, duration)
+            else {
+                anyhow::bail!("--token, --side, --total, and --duration are required");
+            };
+            let duration = parse_duration(&duration)?;
+            anyhow::ensure!(slices > 0, "--slices must be at least 1");
+            anyhow::ensure!(total > Decimal::ZERO, "--total must be positive");
+            if let Some(price) = &price {
+                price.parse::<PriceExpr>()?;
+            }
+            let token_id = parse_token_id(&token)?;
+            let duration_secs = u64::try_from(duration.num_seconds()).unwrap_or_default();
+            let twap = TwapState {
+                token_id: token_id.to_string(),
+                side,
+                total,
+                slices,
+                interval_secs: (duration_secs / u64::from(slices)).max(1),
+                price,
+                history: Vec::new(),
+            };
+            if confirm {
+                let market = super::token_label(token_id).await;
+                super::confirm_submit(&[format!(
+                    "TWAP: {} {total} {market} over {} in {slices} slices{}",
+                    Side::from(side),
+                    format_interval(duration_secs),
+                    twap.price
+                        .as_deref()
+                        .map_or_else(String::new, |p| format!(", no worse than {p}")),
+                )])?;
+            }
+            let path = match state {
+                Some(path) => path,
+                None => algo::new_state_path("twap")?,
+            };
+            let signer = auth::resolve_signer(private_key)?;
+            let client = auth::authenticate_with_signer(&signer, signature_type).await?;
+            algo::save_state(&path, &twap)?;
+            algo::twap::run(client, signer, twap, &path, output).await
+        }
+
+        ClobCommand::Iceberg {
+            resume: Some(path),
+            poll,
+            ..
+        } => {
+            let state: IcebergState = algo::load_state(&path)?;
+            anyhow::ensure!(
+                !state.is_complete(),
+                "This iceberg already finished: filled {} of {} shares",
+                state.filled(),
+                state.total
+            );
+            if confirm {
+                super::confirm_submit(&[format!(
+                    "Resume iceberg: {} {} more shares at {}, showing {} at a time",
+                    Side::from(state.side),
+                    state.total - state.filled(),
+                    state.price,
+                    state.display
+                )])?;
+            }
+            let signer = auth::resolve_signer(private_key)?;
+            let client = auth::authenticate_with_signer(&signer, signature_type).await?;
+            let poll = Duration::from_secs(poll.max(1));
+            algo::iceberg::run(client, signer, state, &path, poll, output).await
+        }
+
+        ClobCommand::Iceberg {
+            token,
+            side,
+            price,
+            display,
+            total,
+            poll,
+            state,
+            resume: None,
+        } => {
+            let (Some(token), Some(side), Some(price), Some(display), Some(total)) =
+                (token, side, price, display, total)
+            else {
+                anyhow::bail!("--token, --side, --price, --display, and --total are required");
+            };
+            anyhow::ensure!(display > Decimal::ZERO, "--display must be positive");
+            anyhow::ensure!(
+                total >= display,
+                "--total must be at least the --display size"
+            );
+            let token_id = parse_token_id(&token)?;
+            let iceberg = IcebergState {
+                token_id: token_id.to_string(),
+                side,
+                price,
+                display,
+                total,
+                retired: Decimal::ZERO,
+                live: None,
+            };
+            if confirm {
+                let market = super::token_label(token_id).await;
+                super::confirm_submit(&[format!(
+                    "Iceberg: {} {total} {market} @ {price}, showing {display} at a time (${})",
+                    Side::from(side),
+                    (total * price).round_dp(2)
+                )])?;
+            }
+            let path = match state {
+                Some(path) => path,
+                None => algo::new_state_path("iceberg")?,
+            };
+            let signer = auth::resolve_signer(private_key)?;
+            let client = auth::authenticate_with_signer(&signer, signature_type).await?;
+            algo::save_state(&path, &iceberg)?;
+            let poll = Duration::from_secs(poll.max(1));
+            algo::iceberg::run(client, signer, iceberg, &path, poll, output).await
+        }
+
+        ClobCommand::Stop {
+            token,
+            side,
+            trigger,
+            size,
+            limit,
+            interval,
+        } => {
+            anyhow::ensure!(size > Decimal::ZERO, "--size must be positive");
+            let token_id = parse_token_id(&token)?;
+            if confirm {
+                let market = super::token_label(token_id).await;
+                let order = limit.map_or_else(|| "at market".to_string(), |p| format!("@ {p}"));
+                super::confirm_submit(&[format!(
+                    "Stop: {} {size} {market} {order} once the price reaches {trigger}",
+                    Side::from(side)
+                )])?;
+            }
+            let signer = auth::resolve_signer(private_key)?;
+            let client = auth::authenticate_with_signer(&signer, signature_type).await?;
+            let stop = StopOrder {
+                token_id,
+                side,
+                trigger,
+                size,
+                limit,
+                interval: Duration::from_secs(interval.max(1)),
+            };
+            algo::stop::run(client, signer, stop, output).await
+        }
+
+        ClobCommand::Bracket {
+            token,
+            side,
+            size,
+            take_profit,
+            stop,
+            stop_limit,
+            interval,
+        } => {
+            anyhow::ensure!(size > Decimal::ZERO, "--size must be positive");
+            let token_id = parse_token_id(&token)?;
+            let bracket = Bracket {
+                stop: StopOrder {
+                    token_id,
+                    side,
+                    trigger: stop,
+                    size,
+                    limit: stop_limit,
+                    interval: Duration::from_secs(interval.max(1)),
+                },
+                take_profit,
+            };
+            bracket.validate()?;
+            if confirm {
+                let market = super::token_label(token_id).await;
+                let stop_order =
+                    stop_limit.map_or_else(|| "at market".to_string(), |p| format!("@ {p}"));
+                super::confirm_submit(&[
+                    format!("Bracket: {} {size} {market}", Side::from(side)),
+                    format!("  take profit @ {take_profit}"),
+                    format!("  stop {stop_order} once the price reaches {stop}"),
+                ])?;
+            }
+            let signer = auth::resolve_signer(private_key)?;
+            let client = auth::authenticate_with_signer(&signer, signature_type).await?;
+            algo::bracket::run(client, signer, bracket, output).await
+        }
+
+        ClobCommand::TrailingStop {
+            token,
+            side,
+            trail,
+            size,
+            interval,
+        } => {
+            anyhow::ensure!(size > Decimal::ZERO, "--size must be positive");
+            anyhow::ensure!(
+                trail > Decimal::ZERO && trail < Decimal::ONE,
+                "--trail must be between 0 and 1"
+            );
+            let token_id = parse_token_id(&token)?;
+            if confirm {
+                let market = super::token_label(token_id).await;
+                super::confirm_submit(&[format!(
+                    "Trailing stop: {} {size} {market} at market after a {trail} retrace",
+                    Side::from(side)
+                )])?;
+            }
+            let signer = auth::resolve_signer(private_key)?;
+            let client = auth::authenticate_with_signer(&signer, signature_type).await?;
+            let trailing = TrailingStop {
+                stop: StopOrder {
+                    token_id,
+                    side,
+                    trigger: Decimal::ZERO,
+                    size,
+                    limit: None,
+                    interval: Duration::from_secs(interval.max(1)),
+                },
+                trail,
+                extreme: None,
+            };
+            algo::trailing::run(client, signer, trailing, output).await
+        }
+
+        ClobCommand::Guard { ttl, misses } => {
+            let ttl = parse_duration(&ttl)?
+                .to_std()
+                .context("--ttl is out of range")?;
+            let client = auth::authenticated_clob_client(private_key, signature_type).await?;
+            let guard = Guard::new(ttl, misses, std::time::Instant::now());
+            algo::guard::run(client, guard, output).await
+        }
+
+        _ => unreachable!(),
     }
 }
 
 async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()> {
     match command {
         ClobCommand::Ok => {
-            let client = clob::Client::default();
+            let client = endpoints::clob();
             let result = client.ok().await?;
             print_ok(&result, output)?;
         }
 
         ClobCommand::Price { token_id, side } => {
-            let client = clob::Client::default();
+            let client = endpoints::clob();
             let request = PriceRequest::builder()
                 .token_id(parse_token_id(&token_id)?)
                 .side(Side::from(side))
@@ -577,13 +2443,13 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
         }
 
         ClobCommand::BatchPrices { token_ids, side } => {
-            let client = clob::Client::default();
+            let client = endpoints::clob();
             let requests: Vec<_> = parse_token_ids(&token_ids)?
                 .into_iter()
                 .map(|id| {
                     PriceRequest::builder()
                         .token_id(id)
-                        .side(Side::from(side.clone()))
+                        .side(Side::from(side))
                         .build()
                 })
                 .collect();
@@ -592,7 +2458,7 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
         }
 
         ClobCommand::Midpoint { token_id } => {
-            let client = clob::Client::default();
+            let client = endpoints::clob();
             let request = MidpointRequest::builder()
                 .token_id(parse_token_id(&token_id)?)
                 .build();
@@ -601,7 +2467,7 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
         }
 
         ClobCommand::Midpoints { token_ids } => {
-            let client = clob::Client::default();
+            let client = endpoints::clob();
             let requests: Vec<_> = parse_token_ids(&token_ids)?
                 .into_iter()
                 .map(|id| MidpointRequest::builder().token_id(id).build())
@@ -611,7 +2477,7 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
         }
 
         ClobCommand::Spread { token_id, side } => {
-            let client = clob::Client::default();
+            let client = endpoints::clob();
             let request = SpreadRequest::builder()
                 .token_id(parse_token_id(&token_id)?)
                 .maybe_side(side.map(Side::from))
@@ -621,7 +2487,7 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
         }
 
         ClobCommand::Spreads { token_ids } => {
-            let client = clob::Client::default();
+            let client = endpoints::clob();
             let requests: Vec<_> = parse_token_ids(&token_ids)?
                 .into_iter()
                 .map(|id| SpreadRequest::builder().token_id(id).build())
@@ -630,17 +2496,26 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
             print_spreads(&result, output)?;
         }
 
-        ClobCommand::Book { token_id } => {
-            let client = clob::Client::default();
+        ClobCommand::Book {
+            token_id,
+            depth,
+            group,
+        } => {
+            let client = endpoints::clob();
             let request = OrderBookSummaryRequest::builder()
                 .token_id(parse_token_id(&token_id)?)
                 .build();
-            let result = client.order_book(&request).await?;
+            let mut result = client.order_book(&request).await?;
+            if depth.is_some() || group.is_some() {
+                let bucket = group.map(|g| Decimal::from(result.tick_size) * Decimal::from(g));
+                result.bids = shape_levels(&result.bids, Side::Buy, bucket, depth);
+                result.asks = shape_levels(&result.asks, Side::Sell, bucket, depth);
+            }
             print_order_book(&result, output)?;
         }
 
         ClobCommand::Books { token_ids } => {
-            let client = clob::Client::default();
+            let client = endpoints::clob();
             let requests: Vec<_> = parse_token_ids(&token_ids)?
                 .into_iter()
                 .map(|id| OrderBookSummaryRequest::builder().token_id(id).build())
@@ -650,7 +2525,7 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
         }
 
         ClobCommand::LastTrade { token_id } => {
-            let client = clob::Client::default();
+            let client = endpoints::clob();
             let request = LastTradePriceRequest::builder()
                 .token_id(parse_token_id(&token_id)?)
                 .build();
@@ -659,7 +2534,7 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
         }
 
         ClobCommand::LastTrades { token_ids } => {
-            let client = clob::Client::default();
+            let client = endpoints::clob();
             let requests: Vec<_> = parse_token_ids(&token_ids)?
                 .into_iter()
                 .map(|id| LastTradePriceRequest::builder().token_id(id).build())
@@ -668,50 +2543,95 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
             print_last_trades_prices(&result, output)?;
         }
 
-        ClobCommand::Market { condition_id } => {
-            let client = clob::Client::default();
+        ClobCommand::Market { condition_id } => {
+            let client = endpoints::clob();
+            let result = client.market(&condition_id).await?;
+            print_clob_market(&result, output)?;
+        }
+
+        ClobCommand::Tokens { condition_id } => {
+            let client = endpoints::clob();
             let result = client.market(&condition_id).await?;
-            print_clob_market(&result, output)?;
+            print_clob_tokens(&result, output)?;
         }
 
-        ClobCommand::Markets { cursor } => {
-            let client = clob::Client::default();
-            let result = client.markets(cursor).await?;
-            print_clob_markets(&result, output)?;
+        ClobCommand::Markets { page } => {
+            let client = endpoints::clob();
+            fetch_pages(page, output, |c| client.markets(c), print_clob_markets).await?;
         }
 
-        ClobCommand::SamplingMarkets { cursor } => {
-            let client = clob::Client::default();
-            let result = client.sampling_markets(cursor).await?;
-            print_clob_markets(&result, output)?;
+        ClobCommand::SamplingMarkets { page } => {
+            let client = endpoints::clob();
+            fetch_pages(
+                page,
+                output,
+                |c| client.sampling_markets(c),
+                print_clob_markets,
+            )
+            .await?;
         }
 
-        ClobCommand::SimplifiedMarkets { cursor } => {
-            let client = clob::Client::default();
-            let result = client.simplified_markets(cursor).await?;
-            print_simplified_markets(&result, output)?;
+        ClobCommand::SimplifiedMarkets { page } => {
+            let client = endpoints::clob();
+            fetch_pages(
+                page,
+                output,
+                |c| client.simplified_markets(c),
+                print_simplified_markets,
+            )
+            .await?;
         }
 
-        ClobCommand::SamplingSimpMarkets { cursor } => {
-            let client = clob::Client::default();
-            let result = client.sampling_simplified_markets(cursor).await?;
-            print_simplified_markets(&result, output)?;
+        ClobCommand::SamplingSimpMarkets { page } => {
+            let client = endpoints::clob();
+            fetch_pages(
+                page,
+                output,
+                |c| client.sampling_simplified_markets(c),
+                print_simplified_markets,
+            )
+            .await?;
         }
 
         ClobCommand::TickSize { token_id } => {
-            let client = clob::Client::default();
+            let client = endpoints::clob();
             let result = client.tick_size(parse_token_id(&token_id)?).await?;
             print_tick_size(&result, output)?;
         }
 
         ClobCommand::FeeRate { token_id } => {
-            let client = clob::Client::default();
+            let client = endpoints::clob();
             let result = client.fee_rate_bps(parse_token_id(&token_id)?).await?;
             print_fee_rate(&result, output)?;
         }
 
+        ClobCommand::FeePreview {
+            token_id,
+            side,
+            price,
+            size,
+        } => {
+            if price <= Decimal::ZERO || price >= Decimal::ONE {
+                anyhow::bail!("--price must be between 0 and 1, got {price}");
+            }
+            if size <= Decimal::ZERO {
+                anyhow::bail!("--size must be positive, got {size}");
+            }
+            let token_id = parse_token_id(&token_id)?;
+            let client = endpoints::clob();
+            let fee_rate_bps = client.fee_rate_bps(token_id).await?.base_fee;
+            let preview = FeePreview {
+                token_id,
+                side: Side::from(side),
+                price,
+                size,
+                fee_rate_bps,
+            };
+            print_fee_preview(&preview, output)?;
+        }
+
         ClobCommand::NegRisk { token_id } => {
-            let client = clob::Client::default();
+            let client = endpoints::clob();
             let result = client.neg_risk(parse_token_id(&token_id)?).await?;
             print_neg_risk(&result, output)?;
         }
@@ -720,25 +2640,31 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
             token_id,
             interval,
             fidelity,
+            chart,
+            log_scale,
         } => {
-            let client = clob::Client::default();
+            let client = endpoints::clob();
             let request = PriceHistoryRequest::builder()
                 .market(parse_token_id(&token_id)?)
                 .time_range(TimeRange::from_interval(Interval::from(interval)))
                 .maybe_fidelity(fidelity)
                 .build();
             let result = client.price_history(&request).await?;
-            print_price_history(&result, output)?;
+            if chart && matches!(output, OutputFormat::Table) {
+                print_price_chart(&result, log_scale)?;
+            } else {
+                print_price_history(&result, output)?;
+            }
         }
 
         ClobCommand::Time => {
-            let client = clob::Client::default();
+            let client = endpoints::clob();
             let result = client.server_time().await?;
             print_server_time(result, output)?;
         }
 
         ClobCommand::Geoblock => {
-            let client = clob::Client::default();
+            let client = endpoints::clob();
             let result = client.check_geoblock().await?;
             print_geoblock(&result, output)?;
         }
@@ -754,20 +2680,40 @@ async fn execute_trade(
     output: &OutputFormat,
     private_key: Option<&str>,
     signature_type: Option<&str>,
+    dry_run: bool,
+    confirm: bool,
 ) -> Result<()> {
     match command {
         ClobCommand::Orders {
             market,
             asset,
-            cursor,
+            status,
+            side,
+            sort,
+            page,
         } => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
             let request = OrdersRequest::builder()
                 .maybe_market(market.map(|m| parse_condition_id(&m)).transpose()?)
                 .maybe_asset_id(asset.map(|a| parse_token_id(&a)).transpose()?)
                 .build();
-            let result = client.orders(&request, cursor).await?;
-            print_orders(&result, output)?;
+            let keep = |o: &OpenOrderResponse| {
+                status.is_none_or(|s| o.status == OrderStatusType::from(s))
+                    && side.is_none_or(|s| o.side == Side::from(s))
+            };
+            fetch_pages(
+                page,
+                output,
+                |c| client.orders(&request, c),
+                |page, output| {
+                    let mut page = filter_page(page, keep);
+                    if let (Some(sort), OutputFormat::Table) = (sort, output) {
+                        sort.apply(&mut page.data);
+                    }
+                    print_orders(&page, output)
+                },
+            )
+            .await?;
         }
 
         ClobCommand::Order { order_id } => {
@@ -783,40 +2729,77 @@ async fn execute_trade(
             size,
             order_type,
             post_only,
+            round_to_tick,
+            force,
+            expiry,
         } => {
+            let token_id = parse_token_id(&token)?;
+            let price: PriceExpr = price.parse()?;
+            let size =
+                Decimal::from_str(&size).map_err(|_| anyhow::anyhow!("Invalid size: {size}"))?;
+            let expiration = expiry.resolve(order_type, Utc::now())?;
             let signer = auth::resolve_signer(private_key)?;
             let client = auth::authenticate_with_signer(&signer, signature_type).await?;
 
-            let price_dec =
-                Decimal::from_str(&price).map_err(|_| anyhow::anyhow!("Invalid price: {price}"))?;
-            let size_dec =
-                Decimal::from_str(&size).map_err(|_| anyhow::anyhow!("Invalid size: {size}"))?;
+            let mut order = [LimitOrder {
+                token_id,
+                side,
+                price: resolve_limit_price(&client, token_id, side, price).await?,
+                size,
+                order_type,
+                post_only,
+                expiration,
+            }];
+
+            preflight_orders(&client, &mut order, round_to_tick, force).await?;
+            let signed = sign_limit_orders(&client, &signer, &order).await?;
+            if let Some(results) = submit_orders(&client, signed, dry_run, confirm, output).await?
+                && let Some(result) = results.first()
+            {
+                print_post_order_result(result, output)?;
+            }
+        }
 
-            let order = client
-                .limit_order()
-                .token_id(parse_token_id(&token)?)
-                .side(Side::from(side))
-                .price(price_dec)
-                .size(size_dec)
-                .order_type(OrderType::from(order_type))
-                .post_only(post_only)
-                .build()
-                .await?;
-            let order = client.sign(&signer, order).await?;
-            let result = client.post_order(order).await?;
-            print_post_order_result(&result, output)?;
+        ClobCommand::PostOrders {
+            file: Some(path),
+            round_to_tick,
+            force,
+            ..
+        } => {
+            if !path.exists() {
+                anyhow::bail!("Order file not found: {}", path.display());
+            }
+            let specs = order_file::load(&path)?;
+            if specs.is_empty() {
+                anyhow::bail!("No orders in {}", path.display());
+            }
+            let mut orders = prepare_file_orders(&specs, Utc::now())?;
+
+            let signer = auth::resolve_signer(private_key)?;
+            let client = auth::authenticate_with_signer(&signer, signature_type).await?;
+            preflight_orders(&client, &mut orders, round_to_tick, force).await?;
+            let signed = sign_limit_orders(&client, &signer, &orders).await?;
+            if let Some(results) = submit_orders(&client, signed, dry_run, confirm, output).await? {
+                print_order_file_results(&specs, &results, output)?;
+            }
         }
 
         ClobCommand::PostOrders {
             tokens,
             side,
             prices,
+            sides,
             sizes,
             order_type,
+            order_types,
+            expiry,
+            file: None,
+            round_to_tick,
+            force,
         } => {
-            let signer = auth::resolve_signer(private_key)?;
-            let client = auth::authenticate_with_signer(&signer, signature_type).await?;
-
+            let (Some(tokens), Some(prices), Some(sizes)) = (tokens, prices, sizes) else {
+                anyhow::bail!("--tokens, --prices, and --sizes are required without --file");
+            };
             let token_ids = parse_token_ids(&tokens)?;
             let price_strs: Vec<&str> = prices.split(',').map(str::trim).collect();
             let size_strs: Vec<&str> = sizes.split(',').map(str::trim).collect();
@@ -826,33 +2809,94 @@ async fn execute_trade(
                     "tokens, prices, and sizes must have the same number of comma-separated values"
                 );
             }
-
-            let sdk_side = Side::from(side);
-            let sdk_order_type = OrderType::from(order_type);
-
-            let mut signed_orders = Vec::with_capacity(token_ids.len());
-            for ((token_id, price_str), size_str) in
-                token_ids.into_iter().zip(price_strs).zip(size_strs)
+            let sides = per_order(side, &sides, token_ids.len(), "sides")?;
+            let order_types = per_order(
+                Some(order_type),
+                &order_types,
+                token_ids.len(),
+                "order types",
+            )?;
+            // One expiry covers every GTD order in the batch; the rest get none.
+            let any_gtd = order_types.contains(&CliOrderType::Gtd);
+            let expiration = expiry.resolve(
+                if any_gtd {
+                    CliOrderType::Gtd
+                } else {
+                    order_type
+                },
+                Utc::now(),
+            )?;
+
+            let mut orders = Vec::with_capacity(token_ids.len());
+            for (((token_id, price_str), size_str), (side, order_type)) in token_ids
+                .into_iter()
+                .zip(price_strs)
+                .zip(size_strs)
+                .zip(sides.into_iter().zip(order_types))
             {
-                let price_dec = Decimal::from_str(price_str)
-                    .map_err(|_| anyhow::anyhow!("Invalid price: {price_str}"))?;
-                let size_dec = Decimal::from_str(size_str)
-                    .map_err(|_| anyhow::anyhow!("Invalid size: {size_str}"))?;
+                orders.push(LimitOrder {
+                    token_id,
+                    side,
+                    price: Decimal::from_str(price_str)
+                        .map_err(|_| anyhow::anyhow!("Invalid price: {price_str}"))?,
+                    size: Decimal::from_str(size_str)
+                        .map_err(|_| anyhow::anyhow!("Invalid size: {size_str}"))?,
+                    order_type,
+                    post_only: false,
+                    expiration: expiration.filter(|_| order_type == CliOrderType::Gtd),
+                });
+            }
 
-                let order = client
-                    .limit_order()
-                    .token_id(token_id)
-                    .side(sdk_side)
-                    .price(price_dec)
-                    .size(size_dec)
-                    .order_type(sdk_order_type.clone())
-                    .build()
-                    .await?;
-                signed_orders.push(client.sign(&signer, order).await?);
+            let signer = auth::resolve_signer(private_key)?;
+            let client = auth::authenticate_with_signer(&signer, signature_type).await?;
+            preflight_orders(&client, &mut orders, round_to_tick, force).await?;
+            let signed = sign_limit_orders(&client, &signer, &orders).await?;
+            if let Some(results) = submit_orders(&client, signed, dry_run, confirm, output).await? {
+                print_post_orders_result(&results, output)?;
             }
+        }
 
-            let results = client.post_orders(signed_orders).await?;
-            print_post_orders_result(&results, output)?;
+        ClobCommand::LadderOrders {
+            token,
+            side,
+            from,
+            to,
+            levels,
+            total_size,
+            skew,
+            order_type,
+            post_only,
+            force,
+            expiry,
+        } => {
+            let token_id = parse_token_id(&token)?;
+            let expiration = expiry.resolve(order_type, Utc::now())?;
+            let tick = endpoints::clob()
+                .tick_size(token_id)
+                .await?
+                .minimum_tick_size
+                .into();
+            let mut orders: Vec<_> =
+                ladder_levels(side, (from, to), levels, total_size, skew, tick)?
+                    .into_iter()
+                    .map(|(price, size)| LimitOrder {
+                        token_id,
+                        side,
+                        price,
+                        size,
+                        order_type,
+                        post_only,
+                        expiration,
+                    })
+                    .collect();
+
+            let signer = auth::resolve_signer(private_key)?;
+            let client = auth::authenticate_with_signer(&signer, signature_type).await?;
+            preflight_orders(&client, &mut orders, false, force).await?;
+            let signed = sign_limit_orders(&client, &signer, &orders).await?;
+            if let Some(results) = submit_orders(&client, signed, dry_run, confirm, output).await? {
+                print_post_orders_result(&results, output)?;
+            }
         }
 
         ClobCommand::MarketOrder {
@@ -881,9 +2925,70 @@ async fn execute_trade(
                 .order_type(OrderType::from(order_type))
                 .build()
                 .await?;
-            let order = client.sign(&signer, order).await?;
-            let result = client.post_order(order).await?;
-            print_post_order_result(&result, output)?;
+            let order = auth::sign_order(&client, &signer, order).await?;
+            if let Some(results) =
+                submit_orders(&client, vec![order], dry_run, confirm, output).await?
+                && let Some(result) = results.first()
+            {
+                print_post_order_result(result, output)?;
+            }
+        }
+
+        ClobCommand::Replace {
+            order_id,
+            price,
+            size,
+            post_only,
+            round_to_tick,
+            force,
+        } => {
+            let price: PriceExpr = price.parse()?;
+            let size = size
+                .map(|s| Decimal::from_str(&s).map_err(|_| anyhow::anyhow!("Invalid size: {s}")))
+                .transpose()?;
+            let signer = auth::resolve_signer(private_key)?;
+            let client = auth::authenticate_with_signer(&signer, signature_type).await?;
+
+            let original = client.order(&order_id).await?;
+            let side = CliSide::try_from(original.side)?;
+            let order_type = CliOrderType::try_from(original.order_type.clone())?;
+            let unfilled = original.original_size - original.size_matched;
+            let size = size.unwrap_or(unfilled);
+            anyhow::ensure!(
+                size > Decimal::ZERO,
+                "Order {order_id} has nothing left unfilled; pass --size to set one"
+            );
+            let mut replacement = [LimitOrder {
+                token_id: original.asset_id,
+                side,
+                price: resolve_limit_price(&client, original.asset_id, side, price).await?,
+                size,
+                order_type,
+                post_only,
+                expiration: (order_type == CliOrderType::Gtd).then_some(original.expiration),
+            }];
+            preflight_orders(&client, &mut replacement, round_to_tick, force).await?;
+            // Sign and confirm before canceling so the replacement goes out
+            // right behind the cancel, and declining leaves the original alone.
+            let signed = sign_limit_orders(&client, &signer, &replacement).await?;
+            if dry_run {
+                submit_orders(&client, signed, true, false, output).await?;
+                return Ok(());
+            }
+            if confirm {
+                confirm_orders(&client, &signed).await?;
+            }
+
+            let canceled = client.cancel_order(&order_id).await?;
+            if !canceled.canceled.contains(&order_id) {
+                print_cancel_result(&canceled, output)?;
+                anyhow::bail!("Order {order_id} was not canceled, so no replacement was posted");
+            }
+            if let Some(results) = submit_orders(&client, signed, false, false, output).await?
+                && let Some(result) = results.first()
+            {
+                print_replace_result(&order_id, result, output)?;
+            }
         }
 
         ClobCommand::Cancel { order_id } => {
@@ -918,15 +3023,35 @@ async fn execute_trade(
         ClobCommand::Trades {
             market,
             asset,
-            cursor,
+            after,
+            before,
+            side,
+            status,
+            min_size,
+            page,
         } => {
+            let after = after.map(|t| parse_time_bound(&t, false)).transpose()?;
+            let before = before.map(|t| parse_time_bound(&t, true)).transpose()?;
+            let filter = TradeFilter {
+                side,
+                status,
+                min_size,
+                funder: auth::resolve_funder_address(private_key, signature_type)?,
+            };
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
             let request = TradesRequest::builder()
                 .maybe_market(market.map(|m| parse_condition_id(&m)).transpose()?)
                 .maybe_asset_id(asset.map(|a| parse_token_id(&a)).transpose()?)
+                .maybe_after(after.map(|t| t.timestamp()))
+                .maybe_before(before.map(|t| t.timestamp()))
                 .build();
-            let result = client.trades(&request, cursor).await?;
-            print_trades(&result, output)?;
+            fetch_pages(
+                page,
+                output,
+                |c| client.trades(&request, c),
+                |page, output| print_trades(&filter_page(page, |t| filter.keeps(t)), output),
+            )
+            .await?;
         }
 
         ClobCommand::Balance { asset_type, token } => {
@@ -940,6 +3065,53 @@ async fn execute_trade(
             print_balance(&result, is_collateral, output)?;
         }
 
+        ClobCommand::Balances => {
+            let funder = auth::resolve_funder_address(private_key, signature_type)?;
+            let client = auth::authenticated_clob_client(private_key, signature_type).await?;
+            let collateral = client
+                .balance_allowance(
+                    BalanceAllowanceRequest::builder()
+                        .asset_type(AssetType::Collateral)
+                        .build(),
+                )
+                .await?;
+
+            // Tokens with open orders, then held positions, each listed once.
+            let mut token_ids: Vec<U256> = Vec::new();
+            let request = OrdersRequest::builder().build();
+            let mut cursor = None;
+            loop {
+                let page = client.orders(&request, cursor).await?;
+                token_ids.extend(page.data.iter().map(|o| o.asset_id));
+                if page.next_cursor == END_CURSOR || page.data.is_empty() {
+                    break;
+                }
+                cursor = Some(page.next_cursor);
+            }
+            let positions = endpoints::data()
+                .positions(&PositionsRequest::builder().user(funder).build())
+                .await?;
+            token_ids.extend(positions.iter().map(|p| p.asset));
+            let mut seen = HashSet::new();
+            token_ids.retain(|id| seen.insert(*id));
+
+            let mut labels = super::token_labels(token_ids.clone()).await;
+            let mut tokens = Vec::with_capacity(token_ids.len());
+            for token_id in token_ids {
+                let request = BalanceAllowanceRequest::builder()
+                    .asset_type(AssetType::Conditional)
+                    .token_id(token_id)
+                    .build();
+                let balance = client.balance_allowance(request).await?;
+                tokens.push(TokenBalance {
+                    token_id,
+                    label: labels.remove(&token_id),
+                    shares: from_raw_decimal(balance.balance),
+                });
+            }
+            print_balances(&collateral, &tokens, output)?;
+        }
+
         ClobCommand::UpdateBalance { asset_type, token } => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
             let request = BalanceAllowanceRequest::builder()
@@ -949,18 +3121,61 @@ async fn execute_trade(
             client.update_balance_allowance(request).await?;
             match output {
                 OutputFormat::Table => println!("Balance allowance updated."),
-                OutputFormat::Json => {
-                    println!("{}", serde_json::json!({"success": true}));
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&serde_json::json!({"success": true}))?;
                 }
             }
         }
 
-        ClobCommand::Notifications => {
+        ClobCommand::Notifications { follow: false, .. } => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
             let result = client.notifications().await?;
             print_notifications(&result, output)?;
         }
 
+        ClobCommand::Notifications {
+            follow: true,
+            interval,
+            exec,
+        } => {
+            let client = auth::authenticated_clob_client(private_key, signature_type).await?;
+            if matches!(output, OutputFormat::Table) {
+                println!(
+                    "{}",
+                    color::dim("Waiting for new notifications (Ctrl+C to stop)...")
+                );
+            }
+            let mut poller = Poller::new(Duration::from_secs(interval.max(1)));
+            let mut seen: Option<HashSet<String>> = None;
+            while poller.tick().await {
+                let notifications = match client.notifications().await {
+                    Ok(notifications) => notifications,
+                    Err(e) => {
+                        eprintln!("Failed to fetch notifications: {e}");
+                        continue;
+                    }
+                };
+                // The first poll only records what is already there.
+                let Some(seen) = &mut seen else {
+                    seen = Some(notifications.iter().map(notification_key).collect());
+                    continue;
+                };
+                let new: Vec<_> = notifications
+                    .into_iter()
+                    .filter(|n| seen.insert(notification_key(n)))
+                    .collect();
+                if new.is_empty() {
+                    continue;
+                }
+                print_notifications(&new, output)?;
+                if let Some(command) = &exec {
+                    for n in &new {
+                        super::run_hook(command, &notification_env(n)).await;
+                    }
+                }
+            }
+        }
+
         ClobCommand::DeleteNotifications { ids } => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
             let notification_ids: Vec<String> =
@@ -971,8 +3186,8 @@ async fn execute_trade(
             client.delete_notifications(&request).await?;
             match output {
                 OutputFormat::Table => println!("Notifications deleted."),
-                OutputFormat::Json => {
-                    println!("{}", serde_json::json!({"success": true}));
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&serde_json::json!({"success": true}))?;
                 }
             }
         }
@@ -990,20 +3205,60 @@ async fn execute_rewards(
     signature_type: Option<&str>,
 ) -> Result<()> {
     match command {
-        ClobCommand::Rewards { date, cursor } => {
+        ClobCommand::Rewards { days, page } => {
+            let days = days.resolve(Utc::now().date_naive())?;
+            if matches!(days, Days::Range(_)) && page.cursor.is_some() {
+                anyhow::bail!("--cursor can't be combined with --from; ranges fetch every page");
+            }
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
-            let result = client
-                .earnings_for_user_for_day(parse_date(&date)?, cursor)
-                .await?;
-            print_rewards(&result, output)?;
+            let days = match days {
+                Days::One(date) => {
+                    return fetch_pages(
+                        page,
+                        output,
+                        |c| client.earnings_for_user_for_day(date, c),
+                        print_rewards,
+                    )
+                    .await;
+                }
+                Days::Range(days) => days,
+            };
+            let range = fetch_market_earnings(&client, &days).await?;
+            print_earnings_range(&range, output)?;
         }
 
-        ClobCommand::Earnings { date } => {
+        ClobCommand::EarningsSummary { month, csv } => {
+            let days = month_days(&month, Utc::now().date_naive())?;
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
-            let result = client
-                .total_earnings_for_user_for_day(parse_date(&date)?)
+            let range = fetch_market_earnings(&client, &days).await?;
+            if let Some(path) = &csv {
+                write_atomic(path, &earnings_csv(&range))
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+            }
+            print_earnings_summary(&month, &range, csv.as_deref(), output)?;
+        }
+
+        ClobCommand::Earnings { days } => {
+            let days = days.resolve(Utc::now().date_naive())?;
+            let client = auth::authenticated_clob_client(private_key, signature_type).await?;
+            let days = match days {
+                Days::One(date) => {
+                    let result = client.total_earnings_for_user_for_day(date).await?;
+                    return print_earnings(&result, output);
+                }
+                Days::Range(days) => days,
+            };
+            let client = &client;
+            let per_day: Vec<Vec<TotalUserEarningResponse>> = futures::stream::iter(days.clone())
+                .map(|date| async move { client.total_earnings_for_user_for_day(date).await })
+                .buffered(CONCURRENT_DAYS)
+                .try_collect()
                 .await?;
-            print_earnings(&result, output)?;
+            let range = earnings_range(
+                &days,
+                per_day.iter().flatten().map(|e| (e.date, None, e.earnings)),
+            );
+            print_earnings_range(&range, output)?;
         }
 
         ClobCommand::EarningsMarkets { date, cursor } => {
@@ -1023,19 +3278,70 @@ async fn execute_rewards(
             print_reward_percentages(&result, output)?;
         }
 
-        ClobCommand::CurrentRewards { cursor } => {
+        ClobCommand::CurrentRewards { page } => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
-            let result = client.current_rewards(cursor).await?;
-            print_current_rewards(&result, output)?;
+            fetch_pages(
+                page,
+                output,
+                |c| client.current_rewards(c),
+                print_current_rewards,
+            )
+            .await?;
         }
 
-        ClobCommand::MarketReward {
+        ClobCommand::MarketReward { condition_id, page } => {
+            let client = auth::authenticated_clob_client(private_key, signature_type).await?;
+            fetch_pages(
+                page,
+                output,
+                |c| client.raw_rewards_for_market(&condition_id, c),
+                print_market_reward,
+            )
+            .await?;
+        }
+
+        ClobCommand::RewardsSuggest {
             condition_id,
-            cursor,
+            size,
+            score,
         } => {
+            anyhow::ensure!(
+                score > Decimal::ZERO && score <= Decimal::ONE,
+                "--score must be above 0 and at most 1, got {score}"
+            );
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
-            let result = client.raw_rewards_for_market(&condition_id, cursor).await?;
-            print_market_reward(&result, output)?;
+            let page = client.raw_rewards_for_market(&condition_id, None).await?;
+            let Some(market) = page.data.into_iter().next() else {
+                anyhow::bail!("Market {condition_id} has no liquidity rewards");
+            };
+            // The rewards API gives the max spread in cents.
+            let max_spread = market.rewards_max_spread / Decimal::ONE_HUNDRED;
+            let size = size.unwrap_or(market.rewards_min_size);
+            anyhow::ensure!(
+                size >= market.rewards_min_size,
+                "--size {size} is below the market's minimum of {} shares for rewards",
+                market.rewards_min_size
+            );
+            let mut suggestions = Vec::with_capacity(market.tokens.len());
+            for token in &market.tokens {
+                let request = OrderBookSummaryRequest::builder()
+                    .token_id(token.token_id)
+                    .build();
+                let book = client.order_book(&request).await?;
+                let quote = BookQuote {
+                    best_bid: book.bids.iter().map(|l| l.price).max(),
+                    best_ask: book.asks.iter().map(|l| l.price).min(),
+                    tick_size: book.tick_size.into(),
+                };
+                let (midpoint, quotes) = reward_quotes(&quote, max_spread, size, score)?;
+                suggestions.push(RewardSuggestion {
+                    outcome: token.outcome.clone(),
+                    token_id: token.token_id,
+                    midpoint,
+                    quotes,
+                });
+            }
+            print_reward_suggestions(&market, &suggestions, output)?;
         }
 
         ClobCommand::OrderScoring { order_id } => {
@@ -1073,16 +3379,47 @@ async fn execute_account(
         ClobCommand::DeleteApiKey => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
             let result = client.delete_api_key().await?;
+            config::clear_api_credentials()?;
             print_delete_api_key(&result, output)?;
         }
 
         ClobCommand::CreateApiKey => {
             let signer = auth::resolve_signer(private_key)?;
-            let client = clob::Client::default();
-            let result = client.create_or_derive_api_key(&signer, None).await?;
+            let result = auth::create_or_derive_api_key(&signer, None).await?;
             print_create_api_key(&result, output)?;
         }
 
+        ClobCommand::RotateApiKey => {
+            let signer = auth::resolve_signer(private_key)?;
+            let address = signer.address();
+            let current = auth::authenticate_with_signer(&signer, signature_type).await?;
+            let old_key = current.credentials().key();
+
+            // Keys are derived per nonce, so a new one needs a nonce past
+            // the current key's.
+            let start = config::api_credentials(address).map_or(0, |c| c.nonce);
+            let mut created = Err(anyhow::anyhow!("no nonce tried"));
+            for nonce in start + 1..=start + ROTATE_NONCE_ATTEMPTS {
+                match auth::create_api_key(&signer, Some(nonce)).await {
+                    Ok(credentials) => {
+                        created = Ok((nonce, credentials));
+                        break;
+                    }
+                    Err(e) => created = Err(e),
+                }
+            }
+            let (nonce, new) = created.context("Failed to create a new API key")?;
+
+            // Switch to the new key before deleting the old one, so a failure
+            // part way never leaves the cache pointing at a deleted key.
+            config::save_api_credentials(auth::from_credentials(address, &new, nonce))?;
+            let deleted = current.delete_api_key().await;
+            print_rotate_api_key(old_key, &new, deleted.is_ok(), output)?;
+            if let Err(e) = deleted {
+                eprintln!("Warning: the old API key {old_key} could not be deleted: {e}");
+            }
+        }
+
         ClobCommand::AccountStatus => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
             let result = client.closed_only_mode().await?;
@@ -1095,9 +3432,455 @@ async fn execute_account(
     Ok(())
 }
 
+async fn execute_stream(
+    command: ClobCommand,
+    output: &OutputFormat,
+    private_key: Option<&str>,
+    signature_type: Option<&str>,
+) -> Result<()> {
+    match command {
+        ClobCommand::WatchPrices { tokens } => {
+            let token_ids = parse_token_ids(&tokens)?;
+            let requests: Vec<_> = token_ids
+                .iter()
+                .map(|id| OrderBookSummaryRequest::builder().token_id(*id).build())
+                .collect();
+            let books = endpoints::clob().order_books(&requests).await?;
+
+            let mut quotes: Vec<TickerQuote> = token_ids
+                .iter()
+                .map(|id| {
+                    let book = books.iter().find(|b| b.asset_id == *id);
+                    TickerQuote {
+                        token_id: *id,
+                        best_bid: book.and_then(|b| b.bids.iter().map(|o| o.price).max()),
+                        best_ask: book.and_then(|b| b.asks.iter().map(|o| o.price).min()),
+                    }
+                })
+                .collect();
+            print_price_ticker(&quotes, output)?;
+
+            let ws = endpoints::clob_ws();
+            let mut stream = Box::pin(ws.subscribe_prices(token_ids)?);
+            loop {
+                tokio::select! {
+                    msg = stream.next() => match msg {
+                        Some(Ok(change)) => {
+                            let mut updated = Vec::new();
+                            for entry in &change.price_changes {
+                                if let Some(q) = quotes.iter_mut().find(|q| q.token_id == entry.asset_id) {
+                                    q.best_bid = entry.best_bid.or(q.best_bid);
+                                    q.best_ask = entry.best_ask.or(q.best_ask);
+                                    updated.push(q.clone());
+                                }
+                            }
+                            match output {
+                                OutputFormat::Table => print_price_ticker(&quotes, output)?,
+                                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => print_price_ticker(&updated, output)?,
+                            }
+                        }
+                        Some(Err(e)) => eprintln!("Stream error: {e}"),
+                        None => break,
+                    },
+                    _ = tokio::signal::ctrl_c() => break,
+                }
+            }
+        }
+
+        ClobCommand::WatchUser { markets } => {
+            let markets = markets
+                .map(|m| parse_condition_ids(&m))
+                .transpose()?
+                .unwrap_or_default();
+            let client = auth::authenticated_clob_client(private_key, signature_type).await?;
+            let ws = endpoints::clob_ws()
+                .authenticate(client.credentials().clone(), client.address())?;
+            let mut stream = Box::pin(ws.subscribe_user_events(markets)?);
+
+            if matches!(output, OutputFormat::Table) {
+                println!("Watching order updates and fills. Press Ctrl+C to stop.");
+            }
+
+            loop {
+                tokio::select! {
+                    msg = stream.next() => match msg {
+                        Some(Ok(WsMessage::Order(order))) => print_order_update(&order, output)?,
+                        Some(Ok(WsMessage::Trade(trade))) => print_trade_update(&trade, output)?,
+                        Some(Ok(_)) => {}
+                        Some(Err(e)) => eprintln!("Stream error: {e}"),
+                        None => break,
+                    },
+                    _ = tokio::signal::ctrl_c() => break,
+                }
+            }
+        }
+
+        _ => unreachable!(),
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
+
+    fn expiry(at: Option<&str>, within: Option<&str>) -> ExpiryArgs {
+        ExpiryArgs {
+            expires_at: at.map(String::from),
+            expires_in: within.map(String::from),
+        }
+    }
+
+    fn levels(levels: &[(Decimal, Decimal)]) -> Vec<OrderSummary> {
+        levels
+            .iter()
+            .map(|&(price, size)| OrderSummary::builder().price(price).size(size).build())
+            .collect()
+    }
+
+    #[test]
+    fn book_levels_sort_best_first_and_cut_to_depth() {
+        let bids = levels(&[
+            (dec!(0.40), dec!(5)),
+            (dec!(0.45), dec!(1)),
+            (dec!(0.42), dec!(2)),
+        ]);
+        let shaped = shape_levels(&bids, Side::Buy, None, Some(2));
+        assert_eq!(
+            shaped,
+            levels(&[(dec!(0.45), dec!(1)), (dec!(0.42), dec!(2))])
+        );
+        let asks = levels(&[(dec!(0.52), dec!(3)), (dec!(0.50), dec!(4))]);
+        assert_eq!(
+            shape_levels(&asks, Side::Sell, None, None)[0].price,
+            dec!(0.50)
+        );
+    }
+
+    #[test]
+    fn book_groups_round_away_from_the_spread() {
+        let bids = levels(&[
+            (dec!(0.47), dec!(1)),
+            (dec!(0.46), dec!(2)),
+            (dec!(0.44), dec!(4)),
+        ]);
+        assert_eq!(
+            shape_levels(&bids, Side::Buy, Some(dec!(0.05)), None),
+            levels(&[(dec!(0.45), dec!(3)), (dec!(0.4), dec!(4))])
+        );
+        let asks = levels(&[(dec!(0.51), dec!(1)), (dec!(0.55), dec!(2))]);
+        assert_eq!(
+            shape_levels(&asks, Side::Sell, Some(dec!(0.05)), None),
+            levels(&[(dec!(0.55), dec!(3))])
+        );
+    }
+
+    fn fake_page(n: u32) -> polymarket_client_sdk::Result<Page<u32>> {
+        let next = if n < 3 {
+            (n + 1).to_string()
+        } else {
+            END_CURSOR.into()
+        };
+        Ok(Page::builder()
+            .data(vec![n])
+            .next_cursor(next)
+            .limit(1)
+            .count(1)
+            .build())
+    }
+
+    async fn collect_pages(page: PageArgs) -> (Vec<u32>, String) {
+        let printed = std::cell::RefCell::new(None);
+        fetch_pages(
+            page,
+            &OutputFormat::Json,
+            |c| async move { fake_page(c.map_or(1, |c| c.parse().unwrap())) },
+            |p, _| {
+                *printed.borrow_mut() = Some((p.data.clone(), p.next_cursor.clone()));
+                Ok(())
+            },
+        )
+        .await
+        .unwrap();
+        printed.into_inner().unwrap()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn all_follows_cursors_to_the_last_page_or_max_pages() {
+        let one = collect_pages(PageArgs::default()).await;
+        assert_eq!(one, (vec![1], "2".into()));
+        let all = collect_pages(PageArgs {
+            all: true,
+            ..PageArgs::default()
+        })
+        .await;
+        assert_eq!(all, (vec![1, 2, 3], END_CURSOR.into()));
+        let capped = collect_pages(PageArgs {
+            cursor: Some("2".into()),
+            max_pages: Some(1),
+            ..PageArgs::default()
+        })
+        .await;
+        assert_eq!(capped, (vec![2], "3".into()));
+    }
+
+    fn spec(token: &str, price: Decimal, order_type: CliOrderType) -> OrderSpec {
+        OrderSpec {
+            token: token.into(),
+            side: CliSide::Buy,
+            price,
+            size: Decimal::from(10),
+            order_type,
+            expiration: None,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn prepare_file_orders_reports_every_invalid_order() {
+        let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let specs = [
+            spec("123", Decimal::new(45, 2), CliOrderType::Gtc),
+            spec("abc", Decimal::new(45, 2), CliOrderType::Gtc),
+            spec("123", Decimal::from(2), CliOrderType::Gtc),
+            spec("123", Decimal::new(45, 2), CliOrderType::Gtd),
+        ];
+        let err = prepare_file_orders(&specs, now).unwrap_err().to_string();
+        assert!(err.starts_with("3 of 4 orders are invalid"));
+        assert!(err.contains("order 2: Invalid token ID"));
+        assert!(err.contains("order 3: price 2"));
+        assert!(err.contains("order 4: GTD orders need"));
+        assert!(!err.contains("order 1"));
+    }
+
+    #[test]
+    fn ladder_levels_spread_prices_and_skew_sizes_away_from_the_market() {
+        let flat = ladder_levels(
+            CliSide::Buy,
+            (dec!(0.40), dec!(0.48)),
+            5,
+            dec!(500),
+            None,
+            dec!(0.01),
+        )
+        .unwrap();
+        assert_eq!(
+            flat,
+            [
+                (dec!(0.4), dec!(100)),
+                (dec!(0.42), dec!(100)),
+                (dec!(0.44), dec!(100)),
+                (dec!(0.46), dec!(100)),
+                (dec!(0.48), dec!(100)),
+            ]
+        );
+
+        let linear = ladder_levels(
+            CliSide::Buy,
+            (dec!(0.40), dec!(0.48)),
+            5,
+            dec!(500),
+            Some(LadderSkew::Linear),
+            dec!(0.01),
+        )
+        .unwrap();
+        let sizes: Vec<_> = linear.iter().map(|(_, s)| *s).collect();
+        assert_eq!(
+            sizes,
+            [
+                dec!(166.68),
+                dec!(133.33),
+                dec!(100),
+                dec!(66.66),
+                dec!(33.33)
+            ]
+        );
+
+        let geometric = ladder_levels(
+            CliSide::Sell,
+            (dec!(0.60), dec!(0.62)),
+            3,
+            dec!(70),
+            Some(LadderSkew::Geometric),
+            dec!(0.01),
+        )
+        .unwrap();
+        assert_eq!(
+            geometric,
+            [
+                (dec!(0.6), dec!(10)),
+                (dec!(0.61), dec!(20)),
+                (dec!(0.62), dec!(40))
+            ]
+        );
+    }
+
+    #[test]
+    fn ladder_levels_reject_levels_finer_than_the_tick() {
+        let err = ladder_levels(
+            CliSide::Buy,
+            (dec!(0.40), dec!(0.42)),
+            5,
+            dec!(100),
+            None,
+            dec!(0.01),
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("don't fit"), "got: {err}");
+    }
+
+    #[test]
+    fn check_order_enforces_the_minimum_size_unless_forced() {
+        let order = LimitOrder {
+            token_id: U256::from(1),
+            side: CliSide::Buy,
+            price: dec!(0.455),
+            size: dec!(2),
+            order_type: CliOrderType::Gtc,
+            post_only: false,
+            expiration: None,
+        };
+        let err = check_order(&order, dec!(0.01), dec!(5), true, false)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("minimum order size of 5"), "got: {err}");
+        assert_eq!(
+            check_order(&order, dec!(0.01), dec!(5), true, true).unwrap(),
+            dec!(0.45)
+        );
+    }
+
+    #[test]
+    fn reward_quotes_sit_as_far_out_as_the_target_score_allows() {
+        let quote = BookQuote {
+            best_bid: Some(dec!(0.48)),
+            best_ask: Some(dec!(0.52)),
+            tick_size: dec!(0.01),
+        };
+        let (mid, quotes) = reward_quotes(&quote, dec!(0.03), dec!(100), dec!(0.25)).unwrap();
+        assert_eq!(mid, dec!(0.50));
+        let prices: Vec<_> = quotes.iter().map(|q| (q.side, q.price)).collect();
+        assert_eq!(prices, [(Side::Buy, dec!(0.49)), (Side::Sell, dec!(0.51))]);
+        assert_eq!(quotes[0].score, dec!(0.4444));
+
+        // A midpoint between ticks and a max spread under half a tick leave
+        // nothing that scores.
+        let between = BookQuote {
+            best_ask: Some(dec!(0.51)),
+            ..quote
+        };
+        assert!(reward_quotes(&between, dec!(0.004), dec!(100), dec!(0.25)).is_err());
+    }
+
+    #[test]
+    fn earnings_range_fills_every_day_and_ranks_markets() {
+        let days = DayArgs {
+            date: None,
+            from: Some("2024-06-01".into()),
+            to: Some("2024-06-03".into()),
+        };
+        let Days::Range(days) = days.resolve(NaiveDate::MIN).unwrap() else {
+            panic!("expected a range");
+        };
+        let day = |d| NaiveDate::from_ymd_opt(2024, 6, d).unwrap();
+        let (a, b) = (B256::repeat_byte(0xa), B256::repeat_byte(0xb));
+        let range = earnings_range(
+            &days,
+            [
+                (day(1), Some(a), dec!(1.5)),
+                (day(3), Some(b), dec!(4)),
+                (day(3), Some(a), dec!(0.5)),
+            ],
+        );
+        assert_eq!(
+            range.daily,
+            [(day(1), dec!(1.5)), (day(2), dec!(0)), (day(3), dec!(4.5))]
+        );
+        assert_eq!(range.markets, [(b, dec!(4)), (a, dec!(2.0))]);
+    }
+
+    #[test]
+    fn month_days_cover_the_month_up_to_today() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        let february = month_days("2024-02", date(6, 1)).unwrap();
+        assert_eq!(february.len(), 29);
+        assert_eq!(february.last(), Some(&date(2, 29)));
+        assert_eq!(month_days("2024-06", date(6, 3)).unwrap().len(), 3);
+        assert!(month_days("2024-07", date(6, 3)).is_err());
+        assert!(month_days("2024-6-1", date(6, 3)).is_err());
+    }
+
+    #[test]
+    fn per_order_repeats_shared_value_or_checks_list_length() {
+        assert_eq!(
+            per_order(Some(CliSide::Buy), &[], 3, "sides").unwrap(),
+            [CliSide::Buy; 3]
+        );
+        assert_eq!(
+            per_order(None, &[CliSide::Buy, CliSide::Sell], 2, "sides").unwrap(),
+            [CliSide::Buy, CliSide::Sell]
+        );
+        assert!(per_order(None, &[CliSide::Buy], 2, "sides").is_err());
+    }
+
+    #[test]
+    fn parse_duration_accepts_compound_units() {
+        assert_eq!(
+            parse_duration("2h30m").unwrap(),
+            chrono::Duration::minutes(150)
+        );
+        assert_eq!(parse_duration("1d").unwrap(), chrono::Duration::hours(24));
+        assert_eq!(
+            parse_duration("90s").unwrap(),
+            chrono::Duration::seconds(90)
+        );
+        assert!(parse_duration("30").is_err());
+        assert!(parse_duration("2x").is_err());
+        assert!(parse_duration("0m").is_err());
+    }
+
+    #[test]
+    fn expiry_adds_security_threshold_for_gtd() {
+        let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let at = expiry(None, Some("1h"))
+            .resolve(CliOrderType::Gtd, now)
+            .unwrap()
+            .unwrap();
+        assert_eq!(at.timestamp(), 1_700_000_000 + 3600 + 60);
+        let at = expiry(Some("2023-11-15T00:00:00Z"), None)
+            .resolve(CliOrderType::Gtd, now)
+            .unwrap()
+            .unwrap();
+        assert_eq!(at.timestamp(), 1_700_006_400 + 60);
+        let at = expiry(Some("1700003600"), None)
+            .resolve(CliOrderType::Gtd, now)
+            .unwrap()
+            .unwrap();
+        assert_eq!(at.timestamp(), 1_700_003_660);
+    }
+
+    #[test]
+    fn expiry_must_match_order_type() {
+        let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        assert!(expiry(None, None).resolve(CliOrderType::Gtd, now).is_err());
+        assert!(
+            expiry(None, Some("1h"))
+                .resolve(CliOrderType::Gtc, now)
+                .is_err()
+        );
+        assert!(
+            expiry(Some("1600000000"), None)
+                .resolve(CliOrderType::Gtd, now)
+                .is_err()
+        );
+        assert_eq!(
+            expiry(None, None).resolve(CliOrderType::Gtc, now).unwrap(),
+            None
+        );
+    }
 
     #[test]
     fn parse_token_id_valid_numeric() {
@@ -1156,6 +3939,22 @@ mod tests {
         assert!(parse_token_ids("1,abc,3").is_err());
     }
 
+    #[test]
+    fn parse_condition_ids_multiple() {
+        let ids = parse_condition_ids(
+            "0x0000000000000000000000000000000000000000000000000000000000000001, \
+             0x0000000000000000000000000000000000000000000000000000000000000002",
+        )
+        .unwrap();
+        assert_eq!(ids.len(), 2);
+        assert_ne!(ids[0], ids[1]);
+    }
+
+    #[test]
+    fn parse_condition_ids_invalid_entry() {
+        assert!(parse_condition_ids("0x01,garbage").is_err());
+    }
+
     #[test]
     fn parse_date_valid() {
         let d = parse_date("2024-06-15").unwrap();
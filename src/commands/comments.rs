@@ -1,13 +1,16 @@
-use super::parse_address;
-use crate::output::comments::{print_comment_detail, print_comments_table};
+use super::{is_numeric_id, parse_address, slug_from_arg};
+use crate::output::comments::{print_comment_detail, print_comment_thread, print_comments_table};
 use crate::output::{OutputFormat, print_json};
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::{Args, Subcommand};
 use polymarket_client_sdk::gamma::{
     self,
     types::{
         ParentEntityType,
-        request::{CommentsByIdRequest, CommentsByUserAddressRequest, CommentsRequest},
+        request::{
+            CommentsByIdRequest, CommentsByUserAddressRequest, CommentsRequest, EventBySlugRequest,
+            MarketBySlugRequest,
+        },
     },
 };
 
@@ -46,6 +49,24 @@ pub enum CommentsCommand {
         ascending: bool,
     },
 
+    /// Show the comment thread for a market or event, with replies nested
+    Thread {
+        /// Event or market slug, polymarket.com URL, or numeric event ID
+        target: String,
+
+        /// Newest first or most reactions first
+        #[arg(long, default_value = "new")]
+        sort: CommentSort,
+
+        /// Max results
+        #[arg(long, default_value = "25")]
+        limit: i32,
+
+        /// Pagination offset
+        #[arg(long)]
+        offset: Option<i32>,
+    },
+
     /// Get a comment by ID
     Get {
         /// Comment ID
@@ -92,6 +113,46 @@ impl From<EntityType> for ParentEntityType {
     }
 }
 
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+pub enum CommentSort {
+    New,
+    Top,
+}
+
+impl CommentSort {
+    /// Gamma field for `order`; both sort descending.
+    fn field(self) -> &'static str {
+        match self {
+            Self::New => "createdAt",
+            Self::Top => "reactionCount",
+        }
+    }
+}
+
+/// Polymarket keeps the discussion on the event page, so a market slug is
+/// mapped to its parent event. A market without one is queried directly.
+async fn thread_parent(client: &gamma::Client, target: &str) -> Result<(ParentEntityType, String)> {
+    if is_numeric_id(target.trim()) {
+        return Ok((ParentEntityType::Event, target.trim().to_string()));
+    }
+    let slug = slug_from_arg(target);
+    let request = EventBySlugRequest::builder().slug(slug).build();
+    if let Ok(event) = client.event_by_slug(&request).await {
+        return Ok((ParentEntityType::Event, event.id));
+    }
+    let request = MarketBySlugRequest::builder().slug(slug).build();
+    let market = client
+        .market_by_slug(&request)
+        .await
+        .with_context(|| format!("No market or event found for '{slug}'"))?;
+    Ok(
+        match market.events.as_deref().and_then(|events| events.first()) {
+            Some(event) => (ParentEntityType::Event, event.id.clone()),
+            None => (ParentEntityType::Market, market.id),
+        },
+    )
+}
+
 pub async fn execute(
     client: &gamma::Client,
     args: CommentsArgs,
@@ -119,7 +180,35 @@ pub async fn execute(
 
             match output {
                 OutputFormat::Table => print_comments_table(&comments),
-                OutputFormat::Json => print_json(&comments)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&comments)?
+                }
+            }
+        }
+
+        CommentsCommand::Thread {
+            target,
+            sort,
+            limit,
+            offset,
+        } => {
+            let (entity_type, entity_id) = thread_parent(client, &target).await?;
+            let request = CommentsRequest::builder()
+                .parent_entity_type(entity_type)
+                .parent_entity_id(entity_id)
+                .limit(limit)
+                .maybe_offset(offset)
+                .order(sort.field().to_string())
+                .ascending(false)
+                .build();
+
+            let comments = client.comments(&request).await?;
+
+            match output {
+                OutputFormat::Table => print_comment_thread(&comments),
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&comments)?
+                }
             }
         }
 
@@ -133,7 +222,9 @@ pub async fn execute(
 
             match output {
                 OutputFormat::Table => print_comment_detail(comment),
-                OutputFormat::Json => print_json(&comment)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&comment)?
+                }
             }
         }
 
@@ -157,7 +248,9 @@ pub async fn execute(
 
             match output {
                 OutputFormat::Table => print_comments_table(&comments),
-                OutputFormat::Json => print_json(&comments)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&comments)?
+                }
             }
         }
     }
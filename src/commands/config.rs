@@ -0,0 +1,503 @@
+use std::fs;
+use std::process::Command;
+
+use anyhow::{Context, Result, bail};
+use clap::{Args, Subcommand, ValueEnum};
+use rust_decimal::Decimal;
+use tabled::Tabled;
+
+use super::clob::CliOrderType;
+use crate::auth;
+use crate::config::{self, Chain, Config};
+use crate::endpoints;
+use crate::output::color::ColorChoice;
+use crate::output::{OutputFormat, print_json, print_raw, render_table};
+
+#[derive(Args)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: ConfigCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// List every setting with its value and default
+    List,
+    /// Show a setting
+    Get {
+        /// Setting name, e.g. output or endpoints.clob
+        key: String,
+    },
+    /// Change a setting
+    Set {
+        /// Setting name, e.g. output or endpoints.clob
+        key: String,
+        value: String,
+    },
+    /// Put a setting back to its default
+    Unset {
+        /// Setting name, e.g. output or endpoints.clob
+        key: String,
+    },
+    /// Open the config file in $VISUAL or $EDITOR
+    Edit,
+}
+
+/// A setting `config` can read and change.
+struct Setting {
+    key: &'static str,
+    /// Shown by `config list`
+    about: &'static str,
+    default: &'static str,
+    /// Kept per wallet profile rather than for the whole config file.
+    per_profile: bool,
+}
+
+const SETTINGS: &[Setting] = &[
+    Setting {
+        key: "output",
+        about: "Output format when -o isn't given",
+        default: "table",
+        per_profile: false,
+    },
+    Setting {
+        key: "color",
+        about: "Color tables: auto, always, or never",
+        default: "auto",
+        per_profile: false,
+    },
+    Setting {
+        key: "order_type",
+        about: "Order type when --order-type isn't given",
+        default: "GTC",
+        per_profile: false,
+    },
+    Setting {
+        key: "confirm_trades",
+        about: "Ask before placing orders and sending transactions",
+        default: "true",
+        per_profile: false,
+    },
+    Setting {
+        key: "chain",
+        about: "Chain when --chain isn't given: polygon or amoy (testnet)",
+        default: "polygon",
+        per_profile: false,
+    },
+    Setting {
+        key: "confirm_above",
+        about: "Only ask for orders worth at least this many dollars",
+        default: "",
+        per_profile: false,
+    },
+    Setting {
+        key: "rpc_url",
+        about: "Polygon RPC endpoints, comma-separated, tried in order",
+        default: auth::RPC_URL,
+        per_profile: true,
+    },
+    Setting {
+        key: "signature_type",
+        about: "Signature type: eoa, proxy, or gnosis-safe",
+        default: config::DEFAULT_SIGNATURE_TYPE,
+        per_profile: true,
+    },
+    Setting {
+        key: "endpoints.clob",
+        about: "CLOB API",
+        default: "https://clob.polymarket.com",
+        per_profile: false,
+    },
+    Setting {
+        key: "endpoints.clob_ws",
+        about: "CLOB WebSocket",
+        default: "wss://ws-subscriptions-clob.polymarket.com",
+        per_profile: false,
+    },
+    Setting {
+        key: "endpoints.gamma",
+        about: "Gamma (markets) API",
+        default: "https://gamma-api.polymarket.com",
+        per_profile: false,
+    },
+    Setting {
+        key: "endpoints.data",
+        about: "Data API",
+        default: "https://data-api.polymarket.com",
+        per_profile: false,
+    },
+    Setting {
+        key: "endpoints.bridge",
+        about: "Bridge API",
+        default: "https://bridge.polymarket.com",
+        per_profile: false,
+    },
+];
+
+fn setting(key: &str) -> Result<&'static Setting> {
+    SETTINGS
+        .iter()
+        .find(|s| s.key == key)
+        .with_context(|| format!("Unknown setting '{key}'. See `polymarket config list`"))
+}
+
+/// The name clap gives a value of `T`, as typed on the command line.
+fn value_name<T: ValueEnum>(value: &T) -> Option<String> {
+    value.to_possible_value().map(|v| v.get_name().to_string())
+}
+
+fn parse_enum<T: ValueEnum>(key: &str, value: &str) -> Result<T> {
+    T::from_str(value, true).map_err(|_| {
+        let names: Vec<_> = T::value_variants().iter().filter_map(value_name).collect();
+        anyhow::anyhow!("{key} must be one of: {}", names.join(", "))
+    })
+}
+
+fn parse_bool(key: &str, value: &str) -> Result<bool> {
+    match value.to_lowercase().as_str() {
+        "true" | "yes" | "on" | "1" => Ok(true),
+        "false" | "no" | "off" | "0" => Ok(false),
+        _ => bail!("{key} must be true or false"),
+    }
+}
+
+fn parse_url(key: &str, value: &str) -> Result<String> {
+    let api = key.strip_prefix("endpoints.").unwrap_or(key);
+    let schemes: &[&str] = if api == "clob_ws" {
+        &["ws://", "wss://"]
+    } else {
+        &["http://", "https://"]
+    };
+    let usable = schemes.iter().any(|s| value.starts_with(s))
+        && (api == "rpc_url" || endpoints::check(api, value));
+    if !usable {
+        bail!("{key} must be a URL starting with {}", schemes.join(" or "));
+    }
+    Ok(value.to_string())
+}
+
+/// The value of `key` in `config`, for the profile `profile`; `None` when
+/// unset.
+fn get(config: &Config, profile: &str, key: &str) -> Option<String> {
+    let settings = config.profile(profile);
+    match key {
+        "output" => config.output.as_ref().and_then(value_name),
+        "color" => config.color.as_ref().and_then(value_name),
+        "order_type" => config.order_type.as_ref().and_then(value_name),
+        "confirm_trades" => config.confirm_trades.map(|b| b.to_string()),
+        "chain" => match Chain::from_id(config.chain_id) {
+            Chain::Polygon => None,
+            chain => value_name(&chain),
+        },
+        "confirm_above" => config.confirm_above.map(|d| d.to_string()),
+        "rpc_url" => settings.and_then(|p| p.rpc_url.clone()),
+        "signature_type" => settings.and_then(|p| p.signature_type.clone()),
+        "endpoints.clob" => config.endpoints.clob.clone(),
+        "endpoints.clob_ws" => config.endpoints.clob_ws.clone(),
+        "endpoints.gamma" => config.endpoints.gamma.clone(),
+        "endpoints.data" => config.endpoints.data.clone(),
+        "endpoints.bridge" => config.endpoints.bridge.clone(),
+        _ => None,
+    }
+}
+
+/// Sets `key` to `value`, or back to its default when `value` is `None`.
+fn set(config: &mut Config, profile: &str, key: &str, value: Option<&str>) -> Result<()> {
+    let url = |value: Option<&str>| value.map(|v| parse_url(key, v)).transpose();
+    match key {
+        "output" => config.output = value.map(|v| parse_enum(key, v)).transpose()?,
+        "color" => {
+            config.color = value
+                .map(|v| parse_enum::<ColorChoice>(key, v))
+                .transpose()?;
+        }
+        "order_type" => {
+            config.order_type = value
+                .map(|v| parse_enum::<CliOrderType>(key, v))
+                .transpose()?;
+        }
+        "confirm_trades" => {
+            config.confirm_trades = value.map(|v| parse_bool(key, v)).transpose()?
+        }
+        "chain" => {
+            config.chain_id = value
+                .map(|v| parse_enum::<Chain>(key, v))
+                .transpose()?
+                .unwrap_or(Chain::Polygon)
+                .id();
+        }
+        "confirm_above" => {
+            config.confirm_above = value
+                .map(|v| {
+                    v.trim_start_matches('$')
+                        .parse::<Decimal>()
+                        .ok()
+                        .filter(|d| !d.is_sign_negative())
+                        .with_context(|| format!("{key} must be a dollar amount, e.g. 50"))
+                })
+                .transpose()?;
+        }
+        "rpc_url" => {
+            let urls = value
+                .map(|v| {
+                    let urls = config::split_urls(v)
+                        .iter()
+                        .map(|u| parse_url(key, u))
+                        .collect::<Result<Vec<_>>>()?;
+                    if urls.is_empty() {
+                        bail!("{key} must be a URL starting with http:// or https://");
+                    }
+                    Ok(urls.join(","))
+                })
+                .transpose()?;
+            config.profile_mut(profile).rpc_url = urls;
+        }
+        "signature_type" => {
+            if let Some(v) = value
+                && !matches!(v, "eoa" | "proxy" | "gnosis-safe")
+            {
+                bail!("signature_type must be one of: eoa, proxy, gnosis-safe");
+            }
+            config.profile_mut(profile).signature_type = value.map(str::to_string);
+        }
+        "endpoints.clob" => config.endpoints.clob = url(value)?,
+        "endpoints.clob_ws" => config.endpoints.clob_ws = url(value)?,
+        "endpoints.gamma" => config.endpoints.gamma = url(value)?,
+        "endpoints.data" => config.endpoints.data = url(value)?,
+        "endpoints.bridge" => config.endpoints.bridge = url(value)?,
+        _ => bail!("Unknown setting '{key}'"),
+    }
+    Ok(())
+}
+
+pub fn execute(args: ConfigArgs, output: &OutputFormat) -> Result<()> {
+    match args.command {
+        ConfigCommand::List => cmd_list(output),
+        ConfigCommand::Get { key } => cmd_get(output, &key),
+        ConfigCommand::Set { key, value } => cmd_set(output, &key, Some(&value)),
+        ConfigCommand::Unset { key } => cmd_set(output, &key, None),
+        ConfigCommand::Edit => cmd_edit(output),
+    }
+}
+
+fn cmd_list(output: &OutputFormat) -> Result<()> {
+    let config = config::load_config()?.unwrap_or_default();
+    let profile = config::active_profile();
+
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let list: Vec<_> = SETTINGS
+                .iter()
+                .map(|s| {
+                    serde_json::json!({
+                        "key": s.key,
+                        "value": get(&config, &profile, s.key),
+                        "default": (!s.default.is_empty()).then_some(s.default),
+                        "profile": s.per_profile.then_some(&profile),
+                        "description": s.about,
+                    })
+                })
+                .collect();
+            print_json(&list)?;
+        }
+        OutputFormat::Table => {
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Setting")]
+                key: String,
+                #[tabled(rename = "Value")]
+                value: String,
+                #[tabled(rename = "Description")]
+                about: &'static str,
+            }
+            let rows: Vec<Row> = SETTINGS
+                .iter()
+                .map(|s| Row {
+                    key: if s.per_profile {
+                        format!("{} ({profile} profile)", s.key)
+                    } else {
+                        s.key.to_string()
+                    },
+                    value: match get(&config, &profile, s.key) {
+                        Some(value) => value,
+                        None if s.default.is_empty() => "-".into(),
+                        None => format!("{} (default)", s.default),
+                    },
+                    about: s.about,
+                })
+                .collect();
+            println!("{}", render_table(rows)?);
+        }
+    }
+    Ok(())
+}
+
+fn cmd_get(output: &OutputFormat, key: &str) -> Result<()> {
+    let setting = setting(key)?;
+    let config = config::load_config()?.unwrap_or_default();
+    let value = get(&config, &config::active_profile(), key);
+    let shown = value
+        .clone()
+        .or_else(|| (!setting.default.is_empty()).then(|| setting.default.to_string()));
+    if print_raw(shown.as_deref().unwrap_or_default()) {
+        return Ok(());
+    }
+
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({
+                "key": key,
+                "value": shown,
+                "default": value.is_none(),
+            }))?;
+        }
+        OutputFormat::Table => match (value, shown) {
+            (Some(value), _) => println!("{value}"),
+            (None, Some(default)) => println!("{default} (default)"),
+            (None, None) => println!("(not set)"),
+        },
+    }
+    Ok(())
+}
+
+fn cmd_set(output: &OutputFormat, key: &str, value: Option<&str>) -> Result<()> {
+    let setting = setting(key)?;
+    let mut profile = String::new();
+    config::update_config(|config, name| {
+        profile = name.to_string();
+        set(config, name, key, value)
+    })?;
+    let scope = if setting.per_profile {
+        format!(" for profile '{profile}'")
+    } else {
+        String::new()
+    };
+
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({
+                "key": key,
+                "value": value,
+                "profile": setting.per_profile.then_some(&profile),
+            }))?;
+        }
+        OutputFormat::Table => match value {
+            Some(value) => println!("Set {key} = {value}{scope}."),
+            None => println!("Reset {key} to its default{scope}."),
+        },
+    }
+    Ok(())
+}
+
+/// The editor to open, as a program and its leading arguments.
+fn editor() -> Vec<String> {
+    let configured = ["VISUAL", "EDITOR"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|e| !e.trim().is_empty());
+    let fallback = if cfg!(windows) { "notepad" } else { "vi" };
+    configured
+        .as_deref()
+        .unwrap_or(fallback)
+        .split_whitespace()
+        .map(str::to_string)
+        .collect()
+}
+
+fn cmd_edit(output: &OutputFormat) -> Result<()> {
+    if !config::config_exists() {
+        config::update_config(|_, _| Ok(()))?;
+    }
+    let path = config::config_path()?;
+    let before = fs::read_to_string(&path).context("Failed to read config file")?;
+
+    let editor = editor();
+    let status = Command::new(&editor[0])
+        .args(&editor[1..])
+        .arg(&path)
+        .status()
+        .with_context(|| format!("Could not run editor `{}`", editor[0]))?;
+    if !status.success() {
+        bail!("Editor `{}` exited with {status}", editor[0]);
+    }
+
+    // A config file that doesn't parse would be ignored, losing the wallet,
+    // so put the old one back and keep the edit beside it.
+    let after = fs::read_to_string(&path).context("Failed to read config file")?;
+    if let Err(e) = serde_json::from_str::<Config>(&after) {
+        let rejected = path.with_extension("json.rejected");
+        fs::write(&rejected, &after).context("Failed to save the rejected edit")?;
+        fs::write(&path, &before).context("Failed to restore the config file")?;
+        bail!(
+            "The edited config isn't valid ({e}), so it was not applied. \
+             Your edit is saved in {}",
+            rejected.display()
+        );
+    }
+
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({
+                "edited": path.display().to_string(),
+                "changed": before != after,
+            }))?;
+        }
+        OutputFormat::Table if before == after => println!("No changes."),
+        OutputFormat::Table => println!("Saved {}", path.display()),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_listed_setting_round_trips() {
+        let mut config = Config::default();
+        let values = [
+            ("output", "json"),
+            ("color", "never"),
+            ("order_type", "FOK"),
+            ("confirm_trades", "false"),
+            ("chain", "amoy"),
+            ("confirm_above", "25.5"),
+            ("rpc_url", "https://polygon.example"),
+            ("signature_type", "eoa"),
+            ("endpoints.clob", "https://clob.example"),
+            ("endpoints.clob_ws", "wss://ws.example"),
+            ("endpoints.gamma", "https://gamma.example"),
+            ("endpoints.data", "https://data.example"),
+            ("endpoints.bridge", "https://bridge.example"),
+        ];
+        assert_eq!(values.len(), SETTINGS.len());
+        for (key, value) in values {
+            set(&mut config, "default", key, Some(value)).unwrap();
+            assert_eq!(
+                get(&config, "default", key).as_deref(),
+                Some(value),
+                "{key}"
+            );
+            set(&mut config, "default", key, None).unwrap();
+            assert_eq!(get(&config, "default", key), None, "{key}");
+        }
+    }
+
+    #[test]
+    fn rejects_bad_values() {
+        let mut config = Config::default();
+        assert!(set(&mut config, "default", "output", Some("xml")).is_err());
+        assert!(set(&mut config, "default", "confirm_above", Some("-5")).is_err());
+        assert!(set(&mut config, "default", "endpoints.clob", Some("ftp://x")).is_err());
+        assert!(
+            set(
+                &mut config,
+                "default",
+                "endpoints.clob_ws",
+                Some("https://x")
+            )
+            .is_err()
+        );
+        assert!(setting("nope").is_err());
+    }
+}
@@ -1,39 +1,132 @@
-use alloy::primitives::U256;
+#![allow(clippy::exhaustive_enums, reason = "Generated by sol! macro")]
+#![allow(clippy::exhaustive_structs, reason = "Generated by sol! macro")]
+
+use std::collections::HashMap;
+
+use alloy::primitives::{Bytes, U256};
+use alloy::sol;
+use alloy::sol_types::SolCall as _;
 use anyhow::{Context, Result};
 use clap::{Args, Subcommand};
 use polymarket_client_sdk::ctf::types::{
-    CollectionIdRequest, ConditionIdRequest, MergePositionsRequest, PositionIdRequest,
-    RedeemNegRiskRequest, RedeemPositionsRequest, SplitPositionRequest,
+    CollectionIdRequest, ConditionIdRequest, PositionIdRequest,
 };
-use polymarket_client_sdk::types::{Address, B256};
-use polymarket_client_sdk::{POLYGON, ctf};
+use polymarket_client_sdk::data::types::request::PositionsRequest;
+use polymarket_client_sdk::data::types::response::Position;
+use polymarket_client_sdk::gamma::types::request::MarketsRequest;
+use polymarket_client_sdk::types::{Address, B256, ChainId, address};
+use polymarket_client_sdk::{POLYGON, contract_config, ctf};
 use rust_decimal::Decimal;
 
-use crate::auth;
+use crate::endpoints;
 use crate::output::OutputFormat;
-use crate::output::ctf as ctf_output;
+use crate::output::ctf::{
+    self as ctf_output, MarketIdChain, OperatorApproval, OutcomeIds, PositionBalance, RedeemResult,
+    RedeemTarget,
+};
+use crate::output::dry_run::PlannedTx;
+use crate::tx::{self, GasArgs, TxOptions, WaitArgs};
+use crate::{auth, config};
+
+// Calls CTF commands send, encoded locally so they can be shown for
+// `--dry-run`, priced up front, and sent with the user's gas settings.
+sol! {
+    #[sol(rpc)]
+    interface IConditionalTokens {
+        function splitPosition(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] partition,
+            uint256 amount
+        ) external;
+        function mergePositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] partition,
+            uint256 amount
+        ) external;
+        function redeemPositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] indexSets
+        ) external;
+        function safeTransferFrom(
+            address from,
+            address to,
+            uint256 id,
+            uint256 value,
+            bytes data
+        ) external;
+        function safeBatchTransferFrom(
+            address from,
+            address to,
+            uint256[] ids,
+            uint256[] values,
+            bytes data
+        ) external;
+        function setApprovalForAll(address operator, bool approved) external;
+        function isApprovedForAll(address account, address operator) external view returns (bool);
+        function balanceOf(address account, uint256 id) external view returns (uint256);
+        function balanceOfBatch(
+            address[] accounts,
+            uint256[] ids
+        ) external view returns (uint256[]);
+    }
+
+    #[sol(rpc)]
+    interface IERC20 {
+        function balanceOf(address account) external view returns (uint256);
+    }
+
+    interface INegRiskAdapter {
+        function redeemPositions(bytes32 conditionId, uint256[] amounts) external;
+    }
+}
 
 const USDC_DECIMALS: Decimal = Decimal::from_parts(1_000_000, 0, 0, false, 0);
 
+/// What neg-risk positions are backed by on Polygon: the adapter wraps USDC
+/// so it can convert between the positions of one event.
+const POLYGON_WRAPPED_COLLATERAL: Address = address!("0x3A3BD7bb9528E159577F7C2e685CC81A765002E2");
+
+/// Position IDs per `balanceOfBatch` call, to stay under RPC payload limits.
+const BALANCE_BATCH: usize = 200;
+
+/// Positions per Data API page, the most it returns at once.
+const POSITIONS_PAGE: i32 = 500;
+
 #[derive(Args)]
 pub struct CtfArgs {
     #[command(subcommand)]
     pub command: CtfCommand,
+
+    #[command(flatten)]
+    pub wait: WaitArgs,
+
+    /// Send without first checking, with `eth_call`, that the call succeeds
+    #[arg(long, global = true)]
+    pub skip_simulation: bool,
 }
 
 #[derive(Subcommand)]
 pub enum CtfCommand {
     /// Split collateral into outcome tokens
     Split {
-        /// Condition ID (0x-prefixed 32-byte hex)
+        /// Condition ID (0x-prefixed 32-byte hex), market slug, or polymarket.com URL
         #[arg(long)]
         condition: String,
         /// Amount in USDC (e.g. 10 for $10)
+        #[arg(long, required_unless_present = "max")]
+        amount: Option<String>,
+        /// Split the wallet's whole collateral balance
+        #[arg(long, conflicts_with = "amount")]
+        max: bool,
+        /// Collateral token address (defaults to the chain's USDC)
         #[arg(long)]
-        amount: String,
-        /// Collateral token address (defaults to USDC)
-        #[arg(long, default_value = "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174")]
-        collateral: String,
+        collateral: Option<String>,
         /// Custom partition as comma-separated index sets (e.g. "1,2" for binary, "1,2,4" for 3-outcome)
         #[arg(long)]
         partition: Option<String>,
@@ -43,15 +136,18 @@ pub enum CtfCommand {
     },
     /// Merge outcome tokens back into collateral
     Merge {
-        /// Condition ID (0x-prefixed 32-byte hex)
+        /// Condition ID (0x-prefixed 32-byte hex), market slug, or polymarket.com URL
         #[arg(long)]
         condition: String,
         /// Amount in USDC (e.g. 10 for $10)
+        #[arg(long, required_unless_present = "max")]
+        amount: Option<String>,
+        /// Merge every complete set: the smallest balance across the partition
+        #[arg(long, conflicts_with = "amount")]
+        max: bool,
+        /// Collateral token address (defaults to the chain's USDC)
         #[arg(long)]
-        amount: String,
-        /// Collateral token address (defaults to USDC)
-        #[arg(long, default_value = "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174")]
-        collateral: String,
+        collateral: Option<String>,
         /// Custom partition as comma-separated index sets (e.g. "1,2" for binary, "1,2,4" for 3-outcome)
         #[arg(long)]
         partition: Option<String>,
@@ -61,12 +157,12 @@ pub enum CtfCommand {
     },
     /// Redeem winning tokens after market resolution
     Redeem {
-        /// Condition ID (0x-prefixed 32-byte hex)
+        /// Condition ID (0x-prefixed 32-byte hex), market slug, or polymarket.com URL
         #[arg(long)]
         condition: String,
-        /// Collateral token address (defaults to USDC)
-        #[arg(long, default_value = "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174")]
-        collateral: String,
+        /// Collateral token address (defaults to the chain's USDC)
+        #[arg(long)]
+        collateral: Option<String>,
         /// Custom index sets as comma-separated values (e.g. "1,2" for binary, "1" for YES only)
         #[arg(long)]
         index_sets: Option<String>,
@@ -74,15 +170,68 @@ pub enum CtfCommand {
         #[arg(long)]
         parent_collection: Option<String>,
     },
+    /// Redeem every resolved market the wallet still holds winnings in, one
+    /// transaction per market
+    RedeemAll {
+        /// Also redeem markets whose held outcomes pay nothing, clearing the
+        /// losing tokens from the wallet
+        #[arg(long)]
+        include_losers: bool,
+    },
     /// Redeem neg-risk positions
     RedeemNegRisk {
-        /// Condition ID (0x-prefixed 32-byte hex)
+        /// Condition ID (0x-prefixed 32-byte hex), market slug, or polymarket.com URL
         #[arg(long)]
         condition: String,
         /// Comma-separated amounts in USDC for each outcome (e.g. "10,5")
         #[arg(long)]
         amounts: String,
     },
+    /// Send outcome tokens from the wallet holding your positions to another address
+    Transfer {
+        /// Position ID (ERC1155 token ID) to send
+        #[arg(long)]
+        position: String,
+        /// Recipient address (0x-prefixed)
+        #[arg(long)]
+        to: String,
+        /// Shares to send (e.g. 25.5)
+        #[arg(long)]
+        amount: String,
+    },
+    /// Send several positions to another address in one transaction
+    TransferBatch {
+        /// Comma-separated position IDs (ERC1155 token IDs) to send
+        #[arg(long)]
+        positions: String,
+        /// Comma-separated shares to send, one per position
+        #[arg(long)]
+        amounts: String,
+        /// Recipient address (0x-prefixed)
+        #[arg(long)]
+        to: String,
+    },
+    /// Check, grant, or revoke ERC1155 operator approvals on your outcome tokens
+    Approval {
+        #[command(subcommand)]
+        command: ApprovalCommand,
+    },
+    /// Show on-chain balances of outcome tokens held by a wallet
+    Balances {
+        /// Comma-separated position IDs (ERC1155 token IDs); defaults to every
+        /// position the Data API lists for the wallet
+        #[arg(long)]
+        tokens: Option<String>,
+        /// Wallet to check (defaults to the wallet that holds your positions)
+        #[arg(long)]
+        address: Option<String>,
+    },
+    /// Derive every ID of a market: question and condition IDs, and the
+    /// collection and position IDs of each outcome
+    Ids {
+        /// Condition ID (0x-prefixed 32-byte hex), market slug, or polymarket.com URL
+        market: String,
+    },
     /// Calculate a condition ID from oracle, question, and outcome count
     ConditionId {
         /// Oracle address (0x-prefixed)
@@ -97,7 +246,7 @@ pub enum CtfCommand {
     },
     /// Calculate a collection ID from condition and index set
     CollectionId {
-        /// Condition ID (0x-prefixed 32-byte hex)
+        /// Condition ID (0x-prefixed 32-byte hex), market slug, or polymarket.com URL
         #[arg(long)]
         condition: String,
         /// Index set (e.g. 1 for YES, 2 for NO in binary markets)
@@ -109,15 +258,40 @@ pub enum CtfCommand {
     },
     /// Calculate a position ID (ERC1155 token ID) from collateral and collection
     PositionId {
-        /// Collateral token address (defaults to USDC)
-        #[arg(long, default_value = "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174")]
-        collateral: String,
+        /// Collateral token address (defaults to the chain's USDC)
+        #[arg(long)]
+        collateral: Option<String>,
         /// Collection ID (0x-prefixed 32-byte hex)
         #[arg(long)]
         collection: String,
     },
 }
 
+#[derive(Subcommand)]
+pub enum ApprovalCommand {
+    /// Show whether each operator may move a wallet's outcome tokens
+    Check {
+        /// Operator address (defaults to the exchanges and the neg-risk adapter)
+        #[arg(long)]
+        operator: Option<String>,
+        /// Wallet to check (defaults to your wallet)
+        #[arg(long)]
+        address: Option<String>,
+    },
+    /// Let operators move your outcome tokens (one transaction per operator)
+    Set {
+        /// Operator address (defaults to the exchanges and the neg-risk adapter)
+        #[arg(long)]
+        operator: Option<String>,
+    },
+    /// Stop operators from moving your outcome tokens (one transaction per operator)
+    Revoke {
+        /// Operator address (defaults to the exchanges and the neg-risk adapter)
+        #[arg(long)]
+        operator: Option<String>,
+    },
+}
+
 fn usdc_to_raw(val: Decimal) -> Result<U256> {
     let raw = val * USDC_DECIMALS;
     anyhow::ensure!(
@@ -130,7 +304,7 @@ fn usdc_to_raw(val: Decimal) -> Result<U256> {
     Ok(U256::from(raw_u64))
 }
 
-fn parse_usdc_amount(s: &str) -> Result<U256> {
+pub(crate) fn parse_usdc_amount(s: &str) -> Result<U256> {
     let val: Decimal = s.trim().parse().context(format!("Invalid amount: {s}"))?;
     anyhow::ensure!(val > Decimal::ZERO, "Amount must be positive");
     usdc_to_raw(val)
@@ -164,6 +338,485 @@ fn parse_u256_csv(s: &str) -> Result<Vec<U256>> {
         .collect()
 }
 
+/// Outcome tokens share USDC's 6 decimals, so raw balances convert the same way.
+fn shares_from_raw(raw: U256) -> Result<Decimal> {
+    let raw = i128::try_from(raw).map_err(|_| anyhow::anyhow!("Balance too large: {raw}"))?;
+    Ok(Decimal::try_from_i128_with_scale(raw, 6)
+        .map_err(|_| anyhow::anyhow!("Balance too large: {raw}"))?
+        .normalize())
+}
+
+/// A transfer of more than the wallet holds reverts without saying why, so
+/// check every position first and name each one that falls short.
+fn check_holdings(from: Address, ids: &[U256], amounts: &[U256], held: &[U256]) -> Result<()> {
+    let short: Vec<String> = ids
+        .iter()
+        .zip(amounts)
+        .zip(held)
+        .filter(|((_, amount), held)| held < amount)
+        .map(|((id, amount), held)| {
+            Ok(format!(
+                "position {id}: holds {}, sending {}",
+                shares_from_raw(*held)?,
+                shares_from_raw(*amount)?
+            ))
+        })
+        .collect::<Result<_>>()?;
+    anyhow::ensure!(
+        short.is_empty(),
+        "{from} doesn't hold enough shares:\n  {}",
+        short.join("\n  ")
+    );
+    Ok(())
+}
+
+/// The funder's whole collateral balance, for `split --max`.
+async fn max_split(owner: Address, collateral: Address) -> Result<U256> {
+    let provider = auth::create_readonly_provider().await?;
+    let balance = IERC20::new(collateral, provider)
+        .balanceOf(owner)
+        .call()
+        .await
+        .context("Failed to read the collateral balance")?;
+    anyhow::ensure!(balance > U256::ZERO, "{owner} has no collateral to split");
+    Ok(balance)
+}
+
+/// The number of complete sets the funder holds, for `merge --max`: a merge
+/// burns one of every outcome in the partition, so the smallest balance is
+/// the most it can merge.
+async fn max_merge(
+    owner: Address,
+    collateral: Address,
+    parent: B256,
+    condition_id: B256,
+    partition: &[U256],
+) -> Result<U256> {
+    let provider = auth::create_readonly_provider().await?;
+    let client = ctf::Client::new(provider.clone(), config::chain_id())?;
+    let mut positions = Vec::with_capacity(partition.len());
+    for &index_set in partition {
+        let collection = client
+            .collection_id(
+                &CollectionIdRequest::builder()
+                    .parent_collection_id(parent)
+                    .condition_id(condition_id)
+                    .index_set(index_set)
+                    .build(),
+            )
+            .await?
+            .collection_id;
+        let position = client
+            .position_id(
+                &PositionIdRequest::builder()
+                    .collateral_token(collateral)
+                    .collection_id(collection)
+                    .build(),
+            )
+            .await?
+            .position_id;
+        positions.push(position);
+    }
+    let balances = IConditionalTokens::new(conditional_tokens()?, provider)
+        .balanceOfBatch(vec![owner; positions.len()], positions)
+        .call()
+        .await
+        .context("Failed to read outcome token balances")?;
+    let sets = balances.into_iter().min().unwrap_or_default();
+    anyhow::ensure!(sets > U256::ZERO, "{owner} holds no complete sets to merge");
+    Ok(sets)
+}
+
+/// One outcome of a resolved market held by the wallet.
+struct HeldOutcome {
+    condition_id: B256,
+    title: String,
+    neg_risk: bool,
+    outcome_index: usize,
+    /// Raw on-chain balance
+    shares: U256,
+    /// What each share pays on redemption
+    price: Decimal,
+}
+
+/// Groups held outcomes by market, in the order first seen. Outcomes with
+/// nothing left on-chain were already redeemed and are dropped.
+fn redeem_targets(held: Vec<HeldOutcome>) -> Result<Vec<RedeemTarget>> {
+    let mut targets: Vec<RedeemTarget> = Vec::new();
+    for outcome in held.into_iter().filter(|h| !h.shares.is_zero()) {
+        let index = match targets
+            .iter()
+            .position(|t| t.condition_id == outcome.condition_id)
+        {
+            Some(index) => index,
+            None => {
+                targets.push(RedeemTarget {
+                    condition_id: outcome.condition_id,
+                    title: outcome.title,
+                    neg_risk: outcome.neg_risk,
+                    amounts: default_index_sets().iter().map(|_| U256::ZERO).collect(),
+                    payout: Decimal::ZERO,
+                });
+                targets.len() - 1
+            }
+        };
+        let target = &mut targets[index];
+        if target.amounts.len() <= outcome.outcome_index {
+            target.amounts.resize(outcome.outcome_index + 1, U256::ZERO);
+        }
+        target.amounts[outcome.outcome_index] += outcome.shares;
+        target.payout += shares_from_raw(outcome.shares)? * outcome.price;
+    }
+    Ok(targets)
+}
+
+/// Index sets covering every outcome of a market, for `redeemPositions`.
+fn full_index_sets(outcomes: usize) -> Vec<U256> {
+    (0..outcomes).map(|i| U256::from(1u64) << i).collect()
+}
+
+/// Every position the Data API lists for `owner`, including dust, paging
+/// until a short page.
+async fn positions(owner: Address, redeemable: Option<bool>) -> Result<Vec<Position>> {
+    let mut all = Vec::new();
+    loop {
+        let request = PositionsRequest::builder()
+            .user(owner)
+            .maybe_redeemable(redeemable)
+            .size_threshold(Decimal::ZERO)
+            .limit(POSITIONS_PAGE)?
+            .offset(i32::try_from(all.len())?)?
+            .build();
+        let page = endpoints::data().positions(&request).await?;
+        let short = page.len() < POSITIONS_PAGE as usize;
+        all.extend(page);
+        if short {
+            return Ok(all);
+        }
+    }
+}
+
+/// Finds resolved markets through the Data API, checks what is still held
+/// on-chain, and redeems each market in turn. One failed redemption doesn't
+/// stop the rest.
+async fn redeem_all(
+    include_losers: bool,
+    signature_type: Option<&str>,
+    options: &TxOptions<'_>,
+    output: &OutputFormat,
+) -> Result<()> {
+    let funder = Funder::resolve(options.private_key, signature_type, options.dry_run)?;
+    let owner = funder.address;
+    let positions = positions(owner, Some(true)).await?;
+
+    let provider = auth::create_readonly_provider().await?;
+    let contract = IConditionalTokens::new(conditional_tokens()?, provider.clone());
+    let token_ids: Vec<U256> = positions.iter().map(|p| p.asset).collect();
+    let mut balances = Vec::with_capacity(token_ids.len());
+    for chunk in token_ids.chunks(BALANCE_BATCH) {
+        balances.extend(
+            contract
+                .balanceOfBatch(vec![owner; chunk.len()], chunk.to_vec())
+                .call()
+                .await
+                .context("Failed to read balances from the Conditional Tokens contract")?,
+        );
+    }
+    let held = positions
+        .into_iter()
+        .zip(balances)
+        .map(|(p, shares)| HeldOutcome {
+            condition_id: p.condition_id,
+            title: p.title,
+            neg_risk: p.negative_risk,
+            outcome_index: usize::try_from(p.outcome_index).unwrap_or_default(),
+            shares,
+            price: p.cur_price,
+        })
+        .collect();
+    let mut targets = redeem_targets(held)?;
+    if !include_losers {
+        targets.retain(|t| !t.payout.is_zero());
+    }
+    ctf_output::print_redeem_targets(owner, &targets, output)?;
+    if targets.is_empty() {
+        return Ok(());
+    }
+
+    let collateral = resolve_collateral(None)?;
+    let ctf_address = conditional_tokens()?;
+    let txs: Vec<PlannedTx> = targets
+        .iter()
+        .map(|t| {
+            let (to, calldata) = if t.neg_risk {
+                let call = INegRiskAdapter::redeemPositionsCall {
+                    conditionId: t.condition_id,
+                    amounts: t.amounts.clone(),
+                };
+                (neg_risk_adapter()?, call.abi_encode())
+            } else {
+                let call = IConditionalTokens::redeemPositionsCall {
+                    collateralToken: collateral,
+                    parentCollectionId: B256::default(),
+                    conditionId: t.condition_id,
+                    indexSets: full_index_sets(t.amounts.len()),
+                };
+                (ctf_address, call.abi_encode())
+            };
+            funder.call(format!("redeem \"{}\"", t.title), to, calldata)
+        })
+        .collect::<Result<_>>()?;
+
+    let payout: Decimal = targets.iter().map(|t| t.payout).sum();
+    let summary = [format!(
+        "Redeem {} resolved markets for about ${}",
+        targets.len(),
+        payout.round_dp(2)
+    )];
+    let Some(gas) = tx::prepare(&txs, &summary, options, output).await? else {
+        return Ok(());
+    };
+
+    let usdc = IERC20::new(collateral, provider);
+    let before = usdc.balanceOf(owner).call().await.ok();
+    let wallet = auth::create_provider(options.private_key).await?;
+    let mut results = Vec::with_capacity(targets.len());
+    for ((target, tx), gas) in targets.iter().zip(&txs).zip(&gas) {
+        let sent = tx::send(&wallet, tx, gas, options.wait).await;
+        let result = RedeemResult {
+            title: target.title.clone(),
+            payout: target.payout,
+            tx_hash: sent.map(|sent| sent.hash).map_err(|e| format!("{e:#}")),
+        };
+        ctf_output::print_redeem_result(&result, output);
+        results.push(result);
+    }
+
+    // What actually arrived, rather than what the Data API expected. Nothing
+    // has yet if we didn't wait.
+    let after = if options.wait.no_wait {
+        None
+    } else {
+        usdc.balanceOf(owner).call().await.ok()
+    };
+    let recovered = match (before, after) {
+        (Some(before), Some(after)) => Some(shares_from_raw(after.saturating_sub(before))?),
+        _ => None,
+    };
+    ctf_output::print_redeem_summary(&results, recovered, output)?;
+    let failed = results.iter().filter(|r| r.tx_hash.is_err()).count();
+    anyhow::ensure!(
+        failed == 0,
+        "{failed} of {} redemptions failed",
+        results.len()
+    );
+    Ok(())
+}
+
+/// The operators an approval command acts on, named when they are one of
+/// Polymarket's contracts.
+fn operators(operator: Option<&str>) -> Result<Vec<(String, Address)>> {
+    let known = super::approve::approval_targets()?;
+    let Some(operator) = operator else {
+        return Ok(known
+            .into_iter()
+            .map(|t| (t.name.to_string(), t.address))
+            .collect());
+    };
+    let address = super::parse_address(operator)?;
+    let name = known
+        .iter()
+        .find(|t| t.address == address)
+        .map_or("Custom operator", |t| t.name);
+    Ok(vec![(name.to_string(), address)])
+}
+
+async fn approval(
+    command: ApprovalCommand,
+    options: &TxOptions<'_>,
+    output: &OutputFormat,
+) -> Result<()> {
+    let (operator, approve) = match &command {
+        ApprovalCommand::Check { operator, address } => {
+            let operators = operators(operator.as_deref())?;
+            let owner = match address {
+                Some(address) => super::parse_address(address)?,
+                None => auth::resolve_address(options.private_key)?,
+            };
+            let provider = auth::create_readonly_provider().await?;
+            let contract = IConditionalTokens::new(conditional_tokens()?, provider);
+            let mut approvals = Vec::with_capacity(operators.len());
+            for (name, operator) in operators {
+                approvals.push(OperatorApproval {
+                    approved: contract.isApprovedForAll(owner, operator).call().await?,
+                    name,
+                    operator,
+                    tx_hash: None,
+                });
+            }
+            return ctf_output::print_operator_approvals(owner, &approvals, output);
+        }
+        ApprovalCommand::Set { operator } => (operator, true),
+        ApprovalCommand::Revoke { operator } => (operator, false),
+    };
+    let operators = operators(operator.as_deref())?;
+    let to = conditional_tokens()?;
+    let plan = |name: &str, operator: Address| {
+        planned(
+            format!("CTF setApprovalForAll({approve}) \u{2192} {name}"),
+            to,
+            IConditionalTokens::setApprovalForAllCall {
+                operator,
+                approved: approve,
+            }
+            .abi_encode(),
+            options.private_key,
+        )
+    };
+    if options.dry_run {
+        let txs: Vec<PlannedTx> = operators.iter().map(|(n, o)| plan(n, *o)).collect();
+        tx::prepare(&txs, &[], options, output).await?;
+        return Ok(());
+    }
+
+    // Skip operators already in the wanted state rather than pay gas for nothing.
+    let owner = auth::resolve_address(options.private_key)?;
+    let contract = IConditionalTokens::new(to, auth::create_readonly_provider().await?);
+    let mut pending = Vec::with_capacity(operators.len());
+    for (_, operator) in &operators {
+        pending.push(contract.isApprovedForAll(owner, *operator).call().await? != approve);
+    }
+    let txs: Vec<PlannedTx> = operators
+        .iter()
+        .zip(&pending)
+        .filter(|(_, pending)| **pending)
+        .map(|((name, operator), _)| plan(name, *operator))
+        .collect();
+
+    let mut hashes = Vec::with_capacity(txs.len());
+    if !txs.is_empty() {
+        let mut summary = vec![if approve {
+            "Let these operators move all of your outcome tokens:".to_string()
+        } else {
+            "Stop these operators from moving your outcome tokens:".to_string()
+        }];
+        summary.extend(
+            operators
+                .iter()
+                .zip(&pending)
+                .filter(|(_, pending)| **pending)
+                .map(|((name, operator), _)| format!("  {name} ({operator})")),
+        );
+        let Some(gas) = tx::prepare(&txs, &summary, options, output).await? else {
+            return Ok(());
+        };
+        let wallet = auth::create_provider(options.private_key).await?;
+        for (tx, gas) in txs.iter().zip(&gas) {
+            hashes.push(tx::send(&wallet, tx, gas, options.wait).await?.hash);
+        }
+    }
+    let mut hashes = hashes.into_iter();
+    let approvals: Vec<OperatorApproval> = operators
+        .into_iter()
+        .zip(pending)
+        .map(|((name, operator), pending)| OperatorApproval {
+            name,
+            operator,
+            approved: approve,
+            tx_hash: if pending { hashes.next() } else { None },
+        })
+        .collect();
+    ctf_output::print_operator_approvals(owner, &approvals, output)
+}
+
+/// Derives a market's IDs on-chain from what Gamma knows about it, checking
+/// them against the condition and token IDs Gamma lists.
+async fn ids(market: &str, output: &OutputFormat) -> Result<()> {
+    let condition_id = super::parse_condition_id(&super::resolve_condition_arg(market).await?)?;
+    let request = MarketsRequest::builder()
+        .condition_ids(vec![condition_id])
+        .build();
+    let market = endpoints::gamma()
+        .markets(&request)
+        .await?
+        .into_iter()
+        .next()
+        .with_context(|| format!("Gamma has no market with condition ID {condition_id}"))?;
+    let question_id = market
+        .question_id
+        .context("Gamma has no question ID for this market")?;
+    let neg_risk = market.neg_risk.unwrap_or(false);
+    // Neg-risk conditions are prepared by the adapter rather than the UMA
+    // oracle that resolves them.
+    let oracle = if neg_risk {
+        Some(neg_risk_adapter()?)
+    } else {
+        market
+            .resolved_by
+            .as_deref()
+            .and_then(|a| super::parse_address(a).ok())
+    };
+    let collateral = if neg_risk {
+        wrapped_collateral(config::chain_id())?
+    } else {
+        contract_config(config::chain_id(), false)
+            .context("No contract config for this chain")?
+            .collateral
+    };
+    let outcomes = market.outcomes.unwrap_or_default();
+    let tokens = market.clob_token_ids.unwrap_or_default();
+
+    let provider = auth::create_readonly_provider().await?;
+    let client = ctf::Client::new(provider, config::chain_id())?;
+    let derived_condition_id = match oracle {
+        Some(oracle) => {
+            let request = ConditionIdRequest::builder()
+                .oracle(oracle)
+                .question_id(question_id)
+                .outcome_slot_count(U256::from(outcomes.len()))
+                .build();
+            Some(client.condition_id(&request).await?.condition_id)
+        }
+        None => None,
+    };
+    let mut outcome_ids = Vec::with_capacity(outcomes.len());
+    for (i, outcome) in outcomes.into_iter().enumerate() {
+        let index_set = U256::from(1) << i;
+        let request = CollectionIdRequest::builder()
+            .parent_collection_id(B256::default())
+            .condition_id(condition_id)
+            .index_set(index_set)
+            .build();
+        let collection_id = client.collection_id(&request).await?.collection_id;
+        let request = PositionIdRequest::builder()
+            .collateral_token(collateral)
+            .collection_id(collection_id)
+            .build();
+        let position_id = client.position_id(&request).await?.position_id;
+        outcome_ids.push(OutcomeIds {
+            outcome,
+            index_set,
+            collection_id,
+            position_id,
+            clob_token_id: tokens.get(i).copied(),
+        });
+    }
+
+    ctf_output::print_market_ids(
+        &MarketIdChain {
+            question: market.question.unwrap_or_default(),
+            question_id,
+            oracle,
+            condition_id,
+            derived_condition_id,
+            neg_risk,
+            collateral,
+            outcomes: outcome_ids,
+        },
+        output,
+    )
+}
+
 fn parse_optional_parent(parent: Option<&str>) -> Result<B256> {
     match parent {
         Some(p) => super::parse_condition_id(p),
@@ -171,8 +824,14 @@ fn parse_optional_parent(parent: Option<&str>) -> Result<B256> {
     }
 }
 
-fn resolve_collateral(collateral: &str) -> Result<Address> {
-    super::parse_address(collateral)
+/// The `--collateral` token, or the chain's USDC when it isn't given.
+fn resolve_collateral(collateral: Option<&str>) -> Result<Address> {
+    match collateral {
+        Some(address) => super::parse_address(address),
+        None => Ok(contract_config(config::chain_id(), false)
+            .context("No contract config for this chain")?
+            .collateral),
+    }
 }
 
 fn default_partition() -> Vec<U256> {
@@ -183,75 +842,371 @@ fn default_index_sets() -> Vec<U256> {
     vec![U256::from(1), U256::from(2)]
 }
 
-pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&str>) -> Result<()> {
+fn neg_risk_adapter() -> Result<Address> {
+    contract_config(config::chain_id(), true)
+        .and_then(|c| c.neg_risk_adapter)
+        .context("No neg-risk adapter configured for this chain")
+}
+
+/// The neg-risk adapter's wrapped collateral on `chain`.
+fn wrapped_collateral(chain: ChainId) -> Result<Address> {
+    match chain {
+        POLYGON => Ok(POLYGON_WRAPPED_COLLATERAL),
+        _ => anyhow::bail!("No wrapped collateral known for neg-risk markets on chain {chain}"),
+    }
+}
+
+fn conditional_tokens() -> Result<Address> {
+    Ok(contract_config(config::chain_id(), false)
+        .context("No contract config for this chain")?
+        .conditional_tokens)
+}
+
+/// A CTF call from the signing wallet, when there is one. `--dry-run` can
+/// show the call without a wallet.
+fn planned(action: String, to: Address, calldata: Vec<u8>, private_key: Option<&str>) -> PlannedTx {
+    let from = auth::resolve_address(private_key).ok();
+    PlannedTx {
+        action,
+        from,
+        to,
+        calldata,
+        value: U256::ZERO,
+    }
+}
+
+/// The wallet holding the user's positions and collateral, and the key that
+/// moves them.
+struct Funder {
+    address: Address,
+    eoa: Address,
+    signature_type: String,
+}
+
+impl Funder {
+    fn resolve(
+        private_key: Option<&str>,
+        signature_type: Option<&str>,
+        dry_run: bool,
+    ) -> Result<Self> {
+        let funder = Self {
+            address: auth::resolve_funder_address(private_key, signature_type)?,
+            eoa: auth::resolve_address(private_key)?,
+            signature_type: config::resolve_signature_type(signature_type),
+        };
+        tx::ensure_sendable(&funder.signature_type, dry_run)?;
+        Ok(funder)
+    }
+
+    /// A call to `to` from the funder; see [`tx::funder_call`].
+    fn call(&self, action: String, to: Address, calldata: Vec<u8>) -> Result<PlannedTx> {
+        tx::funder_call(
+            self.address,
+            self.eoa,
+            &self.signature_type,
+            action,
+            to,
+            calldata,
+        )
+    }
+}
+
+/// What the confirmation prompt says about a CTF transaction, naming the
+/// market when Gamma knows it. Empty when there will be no prompt.
+async fn describe_tx(action: &str, condition_id: B256, options: &TxOptions<'_>) -> String {
+    if !options.prompts() {
+        return String::new();
+    }
+    let market = super::market_question(condition_id).await.map_or_else(
+        || format!("condition {condition_id}"),
+        |question| format!("\"{question}\""),
+    );
+    format!("{action} on {market}")
+}
+
+/// Sends one CTF transaction once `--dry-run`, `--estimate-only`, and the
+/// confirmation prompt have had their say, then prints the receipt.
+async fn submit(
+    operation: &str,
+    tx: PlannedTx,
+    summary: Vec<String>,
+    options: &TxOptions<'_>,
+    output: &OutputFormat,
+) -> Result<()> {
+    let Some(gas) = tx::prepare(std::slice::from_ref(&tx), &summary, options, output).await? else {
+        return Ok(());
+    };
+    let provider = auth::create_provider(options.private_key).await?;
+    let sent = tx::send(&provider, &tx, &gas[0], options.wait).await?;
+    ctf_output::print_tx_result(operation, sent.hash, sent.block, output)
+}
+
+/// Reads balances straight from the Conditional Tokens contract. Positions
+/// found through the Data API keep its labels; others are looked up on Gamma.
+async fn balances(
+    tokens: Option<&str>,
+    address: Option<&str>,
+    private_key: Option<&str>,
+    signature_type: Option<&str>,
+) -> Result<(Address, Vec<PositionBalance>)> {
+    let owner = match address {
+        Some(address) => super::parse_address(address)?,
+        None => auth::resolve_funder_address(private_key, signature_type)?,
+    };
+    let (token_ids, mut labels) = match tokens {
+        Some(tokens) => {
+            let ids = super::clob::parse_token_ids(tokens)?;
+            let labels = super::token_labels(ids.clone()).await;
+            (ids, labels)
+        }
+        None => {
+            let positions = positions(owner, None).await?;
+            let labels: HashMap<U256, String> = positions
+                .iter()
+                .map(|p| (p.asset, format!("{} on \"{}\"", p.outcome, p.title)))
+                .collect();
+            (positions.iter().map(|p| p.asset).collect(), labels)
+        }
+    };
+
+    let provider = auth::create_readonly_provider().await?;
+    let contract = IConditionalTokens::new(conditional_tokens()?, provider);
+    let mut balances = Vec::with_capacity(token_ids.len());
+    for chunk in token_ids.chunks(BALANCE_BATCH) {
+        let raw = contract
+            .balanceOfBatch(vec![owner; chunk.len()], chunk.to_vec())
+            .call()
+            .await
+            .context("Failed to read balances from the Conditional Tokens contract")?;
+        for (&token_id, raw) in chunk.iter().zip(raw) {
+            balances.push(PositionBalance {
+                token_id,
+                label: labels.remove(&token_id),
+                shares: shares_from_raw(raw)?,
+            });
+        }
+    }
+    Ok((owner, balances))
+}
+
+pub async fn execute(
+    args: CtfArgs,
+    output: OutputFormat,
+    private_key: Option<&str>,
+    signature_type: Option<&str>,
+    dry_run: bool,
+    confirm: bool,
+    gas: &GasArgs,
+) -> Result<()> {
+    let options = TxOptions {
+        private_key,
+        dry_run,
+        confirm,
+        gas,
+        wait: args.wait,
+        simulate: !args.skip_simulation,
+    };
     match args.command {
+        CtfCommand::Transfer {
+            position,
+            to,
+            amount,
+        } => {
+            let token_id = super::clob::parse_token_id(position.trim())?;
+            let to = super::parse_address(&to)?;
+            let shares = parse_usdc_amount(&amount)?;
+            let funder = Funder::resolve(private_key, signature_type, dry_run)?;
+            let from = funder.address;
+            let call = IConditionalTokens::safeTransferFromCall {
+                from,
+                to,
+                id: token_id,
+                value: shares,
+                data: Bytes::new(),
+            };
+            let tx = funder.call(
+                format!("transfer {amount} shares"),
+                conditional_tokens()?,
+                call.abi_encode(),
+            )?;
+
+            if !dry_run {
+                let provider = auth::create_readonly_provider().await?;
+                let contract = IConditionalTokens::new(conditional_tokens()?, provider);
+                let held = contract.balanceOf(from, token_id).call().await?;
+                check_holdings(from, &[token_id], &[shares], &[held])?;
+            }
+            let mut summary = Vec::new();
+            if options.prompts() {
+                let position = super::token_label(token_id).await;
+                summary.push(format!("Transfer {amount} shares of {position} to {to}"));
+            }
+            submit("transfer", tx, summary, &options, &output).await
+        }
+        CtfCommand::TransferBatch {
+            positions,
+            amounts,
+            to,
+        } => {
+            let token_ids = super::clob::parse_token_ids(&positions)?;
+            let shares = parse_usdc_amounts(&amounts)?;
+            anyhow::ensure!(
+                token_ids.len() == shares.len(),
+                "--positions has {} entries but --amounts has {}",
+                token_ids.len(),
+                shares.len()
+            );
+            let to = super::parse_address(&to)?;
+            let funder = Funder::resolve(private_key, signature_type, dry_run)?;
+            let from = funder.address;
+            let call = IConditionalTokens::safeBatchTransferFromCall {
+                from,
+                to,
+                ids: token_ids.clone(),
+                values: shares.clone(),
+                data: Bytes::new(),
+            };
+            let tx = funder.call(
+                "transfer-batch".into(),
+                conditional_tokens()?,
+                call.abi_encode(),
+            )?;
+
+            if !dry_run {
+                let provider = auth::create_readonly_provider().await?;
+                let contract = IConditionalTokens::new(conditional_tokens()?, provider);
+                let held = contract
+                    .balanceOfBatch(vec![from; token_ids.len()], token_ids.clone())
+                    .call()
+                    .await?;
+                check_holdings(from, &token_ids, &shares, &held)?;
+            }
+            let mut summary = Vec::new();
+            if options.prompts() {
+                let mut labels = super::token_labels(token_ids.clone()).await;
+                summary.push(format!(
+                    "Transfer {} positions to {to} in one transaction:",
+                    token_ids.len()
+                ));
+                for (token_id, amount) in token_ids.iter().zip(amounts.split(',')) {
+                    let position = labels
+                        .remove(token_id)
+                        .unwrap_or_else(|| format!("token {token_id}"));
+                    summary.push(format!("  {} shares of {position}", amount.trim()));
+                }
+            }
+            submit("transfer-batch", tx, summary, &options, &output).await
+        }
+        CtfCommand::RedeemAll { include_losers } => {
+            redeem_all(include_losers, signature_type, &options, &output).await
+        }
+        CtfCommand::Approval { command } => approval(command, &options, &output).await,
+        CtfCommand::Balances { tokens, address } => {
+            let (owner, balances) = balances(
+                tokens.as_deref(),
+                address.as_deref(),
+                private_key,
+                signature_type,
+            )
+            .await?;
+            ctf_output::print_balances(owner, &balances, &output)
+        }
         CtfCommand::Split {
             condition,
             amount,
+            max: _,
             collateral,
             partition,
             parent_collection,
         } => {
-            let condition_id = super::parse_condition_id(&condition)?;
-            let usdc_amount = parse_usdc_amount(&amount)?;
-            let collateral_addr = resolve_collateral(&collateral)?;
+            let condition_id =
+                super::parse_condition_id(&super::resolve_condition_arg(&condition).await?)?;
+            let collateral_addr = resolve_collateral(collateral.as_deref())?;
             let parent = parse_optional_parent(parent_collection.as_deref())?;
             let partition = match partition {
                 Some(p) => parse_u256_csv(&p)?,
                 None => default_partition(),
             };
+            let funder = Funder::resolve(private_key, signature_type, dry_run)?;
+            let (usdc_amount, amount) = match amount {
+                Some(amount) => (parse_usdc_amount(&amount)?, amount),
+                None => {
+                    let raw = max_split(funder.address, collateral_addr).await?;
+                    (raw, shares_from_raw(raw)?.to_string())
+                }
+            };
 
-            let provider = auth::create_provider(private_key).await?;
-            let client = ctf::Client::new(provider, POLYGON)?;
-
-            let req = SplitPositionRequest::builder()
-                .collateral_token(collateral_addr)
-                .parent_collection_id(parent)
-                .condition_id(condition_id)
-                .partition(partition)
-                .amount(usdc_amount)
-                .build();
-
-            let resp = client
-                .split_position(&req)
-                .await
-                .context("Split position failed")?;
-
-            ctf_output::print_tx_result("split", resp.transaction_hash, resp.block_number, &output)
+            let call = IConditionalTokens::splitPositionCall {
+                collateralToken: collateral_addr,
+                parentCollectionId: parent,
+                conditionId: condition_id,
+                partition,
+                amount: usdc_amount,
+            };
+            let tx = funder.call(
+                format!("split {amount} USDC"),
+                conditional_tokens()?,
+                call.abi_encode(),
+            )?;
+            let summary = describe_tx(
+                &format!("Split {amount} USDC into positions"),
+                condition_id,
+                &options,
+            )
+            .await;
+            submit("split", tx, vec![summary], &options, &output).await
         }
         CtfCommand::Merge {
             condition,
             amount,
+            max: _,
             collateral,
             partition,
             parent_collection,
         } => {
-            let condition_id = super::parse_condition_id(&condition)?;
-            let usdc_amount = parse_usdc_amount(&amount)?;
-            let collateral_addr = resolve_collateral(&collateral)?;
+            let condition_id =
+                super::parse_condition_id(&super::resolve_condition_arg(&condition).await?)?;
+            let collateral_addr = resolve_collateral(collateral.as_deref())?;
             let parent = parse_optional_parent(parent_collection.as_deref())?;
             let partition = match partition {
                 Some(p) => parse_u256_csv(&p)?,
                 None => default_partition(),
             };
+            let funder = Funder::resolve(private_key, signature_type, dry_run)?;
+            let (usdc_amount, amount) = match amount {
+                Some(amount) => (parse_usdc_amount(&amount)?, amount),
+                None => {
+                    let raw = max_merge(
+                        funder.address,
+                        collateral_addr,
+                        parent,
+                        condition_id,
+                        &partition,
+                    )
+                    .await?;
+                    (raw, shares_from_raw(raw)?.to_string())
+                }
+            };
 
-            let provider = auth::create_provider(private_key).await?;
-            let client = ctf::Client::new(provider, POLYGON)?;
-
-            let req = MergePositionsRequest::builder()
-                .collateral_token(collateral_addr)
-                .parent_collection_id(parent)
-                .condition_id(condition_id)
-                .partition(partition)
-                .amount(usdc_amount)
-                .build();
-
-            let resp = client
-                .merge_positions(&req)
-                .await
-                .context("Merge positions failed")?;
-
-            ctf_output::print_tx_result("merge", resp.transaction_hash, resp.block_number, &output)
+            let call = IConditionalTokens::mergePositionsCall {
+                collateralToken: collateral_addr,
+                parentCollectionId: parent,
+                conditionId: condition_id,
+                partition,
+                amount: usdc_amount,
+            };
+            let tx = funder.call(
+                format!("merge {amount} USDC"),
+                conditional_tokens()?,
+                call.abi_encode(),
+            )?;
+            let summary = describe_tx(
+                &format!("Merge positions into {amount} USDC"),
+                condition_id,
+                &options,
+            )
+            .await;
+            submit("merge", tx, vec![summary], &options, &output).await
         }
         CtfCommand::Redeem {
             condition,
@@ -259,55 +1214,45 @@ pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&s
             index_sets,
             parent_collection,
         } => {
-            let condition_id = super::parse_condition_id(&condition)?;
-            let collateral_addr = resolve_collateral(&collateral)?;
+            let condition_id =
+                super::parse_condition_id(&super::resolve_condition_arg(&condition).await?)?;
+            let collateral_addr = resolve_collateral(collateral.as_deref())?;
             let parent = parse_optional_parent(parent_collection.as_deref())?;
             let index_sets = match index_sets {
                 Some(s) => parse_u256_csv(&s)?,
                 None => default_index_sets(),
             };
 
-            let provider = auth::create_provider(private_key).await?;
-            let client = ctf::Client::new(provider, POLYGON)?;
-
-            let req = RedeemPositionsRequest::builder()
-                .collateral_token(collateral_addr)
-                .parent_collection_id(parent)
-                .condition_id(condition_id)
-                .index_sets(index_sets)
-                .build();
-
-            let resp = client
-                .redeem_positions(&req)
-                .await
-                .context("Redeem positions failed")?;
-
-            ctf_output::print_tx_result("redeem", resp.transaction_hash, resp.block_number, &output)
+            let call = IConditionalTokens::redeemPositionsCall {
+                collateralToken: collateral_addr,
+                parentCollectionId: parent,
+                conditionId: condition_id,
+                indexSets: index_sets,
+            };
+            let funder = Funder::resolve(private_key, signature_type, dry_run)?;
+            let tx = funder.call("redeem".into(), conditional_tokens()?, call.abi_encode())?;
+            let summary = describe_tx("Redeem positions", condition_id, &options).await;
+            submit("redeem", tx, vec![summary], &options, &output).await
         }
         CtfCommand::RedeemNegRisk { condition, amounts } => {
-            let condition_id = super::parse_condition_id(&condition)?;
+            let condition_id =
+                super::parse_condition_id(&super::resolve_condition_arg(&condition).await?)?;
             let amounts = parse_usdc_amounts(&amounts)?;
 
-            let provider = auth::create_provider(private_key).await?;
-            let client = ctf::Client::with_neg_risk(provider, POLYGON)?;
-
-            let req = RedeemNegRiskRequest::builder()
-                .condition_id(condition_id)
-                .amounts(amounts)
-                .build();
-
-            let resp = client
-                .redeem_neg_risk(&req)
-                .await
-                .context("Redeem neg-risk positions failed")?;
-
-            ctf_output::print_tx_result(
-                "redeem-neg-risk",
-                resp.transaction_hash,
-                resp.block_number,
-                &output,
-            )
+            let call = INegRiskAdapter::redeemPositionsCall {
+                conditionId: condition_id,
+                amounts,
+            };
+            let funder = Funder::resolve(private_key, signature_type, dry_run)?;
+            let tx = funder.call(
+                "redeem-neg-risk".into(),
+                neg_risk_adapter()?,
+                call.abi_encode(),
+            )?;
+            let summary = describe_tx("Redeem neg-risk positions", condition_id, &options).await;
+            submit("redeem-neg-risk", tx, vec![summary], &options, &output).await
         }
+        CtfCommand::Ids { market } => ids(&market, &output).await,
         CtfCommand::ConditionId {
             oracle,
             question,
@@ -317,7 +1262,7 @@ pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&s
             let question_id = super::parse_condition_id(&question)?;
 
             let provider = auth::create_readonly_provider().await?;
-            let client = ctf::Client::new(provider, POLYGON)?;
+            let client = ctf::Client::new(provider, config::chain_id())?;
 
             let req = ConditionIdRequest::builder()
                 .oracle(oracle_addr)
@@ -333,11 +1278,12 @@ pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&s
             index_set,
             parent_collection,
         } => {
-            let condition_id = super::parse_condition_id(&condition)?;
+            let condition_id =
+                super::parse_condition_id(&super::resolve_condition_arg(&condition).await?)?;
             let parent = parse_optional_parent(parent_collection.as_deref())?;
 
             let provider = auth::create_readonly_provider().await?;
-            let client = ctf::Client::new(provider, POLYGON)?;
+            let client = ctf::Client::new(provider, config::chain_id())?;
 
             let req = CollectionIdRequest::builder()
                 .parent_collection_id(parent)
@@ -352,11 +1298,11 @@ pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&s
             collateral,
             collection,
         } => {
-            let collateral_addr = super::parse_address(&collateral)?;
+            let collateral_addr = resolve_collateral(collateral.as_deref())?;
             let collection_id = super::parse_condition_id(&collection)?;
 
             let provider = auth::create_readonly_provider().await?;
-            let client = ctf::Client::new(provider, POLYGON)?;
+            let client = ctf::Client::new(provider, config::chain_id())?;
 
             let req = PositionIdRequest::builder()
                 .collateral_token(collateral_addr)
@@ -526,9 +1472,95 @@ mod tests {
         assert_eq!(p, vec![U256::from(1u64), U256::from(2u64)]);
     }
 
+    #[test]
+    fn shares_from_raw_uses_six_decimals() {
+        assert_eq!(
+            shares_from_raw(U256::from(12_500_000u64)).unwrap(),
+            Decimal::new(125, 1)
+        );
+        assert_eq!(shares_from_raw(U256::ZERO).unwrap(), Decimal::ZERO);
+        assert!(shares_from_raw(U256::MAX).is_err());
+    }
+
+    #[test]
+    fn check_holdings_names_each_position_that_falls_short() {
+        let ids = [U256::from(1u64), U256::from(2u64)];
+        let amounts = [U256::from(5_000_000u64), U256::from(3_000_000u64)];
+        assert!(check_holdings(Address::ZERO, &ids, &amounts, &amounts).is_ok());
+        let held = [U256::from(6_000_000u64), U256::from(2_500_000u64)];
+        let err = check_holdings(Address::ZERO, &ids, &amounts, &held)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("position 2: holds 2.5, sending 3"),
+            "got: {err}"
+        );
+        assert!(!err.contains("position 1"), "got: {err}");
+    }
+
+    #[test]
+    fn operators_name_polymarket_contracts() {
+        let all = operators(None).unwrap();
+        assert!(all.iter().any(|(name, _)| name == "CTF Exchange"));
+        let exchange = all[0].1.to_string();
+        assert_eq!(operators(Some(&exchange)).unwrap(), vec![all[0].clone()]);
+        let custom = operators(Some("0x0000000000000000000000000000000000000001")).unwrap();
+        assert_eq!(custom[0].0, "Custom operator");
+    }
+
+    fn held(condition: u8, outcome_index: usize, shares: u64, price: Decimal) -> HeldOutcome {
+        HeldOutcome {
+            condition_id: B256::repeat_byte(condition),
+            title: format!("market {condition}"),
+            neg_risk: false,
+            outcome_index,
+            shares: U256::from(shares),
+            price,
+        }
+    }
+
+    #[test]
+    fn redeem_targets_group_outcomes_by_market() {
+        let targets = redeem_targets(vec![
+            held(1, 0, 10_000_000, Decimal::ONE),
+            held(2, 1, 0, Decimal::ONE),
+            held(1, 1, 4_000_000, Decimal::ZERO),
+            held(3, 2, 2_500_000, Decimal::ONE),
+        ])
+        .unwrap();
+        // Market 2 has nothing left on-chain, so it was already redeemed.
+        assert_eq!(targets.len(), 2);
+        assert_eq!(
+            targets[0].amounts,
+            vec![U256::from(10_000_000u64), U256::from(4_000_000u64)]
+        );
+        assert_eq!(targets[0].payout, Decimal::from(10));
+        assert_eq!(targets[1].amounts.len(), 3);
+        assert_eq!(targets[1].payout, Decimal::new(25, 1));
+    }
+
+    #[test]
+    fn full_index_sets_cover_every_outcome() {
+        assert_eq!(full_index_sets(2), default_index_sets());
+        assert_eq!(
+            full_index_sets(3),
+            vec![U256::from(1u64), U256::from(2u64), U256::from(4u64)]
+        );
+    }
+
     #[test]
     fn default_index_sets_is_binary() {
         let s = default_index_sets();
         assert_eq!(s, vec![U256::from(1u64), U256::from(2u64)]);
     }
+
+    #[test]
+    fn wrapped_collateral_is_only_known_on_polygon() {
+        assert_eq!(
+            wrapped_collateral(POLYGON).unwrap(),
+            POLYGON_WRAPPED_COLLATERAL
+        );
+        let err = wrapped_collateral(polymarket_client_sdk::AMOY).unwrap_err();
+        assert!(err.to_string().contains("80002"), "{err}");
+    }
 }
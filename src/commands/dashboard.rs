@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Args;
+
+use super::clob::parse_token_ids;
+use crate::auth;
+use crate::tui::dashboard::{self, DashboardConfig};
+
+#[derive(Args)]
+pub struct DashboardArgs {
+    /// Token IDs to show order books for (comma-separated)
+    #[arg(long)]
+    pub tokens: Option<String>,
+
+    /// Refresh interval in seconds
+    #[arg(long, default_value = "5")]
+    pub interval: u64,
+}
+
+pub async fn execute(
+    args: DashboardArgs,
+    private_key: Option<&str>,
+    signature_type: Option<&str>,
+) -> Result<()> {
+    let watchlist = args
+        .tokens
+        .map(|t| parse_token_ids(&t))
+        .transpose()?
+        .unwrap_or_default();
+    let funder = auth::resolve_funder_address(private_key, signature_type)?;
+    let client = auth::authenticated_clob_client(private_key, signature_type).await?;
+
+    dashboard::run(
+        client,
+        funder,
+        DashboardConfig {
+            watchlist,
+            interval: Duration::from_secs(args.interval.max(1)),
+        },
+    )
+    .await
+}
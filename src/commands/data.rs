@@ -1,20 +1,29 @@
-use super::{parse_address, parse_condition_id};
+use super::{parse_address, parse_condition_id, resolve_condition_arg};
+use crate::endpoints;
 use crate::output::OutputFormat;
 use crate::output::data::{
-    print_activity, print_builder_leaderboard, print_builder_volume, print_closed_positions,
-    print_holders, print_leaderboard, print_live_volume, print_open_interest, print_positions,
-    print_traded, print_trades, print_value,
+    MarketStats, print_activity, print_builder_leaderboard, print_builder_volume,
+    print_closed_positions, print_holders, print_leaderboard, print_live_volume,
+    print_market_stats, print_open_interest, print_positions, print_trade_print, print_traded,
+    print_trades, print_value,
 };
+use crate::poll::Poller;
 use anyhow::Result;
 use clap::{Args, Subcommand};
 use polymarket_client_sdk::data::{
     self,
+    types::MarketFilter,
     types::request::{
         ActivityRequest, BuilderLeaderboardRequest, BuilderVolumeRequest, ClosedPositionsRequest,
         HoldersRequest, LiveVolumeRequest, OpenInterestRequest, PositionsRequest, TradedRequest,
         TraderLeaderboardRequest, TradesRequest, ValueRequest,
     },
+    types::response::Trade,
 };
+use polymarket_client_sdk::gamma::types::request::MarketsRequest;
+use polymarket_client_sdk::types::{Address, B256, Decimal, U256};
+use std::collections::HashSet;
+use std::time::Duration;
 
 #[derive(Args)]
 pub struct DataArgs {
@@ -64,18 +73,26 @@ pub enum DataCommand {
         address: String,
     },
 
-    /// Get trade history
+    /// Get trade history for a wallet, or the public trade feed for a market
     Trades {
-        /// Wallet address (0x...)
-        address: String,
+        /// Wallet address (0x..., 20 bytes), or a market condition ID (0x..., 32 bytes), slug, or URL
+        target: String,
 
         /// Max results
         #[arg(long, default_value = "25")]
         limit: i32,
 
         /// Pagination offset
-        #[arg(long)]
+        #[arg(long, conflicts_with = "follow")]
         offset: Option<i32>,
+
+        /// Keep polling and print new trades as they happen (Ctrl+C to stop)
+        #[arg(long)]
+        follow: bool,
+
+        /// Seconds between polls with --follow
+        #[arg(long, default_value = "5", requires = "follow")]
+        interval: u64,
     },
 
     /// Get on-chain activity for a wallet address
@@ -92,9 +109,9 @@ pub enum DataCommand {
         offset: Option<i32>,
     },
 
-    /// Get top token holders for a market
+    /// Get the largest holders of each outcome token in a market
     Holders {
-        /// Market condition ID (0x...)
+        /// Market condition ID (0x...), slug, or polymarket.com URL
         market: String,
 
         /// Max results per token
@@ -102,9 +119,15 @@ pub enum DataCommand {
         limit: i32,
     },
 
+    /// Get volume, open interest, and liquidity for a market
+    Stats {
+        /// Market condition ID (0x...), slug, or polymarket.com URL
+        market: String,
+    },
+
     /// Get open interest for markets
     OpenInterest {
-        /// Market condition ID (0x...)
+        /// Market condition ID (0x...), slug, or polymarket.com URL
         market: String,
     },
 
@@ -190,6 +213,64 @@ impl From<OrderBy> for polymarket_client_sdk::data::types::LeaderboardOrderBy {
     }
 }
 
+impl DataCommand {
+    /// Commands that keep running and print as data arrives.
+    pub(crate) fn follows(&self) -> bool {
+        matches!(self, Self::Trades { follow: true, .. })
+    }
+}
+
+/// Builds a `/trades` request for a wallet address or a market condition ID.
+/// Anything that isn't hex is resolved as a market slug or URL.
+async fn trades_request(target: &str, limit: i32, offset: Option<i32>) -> Result<TradesRequest> {
+    let target = resolve_condition_arg(target).await?;
+    let target = target.as_str();
+    let builder = TradesRequest::builder()
+        .limit(limit)?
+        .maybe_offset(offset)?;
+    // A condition ID is 32 bytes (66 hex chars with 0x), an address 20 bytes.
+    let request = if target.len() == 66 {
+        builder
+            .filter(MarketFilter::markets([parse_condition_id(target)?]))
+            .build()
+    } else {
+        builder.user(parse_address(target)?).build()
+    };
+    Ok(request)
+}
+
+/// Identity of a trade print; the API has no trade ID, and one transaction
+/// can settle several fills.
+pub(crate) fn trade_key(t: &Trade) -> (B256, Address, U256, Decimal, Decimal) {
+    (t.transaction_hash, t.proxy_wallet, t.asset, t.size, t.price)
+}
+
+async fn follow_trades(
+    client: &data::Client,
+    request: &TradesRequest,
+    interval: u64,
+    output: &OutputFormat,
+) -> Result<()> {
+    let mut poller = Poller::new(Duration::from_secs(interval.max(1)));
+    let mut seen: Option<HashSet<_>> = None;
+    while poller.tick().await {
+        match client.trades(request).await {
+            Ok(trades) => {
+                // Newest first from the API; print oldest first like a tape.
+                for trade in trades.iter().rev() {
+                    if seen.as_ref().is_none_or(|s| !s.contains(&trade_key(trade))) {
+                        print_trade_print(trade, output)?;
+                    }
+                }
+                // Only the latest page can repeat, so forget anything older.
+                seen = Some(trades.iter().map(trade_key).collect());
+            }
+            Err(e) => eprintln!("Failed to fetch trades: {e}"),
+        }
+    }
+    Ok(())
+}
+
 pub async fn execute(client: &data::Client, args: DataArgs, output: OutputFormat) -> Result<()> {
     match args.command {
         // User-focused queries (positions, trades, activity, value)
@@ -202,6 +283,7 @@ pub async fn execute(client: &data::Client, args: DataArgs, output: OutputFormat
 
         // Market-focused queries (holders, open interest, volume)
         DataCommand::Holders { .. }
+        | DataCommand::Stats { .. }
         | DataCommand::OpenInterest { .. }
         | DataCommand::Volume { .. } => execute_market(client, args.command, &output).await,
 
@@ -269,15 +351,16 @@ async fn execute_user(
         }
 
         DataCommand::Trades {
-            address,
+            target,
             limit,
             offset,
+            follow,
+            interval,
         } => {
-            let request = TradesRequest::builder()
-                .user(parse_address(&address)?)
-                .limit(limit)?
-                .maybe_offset(offset)?
-                .build();
+            let request = trades_request(&target, limit, offset).await?;
+            if follow {
+                return follow_trades(client, &request, interval, output).await;
+            }
 
             let trades = client.trades(&request).await?;
             print_trades(&trades, output)?;
@@ -311,18 +394,49 @@ async fn execute_market(
 ) -> Result<()> {
     match command {
         DataCommand::Holders { market, limit } => {
-            let cid = parse_condition_id(&market)?;
+            let cid = parse_condition_id(&resolve_condition_arg(&market).await?)?;
             let request = HoldersRequest::builder()
                 .markets(vec![cid])
                 .limit(limit)?
                 .build();
 
-            let holders = client.holders(&request).await?;
-            print_holders(&holders, output)?;
+            // Every outcome token is minted one-for-one against a USDC-backed
+            // set, so open interest doubles as each token's circulating supply.
+            let oi_request = OpenInterestRequest::builder().markets(vec![cid]).build();
+            let (holders, oi) =
+                tokio::join!(client.holders(&request), client.open_interest(&oi_request));
+            let supply = oi.ok().and_then(|oi| oi.first().map(|o| o.value));
+            print_holders(&holders?, supply, output)?;
+        }
+
+        DataCommand::Stats { market } => {
+            let cid = parse_condition_id(&resolve_condition_arg(&market).await?)?;
+            // Volume and liquidity live on the Gamma market; open interest
+            // comes from the Data API.
+            let gamma_client = endpoints::gamma();
+            let markets_request = MarketsRequest::builder().condition_ids(vec![cid]).build();
+            let oi_request = OpenInterestRequest::builder().markets(vec![cid]).build();
+            let (markets, oi) = tokio::join!(
+                gamma_client.markets(&markets_request),
+                client.open_interest(&oi_request),
+            );
+            let market = markets?
+                .into_iter()
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("Market not found: {cid}"))?;
+            let stats = MarketStats {
+                condition_id: cid,
+                question: market.question,
+                volume_24hr: market.volume_24hr,
+                volume: market.volume_num.or(market.volume),
+                open_interest: oi?.first().map(|o| o.value),
+                liquidity: market.liquidity_num.or(market.liquidity),
+            };
+            print_market_stats(&stats, output)?;
         }
 
         DataCommand::OpenInterest { market } => {
-            let cid = parse_condition_id(&market)?;
+            let cid = parse_condition_id(&resolve_condition_arg(&market).await?)?;
             let request = OpenInterestRequest::builder().markets(vec![cid]).build();
 
             let oi = client.open_interest(&request).await?;
@@ -393,3 +507,26 @@ async fn execute_leaderboard(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn trades_request_targets_market_by_condition_id() {
+        let cid = format!("0x{}", "ab".repeat(32));
+        let request = trades_request(&cid, 25, None).await.unwrap();
+        assert!(request.user.is_none());
+        assert!(matches!(request.filter, Some(MarketFilter::Markets(ref ids)) if ids.len() == 1));
+    }
+
+    #[tokio::test]
+    async fn trades_request_targets_wallet_by_address() {
+        let request = trades_request("0x0000000000000000000000000000000000000001", 25, Some(5))
+            .await
+            .unwrap();
+        assert!(request.user.is_some());
+        assert!(request.filter.is_none());
+        assert_eq!(request.offset, Some(5));
+    }
+}
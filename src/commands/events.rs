@@ -4,8 +4,9 @@ use polymarket_client_sdk::gamma::{
     self,
     types::request::{EventByIdRequest, EventBySlugRequest, EventTagsRequest, EventsRequest},
 };
+use polymarket_client_sdk::types::Decimal;
 
-use super::is_numeric_id;
+use super::{is_numeric_id, parse_date_bound};
 use crate::output::events::{print_event_detail, print_events_table};
 use crate::output::tags::print_tags_table;
 use crate::output::{OutputFormat, print_json};
@@ -47,6 +48,22 @@ pub enum EventsCommand {
         /// Filter by tag slug (e.g. "politics", "crypto")
         #[arg(long)]
         tag: Option<String>,
+
+        /// Only events with at least this much liquidity (USDC)
+        #[arg(long)]
+        liquidity_min: Option<Decimal>,
+
+        /// Only events with at most this much liquidity (USDC)
+        #[arg(long)]
+        liquidity_max: Option<Decimal>,
+
+        /// Only events ending on or after this date (YYYY-MM-DD)
+        #[arg(long)]
+        end_after: Option<String>,
+
+        /// Only events ending on or before this date (YYYY-MM-DD)
+        #[arg(long)]
+        end_before: Option<String>,
     },
 
     /// Get a single event by ID or slug
@@ -72,8 +89,14 @@ pub async fn execute(client: &gamma::Client, args: EventsArgs, output: OutputFor
             order,
             ascending,
             tag,
+            liquidity_min,
+            liquidity_max,
+            end_after,
+            end_before,
         } => {
             let resolved_closed = closed.or_else(|| active.map(|a| !a));
+            let end_after = end_after.map(|d| parse_date_bound(&d, false)).transpose()?;
+            let end_before = end_before.map(|d| parse_date_bound(&d, true)).transpose()?;
 
             let request = EventsRequest::builder()
                 .limit(limit)
@@ -81,6 +104,10 @@ pub async fn execute(client: &gamma::Client, args: EventsArgs, output: OutputFor
                 .maybe_offset(offset)
                 .maybe_ascending(if ascending { Some(true) } else { None })
                 .maybe_tag_slug(tag)
+                .maybe_liquidity_min(liquidity_min)
+                .maybe_liquidity_max(liquidity_max)
+                .maybe_end_date_min(end_after)
+                .maybe_end_date_max(end_before)
                 .order(order.into_iter().collect::<Vec<_>>())
                 .build();
 
@@ -88,7 +115,9 @@ pub async fn execute(client: &gamma::Client, args: EventsArgs, output: OutputFor
 
             match output {
                 OutputFormat::Table => print_events_table(&events),
-                OutputFormat::Json => print_json(&events)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&events)?
+                }
             }
         }
 
@@ -104,7 +133,9 @@ pub async fn execute(client: &gamma::Client, args: EventsArgs, output: OutputFor
 
             match output {
                 OutputFormat::Table => print_event_detail(&event),
-                OutputFormat::Json => print_json(&event)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&event)?
+                }
             }
         }
 
@@ -114,7 +145,9 @@ pub async fn execute(client: &gamma::Client, args: EventsArgs, output: OutputFor
 
             match output {
                 OutputFormat::Table => print_tags_table(&tags),
-                OutputFormat::Json => print_json(&tags)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&tags)?
+                }
             }
         }
     }
@@ -0,0 +1,188 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::Args;
+use polymarket_client_sdk::data::types::Side;
+use polymarket_client_sdk::data::types::request::TradesRequest;
+use polymarket_client_sdk::data::types::response::Trade;
+use polymarket_client_sdk::types::Decimal;
+
+use super::clob::{CliOrderType, CliSide};
+use super::data::trade_key;
+use super::{describe_wallet, parse_address};
+use crate::endpoints;
+use crate::order_file::{self, OrderSpec};
+use crate::output::data::print_trade_print;
+use crate::output::{OutputFormat, color};
+use crate::poll::Poller;
+
+/// Enough recent trades that a busy wallet can't outrun one poll.
+const PAGE_SIZE: i32 = 100;
+
+#[derive(Args)]
+pub struct FollowArgs {
+    /// Wallet address to follow (0x...)
+    pub address: String,
+
+    /// Seconds between polls
+    #[arg(long, default_value = "5")]
+    pub interval: u64,
+
+    /// Ring the terminal bell on each new fill
+    #[arg(long)]
+    pub bell: bool,
+
+    /// Stage a matching limit order for each new fill in this JSON order file (nothing is posted)
+    #[arg(long, value_name = "FILE")]
+    pub mirror: Option<PathBuf>,
+
+    /// Multiply mirrored order sizes by this factor
+    #[arg(long, default_value = "1", requires = "mirror")]
+    pub scale: Decimal,
+}
+
+/// The order that would copy `trade`: same token, side, and price, with the
+/// size scaled. Returns None if the side is unknown or the size rounds to 0.
+fn mirror_order(trade: &Trade, scale: Decimal) -> Option<OrderSpec> {
+    let side = match trade.side {
+        Side::Buy => CliSide::Buy,
+        Side::Sell => CliSide::Sell,
+        _ => return None,
+    };
+    let size = (trade.size * scale).round_dp(2);
+    if size <= Decimal::ZERO {
+        return None;
+    }
+    Some(OrderSpec {
+        token: trade.asset.to_string(),
+        side,
+        price: trade.price,
+        size,
+        order_type: CliOrderType::Gtc,
+        expiration: None,
+        note: Some(format!(
+            "mirrors {} {} of {} ({}) in tx {}",
+            trade.side, trade.size, trade.title, trade.outcome, trade.transaction_hash
+        )),
+    })
+}
+
+pub async fn execute(args: FollowArgs, output: OutputFormat) -> Result<()> {
+    if args.scale <= Decimal::ZERO {
+        anyhow::bail!("--scale must be greater than 0");
+    }
+    let address = parse_address(&args.address)?;
+    let client = endpoints::data();
+    // Maker fills count too: a whale quoting passively is still trading.
+    let request = TradesRequest::builder()
+        .user(address)
+        .taker_only(false)
+        .limit(PAGE_SIZE)?
+        .build();
+    let mut staged = match &args.mirror {
+        Some(path) => order_file::load(path)?,
+        None => Vec::new(),
+    };
+    let already_staged = staged.len();
+    let table = matches!(output, OutputFormat::Table);
+
+    describe_wallet(address, &output).await;
+    if table {
+        println!(
+            "{}",
+            color::dim("Waiting for new fills (Ctrl+C to stop)...")
+        );
+    }
+
+    let mut poller = Poller::new(Duration::from_secs(args.interval.max(1)));
+    let mut seen: Option<HashSet<_>> = None;
+    while poller.tick().await {
+        let trades = match client.trades(&request).await {
+            Ok(trades) => trades,
+            Err(e) => {
+                eprintln!("Failed to fetch trades: {e}");
+                continue;
+            }
+        };
+        // The first poll only records what already happened.
+        if let Some(seen) = &seen {
+            let before = staged.len();
+            for trade in trades.iter().rev() {
+                if seen.contains(&trade_key(trade)) {
+                    continue;
+                }
+                print_trade_print(trade, &output)?;
+                if args.bell {
+                    eprint!("\x07");
+                }
+                if args.mirror.is_some() {
+                    staged.extend(mirror_order(trade, args.scale));
+                }
+            }
+            if let Some(path) = args.mirror.as_deref().filter(|_| staged.len() > before) {
+                order_file::save(path, &staged)?;
+            }
+        }
+        seen = Some(trades.iter().map(trade_key).collect());
+    }
+
+    if let Some(path) = &args.mirror
+        && table
+    {
+        println!(
+            "Staged {} new order(s) in {}. Nothing was posted; review the file, then post it with `polymarket clob post-orders --file {}`.",
+            staged.len() - already_staged,
+            path.display(),
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use polymarket_client_sdk::types::{Address, B256, U256};
+    use rust_decimal_macros::dec;
+
+    fn trade(side: Side, size: Decimal) -> Trade {
+        Trade::builder()
+            .proxy_wallet(Address::ZERO)
+            .side(side)
+            .asset(U256::from(42u64))
+            .condition_id(B256::ZERO)
+            .size(size)
+            .price(dec!(0.37))
+            .timestamp(0)
+            .title("Will it rain?".into())
+            .slug(String::new())
+            .icon(String::new())
+            .event_slug(String::new())
+            .outcome("Yes".into())
+            .outcome_index(0)
+            .transaction_hash(B256::ZERO)
+            .build()
+    }
+
+    #[test]
+    fn mirror_copies_side_token_and_price_with_scaled_size() {
+        let spec = mirror_order(&trade(Side::Sell, dec!(150)), dec!(0.1)).unwrap();
+        assert_eq!(spec.token, "42");
+        assert_eq!(spec.side, CliSide::Sell);
+        assert_eq!(spec.price, dec!(0.37));
+        assert_eq!(spec.size, dec!(15));
+        assert_eq!(spec.order_type, CliOrderType::Gtc);
+    }
+
+    #[test]
+    fn mirror_skips_sizes_that_round_to_zero() {
+        assert!(mirror_order(&trade(Side::Buy, dec!(1)), dec!(0.001)).is_none());
+    }
+
+    #[test]
+    fn mirror_skips_unknown_sides() {
+        assert!(mirror_order(&trade(Side::Unknown("X".into()), dec!(10)), dec!(1)).is_none());
+    }
+}
@@ -1,20 +1,30 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
 use anyhow::Result;
 use clap::{Args, Subcommand};
+use polymarket_client_sdk::clob::types::request::OrderBookSummaryRequest;
 use polymarket_client_sdk::gamma::{
     self,
     types::{
         request::{
             MarketByIdRequest, MarketBySlugRequest, MarketTagsRequest, MarketsRequest,
-            SearchRequest,
+            SearchRequest, TagBySlugRequest,
         },
         response::Market,
     },
 };
+use polymarket_client_sdk::types::Decimal;
 
-use super::is_numeric_id;
-use crate::output::markets::{print_market_detail, print_markets_table};
+use super::{is_numeric_id, parse_condition_id, parse_date_bound, resolve_condition_arg, run_hook};
+use crate::endpoints;
+use crate::output::markets::{
+    MarketInfo, OutcomeQuote, print_market_detail, print_market_info, print_markets_table,
+    print_new_market, print_trending,
+};
 use crate::output::tags::print_tags_table;
-use crate::output::{OutputFormat, print_json};
+use crate::output::{OutputFormat, color, print_json};
+use crate::poll::Poller;
 
 #[derive(Args)]
 pub struct MarketsArgs {
@@ -43,12 +53,36 @@ pub enum MarketsCommand {
         offset: Option<i32>,
 
         /// Sort field (e.g. `volume_num`, `liquidity_num`)
-        #[arg(long)]
+        #[arg(long, conflicts_with = "order_by")]
         order: Option<String>,
 
+        /// Sort by a common field
+        #[arg(long)]
+        order_by: Option<MarketOrder>,
+
         /// Sort ascending instead of descending
         #[arg(long)]
         ascending: bool,
+
+        /// Filter by tag ID or slug (e.g. "politics", "crypto")
+        #[arg(long)]
+        tag: Option<String>,
+
+        /// Only markets with at least this much liquidity (USDC)
+        #[arg(long)]
+        liquidity_min: Option<Decimal>,
+
+        /// Only markets with at least this much total volume (USDC)
+        #[arg(long)]
+        volume_min: Option<Decimal>,
+
+        /// Only markets ending on or after this date (YYYY-MM-DD)
+        #[arg(long)]
+        end_after: Option<String>,
+
+        /// Only markets ending on or before this date (YYYY-MM-DD)
+        #[arg(long)]
+        end_before: Option<String>,
     },
 
     /// Get a single market by ID or slug
@@ -57,6 +91,12 @@ pub enum MarketsCommand {
         id: String,
     },
 
+    /// Gamma metadata, CLOB trading config, and live quotes for one market
+    Info {
+        /// Market slug, polymarket.com URL, condition ID, or numeric ID
+        market: String,
+    },
+
     /// Search markets
     Search {
         /// Search query string
@@ -67,6 +107,36 @@ pub enum MarketsCommand {
         limit: i32,
     },
 
+    /// Rank open markets by what is moving right now
+    Trending {
+        /// How to rank markets
+        #[arg(long, default_value = "volume-surge")]
+        by: TrendMetric,
+
+        /// Max results
+        #[arg(long, default_value = "10")]
+        limit: usize,
+    },
+
+    /// Print markets as they are created (Ctrl+C to stop)
+    WatchNew {
+        /// Only markets with this tag ID or slug
+        #[arg(long)]
+        tag: Option<String>,
+
+        /// Only markets whose question or slug contains this text (repeatable; any match)
+        #[arg(long = "keyword", value_name = "TEXT")]
+        keywords: Vec<String>,
+
+        /// Seconds between polls
+        #[arg(long, default_value = "30")]
+        interval: u64,
+
+        /// Shell command to run for each new market, with `POLYMARKET_MARKET_*` set
+        #[arg(long, value_name = "COMMAND")]
+        exec: Option<String>,
+    },
+
     /// Get tags for a market
     Tags {
         /// Market ID
@@ -74,6 +144,227 @@ pub enum MarketsCommand {
     },
 }
 
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+pub enum MarketOrder {
+    Volume,
+    #[value(name = "volume-24h")]
+    Volume24h,
+    Liquidity,
+    EndDate,
+    StartDate,
+}
+
+impl MarketOrder {
+    /// Gamma API field name for `order`.
+    fn field(self) -> &'static str {
+        match self {
+            Self::Volume => "volumeNum",
+            Self::Volume24h => "volume24hr",
+            Self::Liquidity => "liquidityNum",
+            Self::EndDate => "endDate",
+            Self::StartDate => "startDate",
+        }
+    }
+}
+
+impl MarketsCommand {
+    /// Commands that keep running and print as data arrives.
+    pub(crate) fn follows(&self) -> bool {
+        matches!(self, Self::WatchNew { .. })
+    }
+}
+
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+pub enum TrendMetric {
+    /// 24h volume relative to the daily average over the past week
+    VolumeSurge,
+    /// Raw 24h volume
+    Volume,
+    /// Largest 24h price change, up or down
+    PriceMove,
+    /// Current liquidity (Gamma keeps no liquidity history to diff against)
+    Liquidity,
+}
+
+impl TrendMetric {
+    /// Gamma field the candidate pool is pre-sorted by.
+    fn pool_order(self) -> &'static str {
+        match self {
+            Self::Liquidity => "liquidityNum",
+            Self::VolumeSurge | Self::Volume | Self::PriceMove => "volume24hr",
+        }
+    }
+
+    /// None for markets the metric can't rank, e.g. no weekly volume yet.
+    fn score(self, m: &Market) -> Option<Decimal> {
+        match self {
+            Self::VolumeSurge => volume_surge(m),
+            Self::Volume => m.volume_24hr,
+            Self::PriceMove => m.one_day_price_change.map(|c| c.abs()),
+            Self::Liquidity => m.liquidity_num,
+        }
+    }
+}
+
+/// Markets scored client-side; trending is drawn from this many of the
+/// busiest open markets so tiny ones can't top the list on a single trade.
+const TRENDING_POOL: i32 = 200;
+
+/// 24h volume divided by the average daily volume over the past week.
+fn volume_surge(m: &Market) -> Option<Decimal> {
+    let week = m.volume_1wk.filter(|v| !v.is_zero())?;
+    Some((m.volume_24hr? * Decimal::from(7) / week).round_dp(2))
+}
+
+/// Newest markets fetched per poll; more than Gamma lists in one interval.
+const WATCH_NEW_PAGE_SIZE: i32 = 50;
+
+fn matches_keywords(m: &Market, keywords: &[String]) -> bool {
+    if keywords.is_empty() {
+        return true;
+    }
+    let haystack = [m.question.as_deref(), m.slug.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    keywords
+        .iter()
+        .any(|k| haystack.contains(&k.to_lowercase()))
+}
+
+/// Runs the `--exec` hook for one market.
+async fn run_market_hook(command: &str, m: &Market) {
+    let slug = m.slug.clone().unwrap_or_default();
+    run_hook(
+        command,
+        &[
+            ("POLYMARKET_MARKET_ID", m.id.clone()),
+            ("POLYMARKET_MARKET_SLUG", slug.clone()),
+            (
+                "POLYMARKET_MARKET_QUESTION",
+                m.question.clone().unwrap_or_default(),
+            ),
+            (
+                "POLYMARKET_MARKET_URL",
+                format!("https://polymarket.com/market/{slug}"),
+            ),
+        ],
+    )
+    .await;
+}
+
+async fn watch_new(
+    client: &gamma::Client,
+    tag_id: Option<String>,
+    keywords: &[String],
+    interval: u64,
+    exec: Option<&str>,
+    output: &OutputFormat,
+) -> Result<()> {
+    let request = MarketsRequest::builder()
+        .limit(WATCH_NEW_PAGE_SIZE)
+        .closed(false)
+        .order("createdAt".to_string())
+        .ascending(false)
+        .maybe_tag_id(tag_id)
+        .build();
+    if matches!(output, OutputFormat::Table) {
+        println!(
+            "{}",
+            color::dim("Waiting for new markets (Ctrl+C to stop)...")
+        );
+    }
+
+    let mut poller = Poller::new(Duration::from_secs(interval.max(1)));
+    let mut seen: Option<HashSet<String>> = None;
+    while poller.tick().await {
+        let markets = match client.markets(&request).await {
+            Ok(markets) => markets,
+            Err(e) => {
+                eprintln!("Failed to fetch markets: {e}");
+                continue;
+            }
+        };
+        // The first poll only records what already exists.
+        let Some(seen) = &mut seen else {
+            seen = Some(markets.into_iter().map(|m| m.id).collect());
+            continue;
+        };
+        for m in markets.iter().rev() {
+            if !seen.insert(m.id.clone()) || !matches_keywords(m, keywords) {
+                continue;
+            }
+            print_new_market(m, output)?;
+            if let Some(command) = exec {
+                run_market_hook(command, m).await;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Joins a Gamma market with its tags, CLOB config, and order books.
+async fn market_info(client: &gamma::Client, market: Market) -> Result<MarketInfo> {
+    let condition_id = market
+        .condition_id
+        .ok_or_else(|| anyhow::anyhow!("Market {} has no condition ID", market.id))?;
+    let clob_client = endpoints::clob();
+    let tags_request = MarketTagsRequest::builder().id(market.id.clone()).build();
+    let condition = condition_id.to_string();
+    let (tags, clob_market) = tokio::join!(
+        client.market_tags(&tags_request),
+        clob_client.market(&condition),
+    );
+    let clob_market = clob_market?;
+
+    let book_requests: Vec<_> = clob_market
+        .tokens
+        .iter()
+        .map(|t| {
+            OrderBookSummaryRequest::builder()
+                .token_id(t.token_id)
+                .build()
+        })
+        .collect();
+    // A closed market has no books; the quotes are just left empty.
+    let books = clob_client
+        .order_books(&book_requests)
+        .await
+        .unwrap_or_default();
+    let quotes = clob_market
+        .tokens
+        .iter()
+        .map(|t| {
+            let book = books.iter().find(|b| b.asset_id == t.token_id);
+            OutcomeQuote {
+                outcome: t.outcome.clone(),
+                token_id: t.token_id,
+                price: t.price,
+                best_bid: book.and_then(|b| b.bids.iter().map(|o| o.price).max()),
+                best_ask: book.and_then(|b| b.asks.iter().map(|o| o.price).min()),
+            }
+        })
+        .collect();
+
+    Ok(MarketInfo {
+        tags: tags.unwrap_or_else(|_| market.tags.clone().unwrap_or_default()),
+        market,
+        clob: clob_market,
+        quotes,
+    })
+}
+
+/// Gamma filters markets by tag ID only, so slugs are looked up first.
+async fn resolve_tag_id(client: &gamma::Client, tag: String) -> Result<String> {
+    if is_numeric_id(&tag) {
+        return Ok(tag);
+    }
+    let req = TagBySlugRequest::builder().slug(tag).build();
+    Ok(client.tag_by_slug(&req).await?.id)
+}
+
 pub async fn execute(
     client: &gamma::Client,
     args: MarketsArgs,
@@ -86,23 +377,42 @@ pub async fn execute(
             limit,
             offset,
             order,
+            order_by,
             ascending,
+            tag,
+            liquidity_min,
+            volume_min,
+            end_after,
+            end_before,
         } => {
             let resolved_closed = closed.or_else(|| active.map(|a| !a));
+            let end_after = end_after.map(|d| parse_date_bound(&d, false)).transpose()?;
+            let end_before = end_before.map(|d| parse_date_bound(&d, true)).transpose()?;
+            let tag_id = match tag {
+                Some(tag) => Some(resolve_tag_id(client, tag).await?),
+                None => None,
+            };
 
             let request = MarketsRequest::builder()
                 .limit(limit)
                 .maybe_closed(resolved_closed)
                 .maybe_offset(offset)
-                .maybe_order(order)
+                .maybe_order(order.or_else(|| order_by.map(|o| o.field().to_string())))
                 .maybe_ascending(if ascending { Some(true) } else { None })
+                .maybe_tag_id(tag_id)
+                .maybe_liquidity_num_min(liquidity_min)
+                .maybe_volume_num_min(volume_min)
+                .maybe_end_date_min(end_after)
+                .maybe_end_date_max(end_before)
                 .build();
 
             let markets = client.markets(&request).await?;
 
             match output {
                 OutputFormat::Table => print_markets_table(&markets),
-                OutputFormat::Json => print_json(&markets)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&markets)?
+                }
             }
         }
 
@@ -118,10 +428,30 @@ pub async fn execute(
 
             match output {
                 OutputFormat::Table => print_market_detail(&market),
-                OutputFormat::Json => print_json(&market)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&market)?
+                }
             }
         }
 
+        MarketsCommand::Info { market } => {
+            let market = if is_numeric_id(&market) {
+                let req = MarketByIdRequest::builder().id(market).build();
+                client.market_by_id(&req).await?
+            } else {
+                let cid = parse_condition_id(&resolve_condition_arg(&market).await?)?;
+                let req = MarketsRequest::builder().condition_ids(vec![cid]).build();
+                client
+                    .markets(&req)
+                    .await?
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("Market not found: {cid}"))?
+            };
+            let info = market_info(client, market).await?;
+            print_market_info(&info, &output)?;
+        }
+
         MarketsCommand::Search { query, limit } => {
             let request = SearchRequest::builder()
                 .q(query)
@@ -139,20 +469,84 @@ pub async fn execute(
 
             match output {
                 OutputFormat::Table => print_markets_table(&markets),
-                OutputFormat::Json => print_json(&markets)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&markets)?
+                }
             }
         }
 
+        MarketsCommand::Trending { by, limit } => {
+            let request = MarketsRequest::builder()
+                .limit(TRENDING_POOL)
+                .closed(false)
+                .order(by.pool_order().to_string())
+                .ascending(false)
+                .build();
+
+            let mut ranked: Vec<(Market, Decimal)> = client
+                .markets(&request)
+                .await?
+                .into_iter()
+                .filter_map(|m| by.score(&m).map(|score| (m, score)))
+                .collect();
+            ranked.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+            ranked.truncate(limit);
+
+            print_trending(&ranked, &output)?;
+        }
+
+        MarketsCommand::WatchNew {
+            tag,
+            keywords,
+            interval,
+            exec,
+        } => {
+            let tag_id = match tag {
+                Some(tag) => Some(resolve_tag_id(client, tag).await?),
+                None => None,
+            };
+            watch_new(
+                client,
+                tag_id,
+                &keywords,
+                interval,
+                exec.as_deref(),
+                &output,
+            )
+            .await?;
+        }
+
         MarketsCommand::Tags { id } => {
             let req = MarketTagsRequest::builder().id(id).build();
             let tags = client.market_tags(&req).await?;
 
             match output {
                 OutputFormat::Table => print_tags_table(&tags),
-                OutputFormat::Json => print_json(&tags)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&tags)?
+                }
             }
         }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn market(question: &str, slug: &str) -> Market {
+        serde_json::from_value(json!({"id": "1", "question": question, "slug": slug})).unwrap()
+    }
+
+    #[test]
+    fn keywords_match_question_or_slug_case_insensitively() {
+        let m = market("Will the Fed cut rates in June?", "fed-june-cut");
+        assert!(matches_keywords(&m, &[]));
+        assert!(matches_keywords(&m, &["FED".into()]));
+        assert!(matches_keywords(&m, &["bitcoin".into(), "june-cut".into()]));
+        assert!(!matches_keywords(&m, &["bitcoin".into()]));
+    }
+}
@@ -1,18 +1,41 @@
-use polymarket_client_sdk::types::{Address, B256};
+use std::collections::HashMap;
+use std::io::{self, BufRead as _, IsTerminal as _, Write as _};
 
+use anyhow::{Context, bail};
+use chrono::{DateTime, NaiveDate, Utc};
+use polymarket_client_sdk::gamma::{
+    types::request::{EventBySlugRequest, MarketBySlugRequest, MarketsRequest},
+    types::response::Market,
+};
+use polymarket_client_sdk::types::{Address, B256, U256};
+use serde::{Deserialize, Serialize};
+
+use crate::output::OutputFormat;
+use crate::output::data::print_wallet_kind;
+use crate::{auth, endpoints};
+
+pub mod activity;
 pub mod approve;
 pub mod bridge;
+pub mod browse;
 pub mod clob;
 pub mod comments;
+pub mod config;
 pub mod ctf;
+pub mod dashboard;
 pub mod data;
 pub mod events;
+pub mod follow;
 pub mod markets;
+pub mod pnl;
+pub mod portfolio;
 pub mod profiles;
+pub mod safe;
 pub mod series;
 pub mod setup;
 pub mod sports;
 pub mod tags;
+pub mod tx;
 pub mod upgrade;
 pub mod wallet;
 
@@ -30,10 +53,308 @@ pub fn parse_condition_id(s: &str) -> anyhow::Result<B256> {
         .map_err(|_| anyhow::anyhow!("Invalid condition ID: must be a 0x-prefixed 32-byte hex"))
 }
 
+/// A market's identifiers, cached by slug since they never change.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct MarketIds {
+    condition_id: B256,
+    /// Outcome labels and their token IDs, in Gamma's order.
+    outcomes: Vec<(String, U256)>,
+}
+
+impl MarketIds {
+    fn from_market(market: &Market) -> anyhow::Result<Self> {
+        let slug = market.slug.as_deref().unwrap_or(&market.id);
+        let condition_id = market
+            .condition_id
+            .with_context(|| format!("Market '{slug}' has no condition ID"))?;
+        let labels = market.outcomes.clone().unwrap_or_default();
+        let tokens = market.clob_token_ids.clone().unwrap_or_default();
+        Ok(Self {
+            condition_id,
+            outcomes: labels.into_iter().zip(tokens).collect(),
+        })
+    }
+
+    fn token_for(&self, slug: &str, outcome: Option<&str>) -> anyhow::Result<U256> {
+        let labels = || {
+            self.outcomes
+                .iter()
+                .map(|(label, _)| label.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let Some(outcome) = outcome else {
+            bail!(
+                "Market '{slug}' has several outcomes ({}); pick one with --outcome",
+                labels()
+            );
+        };
+        self.outcomes
+            .iter()
+            .find(|(label, _)| label.eq_ignore_ascii_case(outcome.trim()))
+            .map(|(_, token)| *token)
+            .with_context(|| {
+                format!(
+                    "Market '{slug}' has no outcome '{outcome}'. Valid outcomes: {}",
+                    labels()
+                )
+            })
+    }
+}
+
+/// The slug in a `polymarket.com/event/<event>/<market>` or `/market/<slug>`
+/// URL (the last path segment), or the argument unchanged.
+fn slug_from_arg(arg: &str) -> &str {
+    let Some((_, path)) = arg.split_once("polymarket.com/") else {
+        return arg.trim();
+    };
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    path.rsplit('/').find(|s| !s.is_empty()).unwrap_or(path)
+}
+
+fn load_market_cache() -> HashMap<String, MarketIds> {
+    crate::config::market_cache_path()
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Best effort: a failed write only means the next lookup goes to Gamma.
+fn save_market_cache(cache: &HashMap<String, MarketIds>) {
+    let Ok(path) = crate::config::market_cache_path() else {
+        return;
+    };
+    if let (Some(dir), Ok(json)) = (path.parent(), serde_json::to_string_pretty(cache)) {
+        let _ = std::fs::create_dir_all(dir);
+        let _ = crate::output::write_atomic(&path, &json);
+    }
+}
+
+/// Looks up a market slug, falling back to an event slug whose event has
+/// exactly one market.
+async fn lookup_market_ids(slug: &str) -> anyhow::Result<MarketIds> {
+    let mut cache = load_market_cache();
+    if let Some(ids) = cache.get(slug) {
+        return Ok(ids.clone());
+    }
+    let client = endpoints::gamma();
+    let request = MarketBySlugRequest::builder().slug(slug).build();
+    let ids = match client.market_by_slug(&request).await {
+        Ok(market) => MarketIds::from_market(&market)?,
+        Err(market_err) => {
+            let request = EventBySlugRequest::builder().slug(slug).build();
+            let Ok(event) = client.event_by_slug(&request).await else {
+                return Err(anyhow::Error::from(market_err))
+                    .with_context(|| format!("No market or event found for '{slug}'"));
+            };
+            match event.markets.as_deref().unwrap_or_default() {
+                [market] => MarketIds::from_market(market)?,
+                markets => bail!(
+                    "Event '{slug}' has {} markets; use one of their slugs: {}",
+                    markets.len(),
+                    markets
+                        .iter()
+                        .filter_map(|m| m.slug.as_deref())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            }
+        }
+    };
+    cache.insert(slug.to_string(), ids.clone());
+    save_market_cache(&cache);
+    Ok(ids)
+}
+
+/// Resolves a token ID argument that may also be a market slug or
+/// polymarket.com URL (with `outcome` choosing the token). Numeric IDs are
+/// returned unchanged without a network call.
+pub async fn resolve_token_arg(arg: &str, outcome: Option<&str>) -> anyhow::Result<String> {
+    if is_numeric_id(arg.trim()) {
+        return Ok(arg.to_string());
+    }
+    let slug = slug_from_arg(arg);
+    let ids = lookup_market_ids(slug).await?;
+    Ok(ids.token_for(slug, outcome)?.to_string())
+}
+
+/// Comma-separated form of [`resolve_token_arg`].
+pub async fn resolve_token_list_arg(arg: &str, outcome: Option<&str>) -> anyhow::Result<String> {
+    let mut resolved = Vec::new();
+    for item in arg.split(',') {
+        resolved.push(resolve_token_arg(item.trim(), outcome).await?);
+    }
+    Ok(resolved.join(","))
+}
+
+/// Resolves a condition ID argument that may also be a market slug or URL.
+/// Hex IDs are returned unchanged without a network call.
+pub async fn resolve_condition_arg(arg: &str) -> anyhow::Result<String> {
+    if arg.trim().starts_with("0x") {
+        return Ok(arg.to_string());
+    }
+    let ids = lookup_market_ids(slug_from_arg(arg)).await?;
+    Ok(ids.condition_id.to_string())
+}
+
+/// Comma-separated form of [`resolve_condition_arg`].
+pub async fn resolve_condition_list_arg(arg: &str) -> anyhow::Result<String> {
+    let mut resolved = Vec::new();
+    for item in arg.split(',') {
+        resolved.push(resolve_condition_arg(item.trim()).await?);
+    }
+    Ok(resolved.join(","))
+}
+
+/// In table mode, notes whether a looked-up wallet is an EOA or a proxy wallet.
+pub async fn describe_wallet(address: Address, output: &OutputFormat) {
+    if matches!(output, OutputFormat::Table) {
+        let kind = auth::wallet_kind(address).await.ok();
+        print_wallet_kind(address, kind, auth::derived_wallets(address));
+    }
+}
+
+/// Parses a `YYYY-MM-DD` filter bound as UTC: the start of the day, or its
+/// last second when `end_of_day` is set so `--until` includes that day.
+pub fn parse_date_bound(s: &str, end_of_day: bool) -> anyhow::Result<DateTime<Utc>> {
+    let date = NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .map_err(|_| anyhow::anyhow!("Invalid date: expected YYYY-MM-DD format"))?;
+    let time = if end_of_day {
+        date.and_hms_opt(23, 59, 59)
+    } else {
+        date.and_hms_opt(0, 0, 0)
+    };
+    Ok(time.expect("valid time of day").and_utc())
+}
+
+/// Parses a point in time for `--after`/`--before` filters: a `YYYY-MM-DD`
+/// date (as [`parse_date_bound`]), RFC 3339, a Unix timestamp, or an age
+/// such as `7d` or `12h` counted back from now.
+pub fn parse_time_bound(s: &str, end_of_day: bool) -> anyhow::Result<DateTime<Utc>> {
+    let s = s.trim();
+    if let Ok(ts) = s.parse::<i64>() {
+        return DateTime::from_timestamp(ts, 0)
+            .ok_or_else(|| anyhow::anyhow!("Invalid timestamp: {s}"));
+    }
+    if let Ok(time) = DateTime::parse_from_rfc3339(s) {
+        return Ok(time.to_utc());
+    }
+    if let Ok(age) = clob::parse_duration(s) {
+        return Ok(Utc::now() - age);
+    }
+    parse_date_bound(s, end_of_day).map_err(|_| {
+        anyhow::anyhow!(
+            "Invalid time: {s}. Use YYYY-MM-DD, RFC 3339, a Unix timestamp, or an age like 7d"
+        )
+    })
+}
+
+/// Labels like `Yes on "Will it rain?"` for the given tokens, keyed by
+/// token ID. Best effort: tokens Gamma doesn't know are left out.
+pub async fn token_labels(token_ids: Vec<U256>) -> HashMap<U256, String> {
+    let request = MarketsRequest::builder().clob_token_ids(token_ids).build();
+    let markets = endpoints::gamma()
+        .markets(&request)
+        .await
+        .unwrap_or_default();
+    let mut labels = HashMap::new();
+    for market in markets {
+        let question = market.question.clone().unwrap_or_default();
+        let outcomes = market.outcomes.unwrap_or_default();
+        let tokens = market.clob_token_ids.unwrap_or_default();
+        for (outcome, token) in outcomes.into_iter().zip(tokens) {
+            labels.insert(token, format!("{outcome} on \"{question}\""));
+        }
+    }
+    labels
+}
+
+/// [`token_labels`] for one token, falling back to its ID.
+pub async fn token_label(token_id: U256) -> String {
+    token_labels(vec![token_id])
+        .await
+        .remove(&token_id)
+        .unwrap_or_else(|| format!("token {token_id}"))
+}
+
+/// The question of the market with this condition ID, if Gamma has it.
+pub async fn market_question(condition_id: B256) -> Option<String> {
+    let request = MarketsRequest::builder()
+        .condition_ids(vec![condition_id])
+        .build();
+    endpoints::gamma()
+        .markets(&request)
+        .await
+        .ok()?
+        .into_iter()
+        .next()?
+        .question
+}
+
+/// Runs an `--exec` hook through the shell with `env` set. A failing hook is
+/// reported but doesn't stop the watch that ran it.
+pub async fn run_hook(command: &str, env: &[(&str, String)]) {
+    #[cfg(unix)]
+    let mut cmd = tokio::process::Command::new("sh");
+    #[cfg(unix)]
+    cmd.arg("-c");
+    #[cfg(not(unix))]
+    let mut cmd = tokio::process::Command::new("cmd");
+    #[cfg(not(unix))]
+    cmd.arg("/C");
+    cmd.arg(command).envs(env.iter().map(|(k, v)| (k, v)));
+    match cmd.status().await {
+        Ok(status) if status.success() => {}
+        Ok(status) => eprintln!("--exec hook exited with {status}"),
+        Err(e) => eprintln!("Failed to run --exec hook: {e}"),
+    }
+}
+
+fn is_yes(answer: &str) -> bool {
+    matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+/// Fails when there is no terminal to ask on, so commands that do slow work
+/// before [`confirm_submit`] can give up first.
+pub fn ensure_can_confirm() -> anyhow::Result<()> {
+    if !io::stdin().is_terminal() {
+        bail!("Not submitting without confirmation: pass --yes to skip the prompt");
+    }
+    Ok(())
+}
+
+/// Shows what is about to be submitted and asks to go ahead. Declining, or
+/// having no terminal to ask on, aborts before anything is sent.
+pub fn confirm_submit(summary: &[String]) -> anyhow::Result<()> {
+    ensure_can_confirm()?;
+    let mut stderr = io::stderr().lock();
+    for line in summary {
+        writeln!(stderr, "  {line}")?;
+    }
+    write!(stderr, "Submit? [y/N] ")?;
+    stderr.flush()?;
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    if !is_yes(&answer) {
+        bail!("Cancelled; nothing was submitted");
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn is_yes_accepts_only_explicit_yes() {
+        assert!(is_yes("y\n"));
+        assert!(is_yes(" YES "));
+        assert!(!is_yes(""));
+        assert!(!is_yes("n"));
+        assert!(!is_yes("yeah"));
+    }
+
     #[test]
     fn is_numeric_id_pure_digits() {
         assert!(is_numeric_id("12345"));
@@ -82,6 +403,97 @@ mod tests {
         assert!(err.contains("32-byte"), "got: {err}");
     }
 
+    fn yes_no() -> MarketIds {
+        MarketIds {
+            condition_id: B256::ZERO,
+            outcomes: vec![
+                ("Yes".into(), U256::from(1u64)),
+                ("No".into(), U256::from(2u64)),
+            ],
+        }
+    }
+
+    #[test]
+    fn slug_from_arg_takes_last_url_segment() {
+        assert_eq!(
+            slug_from_arg("https://polymarket.com/event/btc-2025/will-btc-hit-100k?tid=1"),
+            "will-btc-hit-100k"
+        );
+        assert_eq!(
+            slug_from_arg("polymarket.com/market/will-btc-hit-100k/"),
+            "will-btc-hit-100k"
+        );
+        assert_eq!(slug_from_arg("will-btc-hit-100k"), "will-btc-hit-100k");
+    }
+
+    #[test]
+    fn token_for_matches_outcome_case_insensitively() {
+        assert_eq!(
+            yes_no().token_for("m", Some("no")).unwrap(),
+            U256::from(2u64)
+        );
+    }
+
+    #[test]
+    fn token_for_requires_a_valid_outcome() {
+        let err = yes_no().token_for("m", None).unwrap_err().to_string();
+        assert!(
+            err.contains("--outcome") && err.contains("Yes, No"),
+            "got: {err}"
+        );
+        let err = yes_no()
+            .token_for("m", Some("maybe"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Valid outcomes: Yes, No"), "got: {err}");
+    }
+
+    #[tokio::test]
+    async fn numeric_and_hex_ids_pass_through_offline() {
+        assert_eq!(resolve_token_arg("123", None).await.unwrap(), "123");
+        assert_eq!(resolve_token_list_arg("1, 2", None).await.unwrap(), "1,2");
+        assert_eq!(resolve_condition_arg("0xabc").await.unwrap(), "0xabc");
+    }
+
+    #[test]
+    fn parse_date_bound_until_is_end_of_day() {
+        assert_eq!(
+            parse_date_bound("2024-03-01", true).unwrap().to_rfc3339(),
+            "2024-03-01T23:59:59+00:00"
+        );
+        assert_eq!(
+            parse_date_bound("2024-03-01", false).unwrap().to_rfc3339(),
+            "2024-03-01T00:00:00+00:00"
+        );
+        assert!(parse_date_bound("03/01/2024", false).is_err());
+    }
+
+    #[test]
+    fn parse_time_bound_accepts_dates_timestamps_and_ages() {
+        assert_eq!(
+            parse_time_bound("2024-03-01", true).unwrap().to_rfc3339(),
+            "2024-03-01T23:59:59+00:00"
+        );
+        assert_eq!(
+            parse_time_bound("1709251200", false).unwrap().to_rfc3339(),
+            "2024-03-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            parse_time_bound("2024-03-01T12:00:00+02:00", false)
+                .unwrap()
+                .to_rfc3339(),
+            "2024-03-01T10:00:00+00:00"
+        );
+        let week_ago = parse_time_bound("7d", false).unwrap();
+        assert!(
+            (Utc::now() - week_ago - chrono::Duration::days(7))
+                .num_seconds()
+                .abs()
+                < 5
+        );
+        assert!(parse_time_bound("last week", false).is_err());
+    }
+
     #[test]
     fn parse_condition_id_rejects_garbage() {
         let err = parse_condition_id("garbage").unwrap_err().to_string();
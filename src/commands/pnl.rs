@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use clap::Args;
+use polymarket_client_sdk::clob::types::request::{MidpointRequest, TradesRequest};
+use polymarket_client_sdk::clob::types::response::TradeResponse;
+use polymarket_client_sdk::clob::types::{Side, TraderSide};
+use polymarket_client_sdk::data::types::request::PositionsRequest;
+use polymarket_client_sdk::types::{Address, B256, Decimal, U256};
+
+use super::parse_date_bound;
+use crate::auth;
+use crate::endpoints;
+use crate::output::OutputFormat;
+use crate::output::clob::END_CURSOR;
+use crate::output::pnl::{PnlRow, print_pnl};
+
+#[derive(Args)]
+pub struct PnlArgs {
+    /// Only count realized PnL from fills on or after this date (YYYY-MM-DD)
+    #[arg(long)]
+    pub since: Option<String>,
+
+    /// Only count realized PnL from fills on or before this date (YYYY-MM-DD)
+    #[arg(long)]
+    pub until: Option<String>,
+}
+
+/// One of our fills, whether we were the taker or a maker on the trade.
+#[derive(Clone, Debug)]
+pub(crate) struct Fill {
+    asset_id: U256,
+    market: B256,
+    outcome: String,
+    pub(crate) side: Side,
+    pub(crate) size: Decimal,
+    price: Decimal,
+    time: DateTime<Utc>,
+}
+
+/// Our side of a trade. Taker fills use the trade itself; maker fills use the
+/// maker orders placed from our wallet, which may be on the opposite token.
+pub(crate) fn fills_from_trade(trade: &TradeResponse, funder: Address) -> Vec<Fill> {
+    if trade.trader_side == TraderSide::Taker {
+        return vec![Fill {
+            asset_id: trade.asset_id,
+            market: trade.market,
+            outcome: trade.outcome.clone(),
+            side: trade.side,
+            size: trade.size,
+            price: trade.price,
+            time: trade.match_time,
+        }];
+    }
+    trade
+        .maker_orders
+        .iter()
+        .filter(|m| m.maker_address == funder)
+        .map(|m| Fill {
+            asset_id: m.asset_id,
+            market: trade.market,
+            outcome: m.outcome.clone(),
+            side: m.side,
+            size: m.matched_amount,
+            price: m.price,
+            time: trade.match_time,
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Ledger {
+    size: Decimal,
+    avg_cost: Decimal,
+    realized: Decimal,
+}
+
+/// Realized PnL per token using average cost. The cost basis is built from
+/// every fill, but only sells inside `window` count toward realized PnL.
+/// Sells beyond the tracked size (e.g. tokens from a split) realize nothing.
+fn realized_by_asset(
+    fills: &mut [Fill],
+    window: (Option<DateTime<Utc>>, Option<DateTime<Utc>>),
+) -> HashMap<U256, Ledger> {
+    fills.sort_by_key(|f| f.time);
+    let in_window = |t: DateTime<Utc>| {
+        window.0.is_none_or(|since| t >= since) && window.1.is_none_or(|until| t <= until)
+    };
+    let mut ledgers: HashMap<U256, Ledger> = HashMap::new();
+    for fill in fills.iter() {
+        let ledger = ledgers.entry(fill.asset_id).or_default();
+        match fill.side {
+            Side::Buy => {
+                let size = ledger.size + fill.size;
+                if !size.is_zero() {
+                    ledger.avg_cost =
+                        (ledger.size * ledger.avg_cost + fill.size * fill.price) / size;
+                }
+                ledger.size = size;
+            }
+            Side::Sell => {
+                let closed = fill.size.min(ledger.size);
+                if in_window(fill.time) {
+                    ledger.realized += closed * (fill.price - ledger.avg_cost);
+                }
+                ledger.size -= closed;
+            }
+            _ => {}
+        }
+    }
+    ledgers
+}
+
+pub async fn execute(
+    args: PnlArgs,
+    output: OutputFormat,
+    private_key: Option<&str>,
+    signature_type: Option<&str>,
+) -> Result<()> {
+    let since = args
+        .since
+        .map(|s| parse_date_bound(&s, false))
+        .transpose()?;
+    let until = args.until.map(|s| parse_date_bound(&s, true)).transpose()?;
+    let funder = auth::resolve_funder_address(private_key, signature_type)?;
+    let client = auth::authenticated_clob_client(private_key, signature_type).await?;
+
+    // Fills before --since still matter for the cost basis, so fetch all history.
+    let request = TradesRequest::builder()
+        .maybe_before(until.map(|t| t.timestamp()))
+        .build();
+    let mut fills = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = client.trades(&request, cursor).await?;
+        fills.extend(page.data.iter().flat_map(|t| fills_from_trade(t, funder)));
+        if page.next_cursor == END_CURSOR || page.data.is_empty() {
+            break;
+        }
+        cursor = Some(page.next_cursor);
+    }
+    let ledgers = realized_by_asset(&mut fills, (since, until));
+
+    let positions = endpoints::data()
+        .positions(&PositionsRequest::builder().user(funder).build())
+        .await?;
+    let midpoints = if positions.is_empty() {
+        HashMap::new()
+    } else {
+        let requests: Vec<_> = positions
+            .iter()
+            .map(|p| MidpointRequest::builder().token_id(p.asset).build())
+            .collect();
+        endpoints::clob().midpoints(&requests).await?.midpoints
+    };
+
+    let mut rows: Vec<PnlRow> = positions
+        .iter()
+        .map(|p| {
+            let mark = midpoints.get(&p.asset).copied().unwrap_or(p.cur_price);
+            PnlRow {
+                market: p.title.clone(),
+                outcome: p.outcome.clone(),
+                size: p.size,
+                realized: ledgers.get(&p.asset).map_or(Decimal::ZERO, |l| l.realized),
+                unrealized: p.size * (mark - p.avg_price),
+            }
+        })
+        .collect();
+    // Closed positions only have realized PnL; label them by condition ID.
+    for (asset, ledger) in &ledgers {
+        if ledger.realized.is_zero() || positions.iter().any(|p| p.asset == *asset) {
+            continue;
+        }
+        let fill = fills
+            .iter()
+            .find(|f| f.asset_id == *asset)
+            .expect("ledger entries come from fills");
+        rows.push(PnlRow {
+            market: fill.market.to_string(),
+            outcome: fill.outcome.clone(),
+            size: Decimal::ZERO,
+            realized: ledger.realized,
+            unrealized: Decimal::ZERO,
+        });
+    }
+    rows.sort_by_key(|r| std::cmp::Reverse(r.total().abs()));
+
+    print_pnl(&rows, &output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn fill(side: Side, size: Decimal, price: Decimal, day: u32) -> Fill {
+        Fill {
+            asset_id: U256::from(1u64),
+            market: B256::ZERO,
+            outcome: "Yes".into(),
+            side,
+            size,
+            price,
+            time: parse_date_bound(&format!("2024-01-{day:02}"), false).unwrap(),
+        }
+    }
+
+    fn realized(fills: &mut [Fill], since: Option<&str>) -> Ledger {
+        let since = since.map(|s| parse_date_bound(s, false).unwrap());
+        realized_by_asset(fills, (since, None))
+            .remove(&U256::from(1u64))
+            .unwrap()
+    }
+
+    #[test]
+    fn average_cost_realizes_on_sell() {
+        let mut fills = [
+            fill(Side::Buy, dec!(10), dec!(0.40), 1),
+            fill(Side::Buy, dec!(10), dec!(0.60), 2),
+            fill(Side::Sell, dec!(5), dec!(0.70), 3),
+        ];
+        let ledger = realized(&mut fills, None);
+        assert_eq!(ledger.avg_cost, dec!(0.50));
+        assert_eq!(ledger.size, dec!(15));
+        assert_eq!(ledger.realized, dec!(1.00));
+    }
+
+    #[test]
+    fn fills_are_processed_in_time_order() {
+        let mut fills = [
+            fill(Side::Sell, dec!(10), dec!(0.30), 2),
+            fill(Side::Buy, dec!(10), dec!(0.50), 1),
+        ];
+        assert_eq!(realized(&mut fills, None).realized, dec!(-2.00));
+    }
+
+    #[test]
+    fn since_excludes_earlier_sells_but_keeps_cost_basis() {
+        let mut fills = [
+            fill(Side::Buy, dec!(20), dec!(0.50), 1),
+            fill(Side::Sell, dec!(10), dec!(0.60), 2),
+            fill(Side::Sell, dec!(10), dec!(0.70), 5),
+        ];
+        assert_eq!(
+            realized(&mut fills, Some("2024-01-03")).realized,
+            dec!(2.00)
+        );
+    }
+
+    #[test]
+    fn oversell_only_realizes_tracked_size() {
+        let mut fills = [
+            fill(Side::Buy, dec!(5), dec!(0.50), 1),
+            fill(Side::Sell, dec!(8), dec!(0.60), 2),
+        ];
+        let ledger = realized(&mut fills, None);
+        assert_eq!(ledger.realized, dec!(0.50));
+        assert_eq!(ledger.size, dec!(0));
+    }
+}
@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+use anyhow::{Result, bail};
+use clap::{Args, Subcommand};
+use polymarket_client_sdk::clob::types::request::{
+    BalanceAllowanceRequest, MidpointRequest, OrdersRequest,
+};
+use polymarket_client_sdk::clob::types::response::OpenOrderResponse;
+use polymarket_client_sdk::clob::types::{AssetType, Side};
+use polymarket_client_sdk::data::types::request::PositionsRequest;
+use polymarket_client_sdk::types::Decimal;
+
+use super::{describe_wallet, parse_address};
+use crate::auth;
+use crate::endpoints;
+use crate::output::OutputFormat;
+use crate::output::clob::{END_CURSOR, USDC_DECIMALS};
+use crate::output::data::print_positions;
+use crate::output::portfolio::{PortfolioSummary, print_portfolio_summary};
+use crate::tui;
+
+#[derive(Args)]
+pub struct PortfolioArgs {
+    #[command(subcommand)]
+    pub command: Option<PortfolioCommand>,
+
+    /// Wallet address to view (default: your configured wallet)
+    #[arg(long, global = true)]
+    pub address: Option<String>,
+
+    /// Refresh interval in seconds
+    #[arg(long, default_value = "10")]
+    pub interval: u64,
+}
+
+#[derive(Subcommand)]
+pub enum PortfolioCommand {
+    /// Net worth: USDC balance, open order exposure, and marked position value
+    Summary,
+    /// Open positions, for your wallet or any other with --address
+    Positions,
+}
+
+impl PortfolioArgs {
+    /// The live view is full-screen; `summary` prints once.
+    pub(crate) fn is_interactive(&self) -> bool {
+        self.command.is_none()
+    }
+}
+
+pub async fn execute(
+    args: PortfolioArgs,
+    output: OutputFormat,
+    private_key: Option<&str>,
+    signature_type: Option<&str>,
+) -> Result<()> {
+    let address = args.address.as_deref().map(parse_address).transpose()?;
+    let own_wallet = || auth::resolve_funder_address(private_key, signature_type);
+    match args.command {
+        Some(PortfolioCommand::Summary) => {
+            if address.is_some() {
+                bail!(
+                    "portfolio summary needs your own balance and orders; \
+                     use `portfolio positions --address` for other wallets"
+                );
+            }
+            summary(&output, private_key, signature_type).await
+        }
+        Some(PortfolioCommand::Positions) => {
+            let user = address.map_or_else(own_wallet, Ok)?;
+            let positions = endpoints::data()
+                .positions(&PositionsRequest::builder().user(user).build())
+                .await?;
+            if address.is_some() {
+                describe_wallet(user, &output).await;
+            }
+            print_positions(&positions, &output)
+        }
+        None => {
+            let address = address.map_or_else(own_wallet, Ok)?;
+            tui::portfolio::run(address, Duration::from_secs(args.interval.max(1))).await
+        }
+    }
+}
+
+fn unfilled(order: &OpenOrderResponse) -> Decimal {
+    order.original_size - order.size_matched
+}
+
+async fn summary(
+    output: &OutputFormat,
+    private_key: Option<&str>,
+    signature_type: Option<&str>,
+) -> Result<()> {
+    let funder = auth::resolve_funder_address(private_key, signature_type)?;
+    let client = auth::authenticated_clob_client(private_key, signature_type).await?;
+
+    let balance_request = BalanceAllowanceRequest::builder()
+        .asset_type(AssetType::Collateral)
+        .build();
+    let positions_request = PositionsRequest::builder().user(funder).build();
+    let data_client = endpoints::data();
+    let (balance, positions) = tokio::join!(
+        client.balance_allowance(balance_request),
+        data_client.positions(&positions_request),
+    );
+    let balance = balance?.balance / Decimal::from(10u64.pow(USDC_DECIMALS));
+    let positions = positions?;
+
+    let orders_request = OrdersRequest::builder().build();
+    let mut orders = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = client.orders(&orders_request, cursor).await?;
+        orders.extend(page.data);
+        if page.next_cursor == END_CURSOR || page.next_cursor.is_empty() {
+            break;
+        }
+        cursor = Some(page.next_cursor);
+    }
+
+    let midpoints = if positions.is_empty() {
+        Default::default()
+    } else {
+        let requests: Vec<_> = positions
+            .iter()
+            .map(|p| MidpointRequest::builder().token_id(p.asset).build())
+            .collect();
+        endpoints::clob().midpoints(&requests).await?.midpoints
+    };
+
+    let buys: Vec<_> = orders.iter().filter(|o| o.side == Side::Buy).collect();
+    let sells: Vec<_> = orders.iter().filter(|o| o.side == Side::Sell).collect();
+
+    let summary = PortfolioSummary {
+        usdc_balance: balance,
+        open_buy_notional: buys.iter().map(|o| unfilled(o) * o.price).sum(),
+        open_buy_orders: buys.len(),
+        open_sell_shares: sells.iter().map(|o| unfilled(o)).sum(),
+        open_sell_orders: sells.len(),
+        positions_value: positions
+            .iter()
+            .map(|p| p.size * midpoints.get(&p.asset).copied().unwrap_or(p.cur_price))
+            .sum(),
+        positions: positions.len(),
+    };
+    print_portfolio_summary(&summary, output)
+}
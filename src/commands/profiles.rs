@@ -33,7 +33,9 @@ pub async fn execute(
 
             match output {
                 OutputFormat::Table => print_profile_detail(&profile),
-                OutputFormat::Json => print_json(&profile)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&profile)?
+                }
             }
         }
     }
@@ -0,0 +1,700 @@
+#![allow(clippy::exhaustive_enums, reason = "Generated by sol! macro")]
+#![allow(clippy::exhaustive_structs, reason = "Generated by sol! macro")]
+#![allow(clippy::too_many_arguments, reason = "Generated by sol! macro")]
+
+//! Transactions from a Gnosis Safe: a proposal file is built from the calls a
+//! `--dry-run` printed, passed between owners to sign, and executed once it
+//! holds enough signatures.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Read as _;
+use std::path::{Path, PathBuf};
+use std::str::FromStr as _;
+
+use alloy::primitives::{B256, Bytes, Signature, U256, address};
+use alloy::signers::SignerSync as _;
+use alloy::sol;
+use alloy::sol_types::{SolCall as _, SolStruct as _, eip712_domain};
+use anyhow::{Context, Result, bail, ensure};
+use clap::{Args, Subcommand};
+use polymarket_client_sdk::auth::LocalSigner;
+use polymarket_client_sdk::contract_config;
+use polymarket_client_sdk::types::Address;
+use serde::{Deserialize, Serialize};
+
+use crate::output::OutputFormat;
+use crate::output::dry_run::PlannedTx;
+use crate::output::safe::{print_executed, print_proposal, print_safe_info};
+use crate::tx::{self, GasArgs, TxOptions, WaitArgs};
+use crate::{auth, config};
+
+/// Safe's MultiSendCallOnly (v1.3.0), deployed at the same address on
+/// Polygon and Amoy.
+const MULTI_SEND_CALL_ONLY: Address = address!("0x40A2aCCbd92BCA938b02010E17A5b8929b49130D");
+
+/// Safe operations: a plain call, or a delegatecall (used for MultiSend).
+const CALL: u8 = 0;
+const DELEGATE_CALL: u8 = 1;
+
+/// Bytes before each call's data in MultiSend's packing: operation (1), to
+/// (20), value (32), and data length (32).
+const PACKED_HEADER: usize = 1 + 20 + 32 + 32;
+
+sol! {
+    #[sol(rpc)]
+    interface ISafe {
+        function getOwners() external view returns (address[]);
+        function getThreshold() external view returns (uint256);
+        function nonce() external view returns (uint256);
+        function VERSION() external view returns (string);
+        function execTransaction(
+            address to,
+            uint256 value,
+            bytes data,
+            uint8 operation,
+            uint256 safeTxGas,
+            uint256 baseGas,
+            uint256 gasPrice,
+            address gasToken,
+            address refundReceiver,
+            bytes signatures
+        ) external payable returns (bool success);
+    }
+
+    interface IMultiSend {
+        function multiSend(bytes transactions) external payable;
+    }
+
+    /// The EIP-712 message Safe owners sign.
+    struct SafeTx {
+        address to;
+        uint256 value;
+        bytes data;
+        uint8 operation;
+        uint256 safeTxGas;
+        uint256 baseGas;
+        uint256 gasPrice;
+        address gasToken;
+        address refundReceiver;
+        uint256 nonce;
+    }
+}
+
+#[derive(Args)]
+pub struct SafeArgs {
+    #[command(subcommand)]
+    pub command: SafeCommand,
+}
+
+#[derive(Subcommand)]
+pub enum SafeCommand {
+    /// Show a Safe's owners, signature threshold, and next nonce
+    Info {
+        /// Safe address (default: the Safe derived from the configured wallet)
+        #[arg(long)]
+        safe: Option<Address>,
+    },
+    /// Build a Safe transaction from the calls a `--dry-run -o json` printed
+    Propose {
+        /// File with the dry run's JSON output, or - for stdin
+        plan: PathBuf,
+        /// Proposal file to write, to pass to the other owners
+        #[arg(long)]
+        out: PathBuf,
+        /// Safe address (default: the Safe derived from the configured wallet)
+        #[arg(long)]
+        safe: Option<Address>,
+        /// Safe nonce to use (default: the Safe's next nonce)
+        #[arg(long)]
+        nonce: Option<u64>,
+        /// Don't sign the proposal with the configured wallet
+        #[arg(long)]
+        no_sign: bool,
+        /// Overwrite the proposal file if it exists
+        #[arg(long)]
+        force: bool,
+    },
+    /// Add the configured wallet's signature to a proposal file, after showing
+    /// the calls it makes and asking (skipped with --yes)
+    Sign {
+        /// Proposal file from `safe propose`
+        file: PathBuf,
+    },
+    /// Execute a proposal once it holds enough owner signatures
+    Execute {
+        /// Proposal file from `safe propose`
+        file: PathBuf,
+    },
+}
+
+impl SafeCommand {
+    /// `propose` and `sign` write the proposal file; only `execute` has a
+    /// transaction to preview.
+    pub fn rejects_dry_run(&self) -> bool {
+        matches!(self, Self::Propose { .. } | Self::Sign { .. })
+    }
+}
+
+/// A Safe transaction and the owner signatures collected for it so far.
+/// Gas refunds aren't used: `safeTxGas`, `baseGas`, and `gasPrice` are zero,
+/// and whoever executes pays the gas.
+#[derive(Serialize, Deserialize)]
+pub struct Proposal {
+    pub safe: Address,
+    pub chain_id: u64,
+    pub to: Address,
+    pub value: U256,
+    pub data: Bytes,
+    pub operation: u8,
+    pub nonce: U256,
+    /// What the bundled calls do, for the owners reviewing it
+    pub actions: Vec<String>,
+    /// The hash owners sign; checked against the fields on every load
+    pub safe_tx_hash: B256,
+    #[serde(default)]
+    pub signatures: BTreeMap<Address, Bytes>,
+}
+
+impl Proposal {
+    fn message(&self) -> SafeTx {
+        SafeTx {
+            to: self.to,
+            value: self.value,
+            data: self.data.clone(),
+            operation: self.operation,
+            safeTxGas: U256::ZERO,
+            baseGas: U256::ZERO,
+            gasPrice: U256::ZERO,
+            gasToken: Address::ZERO,
+            refundReceiver: Address::ZERO,
+            nonce: self.nonce,
+        }
+    }
+
+    /// The EIP-712 hash of the transaction for Safe 1.3.0 and later.
+    fn hash(&self) -> B256 {
+        let domain = eip712_domain! {
+            chain_id: self.chain_id,
+            verifying_contract: self.safe,
+        };
+        self.message().eip712_signing_hash(&domain)
+    }
+
+    /// The calls the transaction makes, read back from `data` rather than
+    /// from `actions`, which nothing checks. A delegatecall runs code as the
+    /// Safe itself, so the only one allowed is to MultiSendCallOnly.
+    fn calls(&self) -> Result<Vec<SafeCall>> {
+        match self.operation {
+            CALL => Ok(vec![SafeCall {
+                to: self.to,
+                value: self.value,
+                data: self.data.clone(),
+            }]),
+            DELEGATE_CALL if self.to == MULTI_SEND_CALL_ONLY => unbundle(&self.data),
+            DELEGATE_CALL => bail!(
+                "The proposal delegatecalls {}, which could do anything with the Safe; \
+                 only MultiSendCallOnly ({MULTI_SEND_CALL_ONLY}) is allowed",
+                self.to
+            ),
+            operation => bail!("The proposal has unknown Safe operation {operation}"),
+        }
+    }
+
+    fn load(path: &Path) -> Result<Self> {
+        let json = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let proposal: Self = serde_json::from_str(&json)
+            .with_context(|| format!("{} is not a Safe proposal file", path.display()))?;
+        if proposal.hash() != proposal.safe_tx_hash {
+            bail!(
+                "{} was changed after it was proposed: its transaction no longer matches safe_tx_hash",
+                path.display()
+            );
+        }
+        if proposal.chain_id != config::chain_id() {
+            bail!(
+                "{} is for chain {}, but chain {} is selected; pass --chain",
+                path.display(),
+                proposal.chain_id,
+                config::chain_id()
+            );
+        }
+        proposal
+            .calls()
+            .with_context(|| format!("Refusing {}", path.display()))?;
+        Ok(proposal)
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        crate::output::write_atomic(path, &json)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Signs with `key`, replacing any earlier signature by the same owner.
+    fn sign(&mut self, key: &str) -> Result<Address> {
+        let signer = LocalSigner::from_str(key).context("Invalid private key")?;
+        let signature = signer
+            .sign_hash_sync(&self.safe_tx_hash)
+            .context("Failed to sign the Safe transaction")?;
+        self.signatures
+            .insert(signer.address(), Bytes::from(signature.as_bytes().to_vec()));
+        Ok(signer.address())
+    }
+
+    /// Signatures that recover to the address they are filed under, in the
+    /// ascending owner order Safe requires.
+    fn valid_signatures(&self) -> Vec<(Address, &Bytes)> {
+        self.signatures
+            .iter()
+            .filter(|(owner, signature)| {
+                Signature::from_raw(signature)
+                    .and_then(|s| s.recover_address_from_prehash(&self.safe_tx_hash))
+                    .is_ok_and(|signer| signer == **owner)
+            })
+            .map(|(owner, signature)| (*owner, signature))
+            .collect()
+    }
+}
+
+/// A call from a `--dry-run -o json` report.
+#[derive(Deserialize)]
+struct PlannedCall {
+    action: String,
+    /// Who the plan sends it from; it only works from the Safe.
+    #[serde(default)]
+    from: Option<Address>,
+    to: Address,
+    #[serde(default)]
+    value: U256,
+    calldata: Bytes,
+}
+
+fn read_plan(path: &Path) -> Result<Vec<PlannedCall>> {
+    let json = if path == Path::new("-") {
+        let mut json = String::new();
+        std::io::stdin()
+            .read_to_string(&mut json)
+            .context("Failed to read the plan from stdin")?;
+        json
+    } else {
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?
+    };
+    let calls: Vec<PlannedCall> = serde_json::from_str(&json)
+        .or_else(|_| serde_json::from_str(&json).map(|call| vec![call]))
+        .context("The plan must be the JSON a command prints with --dry-run -o json")?;
+    if calls.is_empty() {
+        bail!("The plan has no calls");
+    }
+    Ok(calls)
+}
+
+/// The Safe transaction for `calls`: the call itself when there is one, or a
+/// delegatecall to MultiSendCallOnly running them in order.
+fn bundle(calls: &[PlannedCall]) -> (Address, U256, Bytes, u8) {
+    if let [call] = calls {
+        return (call.to, call.value, call.calldata.clone(), CALL);
+    }
+    let mut packed = Vec::new();
+    for call in calls {
+        packed.push(CALL);
+        packed.extend_from_slice(call.to.as_slice());
+        packed.extend_from_slice(&call.value.to_be_bytes::<32>());
+        packed.extend_from_slice(&U256::from(call.calldata.len()).to_be_bytes::<32>());
+        packed.extend_from_slice(&call.calldata);
+    }
+    let data = IMultiSend::multiSendCall {
+        transactions: packed.into(),
+    }
+    .abi_encode();
+    (MULTI_SEND_CALL_ONLY, U256::ZERO, data.into(), DELEGATE_CALL)
+}
+
+/// One call a Safe transaction makes.
+struct SafeCall {
+    to: Address,
+    value: U256,
+    data: Bytes,
+}
+
+/// The calls in a MultiSend `data`, the reverse of [`bundle`]. Each must be a
+/// plain call, as MultiSendCallOnly itself insists.
+fn unbundle(data: &[u8]) -> Result<Vec<SafeCall>> {
+    let packed = IMultiSend::multiSendCall::abi_decode(data)
+        .context("The proposal's MultiSend data doesn't decode")?
+        .transactions;
+    let mut rest = &packed[..];
+    let mut calls = Vec::new();
+    while !rest.is_empty() {
+        ensure!(
+            rest.len() >= PACKED_HEADER,
+            "The proposal's MultiSend data is cut short"
+        );
+        let (header, tail) = rest.split_at(PACKED_HEADER);
+        ensure!(
+            header[0] == CALL,
+            "Call {} of the proposal's MultiSend is not a plain call",
+            calls.len() + 1
+        );
+        let len = usize::try_from(U256::from_be_slice(&header[53..]))
+            .ok()
+            .filter(|len| *len <= tail.len())
+            .context("The proposal's MultiSend data is cut short")?;
+        let (call_data, tail) = tail.split_at(len);
+        calls.push(SafeCall {
+            to: Address::from_slice(&header[1..21]),
+            value: U256::from_be_slice(&header[21..53]),
+            data: Bytes::copy_from_slice(call_data),
+        });
+        rest = tail;
+    }
+    Ok(calls)
+}
+
+/// What an owner is asked to sign: each call as the Safe will make it, with
+/// Polymarket's contracts named, then the proposer's own labels.
+fn sign_summary(proposal: &Proposal, calls: &[SafeCall]) -> Vec<String> {
+    let mut names = BTreeMap::new();
+    for neg_risk in [false, true] {
+        if let Some(c) = contract_config(proposal.chain_id, neg_risk) {
+            names.insert(c.collateral, "USDC");
+            names.insert(c.conditional_tokens, "Conditional Tokens");
+            names.insert(
+                c.exchange,
+                if neg_risk {
+                    "Neg Risk Exchange"
+                } else {
+                    "CTF Exchange"
+                },
+            );
+            if let Some(adapter) = c.neg_risk_adapter {
+                names.insert(adapter, "Neg Risk Adapter");
+            }
+        }
+    }
+    let mut summary = vec![format!(
+        "Sign Safe {} transaction {}, which makes {} call{}:",
+        proposal.safe,
+        proposal.nonce,
+        calls.len(),
+        if calls.len() == 1 { "" } else { "s" }
+    )];
+    for (i, call) in calls.iter().enumerate() {
+        let name = names
+            .get(&call.to)
+            .map_or_else(String::new, |name| format!(" ({name})"));
+        summary.push(format!("{}. to {}{name}", i + 1, call.to));
+        if !call.value.is_zero() {
+            summary.push(format!("   value {} wei", call.value));
+        }
+        summary.push(format!("   data {}", call.data));
+    }
+    summary.push("Described by the proposer as:".into());
+    summary.extend(proposal.actions.iter().map(|a| format!("- {a}")));
+    summary
+}
+
+/// `--safe`, or the Safe Polymarket derives for the configured wallet.
+fn safe_address(safe: Option<Address>, private_key: Option<&str>) -> Result<Address> {
+    if let Some(safe) = safe {
+        return Ok(safe);
+    }
+    let eoa = auth::resolve_address(private_key)?;
+    auth::derived_wallets(eoa)
+        .1
+        .context("No Safe can be derived on this chain; pass --safe")
+}
+
+pub async fn execute(
+    args: SafeArgs,
+    output: OutputFormat,
+    private_key: Option<&str>,
+    dry_run: bool,
+    confirm: bool,
+    gas: &GasArgs,
+) -> Result<()> {
+    match args.command {
+        SafeCommand::Info { safe } => info(safe_address(safe, private_key)?, &output).await,
+        SafeCommand::Propose {
+            plan,
+            out,
+            safe,
+            nonce,
+            no_sign,
+            force,
+        } => {
+            if !force && out.exists() {
+                bail!(
+                    "{} already exists. Use --force to overwrite.",
+                    out.display()
+                );
+            }
+            let safe = safe_address(safe, private_key)?;
+            let calls = read_plan(&plan)?;
+            if let Some(call) = calls
+                .iter()
+                .find(|c| c.from.is_some_and(|from| from != safe))
+            {
+                bail!(
+                    "'{}' in the plan is sent from {}, not the Safe {safe}; plan it again \
+                     with --signature-type gnosis-safe",
+                    call.action,
+                    call.from.unwrap_or_default()
+                );
+            }
+            let nonce = match nonce {
+                Some(nonce) => U256::from(nonce),
+                None => {
+                    let provider = auth::create_readonly_provider().await?;
+                    ISafe::new(safe, provider)
+                        .nonce()
+                        .call()
+                        .await
+                        .with_context(|| format!("Failed to read the nonce of Safe {safe}"))?
+                }
+            };
+            let (to, value, data, operation) = bundle(&calls);
+            let mut proposal = Proposal {
+                safe,
+                chain_id: config::chain_id(),
+                to,
+                value,
+                data,
+                operation,
+                nonce,
+                actions: calls.into_iter().map(|c| c.action).collect(),
+                safe_tx_hash: B256::ZERO,
+                signatures: BTreeMap::new(),
+            };
+            proposal.safe_tx_hash = proposal.hash();
+            if !no_sign {
+                proposal.sign(&config::signing_key(private_key)?)?;
+            }
+            proposal.save(&out)?;
+            print_proposal(&proposal, &out, proposal.valid_signatures().len(), &output)
+        }
+        SafeCommand::Sign { file } => {
+            let mut proposal = Proposal::load(&file)?;
+            let key = config::signing_key(private_key)?;
+            if confirm {
+                super::confirm_submit(&sign_summary(&proposal, &proposal.calls()?))?;
+            }
+            proposal.sign(&key)?;
+            proposal.save(&file)?;
+            print_proposal(&proposal, &file, proposal.valid_signatures().len(), &output)
+        }
+        SafeCommand::Execute { file } => {
+            let options = TxOptions {
+                private_key,
+                dry_run,
+                confirm,
+                gas,
+                wait: WaitArgs::default(),
+                simulate: true,
+            };
+            execute_proposal(&Proposal::load(&file)?, &options, &output).await
+        }
+    }
+}
+
+async fn info(safe: Address, output: &OutputFormat) -> Result<()> {
+    let provider = auth::create_readonly_provider().await?;
+    let contract = ISafe::new(safe, provider);
+    let owners =
+        contract.getOwners().call().await.with_context(|| {
+            format!("Failed to read Safe {safe}; is it deployed on this chain?")
+        })?;
+    let threshold = contract.getThreshold().call().await?;
+    let nonce = contract.nonce().call().await?;
+    let version = contract.VERSION().call().await.ok();
+    print_safe_info(safe, &owners, threshold, nonce, version.as_deref(), output)
+}
+
+async fn execute_proposal(
+    proposal: &Proposal,
+    options: &TxOptions<'_>,
+    output: &OutputFormat,
+) -> Result<()> {
+    let provider = auth::create_readonly_provider().await?;
+    let contract = ISafe::new(proposal.safe, provider);
+    let owners = contract
+        .getOwners()
+        .call()
+        .await
+        .with_context(|| format!("Failed to read Safe {}", proposal.safe))?;
+    let threshold = contract.getThreshold().call().await?;
+    let nonce = contract.nonce().call().await?;
+    if nonce != proposal.nonce {
+        bail!(
+            "The Safe is at nonce {nonce}, but this proposal is for nonce {}; propose it again",
+            proposal.nonce
+        );
+    }
+
+    let signatures: Vec<_> = proposal
+        .valid_signatures()
+        .into_iter()
+        .filter(|(owner, _)| owners.contains(owner))
+        .collect();
+    let needed = usize::try_from(threshold).unwrap_or(usize::MAX);
+    if signatures.len() < needed {
+        bail!(
+            "{} of {threshold} owner signatures collected; more owners need to run `polymarket safe sign`",
+            signatures.len()
+        );
+    }
+    let packed: Vec<u8> = signatures
+        .iter()
+        .take(needed)
+        .flat_map(|(_, signature)| signature.iter().copied())
+        .collect();
+
+    let call = PlannedTx {
+        action: format!("Safe transaction {}", proposal.nonce),
+        from: auth::resolve_address(options.private_key).ok(),
+        to: proposal.safe,
+        calldata: ISafe::execTransactionCall {
+            to: proposal.to,
+            value: proposal.value,
+            data: proposal.data.clone(),
+            operation: proposal.operation,
+            safeTxGas: U256::ZERO,
+            baseGas: U256::ZERO,
+            gasPrice: U256::ZERO,
+            gasToken: Address::ZERO,
+            refundReceiver: Address::ZERO,
+            signatures: packed.into(),
+        }
+        .abi_encode(),
+        value: U256::ZERO,
+    };
+    let mut summary = vec![format!("Execute Safe {} transaction:", proposal.safe)];
+    summary.extend(proposal.actions.iter().map(|a| format!("  {a}")));
+    let Some(gas) = tx::prepare(std::slice::from_ref(&call), &summary, options, output).await?
+    else {
+        return Ok(());
+    };
+    let provider = auth::create_provider(options.private_key).await?;
+    let sent = tx::send(&provider, &call, &gas[0], options.wait).await?;
+    print_executed(proposal, sent.hash, output)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::{b256, keccak256};
+
+    use super::*;
+
+    fn proposal() -> Proposal {
+        let mut proposal = Proposal {
+            safe: Address::repeat_byte(0x5a),
+            chain_id: polymarket_client_sdk::POLYGON,
+            to: Address::repeat_byte(0x11),
+            value: U256::ZERO,
+            data: Bytes::from_static(&[1, 2, 3]),
+            operation: CALL,
+            nonce: U256::from(7),
+            actions: vec!["split".into()],
+            safe_tx_hash: B256::ZERO,
+            signatures: BTreeMap::new(),
+        };
+        proposal.safe_tx_hash = proposal.hash();
+        proposal
+    }
+
+    #[test]
+    fn signs_the_struct_safe_contracts_hash() {
+        // DOMAIN_SEPARATOR_TYPEHASH from Safe 1.3.0.
+        let domain = eip712_domain! {
+            chain_id: 137,
+            verifying_contract: Address::repeat_byte(0x5a),
+        };
+        assert_eq!(
+            keccak256(domain.encode_type()),
+            b256!("0x47e79534a245952e8b16893a336b85a3d9ea9fa8c573f3d803afb92a79469218")
+        );
+        let message = proposal().message();
+        assert_eq!(
+            message.eip712_type_hash(),
+            keccak256(
+                "SafeTx(address to,uint256 value,bytes data,uint8 operation,uint256 safeTxGas,\
+                 uint256 baseGas,uint256 gasPrice,address gasToken,address refundReceiver,uint256 nonce)"
+            )
+        );
+    }
+
+    #[test]
+    fn only_matching_signatures_count() {
+        let mut proposal = proposal();
+        let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+        let owner = proposal.sign(key).unwrap();
+        assert_eq!(proposal.valid_signatures().len(), 1);
+        // A signature filed under the wrong owner is ignored.
+        let signature = proposal.signatures.remove(&owner).unwrap();
+        proposal
+            .signatures
+            .insert(Address::repeat_byte(0x22), signature);
+        assert!(proposal.valid_signatures().is_empty());
+    }
+
+    #[test]
+    fn bundles_several_calls_through_multisend() {
+        let call = |to: u8| PlannedCall {
+            action: String::new(),
+            from: None,
+            to: Address::repeat_byte(to),
+            value: U256::from(to),
+            calldata: Bytes::from_static(&[0xaa; 4]),
+        };
+        let (to, value, _, operation) = bundle(&[call(1)]);
+        assert_eq!(
+            (to, value, operation),
+            (Address::repeat_byte(1), U256::from(1), CALL)
+        );
+
+        let (to, value, data, operation) = bundle(&[call(1), call(2)]);
+        assert_eq!(value, U256::ZERO);
+        assert_eq!((to, operation), (MULTI_SEND_CALL_ONLY, DELEGATE_CALL));
+        let decoded = IMultiSend::multiSendCall::abi_decode(&data).unwrap();
+        // operation (1) + to (20) + value (32) + length (32) + data (4), twice
+        assert_eq!(decoded.transactions.len(), 2 * (1 + 20 + 32 + 32 + 4));
+        assert_eq!(
+            &decoded.transactions[1..21],
+            Address::repeat_byte(1).as_slice()
+        );
+        assert_eq!(
+            U256::from_be_slice(&decoded.transactions[21..53]),
+            U256::from(1)
+        );
+
+        let calls = unbundle(&data).unwrap();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[1].to, Address::repeat_byte(2));
+        assert_eq!(calls[1].value, U256::from(2));
+        assert_eq!(calls[1].data, Bytes::from_static(&[0xaa; 4]));
+        assert!(unbundle(&data[..data.len() - 40]).is_err());
+    }
+
+    #[test]
+    fn only_delegatecalls_to_multisend_are_signed() {
+        let mut proposal = proposal();
+        assert_eq!(proposal.calls().unwrap()[0].to, Address::repeat_byte(0x11));
+        proposal.operation = DELEGATE_CALL;
+        assert!(proposal.calls().is_err());
+        proposal.operation = 2;
+        assert!(proposal.calls().is_err());
+
+        let mut packed = vec![DELEGATE_CALL];
+        packed.extend_from_slice(Address::repeat_byte(0x33).as_slice());
+        packed.extend_from_slice(&[0; 64]);
+        proposal.operation = DELEGATE_CALL;
+        proposal.to = MULTI_SEND_CALL_ONLY;
+        proposal.data = IMultiSend::multiSendCall {
+            transactions: packed.into(),
+        }
+        .abi_encode()
+        .into();
+        assert!(proposal.calls().is_err());
+    }
+}
@@ -67,7 +67,9 @@ pub async fn execute(client: &gamma::Client, args: SeriesArgs, output: OutputFor
 
             match output {
                 OutputFormat::Table => print_series_table(&series),
-                OutputFormat::Json => print_json(&series)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&series)?
+                }
             }
         }
 
@@ -77,7 +79,9 @@ pub async fn execute(client: &gamma::Client, args: SeriesArgs, output: OutputFor
 
             match output {
                 OutputFormat::Table => print_series_detail(&series),
-                OutputFormat::Json => print_json(&series)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&series)?
+                }
             }
         }
     }
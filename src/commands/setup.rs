@@ -3,19 +3,22 @@ use std::io::{self, BufRead, Write};
 use std::str::FromStr;
 
 use anyhow::{Context, Result};
+use clap::Args;
 use polymarket_client_sdk::auth::{LocalSigner, Signer as _};
+use polymarket_client_sdk::derive_proxy_wallet;
 use polymarket_client_sdk::types::Address;
-use polymarket_client_sdk::{POLYGON, derive_proxy_wallet};
 
 use super::wallet::normalize_key;
 use crate::config;
+use crate::output::color;
 
 fn print_banner() {
     // #2E5CFF → RGB(46, 92, 255)
-    let b = "\x1b[38;2;46;92;255m";
-    let bold = "\x1b[1m";
-    let dim = "\x1b[2m";
-    let r = "\x1b[0m";
+    let (b, bold, dim, r) = if color::enabled() {
+        ("\x1b[38;2;46;92;255m", "\x1b[1m", "\x1b[2m", "\x1b[0m")
+    } else {
+        ("", "", "", "")
+    };
 
     println!();
 
@@ -77,7 +80,14 @@ fn step_header(n: u8, total: u8, label: &str) {
     println!("  {}", "─".repeat(label.len() + 6));
 }
 
-pub fn execute() -> Result<()> {
+#[derive(Args)]
+pub struct SetupArgs {
+    /// Show the deposit address as a QR code in the funding step
+    #[arg(long)]
+    pub qr: bool,
+}
+
+pub fn execute(args: &SetupArgs) -> Result<()> {
     print_banner();
 
     let total = 4;
@@ -85,17 +95,13 @@ pub fn execute() -> Result<()> {
     step_header(1, total, "Wallet");
 
     let address = if config::config_exists() {
-        let (key, source) = config::resolve_key(None);
-        if let Some(k) = &key
-            && let Ok(signer) = LocalSigner::from_str(k)
-        {
-            let addr = signer.address();
+        if let (Some(addr), source) = config::resolve_address(None) {
             println!("  ✓ Wallet already configured ({})", source.label());
             println!("    Address: {addr}");
             println!();
 
             if !prompt_yn("  Reconfigure wallet?", false)? {
-                finish_setup(addr)?;
+                finish_setup(addr, args.qr)?;
                 return Ok(());
             }
             println!();
@@ -107,7 +113,7 @@ pub fn execute() -> Result<()> {
 
     println!();
 
-    finish_setup(address)
+    finish_setup(address, args.qr)
 }
 
 fn setup_wallet() -> Result<Address> {
@@ -118,10 +124,10 @@ fn setup_wallet() -> Result<Address> {
         let normalized = normalize_key(&key);
         let signer = LocalSigner::from_str(&normalized)
             .context("Invalid private key")?
-            .with_chain_id(Some(POLYGON));
+            .with_chain_id(Some(config::chain_id()));
         (signer.address(), normalized)
     } else {
-        let signer = LocalSigner::random().with_chain_id(Some(POLYGON));
+        let signer = LocalSigner::random().with_chain_id(Some(config::chain_id()));
         let address = signer.address();
         let bytes = signer.credential().to_bytes();
         let mut hex = String::with_capacity(2 + bytes.len() * 2);
@@ -132,7 +138,12 @@ fn setup_wallet() -> Result<Address> {
         (address, hex)
     };
 
-    config::save_wallet(&key_hex, POLYGON, config::DEFAULT_SIGNATURE_TYPE)?;
+    config::save_wallet(
+        &key_hex,
+        config::DEFAULT_SIGNATURE_TYPE,
+        None,
+        config::KeyStore::Plain,
+    )?;
 
     if has_key {
         println!("  ✓ Wallet imported");
@@ -151,12 +162,12 @@ fn setup_wallet() -> Result<Address> {
     Ok(address)
 }
 
-fn finish_setup(address: Address) -> Result<()> {
+fn finish_setup(address: Address, qr: bool) -> Result<()> {
     let total = 4;
 
     step_header(2, total, "Proxy Wallet");
 
-    let proxy = derive_proxy_wallet(address, POLYGON);
+    let proxy = derive_proxy_wallet(address, config::chain_id());
     match proxy {
         Some(proxy) => {
             println!("  ✓ Proxy wallet derived");
@@ -175,8 +186,16 @@ fn finish_setup(address: Address) -> Result<()> {
 
     let deposit_addr = proxy.unwrap_or(address);
     println!("  ○ Deposit USDC to your wallet to start trading");
+    if qr {
+        println!();
+        for line in super::wallet::deposit_qr(deposit_addr).lines() {
+            println!("    {line}");
+        }
+        println!();
+    }
     println!("    Run: polymarket bridge deposit {deposit_addr}");
     println!("    Or transfer USDC directly on Polygon");
+    println!("    Check it arrived: polymarket wallet balance");
 
     println!();
 
@@ -50,7 +50,9 @@ pub async fn execute(client: &gamma::Client, args: SportsArgs, output: OutputFor
 
             match output {
                 OutputFormat::Table => print_sports_table(&sports),
-                OutputFormat::Json => print_json(&sports)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&sports)?
+                }
             }
         }
 
@@ -59,7 +61,9 @@ pub async fn execute(client: &gamma::Client, args: SportsArgs, output: OutputFor
 
             match output {
                 OutputFormat::Table => print_sport_types(&types),
-                OutputFormat::Json => print_json(&types)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&types)?
+                }
             }
         }
 
@@ -82,7 +86,9 @@ pub async fn execute(client: &gamma::Client, args: SportsArgs, output: OutputFor
 
             match output {
                 OutputFormat::Table => print_teams_table(&teams),
-                OutputFormat::Json => print_json(&teams)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&teams)?
+                }
             }
         }
     }
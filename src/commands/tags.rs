@@ -6,6 +6,7 @@ use polymarket_client_sdk::gamma::{
         RelatedTagsByIdRequest, RelatedTagsBySlugRequest, TagByIdRequest, TagBySlugRequest,
         TagsRequest,
     },
+    types::response::Tag,
 };
 
 use super::is_numeric_id;
@@ -33,6 +34,10 @@ pub enum TagsCommand {
         /// Sort ascending instead of descending
         #[arg(long)]
         ascending: bool,
+
+        /// Only tags whose label or slug contains this text (e.g. "fed")
+        #[arg(long, conflicts_with = "offset")]
+        search: Option<String>,
     },
 
     /// Get a single tag by ID or slug
@@ -62,24 +67,67 @@ pub enum TagsCommand {
     },
 }
 
+/// Gamma has no text filter for tags, so `--search` pages through all of them.
+const SEARCH_PAGE_SIZE: i32 = 500;
+
+fn matches_search(tag: &Tag, needle: &str) -> bool {
+    let needle = needle.to_lowercase();
+    [tag.label.as_deref(), tag.slug.as_deref()]
+        .into_iter()
+        .flatten()
+        .any(|text| text.to_lowercase().contains(&needle))
+}
+
+async fn search_tags(
+    client: &gamma::Client,
+    needle: &str,
+    limit: i32,
+    ascending: bool,
+) -> Result<Vec<Tag>> {
+    let mut found = Vec::new();
+    let mut offset = 0;
+    loop {
+        let request = TagsRequest::builder()
+            .limit(SEARCH_PAGE_SIZE)
+            .offset(offset)
+            .maybe_ascending(if ascending { Some(true) } else { None })
+            .build();
+        let page = client.tags(&request).await?;
+        let done = page.len() < usize::try_from(SEARCH_PAGE_SIZE)?;
+        found.extend(page.into_iter().filter(|t| matches_search(t, needle)));
+        if done || found.len() >= usize::try_from(limit)? {
+            break;
+        }
+        offset += SEARCH_PAGE_SIZE;
+    }
+    found.truncate(usize::try_from(limit)?);
+    Ok(found)
+}
+
 pub async fn execute(client: &gamma::Client, args: TagsArgs, output: OutputFormat) -> Result<()> {
     match args.command {
         TagsCommand::List {
             limit,
             offset,
             ascending,
+            search,
         } => {
-            let request = TagsRequest::builder()
-                .limit(limit)
-                .maybe_offset(offset)
-                .maybe_ascending(if ascending { Some(true) } else { None })
-                .build();
-
-            let tags = client.tags(&request).await?;
+            let tags = if let Some(needle) = search {
+                search_tags(client, &needle, limit, ascending).await?
+            } else {
+                let request = TagsRequest::builder()
+                    .limit(limit)
+                    .maybe_offset(offset)
+                    .maybe_ascending(if ascending { Some(true) } else { None })
+                    .build();
+                client.tags(&request).await?
+            };
 
             match output {
                 OutputFormat::Table => print_tags_table(&tags),
-                OutputFormat::Json => print_json(&tags)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&tags)?
+                }
             }
         }
 
@@ -95,7 +143,7 @@ pub async fn execute(client: &gamma::Client, args: TagsArgs, output: OutputForma
 
             match output {
                 OutputFormat::Table => print_tag_detail(&tag),
-                OutputFormat::Json => print_json(&tag)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => print_json(&tag)?,
             }
         }
 
@@ -117,7 +165,9 @@ pub async fn execute(client: &gamma::Client, args: TagsArgs, output: OutputForma
 
             match output {
                 OutputFormat::Table => print_related_tags_table(&related),
-                OutputFormat::Json => print_json(&related)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&related)?
+                }
             }
         }
 
@@ -139,10 +189,29 @@ pub async fn execute(client: &gamma::Client, args: TagsArgs, output: OutputForma
 
             match output {
                 OutputFormat::Table => print_tags_table(&tags),
-                OutputFormat::Json => print_json(&tags)?,
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    print_json(&tags)?
+                }
             }
         }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(label: &str, slug: &str) -> Tag {
+        serde_json::from_value(serde_json::json!({"id": "1", "label": label, "slug": slug}))
+            .unwrap()
+    }
+
+    #[test]
+    fn search_matches_label_or_slug_case_insensitively() {
+        assert!(matches_search(&tag("Fed Rates", "fed-rates"), "FED"));
+        assert!(matches_search(&tag("Interest", "fed-rates"), "rates"));
+        assert!(!matches_search(&tag("Crypto", "crypto"), "fed"));
+    }
+}
@@ -0,0 +1,222 @@
+use alloy::consensus::Transaction;
+use alloy::network::TransactionResponse as _;
+use alloy::providers::Provider;
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand};
+use polymarket_client_sdk::types::{Address, B256, U256};
+
+use crate::auth;
+use crate::output::OutputFormat;
+use crate::output::ctf as ctf_output;
+use crate::output::dry_run::{PlannedTx, print_gas_estimates, print_planned_txs};
+use crate::output::tx::{PendingTx, print_pending};
+use crate::tx::{self, GasArgs, GasEstimate, TxOptions, WaitArgs, format_gwei, format_pol};
+
+/// Gas of a plain transfer, which is all a cancel sends.
+const TRANSFER_GAS: u64 = 21_000;
+
+#[derive(Args)]
+pub struct TxArgs {
+    #[command(subcommand)]
+    pub command: TxCommand,
+}
+
+#[derive(Subcommand)]
+pub enum TxCommand {
+    /// List transactions sent by this CLI that are still waiting to be mined
+    Pending {
+        /// Wallet address to check (defaults to configured wallet)
+        #[arg(long)]
+        address: Option<String>,
+    },
+    /// Rebroadcast a pending transaction with higher fees
+    Speedup {
+        /// Hash of the pending transaction
+        hash: String,
+    },
+    /// Replace a pending transaction with an empty transfer to yourself
+    Cancel {
+        /// Hash of the pending transaction
+        hash: String,
+    },
+}
+
+impl TxCommand {
+    /// Only listing is read-only.
+    pub fn is_read_only(&self) -> bool {
+        matches!(self, Self::Pending { .. })
+    }
+}
+
+fn parse_tx_hash(s: &str) -> Result<B256> {
+    s.trim()
+        .parse()
+        .map_err(|_| anyhow::anyhow!("Invalid transaction hash: must be a 0x-prefixed 32-byte hex"))
+}
+
+/// Max fee and tip per gas; a legacy transaction pays its gas price for both.
+fn fees(tx: &impl Transaction) -> (u128, u128) {
+    let max_fee = tx.max_fee_per_gas();
+    (max_fee, tx.max_priority_fee_per_gas().unwrap_or(max_fee))
+}
+
+pub async fn execute(
+    args: TxArgs,
+    output: OutputFormat,
+    private_key: Option<&str>,
+    dry_run: bool,
+    confirm: bool,
+    gas: &GasArgs,
+) -> Result<()> {
+    let options = TxOptions {
+        private_key,
+        dry_run,
+        confirm,
+        gas,
+        wait: WaitArgs::default(),
+        simulate: false,
+    };
+    match args.command {
+        TxCommand::Pending { address } => {
+            let owner = match address {
+                Some(address) => super::parse_address(&address)?,
+                None => auth::resolve_address(private_key)?,
+            };
+            pending(owner, &output).await
+        }
+        TxCommand::Speedup { hash } => {
+            replace(parse_tx_hash(&hash)?, false, &options, &output).await
+        }
+        TxCommand::Cancel { hash } => replace(parse_tx_hash(&hash)?, true, &options, &output).await,
+    }
+}
+
+async fn pending(owner: Address, output: &OutputFormat) -> Result<()> {
+    let provider = auth::create_readonly_provider().await?;
+    let confirmed = provider.get_transaction_count(owner).latest().await?;
+    let queued = provider.get_transaction_count(owner).pending().await?;
+
+    let mut pending = Vec::new();
+    for sent in tx::load_sent().into_iter().rev() {
+        // Mined, replaced, and dropped transactions are all done with.
+        let Some(found) = provider.get_transaction_by_hash(sent.hash).await? else {
+            continue;
+        };
+        if found.block_number().is_some() || found.from() != owner {
+            continue;
+        }
+        pending.push(PendingTx {
+            hash: sent.hash,
+            nonce: found.nonce(),
+            action: sent.action,
+            max_fee_per_gas: fees(&found).0,
+            max_priority_fee_per_gas: fees(&found).1,
+            sent_at: sent.sent_at,
+        });
+    }
+    pending.sort_by_key(|p| p.nonce);
+    let unknown = queued
+        .saturating_sub(confirmed)
+        .saturating_sub(pending.len() as u64);
+    print_pending(owner, confirmed, &pending, unknown, output)
+}
+
+/// Speeds up or cancels the pending transaction `hash` by sending another at
+/// its nonce with higher fees.
+async fn replace(
+    hash: B256,
+    cancel: bool,
+    options: &TxOptions<'_>,
+    output: &OutputFormat,
+) -> Result<()> {
+    let owner = auth::resolve_address(options.private_key)?;
+    let provider = auth::create_readonly_provider().await?;
+    let old = provider
+        .get_transaction_by_hash(hash)
+        .await?
+        .with_context(|| {
+            format!("Transaction {hash} is not known to the node; it may have been dropped")
+        })?;
+    if let Some(block) = old.block_number() {
+        anyhow::bail!("Transaction {hash} was already mined in block {block}");
+    }
+    anyhow::ensure!(
+        old.from() == owner,
+        "Transaction {hash} was sent by {}, not by {owner}",
+        old.from()
+    );
+
+    let nonce = old.nonce();
+    let (tx, gas_limit) = if cancel {
+        let tx = PlannedTx {
+            action: format!("cancel nonce {nonce}"),
+            from: Some(owner),
+            to: owner,
+            calldata: Vec::new(),
+            value: U256::ZERO,
+        };
+        (tx, TRANSFER_GAS)
+    } else {
+        let tx = PlannedTx {
+            action: format!("speed up nonce {nonce}"),
+            from: Some(owner),
+            to: old
+                .to()
+                .context("Contract deployments can't be sped up here")?,
+            calldata: old.input().to_vec(),
+            value: old.value(),
+        };
+        (tx, old.gas_limit())
+    };
+    if options.dry_run {
+        return print_planned_txs(std::slice::from_ref(&tx), output);
+    }
+
+    options.gas.validate()?;
+    let estimated = provider
+        .estimate_eip1559_fees()
+        .await
+        .context("Failed to estimate gas fees")?;
+    let old_fees = fees(&old);
+    let (max_fee_per_gas, max_priority_fee_per_gas) = options.gas.replacement_fees(
+        old_fees,
+        (
+            estimated.max_fee_per_gas,
+            estimated.max_priority_fee_per_gas,
+        ),
+    )?;
+    let gas = GasEstimate {
+        gas_limit: options.gas.gas_limit.unwrap_or(gas_limit),
+        max_fee_per_gas,
+        max_priority_fee_per_gas,
+    };
+    if options.gas.estimate_only {
+        return print_gas_estimates(std::slice::from_ref(&tx), &[gas], output);
+    }
+    if options.confirm {
+        super::confirm_submit(&[
+            if cancel {
+                format!("Cancel {hash} (nonce {nonce}) with an empty transfer to yourself")
+            } else {
+                format!("Resend {hash} (nonce {nonce}) with higher fees")
+            },
+            format!(
+                "Max fee {} gwei (was {}), tip {} gwei (was {})",
+                format_gwei(gas.max_fee_per_gas),
+                format_gwei(old_fees.0),
+                format_gwei(gas.max_priority_fee_per_gas),
+                format_gwei(old_fees.1)
+            ),
+            format!("Gas: up to {} POL", format_pol(gas.max_cost())),
+        ])?;
+    }
+
+    let wallet = auth::create_provider(options.private_key).await?;
+    let sent = tx::replace(&wallet, &tx, nonce, &gas, options.wait).await?;
+    ctf_output::print_tx_result(
+        if cancel { "cancel" } else { "speedup" },
+        sent.hash,
+        sent.block,
+        output,
+    )
+}
@@ -1,117 +1,177 @@
 use std::env;
 use std::fs;
-use std::process::Command;
+use std::io::{self, Cursor, Read as _};
+use std::path::Path;
 
-use anyhow::{Context, bail};
+use alloy::hex;
+use alloy::transports::http::reqwest;
+use anyhow::{Context, Result, bail};
+use aws_lc_rs::digest::{SHA256, digest};
+use clap::Args;
+use flate2::read::GzDecoder;
+use minisign_verify::{PublicKey, Signature};
 
 const REPO: &str = "Polymarket/polymarket-cli";
 const BINARY: &str = "polymarket";
 
-pub fn execute() -> anyhow::Result<()> {
+/// The minisign public key that signs each release's `checksums.txt`; its
+/// secret half is the release workflow's `MINISIGN_SECRET_KEY`.
+const RELEASE_PUBLIC_KEY: &str = "RWS1faZ04Uzbnq/XTEqhZqeqR4gf4/DpKLjidViqTMsdAFzxJBbCWuN2";
+
+#[derive(Args)]
+pub struct UpgradeArgs {
+    /// Print the current and latest versions, exiting non-zero if there is a newer one
+    #[arg(long, conflicts_with = "version")]
+    pub check: bool,
+
+    /// Install this release instead of the latest, e.g. v0.1.4; older releases are allowed
+    #[arg(long, value_name = "TAG")]
+    pub version: Option<String>,
+
+    /// Install without checking the release signature, for releases published before they were signed
+    #[arg(long, conflicts_with = "check")]
+    pub insecure_skip_signature: bool,
+}
+
+pub async fn execute(args: &UpgradeArgs) -> Result<()> {
     let current_version = env!("CARGO_PKG_VERSION");
     println!("Current version: v{current_version}");
-    println!("Checking for updates...");
 
-    let latest_tag = get_latest_tag()?;
-    let latest_version = latest_tag.trim_start_matches('v');
+    let http = reqwest::Client::builder()
+        // GitHub's API rejects requests without a User-Agent.
+        .user_agent(format!("{BINARY}/{current_version}"))
+        .build()
+        .context("Failed to set up the HTTP client")?;
+
+    if let Some(version) = &args.version {
+        let tag = get_release_tag(&http, Some(version)).await?;
+        if tag.trim_start_matches('v') == current_version {
+            println!("Already on {tag}.");
+            return Ok(());
+        }
+        return install(&http, &tag, args.insecure_skip_signature).await;
+    }
 
-    if latest_version == current_version {
+    println!("Checking for updates...");
+    let latest_tag = get_release_tag(&http, None).await?;
+    let outdated = is_newer(&latest_tag, current_version);
+
+    if args.check {
+        println!("Latest version:  {latest_tag}");
+        if outdated {
+            bail!("{latest_tag} is available; run `polymarket upgrade` to install it");
+        }
+        println!("Up to date.");
+        return Ok(());
+    }
+
+    if !outdated {
         println!("Already up to date.");
         return Ok(());
     }
 
     println!("New version available: {latest_tag}");
+    install(&http, &latest_tag, args.insecure_skip_signature).await
+}
 
+/// Downloads release `tag`, verifies it, and swaps it in for the running
+/// binary. `skip_signature` checks only the checksum.
+async fn install(http: &reqwest::Client, tag: &str, skip_signature: bool) -> Result<()> {
     let target = detect_target()?;
-    let url = format!(
-        "https://github.com/{REPO}/releases/download/{latest_tag}/{BINARY}-{latest_tag}-{target}.tar.gz"
-    );
+    let release_url = format!("https://github.com/{REPO}/releases/download/{tag}");
+    let archive_name = format!("{BINARY}-{tag}-{target}.{}", archive_extension(target));
 
     let current_exe = env::current_exe().context("Failed to determine current executable path")?;
 
-    let tmpdir = tempdir()?;
-    let tarball = format!("{tmpdir}/{BINARY}.tar.gz");
-
-    let tarball_name = format!("{BINARY}-{latest_tag}-{target}.tar.gz");
-    let checksums_url =
-        format!("https://github.com/{REPO}/releases/download/{latest_tag}/checksums.txt");
+    println!("Downloading {tag} ({target})...");
 
-    println!("Downloading {latest_tag} ({target})...");
-
-    let status = Command::new("curl")
-        .args(["-sSfL", "-o", &tarball, &url])
-        .status()
-        .context("Failed to run curl")?;
-    if !status.success() {
-        bail!("Download failed (HTTP error)");
-    }
-
-    let checksums_file = format!("{tmpdir}/checksums.txt");
-    let status = Command::new("curl")
-        .args(["-sSfL", "-o", &checksums_file, &checksums_url])
-        .status()
-        .context("Failed to download checksums")?;
-    if !status.success() {
-        bail!("Failed to download checksums.txt — cannot verify integrity");
+    let archive = download(http, &format!("{release_url}/{archive_name}"))
+        .await
+        .context("Download failed")?;
+    let checksums = download(http, &format!("{release_url}/checksums.txt"))
+        .await
+        .context("Failed to download checksums.txt — cannot verify integrity")?;
+    if skip_signature {
+        eprintln!("Warning: not checking the release signature, so only the checksum is verified");
+    } else {
+        let signature = download(http, &format!("{release_url}/checksums.txt.minisig"))
+            .await
+            .context(
+                "Failed to download checksums.txt.minisig — cannot verify the release \
+                 (releases from before signing began need --insecure-skip-signature)",
+            )?;
+        let signature =
+            String::from_utf8(signature).context("checksums.txt.minisig is not text")?;
+        verify_signature(&checksums, &signature, RELEASE_PUBLIC_KEY)?;
     }
+    let checksums = String::from_utf8(checksums).context("checksums.txt is not text")?;
 
-    verify_checksum(&tarball, &checksums_file, &tarball_name)?;
+    verify_checksum(&archive, &checksums, &archive_name)?;
 
-    let status = Command::new("tar")
-        .args(["xzf", &tarball, "-C", &tmpdir])
-        .status()
-        .context("Failed to extract archive")?;
-    if !status.success() {
-        bail!("Failed to extract archive");
-    }
+    let new_binary = extract_binary(&archive, target)?;
+    replace_exe(&current_exe, &new_binary)?;
 
-    let new_binary = format!("{tmpdir}/{BINARY}");
+    println!("Updated to {tag}");
+    Ok(())
+}
 
-    // Replace the current binary
-    let exe_path = current_exe.to_str().context("Non-UTF8 executable path")?;
-    let backup = format!("{exe_path}.bak");
+async fn download(http: &reqwest::Client, url: &str) -> Result<Vec<u8>> {
+    let bytes = http
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .bytes()
+        .await?;
+    Ok(bytes.to_vec())
+}
 
-    // Move current binary to backup, move new binary in, then remove backup
-    fs::rename(exe_path, &backup)
-        .or_else(|_| sudo_mv(exe_path, &backup))
-        .context("Failed to replace binary (try running with sudo)")?;
+/// `1.2.3` from `v1.2.3`, ignoring any pre-release or build suffix, or
+/// `None` when it isn't a version.
+fn parse_version(version: &str) -> Option<Vec<u64>> {
+    let version = version.trim_start_matches('v');
+    let core = version.split(['-', '+']).next()?;
+    core.split('.').map(|part| part.parse().ok()).collect()
+}
 
-    if let Err(e) = fs::rename(&new_binary, exe_path).or_else(|_| sudo_mv(&new_binary, exe_path)) {
-        // Restore backup on failure
-        let _ = fs::rename(&backup, exe_path);
-        return Err(e).context("Failed to install new binary");
+/// Whether release `tag` is newer than `current`. Versions that don't parse
+/// are compared for equality, so an odd tag still offers an upgrade.
+fn is_newer(tag: &str, current: &str) -> bool {
+    match (parse_version(tag), parse_version(current)) {
+        (Some(tag), Some(current)) => tag > current,
+        _ => tag.trim_start_matches('v') != current,
     }
-
-    // Set executable permission
-    #[cfg(unix)]
-    {
-        use std::os::unix::fs::PermissionsExt;
-        let _ = fs::set_permissions(exe_path, fs::Permissions::from_mode(0o755));
-    }
-
-    let _ = fs::remove_file(&backup);
-    let _ = fs::remove_dir_all(&tmpdir);
-
-    println!("Updated to {latest_tag}");
-    Ok(())
 }
 
-fn get_latest_tag() -> anyhow::Result<String> {
-    let output = Command::new("curl")
-        .args([
-            "-sSf",
-            &format!("https://api.github.com/repos/{REPO}/releases/latest"),
-        ])
-        .output()
+/// The tag of release `version`, checking that it exists, or of the latest
+/// release.
+async fn get_release_tag(http: &reqwest::Client, version: Option<&str>) -> Result<String> {
+    let url = match version {
+        Some(version) => {
+            if parse_version(version).is_none() {
+                bail!("Invalid version: {version} (expected e.g. v0.1.4)");
+            }
+            let tag = format!("v{}", version.trim_start_matches('v'));
+            format!("https://api.github.com/repos/{REPO}/releases/tags/{tag}")
+        }
+        None => format!("https://api.github.com/repos/{REPO}/releases/latest"),
+    };
+    let response = http
+        .get(url)
+        .send()
+        .await
         .context("Failed to check for latest release")?;
-
-    if !output.status.success() {
-        bail!("Failed to fetch latest release info from GitHub");
+    if let Some(version) = version
+        && response.status() == reqwest::StatusCode::NOT_FOUND
+    {
+        bail!("No release {version} of {REPO}");
     }
-
-    let body = String::from_utf8_lossy(&output.stdout);
-    let json: serde_json::Value =
-        serde_json::from_str(&body).context("Failed to parse GitHub API response")?;
+    let json: serde_json::Value = response
+        .error_for_status()
+        .context("Failed to fetch release info from GitHub")?
+        .json()
+        .await
+        .context("Failed to parse GitHub API response")?;
 
     json["tag_name"]
         .as_str()
@@ -119,7 +179,7 @@ fn get_latest_tag() -> anyhow::Result<String> {
         .context("No tag_name in release response")
 }
 
-fn detect_target() -> anyhow::Result<&'static str> {
+fn detect_target() -> Result<&'static str> {
     let os = std::env::consts::OS;
     let arch = std::env::consts::ARCH;
 
@@ -128,59 +188,51 @@ fn detect_target() -> anyhow::Result<&'static str> {
         ("macos", "aarch64") => Ok("aarch64-apple-darwin"),
         ("linux", "x86_64") => Ok("x86_64-unknown-linux-gnu"),
         ("linux", "aarch64") => Ok("aarch64-unknown-linux-gnu"),
+        ("windows", "x86_64") => Ok("x86_64-pc-windows-msvc"),
+        ("windows", "aarch64") => Ok("aarch64-pc-windows-msvc"),
         _ => bail!("Unsupported platform: {os}/{arch}"),
     }
 }
 
-fn tempdir() -> anyhow::Result<String> {
-    let output = Command::new("mktemp")
-        .args(["-d"])
-        .output()
-        .context("Failed to create temp directory")?;
-    if !output.status.success() {
-        bail!("mktemp failed");
+/// Checks that `checksums` carries a minisign `signature` by `public_key`,
+/// so a release can't be swapped for one whose checksums match only itself.
+fn verify_signature(checksums: &[u8], signature: &str, public_key: &str) -> Result<()> {
+    let public_key =
+        PublicKey::from_base64(public_key.trim()).context("Invalid release signing key")?;
+    let signature = Signature::decode(signature).context("Invalid checksums.txt.minisig")?;
+    if let Err(e) = public_key.verify(checksums, &signature, false) {
+        bail!(
+            "Signature check failed: {e}\n\nchecksums.txt was not signed with the release key. Aborting."
+        );
     }
-    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
-}
 
-fn verify_checksum(
-    file_path: &str,
-    checksums_file: &str,
-    expected_name: &str,
-) -> anyhow::Result<()> {
-    let checksums = fs::read_to_string(checksums_file).context("Failed to read checksums.txt")?;
+    println!("Signature verified.");
+    Ok(())
+}
 
-    let expected_hash = checksums
+/// The SHA-256 listed for `expected_name` in a `sha256sum`-style file.
+fn expected_checksum(checksums: &str, expected_name: &str) -> Result<String> {
+    checksums
         .lines()
         .find_map(|line| {
             let mut parts = line.split_whitespace();
             let hash = parts.next()?;
-            let name = parts.next()?;
+            // `sha256sum -b` marks binary files with a leading `*`.
+            let name = parts.next()?.trim_start_matches('*');
             if name == expected_name || name.trim_start_matches("./") == expected_name {
-                Some(hash.to_string())
+                Some(hash.to_ascii_lowercase())
             } else {
                 None
             }
         })
         .context(format!(
             "No checksum found for {expected_name} in checksums.txt"
-        ))?;
-
-    let output = Command::new("shasum")
-        .args(["-a", "256", file_path])
-        .output()
-        .or_else(|_| Command::new("sha256sum").arg(file_path).output())
-        .context("Failed to compute SHA256 (need shasum or sha256sum)")?;
-
-    if !output.status.success() {
-        bail!("Failed to compute SHA256 of downloaded file");
-    }
+        ))
+}
 
-    let actual_hash = String::from_utf8_lossy(&output.stdout)
-        .split_whitespace()
-        .next()
-        .unwrap_or("")
-        .to_string();
+fn verify_checksum(file: &[u8], checksums: &str, expected_name: &str) -> Result<()> {
+    let expected_hash = expected_checksum(checksums, expected_name)?;
+    let actual_hash = hex::encode(digest(&SHA256, file));
 
     if actual_hash != expected_hash {
         bail!(
@@ -192,28 +244,230 @@ fn verify_checksum(
     Ok(())
 }
 
-fn sudo_mv(from: &str, to: &str) -> std::io::Result<()> {
-    let status = Command::new("sudo").args(["mv", from, to]).status()?;
-    if status.success() {
-        Ok(())
+fn is_windows(target: &str) -> bool {
+    target.contains("-windows-")
+}
+
+/// Windows releases are zipped; the rest are gzipped tarballs.
+fn archive_extension(target: &str) -> &'static str {
+    if is_windows(target) { "zip" } else { "tar.gz" }
+}
+
+/// The binary for `target` from its release archive, wherever it sits in
+/// the archive.
+fn extract_binary(archive: &[u8], target: &str) -> Result<Vec<u8>> {
+    let (name, binary) = if is_windows(target) {
+        let name = format!("{BINARY}.exe");
+        let binary = extract_from_zip(archive, &name);
+        (name, binary)
     } else {
-        Err(std::io::Error::new(
-            std::io::ErrorKind::PermissionDenied,
-            "sudo mv failed",
-        ))
+        (BINARY.to_string(), extract_from_tar_gz(archive, BINARY))
+    };
+    binary
+        .context("Failed to extract archive")?
+        .with_context(|| format!("No {name} binary in the release archive"))
+}
+
+fn extract_from_tar_gz(archive: &[u8], name: &str) -> Result<Option<Vec<u8>>> {
+    let mut tar = tar::Archive::new(GzDecoder::new(archive));
+    for entry in tar.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?;
+        if entry.header().entry_type().is_file()
+            && path.file_name().is_some_and(|file| file == name)
+        {
+            let mut binary = Vec::new();
+            entry.read_to_end(&mut binary)?;
+            return Ok(Some(binary));
+        }
+    }
+    Ok(None)
+}
+
+fn extract_from_zip(archive: &[u8], name: &str) -> Result<Option<Vec<u8>>> {
+    let mut zip = zip::ZipArchive::new(Cursor::new(archive))?;
+    for i in 0..zip.len() {
+        let mut entry = zip.by_index(i)?;
+        let matches = entry
+            .enclosed_name()
+            .is_some_and(|path| path.file_name().is_some_and(|file| file == name));
+        if entry.is_file() && matches {
+            let mut binary = Vec::new();
+            entry.read_to_end(&mut binary)?;
+            return Ok(Some(binary));
+        }
+    }
+    Ok(None)
+}
+
+/// Swaps `new_binary` in for the executable at `exe`. The new binary is
+/// written next to it first, so the final rename is atomic and a failure
+/// leaves the current binary in place.
+fn replace_exe(exe: &Path, new_binary: &[u8]) -> Result<()> {
+    let dir = exe
+        .parent()
+        .context("Failed to determine the executable's directory")?;
+    let staged = dir.join(format!(".{BINARY}.new"));
+    let no_access = || {
+        format!(
+            "Failed to replace binary in {} (try running with sudo)",
+            dir.display()
+        )
+    };
+
+    fs::write(&staged, new_binary).with_context(no_access)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let _ = fs::set_permissions(&staged, fs::Permissions::from_mode(0o755));
+    }
+
+    if let Err(e) = swap_exe(&staged, exe) {
+        let _ = fs::remove_file(&staged);
+        return Err(e).with_context(no_access);
+    }
+    Ok(())
+}
+
+#[cfg(not(windows))]
+fn swap_exe(staged: &Path, exe: &Path) -> io::Result<()> {
+    fs::rename(staged, exe)
+}
+
+/// Windows won't overwrite a running executable but will rename it, so the
+/// current binary is moved aside first. It stays locked until this process
+/// exits, so the next upgrade deletes it.
+#[cfg(windows)]
+fn swap_exe(staged: &Path, exe: &Path) -> io::Result<()> {
+    let old = exe.with_file_name(format!(".{BINARY}.old.exe"));
+    let _ = fs::remove_file(&old);
+    fs::rename(exe, &old)?;
+    if let Err(e) = fs::rename(staged, exe) {
+        let _ = fs::rename(&old, exe);
+        return Err(e);
     }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
+    use std::io::Write as _;
+
+    use flate2::Compression;
+    use flate2::write::GzEncoder;
+
     use super::*;
 
+    fn release_archive(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut tar = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::fast()));
+        for (path, contents) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o755);
+            header.set_cksum();
+            tar.append_data(&mut header, path, *contents).unwrap();
+        }
+        tar.into_inner().unwrap().finish().unwrap()
+    }
+
     #[test]
     fn detect_target_returns_valid_triple() {
         let target = detect_target().unwrap();
         assert!(
-            target.contains("apple-darwin") || target.contains("unknown-linux"),
+            target.contains("apple-darwin")
+                || target.contains("unknown-linux")
+                || target.contains("pc-windows"),
             "unexpected target: {target}"
         );
     }
+
+    #[test]
+    fn only_newer_releases_are_upgrades() {
+        assert!(is_newer("v0.2.0", "0.1.4"));
+        assert!(is_newer("v0.1.10", "0.1.9"));
+        assert!(!is_newer("v0.1.4", "0.1.4"));
+        assert!(!is_newer("v0.1.3", "0.1.4"));
+        assert!(!is_newer("v0.1.4-rc.1", "0.1.4"));
+        assert!(is_newer("nightly", "0.1.4"));
+        assert_eq!(parse_version("v1.2.3"), Some(vec![1, 2, 3]));
+        assert_eq!(parse_version("1.x"), None);
+    }
+
+    /// A throwaway key and its signature over a one-line `checksums.txt`.
+    const TEST_PUBLIC_KEY: &str = "RWQBAgMEBQYHCAOhB7/zzhC+HXDdGOdLwJln5NYwm6UNXx3chmQSVTG4";
+    const TEST_CHECKSUMS: &str = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824  polymarket-v0.1.5-x86_64-unknown-linux-gnu.tar.gz\n";
+    const TEST_SIGNATURE: &str = "untrusted comment: signature from minisign secret key
+RUQBAgMEBQYHCDuhzuVAx7eXQoXyruleUkjKJoOmsi+CPSHncaQMkd2GcCSgN/3APWwkKsuve1mpk8WlXLAq+haf1kUYE6RqNQA=
+trusted comment: timestamp:1760486400\tfile:checksums.txt\tprehashed
+p7XMg2TqsXW/1Y9dFByqPPGPL+EPUZ2WLS+CCADk5ohmo7Z3hdCdcIGIdX+H4LHttsZRuPY/btheeal34fuUDQ==
+";
+
+    #[test]
+    fn checksums_must_be_signed_with_the_release_key() {
+        let checksums = TEST_CHECKSUMS.as_bytes();
+        assert!(verify_signature(checksums, TEST_SIGNATURE, TEST_PUBLIC_KEY).is_ok());
+
+        let tampered = TEST_CHECKSUMS.replace("2cf2", "0000");
+        assert!(verify_signature(tampered.as_bytes(), TEST_SIGNATURE, TEST_PUBLIC_KEY).is_err());
+
+        let edited_comment = TEST_SIGNATURE.replace("1760486400", "1760486401");
+        assert!(verify_signature(checksums, &edited_comment, TEST_PUBLIC_KEY).is_err());
+
+        assert!(PublicKey::from_base64(RELEASE_PUBLIC_KEY).is_ok());
+        let other_key = "RWQBAgMEBQYHCAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA";
+        assert!(verify_signature(checksums, TEST_SIGNATURE, other_key).is_err());
+        assert!(verify_signature(checksums, "not a signature", TEST_PUBLIC_KEY).is_err());
+    }
+
+    #[test]
+    fn checksum_must_match_the_listed_hash() {
+        // SHA-256 of "hello".
+        let hash = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+        let checksums = format!("{hash}  ./polymarket-v1-x.tar.gz\nffff  other.tar.gz\n");
+        assert!(verify_checksum(b"hello", &checksums, "polymarket-v1-x.tar.gz").is_ok());
+        assert!(verify_checksum(b"hell0", &checksums, "polymarket-v1-x.tar.gz").is_err());
+        assert!(verify_checksum(b"hello", &checksums, "missing.tar.gz").is_err());
+        assert!(verify_checksum(b"hello", &format!("{hash} *a.tgz"), "a.tgz").is_ok());
+    }
+
+    fn windows_archive(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (path, contents) in files {
+            zip.start_file(*path, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            zip.write_all(contents).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn extracts_the_binary_from_the_archive() {
+        let linux = "x86_64-unknown-linux-gnu";
+        let archive = release_archive(&[("README.md", b"docs"), ("dist/polymarket", b"\x7fELF")]);
+        assert_eq!(extract_binary(&archive, linux).unwrap(), b"\x7fELF");
+        let archive = release_archive(&[("README.md", b"docs")]);
+        assert!(extract_binary(&archive, linux).is_err());
+    }
+
+    #[test]
+    fn windows_releases_are_zipped_executables() {
+        let windows = "aarch64-pc-windows-msvc";
+        assert_eq!(archive_extension(windows), "zip");
+        assert_eq!(archive_extension("aarch64-apple-darwin"), "tar.gz");
+        let archive = windows_archive(&[("polymarket", b"no"), ("polymarket.exe", b"MZ")]);
+        assert_eq!(extract_binary(&archive, windows).unwrap(), b"MZ");
+        assert!(extract_binary(&release_archive(&[]), windows).is_err());
+    }
+
+    #[test]
+    fn replaces_the_executable_in_place() {
+        let dir = std::env::temp_dir().join(format!("polymarket-upgrade-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let exe = dir.join("polymarket");
+        fs::File::create(&exe).unwrap().write_all(b"old").unwrap();
+        replace_exe(&exe, b"new").unwrap();
+        assert_eq!(fs::read(&exe).unwrap(), b"new");
+        assert!(!dir.join(".polymarket.new").exists());
+        let _ = fs::remove_dir_all(&dir);
+    }
 }
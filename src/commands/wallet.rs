@@ -1,14 +1,41 @@
 use std::fmt::Write as _;
+use std::fs;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
+use alloy::primitives::utils::parse_units;
+use alloy::primitives::{Signature, U256};
+use alloy::providers::Provider as _;
+use alloy::signers::SignerSync as _;
+use alloy::sol;
 use anyhow::{Context, Result, bail};
-use clap::{Args, Subcommand};
+use clap::{Args, Subcommand, ValueEnum};
 use polymarket_client_sdk::auth::LocalSigner;
 use polymarket_client_sdk::auth::Signer as _;
-use polymarket_client_sdk::{POLYGON, derive_proxy_wallet};
+use polymarket_client_sdk::types::Address;
+use polymarket_client_sdk::{contract_config, derive_proxy_wallet};
+use rust_decimal::Decimal;
+use tabled::Tabled;
 
+use crate::auth;
 use crate::config;
-use crate::output::OutputFormat;
+use crate::keystore;
+use crate::mnemonic;
+use crate::output::dry_run::PlannedTx;
+use crate::output::{OutputFormat, print_json, print_raw, render_table};
+use crate::secret;
+use crate::tx::{self, GasArgs, TxOptions, WaitArgs, format_pol};
+
+sol! {
+    #[sol(rpc)]
+    interface IERC20 {
+        function balanceOf(address account) external view returns (uint256);
+    }
+}
+
+/// Below this much POL, warn that transactions may soon fail for gas.
+const LOW_GAS_WEI: u64 = 100_000_000_000_000_000;
 
 #[derive(Args)]
 pub struct WalletArgs {
@@ -16,32 +43,160 @@ pub struct WalletArgs {
     pub command: WalletCommand,
 }
 
+/// Where a new key is kept.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Storage {
+    /// The config file
+    File,
+    /// The OS keychain (macOS Keychain or Secret Service)
+    Keyring,
+}
+
+/// How `create` and `import` save the key.
+#[derive(Args)]
+pub struct SaveArgs {
+    /// Signature type: eoa, proxy (default), or gnosis-safe
+    #[arg(long, default_value = "proxy")]
+    signature_type: String,
+    /// Where to keep the key
+    #[arg(long, value_enum, default_value_t = Storage::File)]
+    storage: Storage,
+    /// Encrypt the key with a passphrase (read from POLYMARKET_PASSPHRASE or prompted)
+    #[arg(long)]
+    encrypt: bool,
+}
+
+impl SaveArgs {
+    /// Saves `key` into the active profile, asking for a passphrase first
+    /// when it is to be encrypted. RPC endpoints given with `--rpc-url` are
+    /// kept with it.
+    fn save(&self, key: &str) -> Result<()> {
+        if self.encrypt && self.storage == Storage::Keyring {
+            bail!("--encrypt only applies to --storage file; the keyring protects the key itself");
+        }
+        let passphrase = self.encrypt.then(secret::new_passphrase).transpose()?;
+        let store = match (&passphrase, self.storage) {
+            (Some(passphrase), _) => config::KeyStore::Encrypted(passphrase),
+            (None, Storage::Keyring) => config::KeyStore::Keyring,
+            (None, Storage::File) => config::KeyStore::Plain,
+        };
+        let rpc_urls = config::selected_rpc_urls().join(",");
+        config::save_wallet(
+            key,
+            &self.signature_type,
+            (!rpc_urls.is_empty()).then_some(rpc_urls.as_str()),
+            store,
+        )
+    }
+
+    fn stored_in(&self) -> &'static str {
+        match (self.storage, self.encrypt) {
+            (Storage::Keyring, _) => "OS keyring",
+            (Storage::File, true) => "config file (encrypted)",
+            (Storage::File, false) => "config file",
+        }
+    }
+}
+
 #[derive(Subcommand)]
 pub enum WalletCommand {
-    /// Generate a new random wallet and save to config
+    /// Generate a new random wallet and save to config (into `--profile` if given)
     Create {
         /// Overwrite existing wallet
         #[arg(long)]
         force: bool,
-        /// Signature type: eoa, proxy (default), or gnosis-safe
-        #[arg(long, default_value = "proxy")]
-        signature_type: String,
+        #[command(flatten)]
+        save: SaveArgs,
     },
-    /// Import an existing private key
+    /// Import an existing private key, seed phrase, or keystore file
     Import {
-        /// Private key (hex, with or without 0x prefix)
-        key: String,
+        /// Private key (hex, with or without 0x prefix) or a quoted BIP-39 seed phrase
+        #[arg(required_unless_present = "keystore")]
+        key: Option<String>,
+        /// Web3 keystore (v3 JSON) file to import instead, e.g. from geth or MetaMask
+        #[arg(long, conflicts_with_all = ["key", "path", "index"])]
+        keystore: Option<PathBuf>,
+        /// Derivation path for a seed phrase
+        #[arg(long, conflicts_with = "index")]
+        path: Option<String>,
+        /// Account index for a seed phrase: derives m/44'/60'/0'/0/<INDEX>
+        #[arg(long)]
+        index: Option<u32>,
         /// Overwrite existing wallet
         #[arg(long)]
         force: bool,
-        /// Signature type: eoa, proxy (default), or gnosis-safe
-        #[arg(long, default_value = "proxy")]
+        #[command(flatten)]
+        save: SaveArgs,
+    },
+    /// Follow an address without its key, so only read-only commands work
+    Watch {
+        /// Address to follow, e.g. the wallet shown on a Polymarket profile
+        address: Address,
+        /// eoa (default) if the address holds the funds itself; proxy or gnosis-safe if it signs for a Polymarket wallet
+        #[arg(long, default_value = "eoa")]
         signature_type: String,
+        /// Overwrite existing wallet
+        #[arg(long)]
+        force: bool,
+    },
+    /// Export the key as a password-protected Web3 keystore (v3 JSON) file
+    Export {
+        /// File to write
+        #[arg(long)]
+        keystore: PathBuf,
+        /// Overwrite the file if it exists
+        #[arg(long)]
+        force: bool,
+    },
+    /// Encrypt the active profile's plain-text key with a passphrase
+    Encrypt,
+    /// List wallet profiles
+    List,
+    /// Make a profile the one used when `--profile` isn't given
+    Use {
+        /// Profile name (`default` for the top-level wallet)
+        name: String,
     },
     /// Show the address of the configured wallet
-    Address,
+    Address {
+        /// Also draw the deposit address (the proxy wallet, if any) as a QR code
+        #[arg(long)]
+        qr: bool,
+    },
     /// Show wallet info (address, config path, key source)
     Show,
+    /// Show POL (gas) and USDC balances of the wallet and its proxy on Polygon
+    Balance,
+    /// Send POL from the wallet's own address, e.g. to top up another wallet's gas
+    SendPol {
+        /// Recipient address
+        #[arg(long)]
+        to: Address,
+        /// POL to send (e.g. 0.5)
+        #[arg(long)]
+        amount: String,
+        #[command(flatten)]
+        wait: WaitArgs,
+    },
+    /// Check the wallet has enough POL for gas, and what typical operations cost now
+    GasCheck,
+    /// Sign a message with the wallet's key (EIP-191 personal_sign)
+    SignMessage {
+        /// Message text
+        message: String,
+    },
+    /// Check that a personal_sign signature was made by an address
+    Verify {
+        /// Address that should have signed
+        #[arg(long)]
+        address: Address,
+        /// 65-byte signature as hex
+        #[arg(long)]
+        signature: String,
+        /// Message text that was signed
+        #[arg(long)]
+        message: String,
+    },
     /// Delete all config and keys (fresh install)
     Reset {
         /// Skip confirmation prompt
@@ -50,35 +205,100 @@ pub enum WalletCommand {
     },
 }
 
-pub fn execute(
+impl WalletCommand {
+    /// Only sending POL has a transaction to preview.
+    pub fn rejects_dry_run(&self) -> bool {
+        !matches!(self, Self::SendPol { .. })
+    }
+}
+
+pub async fn execute(
     args: WalletArgs,
     output: &OutputFormat,
     private_key_flag: Option<&str>,
+    signature_type_flag: Option<&str>,
+    dry_run: bool,
+    confirm: bool,
+    gas: &GasArgs,
 ) -> Result<()> {
     match args.command {
-        WalletCommand::Create {
-            force,
-            signature_type,
-        } => cmd_create(output, force, &signature_type),
+        WalletCommand::Create { force, save } => cmd_create(output, force, &save),
         WalletCommand::Import {
             key,
+            keystore,
+            path,
+            index,
             force,
+            save,
+        } => {
+            let source = match (key, keystore) {
+                (_, Some(file)) => KeyInput::Keystore(file),
+                (Some(key), None) => match (mnemonic::is_mnemonic(&key), path, index) {
+                    (true, Some(path), _) => KeyInput::Mnemonic(key, path),
+                    (true, None, index) => {
+                        KeyInput::Mnemonic(key, mnemonic::default_path(index.unwrap_or(0)))
+                    }
+                    (false, None, None) => KeyInput::Hex(key),
+                    (false, ..) => bail!("--path and --index only apply to seed phrases"),
+                },
+                (None, None) => bail!("Give a private key, a seed phrase, or --keystore"),
+            };
+            cmd_import(&source, output, force, &save)
+        }
+        WalletCommand::Watch {
+            address,
             signature_type,
-        } => cmd_import(&key, output, force, &signature_type),
-        WalletCommand::Address => cmd_address(output, private_key_flag),
+            force,
+        } => cmd_watch(output, address, &signature_type, force),
+        WalletCommand::Export { keystore, force } => {
+            cmd_export(output, private_key_flag, &keystore, force)
+        }
+        WalletCommand::Encrypt => cmd_encrypt(output),
+        WalletCommand::List => cmd_list(output),
+        WalletCommand::Use { name } => cmd_use(output, &name),
+        WalletCommand::Address { qr } => {
+            cmd_address(output, private_key_flag, signature_type_flag, qr)
+        }
         WalletCommand::Show => cmd_show(output, private_key_flag),
+        WalletCommand::SignMessage { message } => {
+            cmd_sign_message(output, private_key_flag, &message)
+        }
+        WalletCommand::Verify {
+            address,
+            signature,
+            message,
+        } => cmd_verify(output, address, &signature, &message),
+        WalletCommand::Balance => cmd_balance(output, private_key_flag, signature_type_flag).await,
+        WalletCommand::SendPol { to, amount, wait } => {
+            let options = TxOptions {
+                private_key: private_key_flag,
+                dry_run,
+                confirm,
+                gas,
+                wait,
+                simulate: false,
+            };
+            cmd_send_pol(output, &options, to, &amount).await
+        }
+        WalletCommand::GasCheck => cmd_gas_check(output, private_key_flag).await,
         WalletCommand::Reset { force } => cmd_reset(output, force),
     }
 }
 
-fn guard_overwrite(force: bool) -> Result<()> {
-    if !force && config::config_exists() {
+/// Refuses to replace the key of the active profile without `--force`.
+fn guard_overwrite(force: bool) -> Result<String> {
+    let profile = config::active_profile();
+    let has_wallet = config::load_config()?
+        .as_ref()
+        .and_then(|c| c.profile(&profile))
+        .is_some_and(config::Profile::has_wallet);
+    if !force && has_wallet {
         bail!(
-            "A wallet already exists at {}. Use --force to overwrite.",
+            "Profile '{profile}' already has a wallet in {}. Use --force to overwrite.",
             config::config_path()?.display()
         );
     }
-    Ok(())
+    Ok(profile)
 }
 
 pub(crate) fn normalize_key(key: &str) -> String {
@@ -89,10 +309,10 @@ pub(crate) fn normalize_key(key: &str) -> String {
     }
 }
 
-fn cmd_create(output: &OutputFormat, force: bool, signature_type: &str) -> Result<()> {
-    guard_overwrite(force)?;
+fn cmd_create(output: &OutputFormat, force: bool, save: &SaveArgs) -> Result<()> {
+    let profile = guard_overwrite(force)?;
 
-    let signer = LocalSigner::random().with_chain_id(Some(POLYGON));
+    let signer = LocalSigner::random().with_chain_id(Some(config::chain_id()));
     let address = signer.address();
     let bytes = signer.credential().to_bytes();
     let mut key_hex = String::with_capacity(2 + bytes.len() * 2);
@@ -101,121 +321,395 @@ fn cmd_create(output: &OutputFormat, force: bool, signature_type: &str) -> Resul
         write!(key_hex, "{b:02x}").unwrap();
     }
 
-    config::save_wallet(&key_hex, POLYGON, signature_type)?;
+    save.save(&key_hex)?;
     let config_path = config::config_path()?;
-    let proxy_addr = derive_proxy_wallet(address, POLYGON);
+    let proxy_addr = derive_proxy_wallet(address, config::chain_id());
 
     match output {
-        OutputFormat::Json => {
-            println!(
-                "{}",
-                serde_json::json!({
-                    "address": address.to_string(),
-                    "proxy_address": proxy_addr.map(|a| a.to_string()),
-                    "signature_type": signature_type,
-                    "config_path": config_path.display().to_string(),
-                })
-            );
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({
+                "profile": profile,
+                "address": address.to_string(),
+                "proxy_address": proxy_addr.map(|a| a.to_string()),
+                "signature_type": save.signature_type,
+                "key_storage": save.stored_in(),
+                "config_path": config_path.display().to_string(),
+            }))?;
         }
         OutputFormat::Table => {
             println!("Wallet created successfully!");
+            println!("Profile:        {profile}");
             println!("Address:        {address}");
             if let Some(proxy) = proxy_addr {
                 println!("Proxy wallet:   {proxy}");
             }
-            println!("Signature type: {signature_type}");
+            println!("Signature type: {}", save.signature_type);
+            println!("Key stored in:  {}", save.stored_in());
             println!("Config:         {}", config_path.display());
             println!();
-            println!("IMPORTANT: Back up your private key from the config file.");
-            println!("           If lost, your funds cannot be recovered.");
+            match (save.storage, save.encrypt) {
+                (Storage::Keyring, _) => {
+                    println!("IMPORTANT: The key exists only in the OS keyring. Back it up");
+                    println!("           from there; if lost, your funds cannot be recovered.");
+                }
+                (Storage::File, true) => {
+                    println!("IMPORTANT: Back up the config file and remember the passphrase.");
+                    println!("           If either is lost, your funds cannot be recovered.");
+                }
+                (Storage::File, false) => {
+                    println!("IMPORTANT: Back up your private key from the config file.");
+                    println!("           If lost, your funds cannot be recovered.");
+                }
+            }
         }
     }
     Ok(())
 }
 
-fn cmd_import(key: &str, output: &OutputFormat, force: bool, signature_type: &str) -> Result<()> {
-    guard_overwrite(force)?;
+/// What `wallet import` reads the key from.
+enum KeyInput {
+    Hex(String),
+    /// A seed phrase and the derivation path to follow
+    Mnemonic(String, String),
+    Keystore(PathBuf),
+}
 
-    let normalized = normalize_key(key);
+fn cmd_import(
+    source: &KeyInput,
+    output: &OutputFormat,
+    force: bool,
+    save: &SaveArgs,
+) -> Result<()> {
+    let profile = guard_overwrite(force)?;
+
+    let (normalized, path) = match source {
+        KeyInput::Hex(key) => (normalize_key(key), None),
+        KeyInput::Mnemonic(phrase, path) => (mnemonic::derive_key(phrase, path)?, Some(path)),
+        KeyInput::Keystore(file) => {
+            let json = fs::read_to_string(file)
+                .with_context(|| format!("Failed to read {}", file.display()))?;
+            let password = secret::passphrase("Keystore password: ")?;
+            (keystore::decrypt(&json, &password)?, None)
+        }
+    };
     let signer = LocalSigner::from_str(&normalized)
         .context("Invalid private key")?
-        .with_chain_id(Some(POLYGON));
+        .with_chain_id(Some(config::chain_id()));
     let address = signer.address();
-
-    config::save_wallet(&normalized, POLYGON, signature_type)?;
+    save.save(&normalized)?;
     let config_path = config::config_path()?;
-    let proxy_addr = derive_proxy_wallet(address, POLYGON);
+    let proxy_addr = derive_proxy_wallet(address, config::chain_id());
 
     match output {
-        OutputFormat::Json => {
-            println!(
-                "{}",
-                serde_json::json!({
-                    "address": address.to_string(),
-                    "proxy_address": proxy_addr.map(|a| a.to_string()),
-                    "signature_type": signature_type,
-                    "config_path": config_path.display().to_string(),
-                })
-            );
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({
+                "profile": profile,
+                "address": address.to_string(),
+                "proxy_address": proxy_addr.map(|a| a.to_string()),
+                "signature_type": save.signature_type,
+                "key_storage": save.stored_in(),
+                "derivation_path": path,
+                "config_path": config_path.display().to_string(),
+            }))?;
         }
         OutputFormat::Table => {
             println!("Wallet imported successfully!");
+            println!("Profile:        {profile}");
             println!("Address:        {address}");
+            if let Some(path) = path {
+                println!("Derived from:   seed phrase at {path}");
+            }
+            if let KeyInput::Keystore(file) = source {
+                println!("Read from:      {}", file.display());
+            }
             if let Some(proxy) = proxy_addr {
                 println!("Proxy wallet:   {proxy}");
             }
-            println!("Signature type: {signature_type}");
+            println!("Signature type: {}", save.signature_type);
+            println!("Key stored in:  {}", save.stored_in());
             println!("Config:         {}", config_path.display());
         }
     }
     Ok(())
 }
 
-fn cmd_address(output: &OutputFormat, private_key_flag: Option<&str>) -> Result<()> {
-    let (key, _) = config::resolve_key(private_key_flag);
-    let key = key.ok_or_else(|| anyhow::anyhow!("{}", config::NO_WALLET_MSG))?;
+fn cmd_watch(
+    output: &OutputFormat,
+    address: Address,
+    signature_type: &str,
+    force: bool,
+) -> Result<()> {
+    if !matches!(signature_type, "eoa" | "proxy" | "gnosis-safe") {
+        bail!("--signature-type must be one of: eoa, proxy, gnosis-safe");
+    }
+    let profile = guard_overwrite(force)?;
+    let rpc_urls = config::selected_rpc_urls().join(",");
+    config::save_watch_address(
+        address,
+        signature_type,
+        (!rpc_urls.is_empty()).then_some(rpc_urls.as_str()),
+    )?;
+    let funder = auth::resolve_funder_address(None, Some(signature_type))?;
 
-    let signer = LocalSigner::from_str(&key).context("Invalid private key")?;
-    let address = signer.address();
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({
+                "profile": profile,
+                "address": address.to_string(),
+                "funder_address": funder.to_string(),
+                "signature_type": signature_type,
+                "watch_only": true,
+            }))?;
+        }
+        OutputFormat::Table => {
+            println!("Watching {address} in profile '{profile}'.");
+            if funder != address {
+                println!("Funds wallet:   {funder}");
+            }
+            println!("Read-only commands (portfolio, activity, wallet balance, ...) now use it;");
+            println!("anything that signs needs a key: `polymarket wallet import <key> --force`.");
+        }
+    }
+    Ok(())
+}
+
+fn cmd_export(
+    output: &OutputFormat,
+    private_key_flag: Option<&str>,
+    file: &Path,
+    force: bool,
+) -> Result<()> {
+    if !force && file.exists() {
+        bail!(
+            "{} already exists. Use --force to overwrite.",
+            file.display()
+        );
+    }
+    let address = auth::resolve_address(private_key_flag)?;
+    let key = config::signing_key(private_key_flag)?;
+    let password = secret::new_passphrase()?;
+    let json = keystore::encrypt(&key, address, &password)?;
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options
+        .open(file)
+        .and_then(|mut f| f.write_all(json.as_bytes()))
+        .with_context(|| format!("Failed to write {}", file.display()))?;
 
     match output {
-        OutputFormat::Json => {
-            println!("{}", serde_json::json!({"address": address.to_string()}));
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({
+                "address": address.to_string(),
+                "keystore": file.display().to_string(),
+            }))?;
         }
         OutputFormat::Table => {
-            println!("{address}");
+            println!("Exported {address} to {}.", file.display());
+            println!("Import it elsewhere with the password you just chose.");
         }
     }
     Ok(())
 }
 
-fn cmd_show(output: &OutputFormat, private_key_flag: Option<&str>) -> Result<()> {
-    let (key, source) = config::resolve_key(private_key_flag);
-    let signer = key.as_deref().and_then(|k| LocalSigner::from_str(k).ok());
-    let address = signer.as_ref().map(|s| s.address().to_string());
-    let proxy_addr = signer
+/// Where a profile keeps its key: file, encrypted, keyring, or nowhere for
+/// a watch-only wallet.
+fn key_storage(profile: &config::Profile) -> Option<&'static str> {
+    if profile.keyring_address.is_some() {
+        Some("keyring")
+    } else if profile.encrypted_key.is_some() {
+        Some("encrypted")
+    } else if profile.private_key.is_some() {
+        Some("file")
+    } else {
+        profile.watch_address.map(|_| "watch-only")
+    }
+}
+
+fn cmd_list(output: &OutputFormat) -> Result<()> {
+    let config = config::load_config()?.unwrap_or_default();
+    let active = config::active_profile();
+    let profiles: Vec<_> = config
+        .all_profiles()
+        .filter(|(name, p)| *name != config::DEFAULT_PROFILE || p.has_wallet())
+        .collect();
+
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let list: Vec<_> = profiles
+                .iter()
+                .map(|(name, p)| {
+                    serde_json::json!({
+                        "name": name,
+                        "active": *name == active,
+                        "address": p.address().map(|a| a.to_string()),
+                        "key_storage": key_storage(p),
+                        "signature_type": p.signature_type.as_deref()
+                            .unwrap_or(config::DEFAULT_SIGNATURE_TYPE),
+                        "rpc_url": p.rpc_url,
+                    })
+                })
+                .collect();
+            print_json(&list)?;
+        }
+        OutputFormat::Table => {
+            if profiles.is_empty() {
+                println!("No profiles. Run `polymarket wallet create` to make one.");
+                return Ok(());
+            }
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "")]
+                active: &'static str,
+                #[tabled(rename = "Profile")]
+                name: String,
+                #[tabled(rename = "Address")]
+                address: String,
+                #[tabled(rename = "Signature Type")]
+                signature_type: String,
+                #[tabled(rename = "RPC")]
+                rpc_url: String,
+            }
+            let rows: Vec<Row> = profiles
+                .iter()
+                .map(|(name, p)| Row {
+                    active: if *name == active { "*" } else { "" },
+                    name: (*name).to_string(),
+                    address: match (p.address(), key_storage(p)) {
+                        (Some(address), Some("file")) => address.to_string(),
+                        (Some(address), Some(storage)) => format!("{address} ({storage})"),
+                        _ => "(no key)".into(),
+                    },
+                    signature_type: p
+                        .signature_type
+                        .clone()
+                        .unwrap_or_else(|| config::DEFAULT_SIGNATURE_TYPE.into()),
+                    rpc_url: p.rpc_url.clone().unwrap_or_else(|| "(default)".into()),
+                })
+                .collect();
+            println!("{}", render_table(rows)?);
+        }
+    }
+    Ok(())
+}
+
+fn cmd_encrypt(output: &OutputFormat) -> Result<()> {
+    let profile = config::active_profile();
+    let has_plain_key = config::load_config()?
         .as_ref()
-        .and_then(|s| derive_proxy_wallet(s.address(), POLYGON))
+        .and_then(|c| c.profile(&profile))
+        .is_some_and(|p| p.private_key.is_some());
+    if !has_plain_key {
+        bail!("Profile '{profile}' has no plain-text key to encrypt");
+    }
+    let passphrase = secret::new_passphrase()?;
+    let address = config::encrypt_wallet(&passphrase)?;
+
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({
+                "profile": profile,
+                "address": address.to_string(),
+                "encrypted": true,
+            }))?;
+        }
+        OutputFormat::Table => {
+            println!("Encrypted the key of profile '{profile}' ({address}).");
+            println!(
+                "Commands that sign will ask for the passphrase, or read POLYMARKET_PASSPHRASE."
+            );
+        }
+    }
+    Ok(())
+}
+
+fn cmd_use(output: &OutputFormat, name: &str) -> Result<()> {
+    config::use_profile(name)?;
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({"active_profile": name}))?;
+        }
+        OutputFormat::Table => println!("Now using profile '{name}'."),
+    }
+    Ok(())
+}
+
+fn cmd_address(
+    output: &OutputFormat,
+    private_key_flag: Option<&str>,
+    signature_type_flag: Option<&str>,
+    qr: bool,
+) -> Result<()> {
+    let address = auth::resolve_address(private_key_flag)?;
+    if !qr {
+        if print_raw(address) {
+            return Ok(());
+        }
+        match output {
+            OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                print_json(&serde_json::json!({"address": address.to_string()}))?;
+            }
+            OutputFormat::Table => {
+                println!("{address}");
+            }
+        }
+        return Ok(());
+    }
+
+    // Deposits go to the wallet that holds the funds, not the signer.
+    let deposit = auth::resolve_funder_address(private_key_flag, signature_type_flag)?;
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({
+                "address": address.to_string(),
+                "deposit_address": deposit.to_string(),
+            }))?;
+        }
+        OutputFormat::Table => print!("{}", deposit_qr(deposit)),
+    }
+    Ok(())
+}
+
+/// `address` as a QR code with the address written under it, for scanning
+/// into an exchange or mobile wallet. Send only USDC on Polygon to it.
+pub(crate) fn deposit_qr(address: Address) -> String {
+    let text = address.to_string();
+    let code = crate::qr::QrCode::encode(&text).map_or_else(String::new, |code| {
+        code.render(crate::output::color::enabled())
+    });
+    format!("{code}\n{text}\nSend USDC on Polygon only.\n")
+}
+
+fn cmd_show(output: &OutputFormat, private_key_flag: Option<&str>) -> Result<()> {
+    let (address, source) = config::resolve_address(private_key_flag);
+    let proxy_addr = address
+        .and_then(|a| derive_proxy_wallet(a, config::chain_id()))
         .map(|a| a.to_string());
+    let address = address.map(|a| a.to_string());
 
     let sig_type = config::resolve_signature_type(None);
+    let profile = config::active_profile();
     let config_path = config::config_path()?;
 
     match output {
-        OutputFormat::Json => {
-            println!(
-                "{}",
-                serde_json::json!({
-                    "address": address,
-                    "proxy_address": proxy_addr,
-                    "signature_type": sig_type,
-                    "config_path": config_path.display().to_string(),
-                    "source": source.label(),
-                    "configured": address.is_some(),
-                })
-            );
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({
+                "profile": profile,
+                "address": address,
+                "proxy_address": proxy_addr,
+                "signature_type": sig_type,
+                "config_path": config_path.display().to_string(),
+                "source": source.label(),
+                "configured": address.is_some(),
+            }))?;
         }
         OutputFormat::Table => {
+            println!("Profile:        {profile}");
             match &address {
                 Some(addr) => println!("Address:        {addr}"),
                 None => println!("Address:        (not configured)"),
@@ -231,15 +725,326 @@ fn cmd_show(output: &OutputFormat, private_key_flag: Option<&str>) -> Result<()>
     Ok(())
 }
 
+fn cmd_sign_message(
+    output: &OutputFormat,
+    private_key_flag: Option<&str>,
+    message: &str,
+) -> Result<()> {
+    let key = config::signing_key(private_key_flag)?;
+    let signer = LocalSigner::from_str(&key).context("Invalid private key")?;
+    let signature = signer
+        .sign_message_sync(message.as_bytes())
+        .context("Failed to sign the message")?;
+    let signature = format!("0x{}", alloy::hex::encode(signature.as_bytes()));
+    if print_raw(&signature) {
+        return Ok(());
+    }
+
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({
+                "address": signer.address().to_string(),
+                "message": message,
+                "signature": signature,
+            }))?;
+        }
+        OutputFormat::Table => {
+            println!("Address:   {}", signer.address());
+            println!("Signature: {signature}");
+        }
+    }
+    Ok(())
+}
+
+fn cmd_verify(
+    output: &OutputFormat,
+    address: Address,
+    signature: &str,
+    message: &str,
+) -> Result<()> {
+    let signature = Signature::from_str(signature.trim())
+        .context("Invalid signature: expected 65 bytes of hex")?;
+    let signer = signature
+        .recover_address_from_msg(message.as_bytes())
+        .context("Could not recover a signer from the signature")?;
+    if signer != address {
+        bail!("Signature is not from {address}; it was signed by {signer}");
+    }
+
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({"valid": true, "address": address.to_string()}))?;
+        }
+        OutputFormat::Table => println!("\u{2713} Valid signature from {address}"),
+    }
+    Ok(())
+}
+
+/// A wallet's on-chain balances.
+struct Balance {
+    label: &'static str,
+    address: Address,
+    pol: U256,
+    usdc: U256,
+}
+
+async fn cmd_balance(
+    output: &OutputFormat,
+    private_key_flag: Option<&str>,
+    signature_type_flag: Option<&str>,
+) -> Result<()> {
+    let eoa = auth::resolve_address(private_key_flag)?;
+    let funder = auth::resolve_funder_address(private_key_flag, signature_type_flag)?;
+    let mut wallets = vec![("EOA", eoa)];
+    if funder != eoa {
+        wallets.push(("Proxy", funder));
+    }
+
+    let provider = auth::create_readonly_provider().await?;
+    let collateral = contract_config(config::chain_id(), false)
+        .context("No contract config for this chain")?
+        .collateral;
+    let usdc = IERC20::new(collateral, provider.clone());
+    let mut balances = Vec::with_capacity(wallets.len());
+    for (label, address) in wallets {
+        let pol = provider
+            .get_balance(address)
+            .await
+            .with_context(|| format!("Failed to read the POL balance of {address}"))?;
+        let usdc = usdc
+            .balanceOf(address)
+            .call()
+            .await
+            .with_context(|| format!("Failed to read the USDC balance of {address}"))?;
+        balances.push(Balance {
+            label,
+            address,
+            pol,
+            usdc,
+        });
+    }
+    // Gas is always paid by the EOA, even for orders placed through the proxy.
+    let low_gas = balances[0].pol < U256::from(LOW_GAS_WEI);
+
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let list: Vec<_> = balances
+                .iter()
+                .map(|b| {
+                    serde_json::json!({
+                        "wallet": b.label.to_lowercase(),
+                        "address": b.address.to_string(),
+                        "pol": format_pol(b.pol),
+                        "pol_wei": b.pol.to_string(),
+                        "usdc": format_usdc(b.usdc),
+                    })
+                })
+                .collect();
+            print_json(&serde_json::json!({"balances": list, "low_gas": low_gas}))?;
+        }
+        OutputFormat::Table => {
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Wallet")]
+                label: &'static str,
+                #[tabled(rename = "Address")]
+                address: String,
+                #[tabled(rename = "POL")]
+                pol: String,
+                #[tabled(rename = "USDC")]
+                usdc: String,
+            }
+            let rows: Vec<Row> = balances
+                .iter()
+                .map(|b| Row {
+                    label: b.label,
+                    address: b.address.to_string(),
+                    pol: format_pol(b.pol),
+                    usdc: format_usdc(b.usdc),
+                })
+                .collect();
+            println!("{}", render_table(rows)?);
+            if low_gas {
+                println!();
+                println!(
+                    "Warning: {eoa} has less than {} POL for gas. On-chain commands",
+                    format_pol(U256::from(LOW_GAS_WEI))
+                );
+                println!("         (approve, ctf, transfers) may fail until you top it up.");
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn cmd_send_pol(
+    output: &OutputFormat,
+    options: &TxOptions<'_>,
+    to: Address,
+    amount: &str,
+) -> Result<()> {
+    let value = parse_units(amount.trim(), 18)
+        .with_context(|| format!("Invalid amount: {amount}"))?
+        .get_absolute();
+    anyhow::ensure!(!value.is_zero(), "Amount must be positive");
+    let from = auth::resolve_address(options.private_key)?;
+    let call = PlannedTx {
+        action: format!("POL transfer \u{2192} {to}"),
+        from: Some(from),
+        to,
+        calldata: Vec::new(),
+        value,
+    };
+    if !options.dry_run {
+        let provider = auth::create_readonly_provider().await?;
+        let held = provider
+            .get_balance(from)
+            .await
+            .with_context(|| format!("Failed to read the POL balance of {from}"))?;
+        if held < value {
+            bail!(
+                "{from} holds {} POL, less than the {amount} to send",
+                format_pol(held)
+            );
+        }
+    }
+
+    let summary = [format!("Send {amount} POL from {from} to {to}")];
+    let Some(gas) = tx::prepare(std::slice::from_ref(&call), &summary, options, output).await?
+    else {
+        return Ok(());
+    };
+    let provider = auth::create_provider(options.private_key).await?;
+    let sent = tx::send(&provider, &call, &gas[0], options.wait).await?;
+
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({
+                "from": from.to_string(),
+                "to": to.to_string(),
+                "amount": format_pol(value),
+                "amount_wei": value.to_string(),
+                "tx_hash": sent.hash.to_string(),
+                "block": sent.block,
+            }))?;
+        }
+        OutputFormat::Table => {
+            println!("Sent {} POL from {from} to {to}.", format_pol(value));
+            println!("Tx: {}", sent.hash);
+        }
+    }
+    Ok(())
+}
+
+/// Gas the common on-chain commands use, with some headroom over what they
+/// usually take.
+const TYPICAL_GAS: [(&str, u64); 5] = [
+    ("ctf split", 200_000),
+    ("ctf merge", 200_000),
+    ("ctf redeem", 150_000),
+    ("approve set (6 transactions)", 6 * 60_000),
+    ("bridge withdraw", 100_000),
+];
+
+async fn cmd_gas_check(output: &OutputFormat, private_key_flag: Option<&str>) -> Result<()> {
+    // Gas is always paid by the EOA, even for orders placed through the proxy.
+    let eoa = auth::resolve_address(private_key_flag)?;
+    let provider = auth::create_readonly_provider().await?;
+    let balance = provider
+        .get_balance(eoa)
+        .await
+        .with_context(|| format!("Failed to read the POL balance of {eoa}"))?;
+    let max_fee = provider
+        .estimate_eip1559_fees()
+        .await
+        .context("Failed to estimate gas fees")?
+        .max_fee_per_gas;
+    let costs: Vec<(&str, U256)> = TYPICAL_GAS
+        .iter()
+        .map(|(name, gas)| (*name, U256::from(*gas) * U256::from(max_fee)))
+        .collect();
+    let needed = costs
+        .iter()
+        .map(|(_, cost)| *cost)
+        .max()
+        .unwrap_or_default();
+    let short = needed.saturating_sub(balance);
+
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let operations: Vec<_> = costs
+                .iter()
+                .map(|(name, cost)| {
+                    serde_json::json!({
+                        "operation": name,
+                        "max_cost_pol": format_pol(*cost),
+                        "affordable": *cost <= balance,
+                    })
+                })
+                .collect();
+            print_json(&serde_json::json!({
+                "address": eoa.to_string(),
+                "pol": format_pol(balance),
+                "max_fee_gwei": crate::tx::format_gwei(max_fee),
+                "operations": operations,
+                "enough": short.is_zero(),
+                "short_pol": format_pol(short),
+            }))?;
+        }
+        OutputFormat::Table => {
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Operation")]
+                name: &'static str,
+                #[tabled(rename = "Max Cost (POL)")]
+                cost: String,
+                #[tabled(rename = "Affordable")]
+                affordable: &'static str,
+            }
+            let rows: Vec<Row> = costs
+                .iter()
+                .map(|(name, cost)| Row {
+                    name,
+                    cost: format_pol(*cost),
+                    affordable: if *cost <= balance {
+                        "\u{2713}"
+                    } else {
+                        "\u{2717}"
+                    },
+                })
+                .collect();
+            println!(
+                "{eoa} has {} POL; gas is up to {} gwei.\n",
+                format_pol(balance),
+                crate::tx::format_gwei(max_fee)
+            );
+            println!("{}", render_table(rows)?);
+        }
+    }
+    if !short.is_zero() {
+        bail!(
+            "{eoa} needs about {} more POL to cover any of these; top it up with \
+             `polymarket wallet send-pol --to {eoa} --amount <pol>` from another wallet",
+            format_pol(short)
+        );
+    }
+    Ok(())
+}
+
+/// Formats a raw USDC amount (6 decimals) in whole dollars and cents.
+pub(crate) fn format_usdc(raw: U256) -> String {
+    i128::try_from(raw)
+        .ok()
+        .and_then(|raw| Decimal::try_from_i128_with_scale(raw, 6).ok())
+        .map_or_else(|| raw.to_string(), |d| d.round_dp(2).to_string())
+}
+
 fn cmd_reset(output: &OutputFormat, force: bool) -> Result<()> {
     if !config::config_exists() {
         match output {
             OutputFormat::Table => println!("Nothing to reset. No config found."),
-            OutputFormat::Json => {
-                println!(
-                    "{}",
-                    serde_json::json!({"reset": false, "reason": "no config found"})
-                );
+            OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                print_json(&serde_json::json!({"reset": false, "reason": "no config found"}))?;
             }
         }
         return Ok(());
@@ -265,14 +1070,11 @@ fn cmd_reset(output: &OutputFormat, force: bool) -> Result<()> {
             println!("Config deleted: {}", path.display());
             println!("All keys and settings have been removed.");
         }
-        OutputFormat::Json => {
-            println!(
-                "{}",
-                serde_json::json!({
-                    "reset": true,
-                    "deleted": path.display().to_string(),
-                })
-            );
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({
+                "reset": true,
+                "deleted": path.display().to_string(),
+            }))?;
         }
     }
     Ok(())
@@ -296,6 +1098,12 @@ mod tests {
         assert_eq!(normalize_key(key), key);
     }
 
+    #[test]
+    fn format_usdc_rounds_to_cents() {
+        assert_eq!(format_usdc(U256::from(12_345_678u64)), "12.35");
+        assert_eq!(format_usdc(U256::ZERO), "0.00");
+    }
+
     #[test]
     fn normalize_key_uppercase_prefix() {
         let key = "0Xabcdef";
@@ -1,32 +1,259 @@
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use anyhow::{Context, Result};
+use clap::ValueEnum;
+use polymarket_client_sdk::auth::LocalSigner;
+use polymarket_client_sdk::types::{Address, ChainId};
+use polymarket_client_sdk::{AMOY, POLYGON};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::commands::clob::CliOrderType;
+use crate::keyring;
+use crate::output::OutputFormat;
+use crate::output::color::ColorChoice;
+use crate::secret::{self, EncryptedKey};
+
 const ENV_VAR: &str = "POLYMARKET_PRIVATE_KEY";
 const SIG_TYPE_ENV_VAR: &str = "POLYMARKET_SIGNATURE_TYPE";
+const PROFILE_ENV_VAR: &str = "POLYMARKET_PROFILE";
 pub const DEFAULT_SIGNATURE_TYPE: &str = "proxy";
+/// The profile kept at the top level of the config file.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Profile picked with `--profile` for the command being run.
+static SELECTED_PROFILE: Mutex<Option<String>> = Mutex::new(None);
+
+/// Chain picked with `--chain` for the command being run.
+static SELECTED_CHAIN: Mutex<Option<Chain>> = Mutex::new(None);
+
+/// RPC endpoints given with `--rpc-url` for the command being run.
+static SELECTED_RPC_URLS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Key unlocked this session, with its profile, so it's asked for once.
+static UNLOCKED: Mutex<Option<(String, String)>> = Mutex::new(None);
+
+/// Set by `--signer`: signing happens on a hardware wallet, so the stored key
+/// must not be used in its place.
+static HARDWARE_SIGNER: AtomicBool = AtomicBool::new(false);
 
 pub const NO_WALLET_MSG: &str =
     "No wallet configured. Run `polymarket wallet create` or `polymarket wallet import <key>`";
 
-#[derive(Serialize, Deserialize)]
+/// A wallet and the settings that go with it.
+#[derive(Serialize, Deserialize, Default, Clone)]
+pub struct Profile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<String>,
+    /// The private key encrypted with a passphrase, in place of `private_key`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encrypted_key: Option<EncryptedKey>,
+    /// Address of the private key kept in the OS keyring for this profile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyring_address: Option<Address>,
+    /// Address followed without any key (`wallet watch`), for read-only use.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watch_address: Option<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_type: Option<String>,
+    /// Polygon RPC endpoints, comma-separated and tried in order; the public
+    /// one when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rpc_url: Option<String>,
+    /// CLOB API credentials from the first authentication, reused so they
+    /// aren't derived again on every run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_credentials: Option<ApiCredentials>,
+}
+
+/// CLOB API credentials cached for a profile.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ApiCredentials {
+    /// Wallet the credentials belong to.
+    pub address: Address,
+    pub key: String,
+    /// Nonce the key was created with; `clob rotate-api-key` moves past it.
+    #[serde(default)]
+    pub nonce: u32,
+    /// The secret and passphrase; `None` in the config file when the profile
+    /// keeps them in the OS keyring.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passphrase: Option<String>,
+}
+
+/// Where [`save_wallet`] keeps the private key.
+#[derive(Clone, Copy)]
+pub enum KeyStore<'a> {
+    /// Plain text in the config file
+    Plain,
+    /// Encrypted in the config file with this passphrase
+    Encrypted(&'a str),
+    /// In the OS keyring, with only the address in the config file
+    Keyring,
+}
+
+impl Profile {
+    /// Whether the profile has a key or a watched address.
+    pub fn has_wallet(&self) -> bool {
+        self.private_key.is_some()
+            || self.encrypted_key.is_some()
+            || self.keyring_address.is_some()
+            || self.watch_address.is_some()
+    }
+
+    /// The wallet address, read without unlocking the key.
+    pub fn address(&self) -> Option<Address> {
+        match (&self.private_key, &self.encrypted_key) {
+            (Some(key), _) => LocalSigner::from_str(key).ok().map(|s| s.address()),
+            (None, Some(encrypted)) => Some(encrypted.address),
+            (None, None) => self.keyring_address.or(self.watch_address),
+        }
+    }
+
+    /// Stores `key` for the profile `name`, replacing whatever key it had.
+    fn set_key(&mut self, name: &str, key: &str, store: KeyStore) -> Result<()> {
+        let address = LocalSigner::from_str(key)
+            .context("Invalid private key")?
+            .address();
+        if matches!(store, KeyStore::Keyring) {
+            keyring::set(&keyring::key_account(name), key)?;
+        } else if self.keyring_address.is_some() {
+            // The old key isn't needed once the new one is saved elsewhere.
+            let _ = keyring::delete(&keyring::key_account(name));
+        }
+        // API credentials belong to one wallet, and their secret moves with
+        // the key between the keyring and the config file. A profile with an
+        // encrypted key keeps none, as they would sit beside it in plain text.
+        let keep_credentials = self.address() == Some(address)
+            && self.keyring_address.is_some() == matches!(store, KeyStore::Keyring)
+            && !matches!(store, KeyStore::Encrypted(_));
+        if !keep_credentials {
+            self.clear_api_credentials(name);
+        }
+        *self = Self {
+            private_key: matches!(store, KeyStore::Plain).then(|| key.to_string()),
+            encrypted_key: match store {
+                KeyStore::Encrypted(passphrase) => Some(secret::encrypt(key, address, passphrase)?),
+                _ => None,
+            },
+            keyring_address: matches!(store, KeyStore::Keyring).then_some(address),
+            watch_address: None,
+            ..self.clone()
+        };
+        Ok(())
+    }
+
+    fn clear_api_credentials(&mut self, name: &str) {
+        if let Some(cached) = self.api_credentials.take()
+            && cached.secret.is_none()
+        {
+            let _ = keyring::delete(&keyring::api_account(name));
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
 pub struct Config {
-    pub private_key: String,
+    /// The default profile, kept at the top level so older config files
+    /// still load.
+    #[serde(flatten)]
+    pub wallet: Profile,
     pub chain_id: u64,
-    #[serde(default = "default_signature_type")]
-    pub signature_type: String,
+    /// Table color preference; `--color` overrides it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<ColorChoice>,
+    /// Ask before placing orders or sending CTF transactions; `--yes` skips it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm_trades: Option<bool>,
+    /// Orders worth less than this many dollars are placed without asking.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm_above: Option<Decimal>,
+    /// Output format when `-o` isn't given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<OutputFormat>,
+    /// Order type when `--order-type` isn't given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_type: Option<CliOrderType>,
+    /// API endpoints in place of Polymarket's, e.g. for a proxy.
+    #[serde(default, skip_serializing_if = "Endpoints::is_empty")]
+    pub endpoints: Endpoints,
+    /// Named profiles (`--profile <name>`).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Profile>,
+    /// Profile used when none is picked; set with `wallet use`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_profile: Option<String>,
+    /// Shell aliases: a name and the command line it stands for.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub aliases: BTreeMap<String, String>,
+}
+
+/// Base URLs of the APIs; Polymarket's own for those unset.
+#[derive(Serialize, Deserialize, Default, Clone)]
+pub struct Endpoints {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clob: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clob_ws: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gamma: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bridge: Option<String>,
 }
 
-fn default_signature_type() -> String {
-    DEFAULT_SIGNATURE_TYPE.to_string()
+impl Endpoints {
+    fn is_empty(&self) -> bool {
+        self.clob.is_none()
+            && self.clob_ws.is_none()
+            && self.gamma.is_none()
+            && self.data.is_none()
+            && self.bridge.is_none()
+    }
+}
+
+impl Config {
+    pub fn profile(&self, name: &str) -> Option<&Profile> {
+        if name == DEFAULT_PROFILE {
+            Some(&self.wallet)
+        } else {
+            self.profiles.get(name)
+        }
+    }
+
+    pub fn profile_mut(&mut self, name: &str) -> &mut Profile {
+        if name == DEFAULT_PROFILE {
+            &mut self.wallet
+        } else {
+            self.profiles.entry(name.to_string()).or_default()
+        }
+    }
+
+    /// Every profile with a name, the default one first.
+    pub fn all_profiles(&self) -> impl Iterator<Item = (&str, &Profile)> {
+        std::iter::once((DEFAULT_PROFILE, &self.wallet))
+            .chain(self.profiles.iter().map(|(name, p)| (name.as_str(), p)))
+    }
 }
 
 pub enum KeySource {
     Flag,
     EnvVar,
     ConfigFile,
+    /// Encrypted in the config file; see [`signing_key`].
+    Encrypted,
+    /// In the OS keyring; see [`signing_key`].
+    Keyring,
+    /// No key, only an address from `wallet watch`.
+    WatchOnly,
     None,
 }
 
@@ -36,6 +263,9 @@ impl KeySource {
             Self::Flag => "--private-key flag",
             Self::EnvVar => "POLYMARKET_PRIVATE_KEY env var",
             Self::ConfigFile => "config file",
+            Self::Encrypted => "config file (encrypted)",
+            Self::Keyring => "OS keyring",
+            Self::WatchOnly => "watch-only (no key)",
             Self::None => "not configured",
         }
     }
@@ -50,11 +280,54 @@ pub fn config_path() -> Result<PathBuf> {
     Ok(config_dir()?.join("config.json"))
 }
 
+/// Slug-to-ID cache used when commands are given market slugs or URLs.
+pub fn market_cache_path() -> Result<PathBuf> {
+    Ok(config_dir()?.join("markets.json"))
+}
+
+/// Active markets the shell offers as completions, refreshed in the
+/// background when stale.
+pub fn market_index_path() -> Result<PathBuf> {
+    Ok(config_dir()?.join("market-index.json"))
+}
+
+/// Lines entered in the shell, kept across sessions.
+pub fn shell_history_path() -> Result<PathBuf> {
+    Ok(config_dir()?.join("shell-history.txt"))
+}
+
+/// Transactions sent by this CLI, for `tx pending`.
+pub fn sent_txs_path() -> Result<PathBuf> {
+    Ok(config_dir()?.join("sent-txs.json"))
+}
+
+/// State files for managed orders (`clob twap`, ...), kept so a stopped run
+/// can be resumed.
+pub fn algo_state_dir() -> Result<PathBuf> {
+    Ok(config_dir()?.join("algos"))
+}
+
 pub fn config_exists() -> bool {
     config_path().is_ok_and(|p| p.exists())
 }
 
 pub fn delete_config() -> Result<()> {
+    // A config file that doesn't parse is removed all the same; only the
+    // keyring entries it names are left behind.
+    if let Ok(Some(config)) = load_config() {
+        for (name, profile) in config.all_profiles() {
+            if profile.keyring_address.is_some() {
+                let _ = keyring::delete(&keyring::key_account(name));
+            }
+            if profile
+                .api_credentials
+                .as_ref()
+                .is_some_and(|c| c.secret.is_none())
+            {
+                let _ = keyring::delete(&keyring::api_account(name));
+            }
+        }
+    }
     let dir = config_dir()?;
     if dir.exists() {
         fs::remove_dir_all(&dir).context("Failed to remove config directory")?;
@@ -62,13 +335,80 @@ pub fn delete_config() -> Result<()> {
     Ok(())
 }
 
-pub fn load_config() -> Option<Config> {
-    let path = config_path().ok()?;
-    let data = fs::read_to_string(path).ok()?;
-    serde_json::from_str(&data).ok()
+/// The config file, or `None` when there isn't one yet. A file that can't be
+/// read or parsed is an error, so nothing overwrites it with defaults.
+pub fn load_config() -> Result<Option<Config>> {
+    let path = config_path()?;
+    let data = match fs::read_to_string(&path) {
+        Ok(data) => data,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(e).with_context(|| format!("Failed to read {}", path.display()));
+        }
+    };
+    let config = serde_json::from_str(&data).with_context(|| {
+        format!(
+            "{} is not a valid config file; fix it or move it aside",
+            path.display()
+        )
+    })?;
+    Ok(Some(config))
+}
+
+/// The config file as far as settings go: one that is missing or unreadable
+/// leaves every setting at its default.
+fn settings() -> Option<Config> {
+    load_config().ok().flatten()
+}
+
+/// Picks the profile for the command about to run (the `--profile` flag).
+pub fn select_profile(name: Option<&str>) {
+    *SELECTED_PROFILE.lock().unwrap_or_else(|e| e.into_inner()) = name.map(str::to_string);
+}
+
+/// Priority: `--profile` > env var > `wallet use` > "default".
+pub fn active_profile() -> String {
+    active_profile_in(settings().as_ref())
+}
+
+fn active_profile_in(config: Option<&Config>) -> String {
+    if let Some(name) = SELECTED_PROFILE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+    {
+        return name;
+    }
+    if let Ok(name) = std::env::var(PROFILE_ENV_VAR)
+        && !name.is_empty()
+    {
+        return name;
+    }
+    config
+        .and_then(|c| c.active_profile.clone())
+        .unwrap_or_else(|| DEFAULT_PROFILE.to_string())
+}
+
+/// Settings of the active profile, if the config file has it.
+fn active_settings() -> Option<Profile> {
+    let config = settings()?;
+    config.profile(&active_profile_in(Some(&config))).cloned()
+}
+
+/// Fails when a named profile is picked that the config file doesn't have.
+pub fn ensure_active_profile() -> Result<()> {
+    let config = settings();
+    let name = active_profile_in(config.as_ref());
+    if name != DEFAULT_PROFILE && config.as_ref().and_then(|c| c.profile(&name)).is_none() {
+        anyhow::bail!(
+            "No profile named '{name}'. Create it with `polymarket wallet create --profile {name}` \
+             or see `polymarket wallet list`"
+        );
+    }
+    Ok(())
 }
 
-/// Priority: CLI flag > env var > config file > default ("proxy").
+/// Priority: CLI flag > env var > profile > default ("proxy").
 pub fn resolve_signature_type(cli_flag: Option<&str>) -> String {
     if let Some(st) = cli_flag {
         return st.to_string();
@@ -78,13 +418,306 @@ pub fn resolve_signature_type(cli_flag: Option<&str>) -> String {
     {
         return st;
     }
-    if let Some(config) = load_config() {
-        return config.signature_type;
+    active_settings()
+        .and_then(|p| p.signature_type)
+        .unwrap_or_else(|| DEFAULT_SIGNATURE_TYPE.to_string())
+}
+
+/// A chain Polymarket's contracts are deployed on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Chain {
+    /// Polygon mainnet
+    Polygon,
+    /// The Amoy testnet
+    Amoy,
+}
+
+impl Chain {
+    pub fn id(self) -> ChainId {
+        match self {
+            Chain::Polygon => POLYGON,
+            Chain::Amoy => AMOY,
+        }
+    }
+
+    /// The chain with `id`; anything unknown (including a config file
+    /// written before chains were selectable) is Polygon.
+    pub fn from_id(id: ChainId) -> Self {
+        if id == AMOY {
+            Chain::Amoy
+        } else {
+            Chain::Polygon
+        }
+    }
+}
+
+/// Picks the chain for the command about to run (the `--chain` flag).
+pub fn select_chain(chain: Option<Chain>) {
+    *SELECTED_CHAIN.lock().unwrap_or_else(|e| e.into_inner()) = chain;
+}
+
+/// Priority: `--chain` > `config set chain` > Polygon.
+pub fn chain() -> Chain {
+    let selected = *SELECTED_CHAIN.lock().unwrap_or_else(|e| e.into_inner());
+    selected
+        .or_else(|| settings().map(|c| Chain::from_id(c.chain_id)))
+        .unwrap_or(Chain::Polygon)
+}
+
+/// Id of the chain orders are signed for and transactions sent to.
+pub fn chain_id() -> ChainId {
+    chain().id()
+}
+
+/// Picks the RPC endpoints for the command about to run (the `--rpc-url` flag).
+pub fn select_rpc_urls(urls: &[String]) {
+    *SELECTED_RPC_URLS.lock().unwrap_or_else(|e| e.into_inner()) = urls.to_vec();
+}
+
+/// Polygon RPC endpoints in the order to try them: `--rpc-url`, else the
+/// active profile's. Empty when neither sets any.
+pub fn rpc_urls() -> Vec<String> {
+    let selected = selected_rpc_urls();
+    if !selected.is_empty() {
+        return selected;
+    }
+    active_settings()
+        .and_then(|p| p.rpc_url)
+        .map(|urls| split_urls(&urls))
+        .unwrap_or_default()
+}
+
+/// The endpoints given with `--rpc-url`, if any.
+pub fn selected_rpc_urls() -> Vec<String> {
+    SELECTED_RPC_URLS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// The endpoints in a comma-separated list.
+pub fn split_urls(urls: &str) -> Vec<String> {
+    urls.split(',')
+        .map(str::trim)
+        .filter(|u| !u.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Marks the command about to run as signing on a hardware wallet (the
+/// `--signer` flag).
+pub fn select_hardware_signer(selected: bool) {
+    HARDWARE_SIGNER.store(selected, Ordering::Relaxed);
+}
+
+/// Trades are confirmed unless the config file turns it off.
+pub fn confirm_trades() -> bool {
+    settings().and_then(|c| c.confirm_trades).unwrap_or(true)
+}
+
+/// Order total below which confirmation is skipped, if set.
+pub fn confirm_above() -> Option<Decimal> {
+    settings().and_then(|c| c.confirm_above)
+}
+
+/// Output format used when `-o` isn't given.
+pub fn default_output() -> OutputFormat {
+    settings()
+        .and_then(|c| c.output)
+        .unwrap_or(OutputFormat::Table)
+}
+
+/// Order type used when `--order-type` isn't given.
+pub fn default_order_type() -> CliOrderType {
+    settings()
+        .and_then(|c| c.order_type)
+        .unwrap_or(CliOrderType::Gtc)
+}
+
+/// The API endpoints set in the config file.
+pub fn endpoints() -> Endpoints {
+    settings().map(|c| c.endpoints).unwrap_or_default()
+}
+
+/// Whether to color output, as set in the config file.
+pub fn color() -> Option<ColorChoice> {
+    settings().and_then(|c| c.color)
+}
+
+/// The shell aliases set in the config file.
+pub fn aliases() -> BTreeMap<String, String> {
+    settings().map(|c| c.aliases).unwrap_or_default()
+}
+
+/// Loads the config file (or starts a new one), lets `change` edit it along
+/// with the name of the active profile, and saves it.
+pub fn update_config(change: impl FnOnce(&mut Config, &str) -> Result<()>) -> Result<()> {
+    let mut config = load_config()?.unwrap_or_else(|| Config {
+        chain_id: POLYGON,
+        ..Config::default()
+    });
+    let name = active_profile_in(Some(&config));
+    change(&mut config, &name)?;
+    save_config(&config)
+}
+
+/// Saves a wallet into the active profile, creating the profile if needed.
+/// `rpc_url` replaces the profile's endpoint when given.
+pub fn save_wallet(
+    key: &str,
+    signature_type: &str,
+    rpc_url: Option<&str>,
+    store: KeyStore,
+) -> Result<()> {
+    let mut config = load_config()?.unwrap_or_default();
+    let name = active_profile_in(Some(&config));
+    let profile = config.profile_mut(&name);
+    profile.set_key(&name, key, store)?;
+    profile.signature_type = Some(signature_type.to_string());
+    if let Some(url) = rpc_url {
+        profile.rpc_url = Some(url.to_string());
+    }
+    if config.chain_id == 0 {
+        config.chain_id = POLYGON;
+    }
+    save_config(&config)
+}
+
+/// Makes the active profile a watch-only wallet for `address`, dropping any
+/// key it had. `rpc_url` replaces the profile's endpoint when given.
+pub fn save_watch_address(
+    address: Address,
+    signature_type: &str,
+    rpc_url: Option<&str>,
+) -> Result<()> {
+    let mut config = load_config()?.unwrap_or_default();
+    let name = active_profile_in(Some(&config));
+    let profile = config.profile_mut(&name);
+    if profile.keyring_address.is_some() {
+        let _ = keyring::delete(&keyring::key_account(&name));
+    }
+    profile.clear_api_credentials(&name);
+    *profile = Profile {
+        watch_address: Some(address),
+        signature_type: Some(signature_type.to_string()),
+        rpc_url: rpc_url.map(str::to_string).or(profile.rpc_url.take()),
+        ..Profile::default()
+    };
+    if config.chain_id == 0 {
+        config.chain_id = POLYGON;
+    }
+    save_config(&config)
+}
+
+/// Encrypts the active profile's plain-text key in place, returning its address.
+pub fn encrypt_wallet(passphrase: &str) -> Result<Address> {
+    let mut config = load_config()?.context(NO_WALLET_MSG)?;
+    let name = active_profile_in(Some(&config));
+    anyhow::ensure!(config.profile(&name).is_some(), "No profile named '{name}'");
+    let profile = config.profile_mut(&name);
+    if profile.encrypted_key.is_some() {
+        anyhow::bail!("The key of profile '{name}' is already encrypted");
+    }
+    let key = profile
+        .private_key
+        .clone()
+        .with_context(|| format!("Profile '{name}' has no plain-text key to encrypt"))?;
+    profile.set_key(&name, &key, KeyStore::Encrypted(passphrase))?;
+    let address = profile.address().context("Invalid private key")?;
+    save_config(&config)?;
+    Ok(address)
+}
+
+/// The active profile's cached CLOB API credentials, if they are for
+/// `address`, with the secret and passphrase filled in.
+pub fn api_credentials(address: Address) -> Option<ApiCredentials> {
+    let name = active_profile();
+    let profile = active_settings()?;
+    if profile.encrypted_key.is_some() {
+        return None;
+    }
+    let mut cached = profile.api_credentials?;
+    if cached.address != address {
+        return None;
+    }
+    if cached.secret.is_none() {
+        let stored = keyring::get(&keyring::api_account(&name)).ok()?;
+        let (secret, passphrase): (String, String) = serde_json::from_str(&stored).ok()?;
+        cached.secret = Some(secret);
+        cached.passphrase = Some(passphrase);
     }
-    DEFAULT_SIGNATURE_TYPE.to_string()
+    Some(cached)
 }
 
-pub fn save_wallet(key: &str, chain_id: u64, signature_type: &str) -> Result<()> {
+/// Caches CLOB API credentials in the active profile, or in the OS keyring
+/// when that is where the profile keeps its key. Nothing is cached for a
+/// wallet the profile doesn't hold, e.g. one given with `--private-key`, or
+/// for an encrypted key, whose secret would otherwise sit in plain text.
+pub fn save_api_credentials(credentials: ApiCredentials) -> Result<()> {
+    let Some(mut config) = load_config()? else {
+        return Ok(());
+    };
+    let name = active_profile_in(Some(&config));
+    if config.profile(&name).and_then(Profile::address) != Some(credentials.address) {
+        return Ok(());
+    }
+    let profile = config.profile_mut(&name);
+    if profile.encrypted_key.is_some() {
+        if profile.api_credentials.is_none() {
+            return Ok(());
+        }
+        // Left over from before the key was encrypted.
+        profile.clear_api_credentials(&name);
+        return save_config(&config);
+    }
+    let stored = if profile.keyring_address.is_some() {
+        let secrets = (&credentials.secret, &credentials.passphrase);
+        keyring::set(
+            &keyring::api_account(&name),
+            &serde_json::to_string(&secrets)?,
+        )?;
+        ApiCredentials {
+            secret: None,
+            passphrase: None,
+            ..credentials
+        }
+    } else {
+        credentials
+    };
+    profile.api_credentials = Some(stored);
+    save_config(&config)
+}
+
+/// Forgets the active profile's cached CLOB API credentials, e.g. after the
+/// key is deleted.
+pub fn clear_api_credentials() -> Result<()> {
+    let Some(mut config) = load_config()? else {
+        return Ok(());
+    };
+    let name = active_profile_in(Some(&config));
+    if config
+        .profile(&name)
+        .is_none_or(|p| p.api_credentials.is_none())
+    {
+        return Ok(());
+    }
+    config.profile_mut(&name).clear_api_credentials(&name);
+    save_config(&config)
+}
+
+/// Makes `name` the profile used when none is picked.
+pub fn use_profile(name: &str) -> Result<()> {
+    let mut config =
+        load_config()?.context("No config file. Run `polymarket wallet create` first")?;
+    anyhow::ensure!(
+        config.profile(name).is_some(),
+        "No profile named '{name}'. See `polymarket wallet list`"
+    );
+    config.active_profile = (name != DEFAULT_PROFILE).then(|| name.to_string());
+    save_config(&config)
+}
+
+fn save_config(config: &Config) -> Result<()> {
     let dir = config_dir()?;
     fs::create_dir_all(&dir).context("Failed to create config directory")?;
 
@@ -94,12 +727,7 @@ pub fn save_wallet(key: &str, chain_id: u64, signature_type: &str) -> Result<()>
         fs::set_permissions(&dir, fs::Permissions::from_mode(0o700))?;
     }
 
-    let config = Config {
-        private_key: key.to_string(),
-        chain_id,
-        signature_type: signature_type.to_string(),
-    };
-    let json = serde_json::to_string_pretty(&config)?;
+    let json = serde_json::to_string_pretty(config)?;
     let path = config_path()?;
 
     #[cfg(unix)]
@@ -125,7 +753,7 @@ pub fn save_wallet(key: &str, chain_id: u64, signature_type: &str) -> Result<()>
     Ok(())
 }
 
-/// Priority: CLI flag > env var > config file.
+/// Priority: CLI flag > env var > profile in the config file.
 pub fn resolve_key(cli_flag: Option<&str>) -> (Option<String>, KeySource) {
     if let Some(key) = cli_flag {
         return (Some(key.to_string()), KeySource::Flag);
@@ -135,10 +763,79 @@ pub fn resolve_key(cli_flag: Option<&str>) -> (Option<String>, KeySource) {
     {
         return (Some(key), KeySource::EnvVar);
     }
-    if let Some(config) = load_config() {
-        return (Some(config.private_key), KeySource::ConfigFile);
+    match active_settings() {
+        Some(Profile {
+            private_key: Some(key),
+            ..
+        }) => (Some(key), KeySource::ConfigFile),
+        Some(Profile {
+            encrypted_key: Some(_),
+            ..
+        }) => (None, KeySource::Encrypted),
+        Some(Profile {
+            keyring_address: Some(_),
+            ..
+        }) => (None, KeySource::Keyring),
+        Some(Profile {
+            watch_address: Some(_),
+            ..
+        }) => (None, KeySource::WatchOnly),
+        _ => (None, KeySource::None),
+    }
+}
+
+/// The wallet address and where its key comes from, without unlocking the
+/// key.
+pub fn resolve_address(cli_flag: Option<&str>) -> (Option<Address>, KeySource) {
+    let (key, source) = resolve_key(cli_flag);
+    let address = match &source {
+        KeySource::Encrypted | KeySource::Keyring | KeySource::WatchOnly => {
+            active_settings().and_then(|p| p.address())
+        }
+        _ => key
+            .as_deref()
+            .and_then(|k| LocalSigner::from_str(k).ok())
+            .map(|s| s.address()),
+    };
+    (address, source)
+}
+
+/// The key to sign with, unlocking an encrypted one with its passphrase or
+/// reading it from the OS keyring.
+pub fn signing_key(cli_flag: Option<&str>) -> Result<String> {
+    anyhow::ensure!(
+        !HARDWARE_SIGNER.load(Ordering::Relaxed),
+        "This command needs a private key and can't sign on a Ledger; run it without --signer"
+    );
+    match resolve_key(cli_flag) {
+        (Some(key), _) => Ok(key),
+        (None, source @ (KeySource::Encrypted | KeySource::Keyring)) => {
+            let name = active_profile();
+            let mut unlocked = UNLOCKED.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some((profile, key)) = unlocked.as_ref()
+                && *profile == name
+            {
+                return Ok(key.clone());
+            }
+            let key = if matches!(source, KeySource::Keyring) {
+                keyring::get(&keyring::key_account(&name))?
+            } else {
+                let encrypted = active_settings()
+                    .and_then(|p| p.encrypted_key)
+                    .context(NO_WALLET_MSG)?;
+                let passphrase = secret::passphrase(&format!("Passphrase for profile '{name}': "))?;
+                secret::decrypt(&encrypted, &passphrase)?
+            };
+            *unlocked = Some((name, key.clone()));
+            Ok(key)
+        }
+        (None, KeySource::WatchOnly) => anyhow::bail!(
+            "Profile '{}' is a watch-only wallet with no key to sign with. \
+             Run `polymarket wallet import <key> --force` to add one",
+            active_profile()
+        ),
+        (None, _) => anyhow::bail!("{NO_WALLET_MSG}"),
     }
-    (None, KeySource::None)
 }
 
 #[cfg(test)]
@@ -202,6 +899,106 @@ mod tests {
         unsafe { unset(SIG_TYPE_ENV_VAR) };
     }
 
+    #[test]
+    fn old_config_files_load_as_the_default_profile() {
+        let config: Config = serde_json::from_str(
+            r#"{"private_key":"0xabc","chain_id":137,"signature_type":"eoa"}"#,
+        )
+        .unwrap();
+        let default = config.profile(DEFAULT_PROFILE).unwrap();
+        assert_eq!(default.private_key.as_deref(), Some("0xabc"));
+        assert_eq!(default.signature_type.as_deref(), Some("eoa"));
+        assert!(config.profile("cold").is_none());
+    }
+
+    #[test]
+    fn named_profiles_round_trip_beside_the_default_one() {
+        let mut config = Config {
+            chain_id: 137,
+            ..Config::default()
+        };
+        config.profile_mut(DEFAULT_PROFILE).private_key = Some("0x1".into());
+        config.profile_mut("cold").rpc_url = Some("https://rpc.example".into());
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["private_key"], "0x1");
+        assert_eq!(json["profiles"]["cold"]["rpc_url"], "https://rpc.example");
+
+        let loaded: Config = serde_json::from_value(json).unwrap();
+        let names: Vec<_> = loaded.all_profiles().map(|(name, _)| name).collect();
+        assert_eq!(names, ["default", "cold"]);
+    }
+
+    #[test]
+    fn encrypted_profiles_keep_their_address_readable() {
+        let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+        let mut plain = Profile::default();
+        plain.set_key("test", key, KeyStore::Plain).unwrap();
+        let mut encrypted = Profile::default();
+        encrypted
+            .set_key("test", key, KeyStore::Encrypted("hunter2"))
+            .unwrap();
+        assert!(encrypted.private_key.is_none());
+        assert!(encrypted.has_wallet());
+        assert_eq!(encrypted.address(), plain.address());
+    }
+
+    #[test]
+    fn api_credentials_are_dropped_with_their_wallet() {
+        let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+        let mut profile = Profile::default();
+        profile.set_key("test", key, KeyStore::Plain).unwrap();
+        profile.api_credentials = Some(ApiCredentials {
+            address: profile.address().unwrap(),
+            key: "00000000-0000-0000-0000-000000000000".into(),
+            nonce: 0,
+            secret: Some("secret".into()),
+            passphrase: Some("passphrase".into()),
+        });
+
+        profile.set_key("test", key, KeyStore::Plain).unwrap();
+        assert!(profile.api_credentials.is_some());
+        let other = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        profile.set_key("test", other, KeyStore::Plain).unwrap();
+        assert!(profile.api_credentials.is_none());
+    }
+
+    #[test]
+    fn encrypting_a_key_drops_its_cached_api_secret() {
+        let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+        let mut profile = Profile::default();
+        profile.set_key("test", key, KeyStore::Plain).unwrap();
+        profile.api_credentials = Some(ApiCredentials {
+            address: profile.address().unwrap(),
+            key: "00000000-0000-0000-0000-000000000000".into(),
+            nonce: 0,
+            secret: Some("secret".into()),
+            passphrase: Some("passphrase".into()),
+        });
+
+        profile
+            .set_key("test", key, KeyStore::Encrypted("hunter2"))
+            .unwrap();
+        assert!(profile.api_credentials.is_none());
+    }
+
+    #[test]
+    fn profile_flag_overrides_env_and_config() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        let config = Config {
+            active_profile: Some("trading".into()),
+            ..Config::default()
+        };
+        unsafe { unset(PROFILE_ENV_VAR) };
+        assert_eq!(active_profile_in(Some(&config)), "trading");
+        assert_eq!(active_profile_in(None), DEFAULT_PROFILE);
+        unsafe { set(PROFILE_ENV_VAR, "cold") };
+        assert_eq!(active_profile_in(Some(&config)), "cold");
+        select_profile(Some("hot"));
+        assert_eq!(active_profile_in(Some(&config)), "hot");
+        select_profile(None);
+        unsafe { unset(PROFILE_ENV_VAR) };
+    }
+
     #[test]
     fn resolve_sig_type_without_env_returns_nonempty() {
         let _lock = ENV_LOCK.lock().unwrap();
@@ -209,4 +1006,13 @@ mod tests {
         let result = resolve_signature_type(None);
         assert!(!result.is_empty());
     }
+
+    #[test]
+    fn rpc_url_lists_skip_blanks() {
+        assert_eq!(
+            split_urls(" https://a.example, ,https://b.example,"),
+            ["https://a.example", "https://b.example"]
+        );
+        assert!(split_urls("").is_empty());
+    }
 }
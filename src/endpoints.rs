@@ -0,0 +1,58 @@
+//! API clients for the endpoints set with `config set endpoints.<api>`,
+//! falling back to Polymarket's own when unset or unusable.
+
+use polymarket_client_sdk::{bridge, clob, data, gamma};
+
+use crate::config;
+
+pub fn clob() -> clob::Client {
+    config::endpoints()
+        .clob
+        .and_then(|url| clob::Client::new(&url, clob::Config::default()).ok())
+        .unwrap_or_default()
+}
+
+pub fn clob_ws() -> clob::ws::Client {
+    config::endpoints()
+        .clob_ws
+        .and_then(|url| {
+            clob::ws::Client::new(&url, polymarket_client_sdk::ws::config::Config::default()).ok()
+        })
+        .unwrap_or_default()
+}
+
+pub fn gamma() -> gamma::Client {
+    config::endpoints()
+        .gamma
+        .and_then(|url| gamma::Client::new(&url).ok())
+        .unwrap_or_default()
+}
+
+pub fn data() -> data::Client {
+    config::endpoints()
+        .data
+        .and_then(|url| data::Client::new(&url).ok())
+        .unwrap_or_default()
+}
+
+pub fn bridge() -> bridge::Client {
+    config::endpoints()
+        .bridge
+        .and_then(|url| bridge::Client::new(&url).ok())
+        .unwrap_or_default()
+}
+
+/// Whether `url` works as the endpoint of `api` (a key of
+/// [`config::Endpoints`]).
+pub fn check(api: &str, url: &str) -> bool {
+    match api {
+        "clob" => clob::Client::new(url, clob::Config::default()).is_ok(),
+        "clob_ws" => {
+            clob::ws::Client::new(url, polymarket_client_sdk::ws::config::Config::default()).is_ok()
+        }
+        "gamma" => gamma::Client::new(url).is_ok(),
+        "data" => data::Client::new(url).is_ok(),
+        "bridge" => bridge::Client::new(url).is_ok(),
+        _ => false,
+    }
+}
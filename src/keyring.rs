@@ -0,0 +1,40 @@
+//! Secrets in the OS keychain, kept under the `polymarket-cli` service: the
+//! login Keychain on macOS, the Credential Manager on Windows, and the Secret
+//! Service (GNOME Keyring, KWallet) on Linux.
+
+use ::keyring::Entry;
+use anyhow::{Context, Result};
+
+const SERVICE: &str = "polymarket-cli";
+
+/// Keychain account holding a profile's private key.
+pub fn key_account(profile: &str) -> String {
+    format!("{profile}/private-key")
+}
+
+/// Keychain account holding a profile's CLOB API secret and passphrase.
+pub fn api_account(profile: &str) -> String {
+    format!("{profile}/clob-api")
+}
+
+fn entry(account: &str) -> Result<Entry> {
+    Entry::new(SERVICE, account).context("Could not reach the OS keyring")
+}
+
+pub fn set(account: &str, secret: &str) -> Result<()> {
+    entry(account)?
+        .set_password(secret)
+        .with_context(|| format!("Could not store '{account}' in the OS keyring"))
+}
+
+pub fn get(account: &str) -> Result<String> {
+    entry(account)?
+        .get_password()
+        .with_context(|| format!("No '{account}' in the OS keyring"))
+}
+
+pub fn delete(account: &str) -> Result<()> {
+    entry(account)?
+        .delete_credential()
+        .with_context(|| format!("Could not remove '{account}' from the OS keyring"))
+}
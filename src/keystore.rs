@@ -0,0 +1,326 @@
+//! Web3 Secret Storage (geth keystore v3) files, for moving keys to and from
+//! other wallets. Exports use PBKDF2; imports also read scrypt, which geth,
+//! MetaMask, and most wallets write by default.
+
+use std::num::NonZeroU32;
+
+use alloy::hex;
+use alloy::primitives::keccak256;
+use anyhow::{Context, Result, bail};
+use aws_lc_rs::cipher::{AES_128, EncryptingKey, EncryptionContext, UnboundCipherKey};
+use aws_lc_rs::iv::FixedLength;
+use aws_lc_rs::{pbkdf2, rand};
+use polymarket_client_sdk::types::Address;
+use serde::{Deserialize, Serialize};
+
+/// What geth uses for its "light" PBKDF2 keystores.
+const PBKDF2_ROUNDS: u32 = 262_144;
+const DKLEN: usize = 32;
+/// Refuses scrypt parameters needing more memory than this.
+const MAX_SCRYPT_MEMORY: u64 = 1 << 30;
+
+#[derive(Serialize, Deserialize)]
+struct Keystore {
+    version: u32,
+    id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+    #[serde(alias = "Crypto")]
+    crypto: Crypto,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Crypto {
+    cipher: String,
+    cipherparams: CipherParams,
+    ciphertext: String,
+    kdf: String,
+    kdfparams: serde_json::Value,
+    mac: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CipherParams {
+    iv: String,
+}
+
+#[derive(Deserialize)]
+struct ScryptParams {
+    dklen: usize,
+    n: u64,
+    r: u32,
+    p: u32,
+    salt: String,
+}
+
+#[derive(Deserialize)]
+struct Pbkdf2Params {
+    c: u32,
+    dklen: usize,
+    prf: String,
+    salt: String,
+}
+
+fn random<const N: usize>() -> Result<[u8; N]> {
+    let mut bytes = [0u8; N];
+    rand::fill(&mut bytes).map_err(|_| anyhow::anyhow!("Failed to generate random bytes"))?;
+    Ok(bytes)
+}
+
+fn pbkdf2_sha256(password: &[u8], salt: &[u8], rounds: u32, out: &mut [u8]) -> Result<()> {
+    let rounds = NonZeroU32::new(rounds).context("Invalid keystore: zero PBKDF2 rounds")?;
+    pbkdf2::derive(pbkdf2::PBKDF2_HMAC_SHA256, rounds, salt, password, out);
+    Ok(())
+}
+
+/// AES-128-CTR, which is its own inverse.
+fn aes_ctr(key: &[u8], iv: &[u8], data: &mut [u8]) -> Result<()> {
+    let iv: [u8; 16] = iv
+        .try_into()
+        .context("Invalid keystore: IV must be 16 bytes")?;
+    let key = UnboundCipherKey::new(&AES_128, key)
+        .and_then(EncryptingKey::ctr)
+        .map_err(|_| anyhow::anyhow!("Failed to set up AES-128-CTR"))?;
+    key.less_safe_encrypt(data, EncryptionContext::Iv128(FixedLength::from(iv)))
+        .map_err(|_| anyhow::anyhow!("AES-128-CTR failed"))?;
+    Ok(())
+}
+
+fn mac(derived: &[u8], ciphertext: &[u8]) -> [u8; 32] {
+    keccak256([&derived[16..32], ciphertext].concat()).0
+}
+
+/// A keystore JSON holding `key` (0x-prefixed hex), locked with `password`.
+pub fn encrypt(key: &str, address: Address, password: &str) -> Result<String> {
+    let mut data = hex::decode(key).context("Invalid private key")?;
+    let salt: [u8; 32] = random()?;
+    let iv: [u8; 16] = random()?;
+    let mut derived = [0u8; DKLEN];
+    pbkdf2_sha256(password.as_bytes(), &salt, PBKDF2_ROUNDS, &mut derived)?;
+    aes_ctr(&derived[..16], &iv, &mut data)?;
+
+    let mut id: [u8; 16] = random()?;
+    id[6] = (id[6] & 0x0f) | 0x40;
+    id[8] = (id[8] & 0x3f) | 0x80;
+    let id = hex::encode(id);
+    let keystore = Keystore {
+        version: 3,
+        id: format!(
+            "{}-{}-{}-{}-{}",
+            &id[..8],
+            &id[8..12],
+            &id[12..16],
+            &id[16..20],
+            &id[20..]
+        ),
+        address: Some(hex::encode(address)),
+        crypto: Crypto {
+            cipher: "aes-128-ctr".to_string(),
+            cipherparams: CipherParams {
+                iv: hex::encode(iv),
+            },
+            ciphertext: hex::encode(&data),
+            kdf: "pbkdf2".to_string(),
+            kdfparams: serde_json::json!({
+                "c": PBKDF2_ROUNDS,
+                "dklen": DKLEN,
+                "prf": "hmac-sha256",
+                "salt": hex::encode(salt),
+            }),
+            mac: hex::encode(mac(&derived, &data)),
+        },
+    };
+    Ok(serde_json::to_string_pretty(&keystore)?)
+}
+
+/// The 0x-prefixed private key in keystore `json`.
+pub fn decrypt(json: &str, password: &str) -> Result<String> {
+    let keystore: Keystore = serde_json::from_str(json).context("Not a keystore file")?;
+    if keystore.version != 3 {
+        bail!("Only version 3 keystores are supported");
+    }
+    let crypto = keystore.crypto;
+    if crypto.cipher != "aes-128-ctr" {
+        bail!("Unsupported keystore cipher '{}'", crypto.cipher);
+    }
+
+    let derived = match crypto.kdf.as_str() {
+        "scrypt" => {
+            let params: ScryptParams = serde_json::from_value(crypto.kdfparams)
+                .context("Invalid keystore: bad scrypt parameters")?;
+            let salt = hex::decode(&params.salt).context("Invalid keystore: bad salt")?;
+            scrypt(
+                password.as_bytes(),
+                &salt,
+                params.n,
+                params.r,
+                params.p,
+                params.dklen,
+            )?
+        }
+        "pbkdf2" => {
+            let params: Pbkdf2Params = serde_json::from_value(crypto.kdfparams)
+                .context("Invalid keystore: bad PBKDF2 parameters")?;
+            if params.prf != "hmac-sha256" {
+                bail!("Unsupported keystore PRF '{}'", params.prf);
+            }
+            let salt = hex::decode(&params.salt).context("Invalid keystore: bad salt")?;
+            let mut derived = vec![0u8; params.dklen];
+            pbkdf2_sha256(password.as_bytes(), &salt, params.c, &mut derived)?;
+            derived
+        }
+        other => bail!("Unsupported keystore KDF '{other}'"),
+    };
+    if derived.len() < DKLEN {
+        bail!("Invalid keystore: derived key shorter than {DKLEN} bytes");
+    }
+
+    let mut data = hex::decode(&crypto.ciphertext).context("Invalid keystore: bad ciphertext")?;
+    let expected = hex::decode(&crypto.mac).context("Invalid keystore: bad MAC")?;
+    if mac(&derived, &data)[..] != expected[..] {
+        bail!("Wrong keystore password");
+    }
+    let iv = hex::decode(&crypto.cipherparams.iv).context("Invalid keystore: bad IV")?;
+    aes_ctr(&derived[..16], &iv, &mut data)?;
+    Ok(format!("0x{}", hex::encode(data)))
+}
+
+/// scrypt (RFC 7914).
+fn scrypt(password: &[u8], salt: &[u8], n: u64, r: u32, p: u32, dklen: usize) -> Result<Vec<u8>> {
+    if n < 2 || !n.is_power_of_two() || n > u64::from(u32::MAX) || r == 0 || p == 0 {
+        bail!("Invalid keystore: bad scrypt parameters");
+    }
+    if 128 * u64::from(r) * n > MAX_SCRYPT_MEMORY {
+        bail!("Keystore scrypt parameters need more than 1 GiB of memory");
+    }
+    let block = 128 * r as usize;
+    let mut b = vec![0u8; block * p as usize];
+    pbkdf2_sha256(password, salt, 1, &mut b)?;
+    for chunk in b.chunks_mut(block) {
+        romix(chunk, n as usize, r as usize);
+    }
+    let mut out = vec![0u8; dklen];
+    pbkdf2_sha256(password, &b, 1, &mut out)?;
+    Ok(out)
+}
+
+fn romix(block: &mut [u8], n: usize, r: usize) {
+    let words = 32 * r;
+    let mut x: Vec<u32> = block
+        .chunks_exact(4)
+        .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+        .collect();
+    let mut v = vec![0u32; n * words];
+    let mut scratch = vec![0u32; words];
+    for i in 0..n {
+        v[i * words..(i + 1) * words].copy_from_slice(&x);
+        block_mix(&mut x, &mut scratch);
+    }
+    for _ in 0..n {
+        let j = x[(2 * r - 1) * 16] as usize & (n - 1);
+        for (x, v) in x.iter_mut().zip(&v[j * words..(j + 1) * words]) {
+            *x ^= v;
+        }
+        block_mix(&mut x, &mut scratch);
+    }
+    for (chunk, word) in block.chunks_exact_mut(4).zip(&x) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+}
+
+fn block_mix(b: &mut [u32], y: &mut [u32]) {
+    let blocks = b.len() / 16;
+    let mut x = [0u32; 16];
+    x.copy_from_slice(&b[(blocks - 1) * 16..]);
+    for i in 0..blocks {
+        for (x, b) in x.iter_mut().zip(&b[i * 16..(i + 1) * 16]) {
+            *x ^= b;
+        }
+        salsa20_8(&mut x);
+        // Even blocks go to the first half, odd ones to the second.
+        let to = (i / 2 + (i % 2) * blocks / 2) * 16;
+        y[to..to + 16].copy_from_slice(&x);
+    }
+    b.copy_from_slice(y);
+}
+
+fn salsa20_8(b: &mut [u32; 16]) {
+    fn quarter(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+        x[b] ^= x[a].wrapping_add(x[d]).rotate_left(7);
+        x[c] ^= x[b].wrapping_add(x[a]).rotate_left(9);
+        x[d] ^= x[c].wrapping_add(x[b]).rotate_left(13);
+        x[a] ^= x[d].wrapping_add(x[c]).rotate_left(18);
+    }
+    let mut x = *b;
+    for _ in 0..4 {
+        quarter(&mut x, 0, 4, 8, 12);
+        quarter(&mut x, 5, 9, 13, 1);
+        quarter(&mut x, 10, 14, 2, 6);
+        quarter(&mut x, 15, 3, 7, 11);
+        quarter(&mut x, 0, 1, 2, 3);
+        quarter(&mut x, 5, 6, 7, 4);
+        quarter(&mut x, 10, 11, 8, 9);
+        quarter(&mut x, 15, 12, 13, 14);
+    }
+    for (b, x) in b.iter_mut().zip(x) {
+        *b = b.wrapping_add(x);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scrypt_matches_rfc_7914() {
+        assert_eq!(
+            hex::encode(scrypt(b"", b"", 16, 1, 1, 64).unwrap()),
+            "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442\
+             fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906"
+        );
+        assert_eq!(
+            hex::encode(scrypt(b"password", b"NaCl", 1024, 8, 16, 64).unwrap()),
+            "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b373162\
+             2eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640"
+        );
+    }
+
+    #[test]
+    fn reads_the_web3_secret_storage_test_vector() {
+        let json = r#"{
+            "crypto": {
+                "cipher": "aes-128-ctr",
+                "cipherparams": {"iv": "6087dab2f9fdbbfaddc31a909735c1e6"},
+                "ciphertext": "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+                "kdf": "pbkdf2",
+                "kdfparams": {
+                    "c": 262144,
+                    "dklen": 32,
+                    "prf": "hmac-sha256",
+                    "salt": "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+                },
+                "mac": "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+            },
+            "id": "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+            "version": 3
+        }"#;
+        assert_eq!(
+            decrypt(json, "testpassword").unwrap(),
+            "0x7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d"
+        );
+        assert!(
+            decrypt(json, "wrong")
+                .unwrap_err()
+                .to_string()
+                .contains("Wrong")
+        );
+    }
+
+    #[test]
+    fn round_trips_an_exported_key() {
+        let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+        let json = encrypt(key, Address::ZERO, "hunter2").unwrap();
+        assert!(!json.contains(&key[2..]));
+        assert_eq!(decrypt(&json, "hunter2").unwrap(), key);
+    }
+}
@@ -0,0 +1,144 @@
+//! Signing on a Ledger, for `--signer ledger`. The Ethereum app signs
+//! EIP-712 typed data but refuses bare hashes, which is all the SDK hands a
+//! signer, so orders and CLOB logins are signed here from their structs.
+
+#![allow(clippy::exhaustive_structs, reason = "Generated by sol! macro")]
+
+use std::borrow::Cow;
+
+use alloy::signers::Signer as _;
+use alloy::signers::ledger::{HDPath, LedgerSigner};
+use alloy::sol;
+use alloy::sol_types::Eip712Domain;
+use alloy::transports::http::reqwest::{self, Method};
+use anyhow::{Context, Result, bail};
+use polymarket_client_sdk::auth::state::Authenticated;
+use polymarket_client_sdk::auth::{Credentials, Normal};
+use polymarket_client_sdk::clob;
+use polymarket_client_sdk::clob::types::{SignableOrder, SignedOrder};
+use polymarket_client_sdk::contract_config;
+use polymarket_client_sdk::types::{ChainId, U256};
+
+use crate::endpoints;
+
+sol! {
+    /// The message a CLOB login signs, as the SDK defines it.
+    struct ClobAuth {
+        address address;
+        string  timestamp;
+        uint256 nonce;
+        string  message;
+    }
+}
+
+const CLOB_AUTH_MESSAGE: &str = "This message attests that I control the given wallet";
+
+/// Connects to the first Ledger found, on the Ethereum app, at `path` or the
+/// first Ledger Live account.
+pub async fn connect(path: Option<&str>, chain_id: ChainId) -> Result<LedgerSigner> {
+    let path = path.map_or(HDPath::LedgerLive(0), |p| HDPath::Other(p.to_string()));
+    LedgerSigner::new(path, Some(chain_id))
+        .await
+        .context("Could not reach a Ledger; plug it in, unlock it, and open the Ethereum app")
+}
+
+/// Signs `order` the way [`clob::Client::sign`] does, on the device.
+pub async fn sign_order(
+    client: &clob::Client<Authenticated<Normal>>,
+    ledger: &LedgerSigner,
+    order: SignableOrder,
+) -> Result<SignedOrder> {
+    let chain_id = ledger.chain_id().context("The Ledger has no chain id")?;
+    let neg_risk = client.neg_risk(order.order.tokenId).await?.neg_risk;
+    let exchange = contract_config(chain_id, neg_risk)
+        .context("No contract config for this chain")?
+        .exchange;
+    let domain = Eip712Domain {
+        name: Some(Cow::Borrowed("Polymarket CTF Exchange")),
+        version: Some(Cow::Borrowed("1")),
+        chain_id: Some(U256::from(chain_id)),
+        verifying_contract: Some(exchange),
+        ..Eip712Domain::default()
+    };
+    eprintln!("Confirm the order on your Ledger...");
+    let signature = ledger
+        .sign_typed_data(&order.order, &domain)
+        .await
+        .context("The Ledger didn't sign the order")?;
+    Ok(SignedOrder::builder()
+        .order(order.order)
+        .signature(signature)
+        .order_type(order.order_type)
+        .owner(client.credentials().key())
+        .maybe_post_only(order.post_only)
+        .build())
+}
+
+/// Creates CLOB API credentials for the Ledger's address, or with `derive`
+/// looks up the ones it already has for `nonce`.
+async fn request_api_key(
+    ledger: &LedgerSigner,
+    nonce: Option<u32>,
+    derive: bool,
+) -> Result<Credentials> {
+    let nonce = nonce.unwrap_or(0);
+    let timestamp = chrono::Utc::now().timestamp().to_string();
+    let chain_id = ledger.chain_id().context("The Ledger has no chain id")?;
+    let auth = ClobAuth {
+        address: ledger.address(),
+        timestamp: timestamp.clone(),
+        nonce: U256::from(nonce),
+        message: CLOB_AUTH_MESSAGE.to_string(),
+    };
+    let domain = Eip712Domain {
+        name: Some(Cow::Borrowed("ClobAuthDomain")),
+        version: Some(Cow::Borrowed("1")),
+        chain_id: Some(U256::from(chain_id)),
+        ..Eip712Domain::default()
+    };
+    eprintln!("Confirm the Polymarket login on your Ledger...");
+    let signature = ledger
+        .sign_typed_data(&auth, &domain)
+        .await
+        .context("The Ledger didn't sign the login")?;
+
+    let (method, path) = if derive {
+        (Method::GET, "auth/derive-api-key")
+    } else {
+        (Method::POST, "auth/api-key")
+    };
+    let response = reqwest::Client::new()
+        .request(method, format!("{}{path}", endpoints::clob().host()))
+        .header("POLY_ADDRESS", format!("{:#x}", ledger.address()))
+        .header("POLY_NONCE", nonce.to_string())
+        .header("POLY_SIGNATURE", signature.to_string())
+        .header("POLY_TIMESTAMP", timestamp)
+        .send()
+        .await
+        .context("Failed to reach the CLOB API")?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        bail!("The CLOB API refused the Ledger's login ({status}): {body}");
+    }
+    response
+        .json()
+        .await
+        .context("Unexpected response from the CLOB API")
+}
+
+/// New CLOB API credentials for `nonce`.
+pub async fn create_api_key(ledger: &LedgerSigner, nonce: Option<u32>) -> Result<Credentials> {
+    request_api_key(ledger, nonce, false).await
+}
+
+/// The Ledger's CLOB API credentials for `nonce`, created if it has none.
+pub async fn create_or_derive_api_key(
+    ledger: &LedgerSigner,
+    nonce: Option<u32>,
+) -> Result<Credentials> {
+    match request_api_key(ledger, nonce, false).await {
+        Ok(credentials) => Ok(credentials),
+        Err(_) => request_api_key(ledger, nonce, true).await,
+    }
+}
@@ -1,9 +1,25 @@
+mod algo;
 mod auth;
 mod commands;
 mod config;
+mod endpoints;
+mod keyring;
+mod keystore;
+#[cfg(feature = "ledger")]
+mod ledger;
+mod mnemonic;
+mod order_file;
 mod output;
+mod poll;
+mod price_expr;
+mod qr;
+mod secret;
 mod shell;
+mod tui;
+mod tx;
+mod watch;
 
+use std::path::PathBuf;
 use std::process::ExitCode;
 
 use clap::{Parser, Subcommand};
@@ -15,23 +31,83 @@ pub(crate) struct Cli {
     #[command(subcommand)]
     command: Commands,
 
-    /// Output format: table or json
-    #[arg(short, long, global = true, default_value = "table")]
+    /// Output format: table, json, ndjson, or yaml (default: table, or `config set output`)
+    #[arg(short, long, global = true, value_enum, default_value_t = config::default_output())]
     pub(crate) output: OutputFormat,
 
+    /// Write structured output to a file (atomically) instead of stdout; `-` means stdout
+    #[arg(long, global = true, value_name = "PATH")]
+    output_file: Option<PathBuf>,
+
+    /// Print only the bare value for single-value commands (price, midpoint, address, ...)
+    #[arg(long, global = true)]
+    raw: bool,
+
+    /// JMESPath expression to filter/reshape the result (e.g. "[].question")
+    #[arg(long, global = true, value_name = "EXPR")]
+    query: Option<String>,
+
+    /// Only show these table columns, in this order (e.g. "side,price,size")
+    #[arg(long, global = true, value_delimiter = ',', value_name = "COLS")]
+    columns: Option<Vec<String>>,
+
+    /// Render results through a minijinja template (file path or inline text)
+    #[arg(long, global = true, value_name = "FILE_OR_TEMPLATE")]
+    template: Option<String>,
+
+    /// Color table output: auto, always, or never (also honors NO_COLOR)
+    #[arg(long, global = true, value_name = "WHEN")]
+    color: Option<output::color::ColorChoice>,
+
+    /// Never send long table output through $PAGER
+    #[arg(long, global = true)]
+    no_pager: bool,
+
+    /// Re-run a read-only command every N seconds, highlighting changes
+    #[arg(long, global = true, value_name = "SECONDS")]
+    watch: Option<u64>,
+
+    /// Build and sign orders and transactions, print them, and submit nothing
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    #[command(flatten)]
+    gas: tx::GasArgs,
+
+    /// Place orders and send CTF transactions without asking for confirmation
+    #[arg(short = 'y', long, global = true)]
+    yes: bool,
+
     /// Private key (overrides env var and config file)
     #[arg(long, global = true)]
     private_key: Option<String>,
 
+    /// Sign on a hardware wallet instead of with the stored key: ledger, or
+    /// ledger:<derivation path> (default: the first Ledger Live account)
+    #[arg(long, global = true, value_name = "SIGNER")]
+    signer: Option<String>,
+
     /// Signature type: eoa, proxy, or gnosis-safe
     #[arg(long, global = true)]
     signature_type: Option<String>,
+
+    /// Wallet profile to use (overrides POLYMARKET_PROFILE and `wallet use`)
+    #[arg(long, global = true, value_name = "NAME")]
+    profile: Option<String>,
+
+    /// Chain to trade on: polygon, or amoy for the testnet (default: polygon, or `config set chain`)
+    #[arg(long, global = true, value_enum)]
+    chain: Option<config::Chain>,
+
+    /// Polygon RPC endpoints, comma-separated; later ones are used when earlier ones fail
+    #[arg(long, global = true, value_delimiter = ',', value_name = "URLS")]
+    rpc_url: Vec<String>,
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Guided first-time setup (wallet, proxy, approvals)
-    Setup,
+    Setup(commands::setup::SetupArgs),
     /// Launch interactive shell
     Shell,
     /// Interact with markets
@@ -54,16 +130,34 @@ enum Commands {
     Clob(commands::clob::ClobArgs),
     /// CTF operations: split, merge, redeem positions
     Ctf(commands::ctf::CtfArgs),
+    /// List, speed up, or cancel your pending transactions
+    Tx(commands::tx::TxArgs),
+    /// Propose, sign, and execute Gnosis Safe transactions
+    Safe(commands::safe::SafeArgs),
     /// Query on-chain data (positions, trades, leaderboards)
     Data(commands::data::DataArgs),
-    /// Bridge assets from other chains to Polymarket
+    /// Browse markets interactively (full-screen)
+    Browse(commands::browse::BrowseArgs),
+    /// Full-screen live dashboard: orders, positions, balance, watchlist books
+    Dashboard(commands::dashboard::DashboardArgs),
+    /// Live portfolio view: positions marked to midpoint, unrealized PnL
+    Portfolio(commands::portfolio::PortfolioArgs),
+    /// Realized and unrealized profit and loss per market
+    Pnl(commands::pnl::PnlArgs),
+    /// Wallet activity history: trades, splits, merges, redemptions, rewards
+    Activity(commands::activity::ActivityArgs),
+    /// Print another wallet's new fills as they happen, optionally staging copies
+    Follow(commands::follow::FollowArgs),
+    /// Move assets between other chains and Polymarket
     Bridge(commands::bridge::BridgeArgs),
     /// Manage wallet and authentication
     Wallet(commands::wallet::WalletArgs),
+    /// Read and change settings (output format, order type, endpoints, ...)
+    Config(commands::config::ConfigArgs),
     /// Check API health status
     Status,
     /// Update to the latest version
-    Upgrade,
+    Upgrade(commands::upgrade::UpgradeArgs),
 }
 
 #[tokio::main]
@@ -71,90 +165,295 @@ async fn main() -> ExitCode {
     let cli = Cli::parse();
     let output = cli.output;
 
-    if let Err(e) = run(cli).await {
-        match output {
-            OutputFormat::Json => {
-                println!("{}", serde_json::json!({"error": e.to_string()}));
-            }
-            OutputFormat::Table => {
-                eprintln!("Error: {e}");
-            }
-        }
+    if let Err(e) = run(cli, &std::env::args().collect::<Vec<_>>()).await {
+        output::print_error(&e, output);
         return ExitCode::FAILURE;
     }
 
     ExitCode::SUCCESS
 }
 
+/// Runs a parsed command. `args` is the raw command line, used to re-run it for `--watch`.
+pub(crate) async fn run(mut cli: Cli, args: &[String]) -> anyhow::Result<()> {
+    config::select_profile(cli.profile.as_deref());
+    config::select_chain(cli.chain);
+    config::select_rpc_urls(&cli.rpc_url);
+    auth::select_signer(cli.signer.as_deref()).await?;
+    if !cli.command.manages_profiles() {
+        config::ensure_active_profile()?;
+    }
+    if let Some(interval) = cli.watch {
+        anyhow::ensure!(
+            cli.command.watchable(),
+            "--watch only works with read-only query commands"
+        );
+        anyhow::ensure!(interval > 0, "--watch interval must be at least 1 second");
+        output::color::configure(cli.color, config::color());
+        return watch::run(args, interval).await;
+    }
+    anyhow::ensure!(
+        !(cli.dry_run && cli.command.rejects_dry_run()),
+        "--dry-run is not supported for this command, so it was not run"
+    );
+    let output_file = cli.output_file.clone().filter(|p| p.as_os_str() != "-");
+    if output_file.is_some() && matches!(cli.output, OutputFormat::Table) && cli.template.is_none()
+    {
+        anyhow::bail!(
+            "--output-file requires a structured format (-o json, ndjson, or yaml) or --template"
+        );
+    }
+    if let Some(expr) = &cli.query {
+        output::query::validate(expr)?;
+    }
+    let template = cli
+        .template
+        .as_deref()
+        .map(output::template::load)
+        .transpose()?;
+    if let Some(source) = &template {
+        output::template::validate(source)?;
+    }
+    output::configure(output::OutputOptions {
+        format: cli.output,
+        output_file,
+        raw: cli.raw,
+        query: cli.query.clone(),
+        columns: cli.columns.clone(),
+        template,
+    });
+    // Decide on color before the pager replaces stdout with a pipe.
+    output::color::configure(cli.color, config::color());
+    let pager = (matches!(cli.output, OutputFormat::Table)
+        && cli.output_file.is_none()
+        && !cli.no_pager
+        && cli.command.pageable())
+    .then(output::pager::start)
+    .flatten();
+    // Queries and templates run against structured data; the output layer
+    // renders the result as a table again when table output was requested.
+    if cli.query.is_some() || cli.template.is_some() {
+        cli.output = OutputFormat::Json;
+    }
+    let result = dispatch(cli).await;
+    if let Some(pager) = pager {
+        pager.finish();
+    }
+    result?;
+    output::finish()
+}
+
+impl Commands {
+    /// Wallet commands create and switch profiles, so they run even when the
+    /// picked profile doesn't exist yet.
+    fn manages_profiles(&self) -> bool {
+        matches!(self, Self::Setup(_) | Self::Wallet(_))
+    }
+
+    /// Commands that change state without a way to preview it.
+    fn rejects_dry_run(&self) -> bool {
+        match self {
+            Self::Markets(_)
+            | Self::Events(_)
+            | Self::Tags(_)
+            | Self::Series(_)
+            | Self::Comments(_)
+            | Self::Profiles(_)
+            | Self::Sports(_)
+            | Self::Approve(_)
+            | Self::Ctf(_)
+            | Self::Tx(_)
+            | Self::Data(_)
+            | Self::Portfolio(_)
+            | Self::Pnl(_)
+            | Self::Activity(_)
+            | Self::Follow(_)
+            | Self::Bridge(_)
+            | Self::Status => false,
+            Self::Clob(args) => args.command.rejects_dry_run(),
+            Self::Safe(args) => args.command.rejects_dry_run(),
+            Self::Wallet(args) => args.command.rejects_dry_run(),
+            Self::Setup(_)
+            | Self::Shell
+            | Self::Browse(_)
+            | Self::Dashboard(_)
+            | Self::Config(_)
+            | Self::Upgrade(_) => true,
+        }
+    }
+
+    /// Read-only commands that can be re-run with `--watch`.
+    fn watchable(&self) -> bool {
+        match self {
+            Self::Events(_)
+            | Self::Tags(_)
+            | Self::Series(_)
+            | Self::Comments(_)
+            | Self::Profiles(_)
+            | Self::Sports(_)
+            | Self::Pnl(_)
+            | Self::Activity(_)
+            | Self::Status => true,
+            Self::Markets(args) => !args.command.follows(),
+            Self::Data(args) => !args.command.follows(),
+            Self::Clob(args) => args.command.watchable(),
+            Self::Tx(args) => args.command.is_read_only(),
+            Self::Portfolio(args) => !args.is_interactive(),
+            Self::Setup(_)
+            | Self::Shell
+            | Self::Approve(_)
+            | Self::Ctf(_)
+            | Self::Safe(_)
+            | Self::Bridge(_)
+            | Self::Browse(_)
+            | Self::Dashboard(_)
+            | Self::Follow(_)
+            | Self::Wallet(_)
+            | Self::Config(_)
+            | Self::Upgrade(_) => false,
+        }
+    }
+
+    /// Whether long output may go through the pager. Interactive, full-screen,
+    /// streaming, and transaction-sending commands write straight to the terminal.
+    fn pageable(&self) -> bool {
+        match self {
+            Self::Events(_)
+            | Self::Tags(_)
+            | Self::Series(_)
+            | Self::Comments(_)
+            | Self::Profiles(_)
+            | Self::Sports(_)
+            | Self::Pnl(_)
+            | Self::Activity(_)
+            | Self::Status => true,
+            Self::Markets(args) => !args.command.follows(),
+            Self::Data(args) => !args.command.follows(),
+            Self::Clob(args) => args.command.pageable(),
+            Self::Tx(args) => args.command.is_read_only(),
+            Self::Bridge(args) => args.command.pageable(),
+            Self::Portfolio(args) => !args.is_interactive(),
+            Self::Setup(_)
+            | Self::Shell
+            | Self::Approve(_)
+            | Self::Ctf(_)
+            | Self::Safe(_)
+            | Self::Browse(_)
+            | Self::Dashboard(_)
+            | Self::Follow(_)
+            | Self::Wallet(_)
+            | Self::Config(_)
+            | Self::Upgrade(_) => false,
+        }
+    }
+}
+
 #[allow(clippy::too_many_lines)]
-pub(crate) async fn run(cli: Cli) -> anyhow::Result<()> {
+async fn dispatch(cli: Cli) -> anyhow::Result<()> {
     match cli.command {
-        Commands::Setup => commands::setup::execute(),
+        Commands::Setup(args) => commands::setup::execute(&args),
         Commands::Shell => {
             Box::pin(shell::run_shell()).await;
             Ok(())
         }
         Commands::Markets(args) => {
-            commands::markets::execute(
-                &polymarket_client_sdk::gamma::Client::default(),
+            commands::markets::execute(&crate::endpoints::gamma(), args, cli.output).await
+        }
+        Commands::Events(args) => {
+            commands::events::execute(&crate::endpoints::gamma(), args, cli.output).await
+        }
+        Commands::Tags(args) => {
+            commands::tags::execute(&crate::endpoints::gamma(), args, cli.output).await
+        }
+        Commands::Series(args) => {
+            commands::series::execute(&crate::endpoints::gamma(), args, cli.output).await
+        }
+        Commands::Comments(args) => {
+            commands::comments::execute(&crate::endpoints::gamma(), args, cli.output).await
+        }
+        Commands::Profiles(args) => {
+            commands::profiles::execute(&crate::endpoints::gamma(), args, cli.output).await
+        }
+        Commands::Sports(args) => {
+            commands::sports::execute(&crate::endpoints::gamma(), args, cli.output).await
+        }
+        Commands::Approve(args) => {
+            commands::approve::execute(
                 args,
                 cli.output,
+                cli.private_key.as_deref(),
+                cli.dry_run,
+                !cli.yes && config::confirm_trades(),
+                &cli.gas,
             )
             .await
         }
-        Commands::Events(args) => {
-            commands::events::execute(
-                &polymarket_client_sdk::gamma::Client::default(),
+        Commands::Clob(args) => {
+            commands::clob::execute(
                 args,
                 cli.output,
+                cli.private_key.as_deref(),
+                cli.signature_type.as_deref(),
+                cli.dry_run,
+                !cli.yes && config::confirm_trades(),
             )
             .await
         }
-        Commands::Tags(args) => {
-            commands::tags::execute(
-                &polymarket_client_sdk::gamma::Client::default(),
+        Commands::Ctf(args) => {
+            commands::ctf::execute(
                 args,
                 cli.output,
+                cli.private_key.as_deref(),
+                cli.signature_type.as_deref(),
+                cli.dry_run,
+                !cli.yes && config::confirm_trades(),
+                &cli.gas,
             )
             .await
         }
-        Commands::Series(args) => {
-            commands::series::execute(
-                &polymarket_client_sdk::gamma::Client::default(),
+        Commands::Tx(args) => {
+            commands::tx::execute(
                 args,
                 cli.output,
+                cli.private_key.as_deref(),
+                cli.dry_run,
+                !cli.yes && config::confirm_trades(),
+                &cli.gas,
             )
             .await
         }
-        Commands::Comments(args) => {
-            commands::comments::execute(
-                &polymarket_client_sdk::gamma::Client::default(),
+        Commands::Safe(args) => {
+            commands::safe::execute(
                 args,
                 cli.output,
+                cli.private_key.as_deref(),
+                cli.dry_run,
+                !cli.yes && config::confirm_trades(),
+                &cli.gas,
             )
             .await
         }
-        Commands::Profiles(args) => {
-            commands::profiles::execute(
-                &polymarket_client_sdk::gamma::Client::default(),
+        Commands::Data(args) => {
+            commands::data::execute(&crate::endpoints::data(), args, cli.output).await
+        }
+        Commands::Browse(args) => {
+            commands::browse::execute(
+                &crate::endpoints::gamma(),
                 args,
-                cli.output,
+                cli.private_key.as_deref(),
+                cli.signature_type.as_deref(),
             )
             .await
         }
-        Commands::Sports(args) => {
-            commands::sports::execute(
-                &polymarket_client_sdk::gamma::Client::default(),
+        Commands::Dashboard(args) => {
+            commands::dashboard::execute(
                 args,
-                cli.output,
+                cli.private_key.as_deref(),
+                cli.signature_type.as_deref(),
             )
             .await
         }
-        Commands::Approve(args) => {
-            commands::approve::execute(args, cli.output, cli.private_key.as_deref()).await
-        }
-        Commands::Clob(args) => {
-            commands::clob::execute(
+        Commands::Portfolio(args) => {
+            commands::portfolio::execute(
                 args,
                 cli.output,
                 cli.private_key.as_deref(),
@@ -162,36 +461,56 @@ pub(crate) async fn run(cli: Cli) -> anyhow::Result<()> {
             )
             .await
         }
-        Commands::Ctf(args) => {
-            commands::ctf::execute(args, cli.output, cli.private_key.as_deref()).await
+        Commands::Pnl(args) => {
+            commands::pnl::execute(
+                args,
+                cli.output,
+                cli.private_key.as_deref(),
+                cli.signature_type.as_deref(),
+            )
+            .await
         }
-        Commands::Data(args) => {
-            commands::data::execute(
-                &polymarket_client_sdk::data::Client::default(),
+        Commands::Activity(args) => {
+            commands::activity::execute(
                 args,
                 cli.output,
+                cli.private_key.as_deref(),
+                cli.signature_type.as_deref(),
             )
             .await
         }
+        Commands::Follow(args) => commands::follow::execute(args, cli.output).await,
         Commands::Bridge(args) => {
             commands::bridge::execute(
-                &polymarket_client_sdk::bridge::Client::default(),
                 args,
                 cli.output,
+                cli.private_key.as_deref(),
+                cli.signature_type.as_deref(),
+                cli.dry_run,
+                !cli.yes && config::confirm_trades(),
+                &cli.gas,
             )
             .await
         }
         Commands::Wallet(args) => {
-            commands::wallet::execute(args, &cli.output, cli.private_key.as_deref())
+            commands::wallet::execute(
+                args,
+                &cli.output,
+                cli.private_key.as_deref(),
+                cli.signature_type.as_deref(),
+                cli.dry_run,
+                !cli.yes && config::confirm_trades(),
+                &cli.gas,
+            )
+            .await
         }
-        Commands::Upgrade => commands::upgrade::execute(),
+        Commands::Config(args) => commands::config::execute(args, &cli.output),
+        Commands::Upgrade(args) => commands::upgrade::execute(&args).await,
         Commands::Status => {
-            let status = polymarket_client_sdk::gamma::Client::default()
-                .status()
-                .await?;
+            let status = crate::endpoints::gamma().status().await?;
             match cli.output {
-                OutputFormat::Json => {
-                    println!("{}", serde_json::json!({"status": status}));
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                    output::print_json(&serde_json::json!({"status": status}))?;
                 }
                 OutputFormat::Table => {
                     println!("API Status: {status}");
@@ -0,0 +1,118 @@
+//! Private keys from BIP-39 seed phrases, derived along a BIP-32 path.
+//! Phrases are checked against the English word list and their checksum,
+//! so a mistyped word is caught rather than opening some other wallet.
+
+use alloy::signers::local::MnemonicBuilder;
+use alloy::signers::local::coins_bip39::{English, Wordlist as _};
+use anyhow::{Result, bail};
+
+const WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+/// Whether `input` looks like a seed phrase rather than a hex key.
+pub fn is_mnemonic(input: &str) -> bool {
+    input.split_whitespace().nth(1).is_some()
+}
+
+/// Ethereum's standard path for account `index`.
+pub fn default_path(index: u32) -> String {
+    format!("m/44'/60'/0'/0/{index}")
+}
+
+/// Joins the words with single spaces, checking each against the word list.
+/// Errors name a word by position only, so the phrase never reaches logs.
+fn normalize(phrase: &str) -> Result<String> {
+    let words: Vec<String> = phrase.split_whitespace().map(str::to_lowercase).collect();
+    if !WORD_COUNTS.contains(&words.len()) {
+        bail!(
+            "A seed phrase has 12, 15, 18, 21, or 24 words, not {}",
+            words.len()
+        );
+    }
+    if let Some(position) = words.iter().position(|w| English::get_index(w).is_err()) {
+        bail!(
+            "Word {} of the seed phrase isn't in the BIP-39 English word list",
+            position + 1
+        );
+    }
+    Ok(words.join(" "))
+}
+
+/// The 0x-prefixed private key at `path` for `phrase`.
+pub fn derive_key(phrase: &str, path: &str) -> Result<String> {
+    let phrase = normalize(phrase)?;
+    let path = path.trim();
+    let builder = MnemonicBuilder::<English>::default().derivation_path(path);
+    let (true, Ok(builder)) = (path.starts_with("m/"), builder) else {
+        bail!("Invalid derivation path '{path}'; use one like m/44'/60'/0'/0/0");
+    };
+    // Every word is known by now, so the checksum is all that can fail.
+    let Ok(signer) = builder.phrase(phrase).build() else {
+        bail!("The seed phrase's checksum doesn't match; check the words and their order");
+    };
+    Ok(signer.to_bytes().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use polymarket_client_sdk::auth::LocalSigner;
+
+    fn address(key: &str) -> String {
+        LocalSigner::from_str(key).unwrap().address().to_string()
+    }
+
+    #[test]
+    fn derives_the_well_known_test_accounts() {
+        let hardhat = "test test test test test test test test test test test junk";
+        assert_eq!(
+            address(&derive_key(hardhat, &default_path(0)).unwrap()),
+            "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+        );
+        assert_eq!(
+            address(&derive_key(hardhat, &default_path(1)).unwrap()),
+            "0x70997970C51812dc3A010C7d01b50e0d17dc79C8"
+        );
+        let abandon = "abandon abandon abandon abandon abandon abandon \
+                       abandon abandon abandon abandon abandon about";
+        assert_eq!(
+            address(&derive_key(abandon, "m/44'/60'/0'/0/0").unwrap()),
+            "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"
+        );
+    }
+
+    #[test]
+    fn accepts_hardened_segments_and_rejects_bad_paths() {
+        let hardhat = "test test test test test test test test test test test junk";
+        assert_eq!(
+            derive_key(hardhat, "m/44h/60h/0h/0/1").unwrap(),
+            derive_key(hardhat, &default_path(1)).unwrap()
+        );
+        assert!(derive_key(hardhat, "44'/60'").is_err());
+        assert!(derive_key(hardhat, "m/x").is_err());
+    }
+
+    #[test]
+    fn rejects_a_bad_checksum() {
+        // Every word is valid, but the last no longer matches the checksum.
+        let swapped = "test test test test test test test test test test test test";
+        let err = derive_key(swapped, &default_path(0)).unwrap_err();
+        assert!(err.to_string().contains("checksum"));
+        assert!(!err.to_string().contains("test"));
+    }
+
+    #[test]
+    fn rejects_words_outside_the_word_list() {
+        let typo = "test test test test test test test test test test test junkk";
+        let err = derive_key(typo, &default_path(0)).unwrap_err();
+        assert!(err.to_string().contains("Word 12"));
+    }
+
+    #[test]
+    fn rejects_phrases_of_the_wrong_length() {
+        assert!(derive_key("test test test", &default_path(0)).is_err());
+        assert!(is_mnemonic("test test test"));
+        assert!(!is_mnemonic("0xabc"));
+    }
+}
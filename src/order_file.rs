@@ -0,0 +1,170 @@
+//! Order files: lists of order specs that are staged for review before
+//! anything is signed, e.g. by `follow --mirror`, and posted in bulk by
+//! `clob post-orders --file`. Files are JSON, or CSV when the name ends in
+//! `.csv`.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use clap::ValueEnum;
+use polymarket_client_sdk::types::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::commands::clob::{CliOrderType, CliSide};
+use crate::output::write_atomic;
+
+fn default_order_type() -> CliOrderType {
+    CliOrderType::Gtc
+}
+
+/// One limit order, in the same terms as `clob create-order`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OrderSpec {
+    /// Token ID (numeric string)
+    pub token: String,
+    pub side: CliSide,
+    pub price: Decimal,
+    pub size: Decimal,
+    #[serde(rename = "type", default = "default_order_type")]
+    pub order_type: CliOrderType,
+    /// Unix timestamp after which a GTD order expires
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<i64>,
+    /// Where the order came from; ignored when posting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+fn is_csv(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"))
+}
+
+/// Parses CSV with a header row naming the columns: `token`, `side`,
+/// `price`, `size`, and optionally `type`, `expiration`, and `note`. Fields
+/// are split on commas; quoting is not supported.
+fn parse_csv(contents: &str) -> Result<Vec<OrderSpec>> {
+    let mut lines = contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let Some((_, header)) = lines.next() else {
+        return Ok(Vec::new());
+    };
+    let columns: Vec<String> = header
+        .split(',')
+        .map(|c| c.trim().to_ascii_lowercase())
+        .collect();
+    for required in ["token", "side", "price", "size"] {
+        if !columns.iter().any(|c| c == required) {
+            bail!("CSV header is missing the '{required}' column");
+        }
+    }
+
+    lines
+        .map(|(i, line)| {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let field = |name: &str| {
+                columns
+                    .iter()
+                    .position(|c| c == name)
+                    .and_then(|idx| fields.get(idx).copied())
+                    .filter(|f| !f.is_empty())
+            };
+            let parse = || -> Result<OrderSpec> {
+                let required = |name: &str| field(name).with_context(|| format!("no {name}"));
+                Ok(OrderSpec {
+                    token: required("token")?.to_string(),
+                    side: CliSide::from_str(required("side")?, true).map_err(anyhow::Error::msg)?,
+                    price: required("price")?.parse().context("invalid price")?,
+                    size: required("size")?.parse().context("invalid size")?,
+                    order_type: field("type")
+                        .map(|t| CliOrderType::from_str(t, true).map_err(anyhow::Error::msg))
+                        .transpose()?
+                        .unwrap_or_else(default_order_type),
+                    expiration: field("expiration")
+                        .map(|e| e.parse().context("invalid expiration"))
+                        .transpose()?,
+                    note: field("note").map(String::from),
+                })
+            };
+            parse().with_context(|| format!("line {}", i + 1))
+        })
+        .collect()
+}
+
+/// Reads an order file; a missing file is an empty list.
+pub fn load(path: &Path) -> Result<Vec<OrderSpec>> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) if is_csv(path) => {
+            parse_csv(&contents).with_context(|| format!("Invalid order file {}", path.display()))
+        }
+        Ok(contents) => serde_json::from_str(&contents)
+            .with_context(|| format!("Invalid order file {}", path.display())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e).with_context(|| format!("Failed to read order file {}", path.display())),
+    }
+}
+
+pub fn save(path: &Path, orders: &[OrderSpec]) -> Result<()> {
+    let json = serde_json::to_string_pretty(orders)?;
+    write_atomic(path, &format!("{json}\n"))
+        .with_context(|| format!("Failed to write order file {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn order_type_defaults_to_gtc() {
+        let orders: Vec<OrderSpec> = serde_json::from_str(
+            r#"[{"token": "123", "side": "buy", "price": "0.45", "size": "10"}]"#,
+        )
+        .unwrap();
+        assert_eq!(orders[0].order_type, CliOrderType::Gtc);
+        assert_eq!(orders[0].side, CliSide::Buy);
+        assert_eq!(orders[0].price, dec!(0.45));
+    }
+
+    #[test]
+    fn csv_columns_are_matched_by_header() {
+        let orders = parse_csv(
+            "side,token,price,size,type,expiration\n\
+             buy,123,0.45,10,,\n\
+             SELL,123,0.55,10,gtd,1700000000\n",
+        )
+        .unwrap();
+        assert_eq!(orders.len(), 2);
+        assert_eq!(orders[0].order_type, CliOrderType::Gtc);
+        assert_eq!(orders[1].side, CliSide::Sell);
+        assert_eq!(orders[1].order_type, CliOrderType::Gtd);
+        assert_eq!(orders[1].expiration, Some(1_700_000_000));
+    }
+
+    #[test]
+    fn csv_errors_name_the_line() {
+        let err = parse_csv("token,side,price,size\n123,buy,abc,10\n").unwrap_err();
+        assert!(format!("{err:#}").contains("line 2"));
+        assert!(parse_csv("token,side,size\n").is_err());
+    }
+
+    #[test]
+    fn save_then_load_round_trips() {
+        let path = std::env::temp_dir().join(format!("orders-{}.json", std::process::id()));
+        let orders = vec![OrderSpec {
+            token: "123".into(),
+            side: CliSide::Sell,
+            price: dec!(0.6),
+            size: dec!(5),
+            order_type: CliOrderType::Gtd,
+            expiration: Some(1_700_000_000),
+            note: None,
+        }];
+        save(&path, &orders).unwrap();
+        assert_eq!(load(&path).unwrap(), orders);
+        std::fs::remove_file(&path).unwrap();
+        assert!(load(&path).unwrap().is_empty());
+    }
+}
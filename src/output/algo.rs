@@ -0,0 +1,622 @@
+//! Progress reports for managed orders (`clob twap`, `clob stop`, ...):
+//! one line per decision as it happens, then a summary.
+
+use std::path::Path;
+
+use chrono::Local;
+use polymarket_client_sdk::clob::types::Side;
+use polymarket_client_sdk::types::Decimal;
+use serde_json::json;
+
+use super::{OutputFormat, color, print_json};
+use crate::algo::bracket::{Bracket, Outcome, TakeProfit};
+use crate::algo::guard::{Guard, GuardEvent, Trip};
+use crate::algo::iceberg::{IcebergEvent, IcebergState};
+use crate::algo::stop::{Decision, StopOrder};
+use crate::algo::trailing::{TrailEvent, TrailingStop};
+use crate::algo::twap::{Slice, TwapState};
+use crate::commands::clob::CliSide;
+
+fn average_price(shares: Decimal, usdc: Decimal) -> Option<Decimal> {
+    (!shares.is_zero()).then(|| (usdc / shares).round_dp(4))
+}
+
+pub fn format_interval(secs: u64) -> String {
+    match secs {
+        s if s >= 3600 && s % 3600 == 0 => format!("{}h", s / 3600),
+        s if s >= 60 && s % 60 == 0 => format!("{}m", s / 60),
+        s => format!("{s}s"),
+    }
+}
+
+fn side_label(state: &TwapState) -> String {
+    Side::from(state.side).to_string()
+}
+
+pub fn print_twap_start(state: &TwapState, state_path: &Path, output: &OutputFormat) {
+    if !matches!(output, OutputFormat::Table) {
+        return;
+    }
+    let limit = state
+        .price
+        .as_deref()
+        .map_or_else(String::new, |p| format!(", no worse than {p}"));
+    println!(
+        "TWAP: {} {} shares in {} slices every {}{limit}",
+        color::side(side_label(state)),
+        state.total,
+        state.slices,
+        format_interval(state.interval_secs),
+    );
+    println!(
+        "{}",
+        color::dim(format!(
+            "State: {}. Ctrl+C stops; resume with `polymarket clob twap --resume {}`.",
+            state_path.display(),
+            state_path.display()
+        ))
+    );
+}
+
+pub fn print_twap_slice(
+    state: &TwapState,
+    slice: &Slice,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    let filled = state.filled();
+    match output {
+        OutputFormat::Table => {
+            let progress = format!("[{}/{}]", slice.number, state.slices);
+            let detail = match &slice.error {
+                Some(error) => format!("failed: {error}"),
+                None => match average_price(slice.shares, slice.usdc) {
+                    Some(price) => format!(
+                        "{} {} @ {price}",
+                        color::side(side_label(state)),
+                        slice.shares
+                    ),
+                    None => "nothing filled".into(),
+                },
+            };
+            println!(
+                "{}  {progress:<9} {detail}  {}",
+                color::dim(slice.at.format("%H:%M:%S")),
+                color::dim(format!("filled {filled}/{}", state.total)),
+            );
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&json!({
+                "slice": slice.number,
+                "slices": state.slices,
+                "at": slice.at.to_rfc3339(),
+                "order_id": slice.order_id,
+                "shares": slice.shares.to_string(),
+                "usdc": slice.usdc.to_string(),
+                "avg_price": average_price(slice.shares, slice.usdc).map(|p| p.to_string()),
+                "error": slice.error,
+                "filled": filled.to_string(),
+                "total": state.total.to_string(),
+            }))?;
+        }
+    }
+    Ok(())
+}
+
+pub fn print_twap_finished(
+    state: &TwapState,
+    state_path: &Path,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    let (filled, usdc) = (state.filled(), state.usdc());
+    let status = if state.is_complete() {
+        "complete"
+    } else {
+        "stopped"
+    };
+    match output {
+        OutputFormat::Table => {
+            let average = average_price(filled, usdc)
+                .map_or_else(String::new, |p| format!(", average price {p}"));
+            println!(
+                "TWAP {status}: filled {filled} of {} shares for ${usdc}{average}.",
+                state.total
+            );
+            if !state.is_complete() {
+                println!(
+                    "Resume with `polymarket clob twap --resume {}`.",
+                    state_path.display()
+                );
+            }
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&json!({
+                "status": status,
+                "filled": filled.to_string(),
+                "total": state.total.to_string(),
+                "usdc": usdc.to_string(),
+                "avg_price": average_price(filled, usdc).map(|p| p.to_string()),
+                "slices_done": state.slices_done(),
+                "slices": state.slices,
+                "state_file": state_path.display().to_string(),
+            }))?;
+        }
+    }
+    Ok(())
+}
+
+pub fn print_iceberg_start(state: &IcebergState, state_path: &Path, output: &OutputFormat) {
+    if !matches!(output, OutputFormat::Table) {
+        return;
+    }
+    println!(
+        "Iceberg: {} {} shares at {}, showing {} at a time",
+        color::side(Side::from(state.side).to_string()),
+        state.total,
+        state.price,
+        state.display,
+    );
+    println!(
+        "{}",
+        color::dim(format!(
+            "State: {}. Ctrl+C cancels the visible order; resume with `polymarket clob iceberg --resume {}`.",
+            state_path.display(),
+            state_path.display()
+        ))
+    );
+}
+
+pub fn print_iceberg_event(
+    state: &IcebergState,
+    event: &IcebergEvent,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    let filled = state.filled();
+    let (kind, clip, error) = match event {
+        IcebergEvent::Posted(clip) => ("posted", Some(clip), None),
+        IcebergEvent::Matched(clip) => ("matched", Some(clip), None),
+        IcebergEvent::Filled(clip) => ("filled", Some(clip), None),
+        IcebergEvent::Closed(clip) => ("closed", Some(clip), None),
+        IcebergEvent::Failed(error) => ("failed", None, Some(error)),
+    };
+    match output {
+        OutputFormat::Table => {
+            let detail = match (clip, error) {
+                (Some(clip), _) => format!(
+                    "{kind} {}  {}/{} matched",
+                    clip.order_id, clip.matched, clip.size
+                ),
+                (None, Some(error)) => format!("failed to post: {error}"),
+                (None, None) => kind.to_string(),
+            };
+            println!(
+                "{}  {detail}  {}",
+                color::dim(Local::now().format("%H:%M:%S")),
+                color::dim(format!("filled {filled}/{}", state.total)),
+            );
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&json!({
+                "event": kind,
+                "order_id": clip.map(|c| &c.order_id),
+                "size": clip.map(|c| c.size.to_string()),
+                "matched": clip.map(|c| c.matched.to_string()),
+                "error": error,
+                "filled": filled.to_string(),
+                "total": state.total.to_string(),
+            }))?;
+        }
+    }
+    Ok(())
+}
+
+pub fn print_iceberg_finished(
+    state: &IcebergState,
+    state_path: &Path,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    let filled = state.filled();
+    let status = if state.is_complete() {
+        "complete"
+    } else {
+        "stopped"
+    };
+    match output {
+        OutputFormat::Table => {
+            println!(
+                "Iceberg {status}: filled {filled} of {} shares at {}.",
+                state.total, state.price
+            );
+            if !state.is_complete() {
+                println!(
+                    "Resume with `polymarket clob iceberg --resume {}`.",
+                    state_path.display()
+                );
+            }
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&json!({
+                "status": status,
+                "filled": filled.to_string(),
+                "total": state.total.to_string(),
+                "price": state.price.to_string(),
+                "state_file": state_path.display().to_string(),
+            }))?;
+        }
+    }
+    Ok(())
+}
+
+pub fn print_stop_start(stop: &StopOrder, output: &OutputFormat) {
+    if !matches!(output, OutputFormat::Table) {
+        return;
+    }
+    let (watched, direction) = match stop.side {
+        CliSide::Sell => ("bid", "falls to"),
+        CliSide::Buy => ("ask", "rises to"),
+    };
+    let order = stop
+        .limit
+        .map_or_else(|| "at market".to_string(), |p| format!("limit {p}"));
+    println!(
+        "Stop: {} {} shares {order} once the best {watched} {direction} {}",
+        color::side(Side::from(stop.side).to_string()),
+        stop.size,
+        stop.trigger,
+    );
+    println!(
+        "{}",
+        color::dim(format!(
+            "Checking every {}. Ctrl+C cancels the stop.",
+            format_interval(stop.interval.as_secs())
+        ))
+    );
+}
+
+pub fn print_stop_decision(
+    stop: &StopOrder,
+    decision: &Decision,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    let watched = match stop.side {
+        CliSide::Sell => "bid",
+        CliSide::Buy => "ask",
+    };
+    match output {
+        OutputFormat::Table => {
+            let detail = match decision {
+                Decision::Waiting(Some(price)) => {
+                    format!("{watched} {price}, trigger {}: waiting", stop.trigger)
+                }
+                Decision::Waiting(None) => format!("no {watched}s on the book: waiting"),
+                Decision::Triggered(price) => {
+                    format!("{watched} {price} crossed trigger {}: firing", stop.trigger)
+                }
+                Decision::Fired(fill) => match stop.limit {
+                    Some(price) => format!("posted limit order {} at {price}", fill.order_id),
+                    None => format!(
+                        "{} {} shares{}",
+                        color::side(Side::from(stop.side).to_string()),
+                        fill.shares,
+                        average_price(fill.shares, fill.usdc)
+                            .map_or_else(String::new, |p| format!(" @ {p}")),
+                    ),
+                },
+                Decision::FireFailed(error) => format!("order failed, retrying: {error}"),
+                Decision::CheckFailed(error) => format!("check failed, retrying: {error}"),
+            };
+            println!("{}  {detail}", color::dim(Local::now().format("%H:%M:%S")));
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let (kind, price, fill, error) = match decision {
+                Decision::Waiting(price) => ("waiting", *price, None, None),
+                Decision::Triggered(price) => ("triggered", Some(*price), None, None),
+                Decision::Fired(fill) => ("fired", None, Some(fill), None),
+                Decision::FireFailed(error) => ("fire_failed", None, None, Some(error)),
+                Decision::CheckFailed(error) => ("check_failed", None, None, Some(error)),
+            };
+            print_json(&json!({
+                "at": Local::now().to_rfc3339(),
+                "decision": kind,
+                "price": price.map(|p| p.to_string()),
+                "trigger": stop.trigger.to_string(),
+                "order_id": fill.map(|f| &f.order_id),
+                "shares": fill.map(|f| f.shares.to_string()),
+                "usdc": fill.map(|f| f.usdc.to_string()),
+                "error": error,
+            }))?;
+        }
+    }
+    Ok(())
+}
+
+pub fn print_stop_finished(
+    stop: &StopOrder,
+    triggered: bool,
+    placed: Decimal,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    let status = match (triggered, placed >= stop.size) {
+        (_, true) => "done",
+        (true, false) => "incomplete",
+        (false, false) => "cancelled",
+    };
+    match output {
+        OutputFormat::Table => match status {
+            "done" => println!("Stop done: placed {placed} of {} shares.", stop.size),
+            "incomplete" => println!(
+                "Stop triggered but only placed {placed} of {} shares.",
+                stop.size
+            ),
+            _ => println!("Stop cancelled before it triggered; nothing was placed."),
+        },
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&json!({
+                "status": status,
+                "triggered": triggered,
+                "placed": placed.to_string(),
+                "size": stop.size.to_string(),
+            }))?;
+        }
+    }
+    Ok(())
+}
+
+pub fn print_bracket_start(bracket: &Bracket, output: &OutputFormat) {
+    if !matches!(output, OutputFormat::Table) {
+        return;
+    }
+    let stop = &bracket.stop;
+    let stop_order = stop
+        .limit
+        .map_or_else(|| "at market".to_string(), |p| format!("limit {p}"));
+    println!(
+        "Bracket: {} {} shares, take profit at {}, stop at {} ({stop_order})",
+        color::side(Side::from(stop.side).to_string()),
+        stop.size,
+        bracket.take_profit,
+        stop.trigger,
+    );
+    println!(
+        "{}",
+        color::dim(format!(
+            "Checking every {}. Ctrl+C cancels both legs.",
+            format_interval(stop.interval.as_secs())
+        ))
+    );
+}
+
+pub fn print_take_profit(
+    bracket: &Bracket,
+    take_profit: &TakeProfit,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    let size = bracket.stop.size;
+    match output {
+        OutputFormat::Table => println!(
+            "{}  take-profit {} at {}: {}/{size} matched",
+            color::dim(Local::now().format("%H:%M:%S")),
+            take_profit.order_id,
+            bracket.take_profit,
+            take_profit.matched,
+        ),
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&json!({
+                "at": Local::now().to_rfc3339(),
+                "leg": "take_profit",
+                "order_id": take_profit.order_id,
+                "price": bracket.take_profit.to_string(),
+                "matched": take_profit.matched.to_string(),
+                "size": size.to_string(),
+            }))?;
+        }
+    }
+    Ok(())
+}
+
+pub fn print_bracket_finished(
+    bracket: &Bracket,
+    take_profit: &TakeProfit,
+    outcome: &Outcome,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    let size = bracket.stop.size;
+    let (status, stop_placed) = match outcome {
+        Outcome::TakeProfit => ("take_profit", None),
+        Outcome::Stopped(placed) => ("stopped", Some(*placed)),
+        Outcome::Closed => ("closed", None),
+        Outcome::Cancelled => ("cancelled", None),
+    };
+    match output {
+        OutputFormat::Table => match outcome {
+            Outcome::TakeProfit => {
+                println!("Take-profit filled {size} shares; the stop is cancelled.");
+            }
+            Outcome::Stopped(placed) => println!(
+                "Stop fired: placed {placed} shares; the take-profit had matched {} and is \
+                 cancelled.",
+                take_profit.matched
+            ),
+            Outcome::Closed => println!(
+                "The take-profit order was closed elsewhere after matching {}; the stop is \
+                 cancelled.",
+                take_profit.matched
+            ),
+            Outcome::Cancelled => println!(
+                "Bracket cancelled; the take-profit had matched {} of {size}.",
+                take_profit.matched
+            ),
+        },
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&json!({
+                "status": status,
+                "take_profit_order_id": take_profit.order_id,
+                "take_profit_matched": take_profit.matched.to_string(),
+                "stop_placed": stop_placed.map(|p| p.to_string()),
+                "size": size.to_string(),
+            }))?;
+        }
+    }
+    Ok(())
+}
+
+pub fn print_trailing_start(trailing: &TrailingStop, output: &OutputFormat) {
+    if !matches!(output, OutputFormat::Table) {
+        return;
+    }
+    let stop = &trailing.stop;
+    let watched = match stop.side {
+        CliSide::Sell => "bid",
+        CliSide::Buy => "ask",
+    };
+    println!(
+        "Trailing stop: {} {} shares at market once the best {watched} retraces {} from its best",
+        color::side(Side::from(stop.side).to_string()),
+        stop.size,
+        trailing.trail,
+    );
+    println!(
+        "{}",
+        color::dim("Following the price stream. Ctrl+C cancels the stop.")
+    );
+}
+
+pub fn print_trailing_event(
+    trailing: &TrailingStop,
+    event: &TrailEvent,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            let detail = match event {
+                TrailEvent::Ratcheted { price, trigger } => {
+                    format!("best {price}: trigger moved to {trigger}")
+                }
+                TrailEvent::Triggered(price) => {
+                    format!("{price} reached trigger {}: firing", trailing.stop.trigger)
+                }
+            };
+            println!("{}  {detail}", color::dim(Local::now().format("%H:%M:%S")));
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let (kind, price) = match event {
+                TrailEvent::Ratcheted { price, .. } => ("ratcheted", price),
+                TrailEvent::Triggered(price) => ("triggered", price),
+            };
+            print_json(&json!({
+                "at": Local::now().to_rfc3339(),
+                "decision": kind,
+                "price": price.to_string(),
+                "trigger": trailing.stop.trigger.to_string(),
+            }))?;
+        }
+    }
+    Ok(())
+}
+
+pub fn print_guard_start(guard: &Guard, output: &OutputFormat) {
+    if !matches!(output, OutputFormat::Table) {
+        return;
+    }
+    println!(
+        "Guard: cancels all open orders after {} missed heartbeats or {} without one",
+        guard.misses,
+        format_interval(guard.ttl.as_secs())
+    );
+    println!(
+        "{}",
+        color::dim(format!(
+            "Heartbeat every {}. Ctrl+C cancels all open orders and stops the guard.",
+            format_interval(guard.interval().as_secs())
+        ))
+    );
+}
+
+fn trip_reason(trip: &Trip) -> String {
+    match trip {
+        Trip::Missed(missed) => format!("{missed} heartbeats missed in a row"),
+        Trip::Expired(since) => format!("no heartbeat for {}", format_interval(since.as_secs())),
+        Trip::Stopped => "stopped".to_string(),
+    }
+}
+
+pub fn print_guard_event(event: &GuardEvent, output: &OutputFormat) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            let detail = match event {
+                GuardEvent::Beat(open) => format!("heartbeat ok, {open} open orders"),
+                GuardEvent::Missed { missed, error } => {
+                    format!("heartbeat missed ({missed} in a row): {error}")
+                }
+                GuardEvent::Tripped(trip) => {
+                    format!("{}: canceling all open orders", trip_reason(trip))
+                }
+                GuardEvent::Canceled {
+                    canceled,
+                    not_canceled,
+                } => match not_canceled {
+                    0 => format!("canceled {canceled} orders"),
+                    n => format!("canceled {canceled} orders, {n} could not be canceled"),
+                },
+                GuardEvent::CancelFailed(error) => format!("cancel failed: {error}"),
+            };
+            println!("{}  {detail}", color::dim(Local::now().format("%H:%M:%S")));
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let mut data = match event {
+                GuardEvent::Beat(open) => json!({"event": "heartbeat", "open_orders": open}),
+                GuardEvent::Missed { missed, error } => {
+                    json!({"event": "missed", "missed": missed, "error": error})
+                }
+                GuardEvent::Tripped(trip) => {
+                    json!({"event": "tripped", "reason": trip_reason(trip)})
+                }
+                GuardEvent::Canceled {
+                    canceled,
+                    not_canceled,
+                } => json!({
+                    "event": "canceled",
+                    "canceled": canceled,
+                    "not_canceled": not_canceled,
+                }),
+                GuardEvent::CancelFailed(error) => {
+                    json!({"event": "cancel_failed", "error": error})
+                }
+            };
+            data["at"] = json!(Local::now().to_rfc3339());
+            print_json(&data)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn print_guard_finished(trip: &Trip, output: &OutputFormat) -> anyhow::Result<()> {
+    let tripped = !matches!(trip, Trip::Stopped);
+    match output {
+        OutputFormat::Table if tripped => {
+            println!(
+                "Guard tripped ({}); open orders canceled.",
+                trip_reason(trip)
+            );
+        }
+        OutputFormat::Table => println!("Guard stopped; open orders canceled."),
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&json!({
+                "status": if tripped { "tripped" } else { "stopped" },
+                "reason": trip_reason(trip),
+            }))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intervals_use_the_largest_whole_unit() {
+        assert_eq!(format_interval(7200), "2h");
+        assert_eq!(format_interval(300), "5m");
+        assert_eq!(format_interval(90), "90s");
+    }
+}
@@ -3,6 +3,7 @@
 
 use alloy::primitives::U256;
 use anyhow::Result;
+use rust_decimal::Decimal;
 use tabled::Tabled;
 use tabled::settings::Style;
 
@@ -12,7 +13,8 @@ pub struct ApprovalStatus {
     pub contract_name: String,
     pub contract_address: String,
     pub usdc_allowance: U256,
-    pub ctf_approved: bool,
+    /// `None` for a contract no outcome token approval applies to.
+    pub ctf_approved: Option<bool>,
     pub usdc_error: Option<String>,
     pub ctf_error: Option<String>,
 }
@@ -27,49 +29,63 @@ struct ApprovalRow {
     ctf: String,
 }
 
+/// `raw` USDC units as an exact decimal amount.
+fn usdc_amount(raw: U256) -> String {
+    i128::try_from(raw)
+        .ok()
+        .and_then(|raw| Decimal::try_from_i128_with_scale(raw, 6).ok())
+        .map_or_else(|| raw.to_string(), |d| d.normalize().to_string())
+}
+
 fn format_allowance(allowance: U256) -> String {
     if allowance == U256::MAX {
         "\u{2713} Unlimited".to_string()
     } else if allowance == U256::ZERO {
         "\u{2717} None".to_string()
     } else {
-        let usdc_decimals = U256::from(1_000_000);
-        let whole = allowance / usdc_decimals;
-        format!("\u{2713} {whole} USDC")
+        format!("\u{2713} {} USDC", usdc_amount(allowance))
     }
 }
 
-fn format_ctf(approved: bool) -> String {
-    if approved {
-        "\u{2713} Approved".to_string()
-    } else {
-        "\u{2717} Not set".to_string()
+fn format_ctf(approved: Option<bool>) -> String {
+    match approved {
+        Some(true) => "\u{2713} Approved".to_string(),
+        Some(false) => "\u{2717} Not set".to_string(),
+        None => "-".to_string(),
     }
 }
 
+pub fn approval_status_json(statuses: &[ApprovalStatus]) -> Vec<serde_json::Value> {
+    statuses
+        .iter()
+        .map(|s| {
+            let mut obj = serde_json::json!({
+                "contract": s.contract_name,
+                "address": s.contract_address,
+                "usdc_allowance": s.usdc_allowance.to_string(),
+                "usdc_allowance_usdc": if s.usdc_allowance == U256::MAX {
+                    "unlimited".to_string()
+                } else {
+                    usdc_amount(s.usdc_allowance)
+                },
+                "usdc_approved": s.usdc_allowance > U256::ZERO,
+                "ctf_approved": s.ctf_approved,
+            });
+            if let Some(ref err) = s.usdc_error {
+                obj["usdc_error"] = serde_json::Value::String(err.clone());
+            }
+            if let Some(ref err) = s.ctf_error {
+                obj["ctf_error"] = serde_json::Value::String(err.clone());
+            }
+            obj
+        })
+        .collect()
+}
+
 pub fn print_approval_status(statuses: &[ApprovalStatus], output: &OutputFormat) -> Result<()> {
     match output {
-        OutputFormat::Json => {
-            let json: Vec<serde_json::Value> = statuses
-                .iter()
-                .map(|s| {
-                    let mut obj = serde_json::json!({
-                        "contract": s.contract_name,
-                        "address": s.contract_address,
-                        "usdc_allowance": s.usdc_allowance.to_string(),
-                        "usdc_approved": s.usdc_allowance > U256::ZERO,
-                        "ctf_approved": s.ctf_approved,
-                    });
-                    if let Some(ref err) = s.usdc_error {
-                        obj["usdc_error"] = serde_json::Value::String(err.clone());
-                    }
-                    if let Some(ref err) = s.ctf_error {
-                        obj["ctf_error"] = serde_json::Value::String(err.clone());
-                    }
-                    obj
-                })
-                .collect();
-            println!("{}", serde_json::to_string_pretty(&json)?);
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            super::print_json(&approval_status_json(statuses))?;
             Ok(())
         }
         OutputFormat::Table => {
@@ -101,3 +117,19 @@ pub fn print_tx_result(step: usize, total: usize, label: &str, tx_hash: alloy::p
     let short = &hash_str[..10];
     println!("  [{step}/{total}] {label:<30} \u{2713} {short}\u{2026}");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allowances_show_their_exact_amount() {
+        assert_eq!(format_allowance(U256::MAX), "\u{2713} Unlimited");
+        assert_eq!(format_allowance(U256::ZERO), "\u{2717} None");
+        assert_eq!(
+            format_allowance(U256::from(25_500_000)),
+            "\u{2713} 25.5 USDC"
+        );
+        assert_eq!(format_allowance(U256::from(1)), "\u{2713} 0.000001 USDC");
+    }
+}
@@ -1,8 +1,10 @@
 #![allow(clippy::items_after_statements)]
 
 use polymarket_client_sdk::bridge::types::{
-    DepositResponse, DepositTransactionStatus, StatusResponse, SupportedAssetsResponse,
+    DepositResponse, DepositTransaction, DepositTransactionStatus, StatusResponse,
+    SupportedAssetsResponse,
 };
+use polymarket_client_sdk::types::{Address, B256};
 use serde_json::json;
 use tabled::settings::Style;
 use tabled::{Table, Tabled};
@@ -21,7 +23,7 @@ pub fn print_deposit(response: &DepositResponse, output: &OutputFormat) -> anyho
             }
             print_detail_table(rows);
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data = json!({
                 "evm": format!("{}", response.address.evm),
                 "svm": response.address.svm,
@@ -74,7 +76,7 @@ pub fn print_supported_assets(
             let table = Table::new(rows).with(Style::rounded()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = response
                 .supported_assets
                 .iter()
@@ -96,7 +98,7 @@ pub fn print_supported_assets(
     Ok(())
 }
 
-fn format_status(s: &DepositTransactionStatus) -> &'static str {
+pub fn format_status(s: &DepositTransactionStatus) -> &'static str {
     match s {
         DepositTransactionStatus::DepositDetected => "Detected",
         DepositTransactionStatus::Processing => "Processing",
@@ -148,7 +150,7 @@ pub fn print_status(response: &StatusResponse, output: &OutputFormat) -> anyhow:
             let table = Table::new(rows).with(Style::rounded()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = response
                 .transactions
                 .iter()
@@ -171,6 +173,125 @@ pub fn print_status(response: &StatusResponse, output: &OutputFormat) -> anyhow:
     Ok(())
 }
 
+/// A sent `bridge withdraw`.
+pub struct Withdrawal {
+    pub amount: String,
+    pub from: Address,
+    pub to: String,
+    pub to_chain: u64,
+    /// Where the USDC went on Polygon to be bridged; `None` when it went
+    /// straight to `to`.
+    pub bridge_address: Option<Address>,
+    pub note: Option<String>,
+    pub tx_hash: B256,
+    pub block: Option<u64>,
+}
+
+pub fn print_withdraw(withdrawal: &Withdrawal, output: &OutputFormat) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            let mut rows = Vec::new();
+            detail_field!(rows, "Amount", format!("{} USDC", withdrawal.amount));
+            detail_field!(rows, "From", withdrawal.from.to_string());
+            detail_field!(rows, "To", withdrawal.to.clone());
+            detail_field!(rows, "To Chain", withdrawal.to_chain.to_string());
+            if let Some(bridge) = withdrawal.bridge_address {
+                detail_field!(rows, "Bridge Address", bridge.to_string());
+            }
+            detail_field!(rows, "Tx", withdrawal.tx_hash.to_string());
+            if let Some(block) = withdrawal.block {
+                detail_field!(rows, "Block", block.to_string());
+            }
+            if let Some(note) = &withdrawal.note {
+                detail_field!(rows, "Note", note.clone());
+            }
+            print_detail_table(rows);
+            if let Some(bridge) = withdrawal.bridge_address {
+                println!("Track the bridge transfer with `polymarket bridge status {bridge}`.");
+            }
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            super::print_json(&json!({
+                "amount": withdrawal.amount,
+                "from": withdrawal.from.to_string(),
+                "to": withdrawal.to,
+                "to_chain_id": withdrawal.to_chain,
+                "bridge_address": withdrawal.bridge_address.map(|a| a.to_string()),
+                "note": withdrawal.note,
+                "tx_hash": withdrawal.tx_hash.to_string(),
+                "block": withdrawal.block,
+            }))?;
+        }
+    }
+    Ok(())
+}
+
+/// A sent cross-chain `bridge deposit`.
+pub struct BridgeDeposit {
+    pub amount: String,
+    pub symbol: String,
+    pub from_chain: u64,
+    pub from: Address,
+    pub deposit_address: Address,
+    /// The Polymarket wallet the funds arrive in.
+    pub wallet: Address,
+    pub tx_hash: B256,
+    pub block: Option<u64>,
+    /// Where the bridge got to, when the transfer was tracked.
+    pub bridged: Option<DepositTransaction>,
+}
+
+pub fn print_bridge_deposit(deposit: &BridgeDeposit, output: &OutputFormat) -> anyhow::Result<()> {
+    let status = deposit.bridged.as_ref().map(|tx| format_status(&tx.status));
+    let bridge_tx = deposit.bridged.as_ref().and_then(|tx| tx.tx_hash.clone());
+    match output {
+        OutputFormat::Table => {
+            let mut rows = Vec::new();
+            detail_field!(
+                rows,
+                "Amount",
+                format!("{} {}", deposit.amount, deposit.symbol)
+            );
+            detail_field!(rows, "From", deposit.from.to_string());
+            detail_field!(rows, "From Chain", deposit.from_chain.to_string());
+            detail_field!(rows, "Deposit Address", deposit.deposit_address.to_string());
+            detail_field!(rows, "Wallet", deposit.wallet.to_string());
+            detail_field!(rows, "Tx", deposit.tx_hash.to_string());
+            if let Some(block) = deposit.block {
+                detail_field!(rows, "Block", block.to_string());
+            }
+            if let Some(status) = status {
+                detail_field!(rows, "Bridge Status", status.to_string());
+            }
+            if let Some(hash) = &bridge_tx {
+                detail_field!(rows, "Polygon Tx", hash.clone());
+            }
+            print_detail_table(rows);
+            if status.is_none_or(|s| s != "Completed" && s != "Failed") {
+                println!(
+                    "Track the bridge transfer with `polymarket bridge status {} --wait`.",
+                    deposit.deposit_address
+                );
+            }
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            super::print_json(&json!({
+                "amount": deposit.amount,
+                "symbol": deposit.symbol,
+                "from": deposit.from.to_string(),
+                "from_chain_id": deposit.from_chain,
+                "deposit_address": deposit.deposit_address.to_string(),
+                "wallet": deposit.wallet.to_string(),
+                "tx_hash": deposit.tx_hash.to_string(),
+                "block": deposit.block,
+                "bridge_status": status,
+                "bridge_tx_hash": bridge_tx,
+            }))?;
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,250 @@
+//! Braille line charts for time series, sized to the terminal.
+//!
+//! Each braille character is a 2×4 grid of dots, so a chart of `w` columns
+//! and `h` rows has `2w × 4h` points of resolution.
+
+use anyhow::{Result, bail};
+
+const LABEL_WIDTH: usize = 10;
+const DEFAULT_SIZE: (usize, usize) = (80, 24);
+const MIN_PLOT_WIDTH: usize = 10;
+const MIN_PLOT_HEIGHT: usize = 4;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ChartOptions {
+    /// Total width in terminal columns, including the y-axis labels.
+    pub width: usize,
+    /// Plot height in terminal rows, excluding the x-axis.
+    pub height: usize,
+    /// Plot `ln(value)` so equal ratios get equal vertical distance.
+    pub log_scale: bool,
+}
+
+impl ChartOptions {
+    /// Fills the terminal, leaving room for the x-axis and the shell prompt.
+    pub fn for_terminal(log_scale: bool) -> Self {
+        let (cols, rows) = crossterm::terminal::size()
+            .map_or(DEFAULT_SIZE, |(c, r)| (usize::from(c), usize::from(r)));
+        Self {
+            width: cols,
+            height: rows.saturating_sub(4),
+            log_scale,
+        }
+    }
+}
+
+/// Bit for the dot at `(col, row)` inside a braille cell (U+2800 block).
+fn braille_bit(col: usize, row: usize) -> u32 {
+    const BITS: [[u32; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+    BITS[col][row]
+}
+
+struct Canvas {
+    width: usize,
+    height: usize,
+    cells: Vec<u32>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![0; width * height],
+        }
+    }
+
+    /// Sets a dot; `y` counts up from the bottom.
+    fn set(&mut self, x: usize, y: usize) {
+        let row = self.height * 4 - 1 - y;
+        self.cells[(row / 4) * self.width + x / 2] |= braille_bit(x % 2, row % 4);
+    }
+
+    /// Bresenham line between two dots.
+    fn line(&mut self, (x0, y0): (usize, usize), (x1, y1): (usize, usize)) {
+        let (mut x, mut y) = (x0.cast_signed(), y0.cast_signed());
+        let (x1, y1) = (x1.cast_signed(), y1.cast_signed());
+        let dx = (x1 - x).abs();
+        let dy = -(y1 - y).abs();
+        let (sx, sy) = (if x < x1 { 1 } else { -1 }, if y < y1 { 1 } else { -1 });
+        let mut err = dx + dy;
+        loop {
+            self.set(x.cast_unsigned(), y.cast_unsigned());
+            if x == x1 && y == y1 {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    fn row(&self, row: usize) -> String {
+        self.cells[row * self.width..(row + 1) * self.width]
+            .iter()
+            .map(|&bits| char::from_u32(0x2800 + bits).unwrap_or(' '))
+            .collect()
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn scale(value: f64, min: f64, max: f64, steps: usize) -> usize {
+    if max <= min {
+        return steps / 2;
+    }
+    (((value - min) / (max - min)) * (steps - 1) as f64).round() as usize
+}
+
+fn format_time(ts: i64) -> String {
+    chrono::DateTime::from_timestamp(ts, 0).map_or_else(
+        || ts.to_string(),
+        |dt| dt.format("%Y-%m-%d %H:%M").to_string(),
+    )
+}
+
+/// Renders `(unix_timestamp, value)` points as a line chart with a labelled
+/// y-axis (min, mid, max) and the first and last timestamps under the x-axis.
+pub fn render(points: &[(i64, f64)], options: ChartOptions) -> Result<String> {
+    if points.is_empty() {
+        bail!("No data to chart");
+    }
+    if options.log_scale && points.iter().any(|&(_, v)| v <= 0.0) {
+        bail!("Log scale needs all values to be positive");
+    }
+    let plot_width = options
+        .width
+        .saturating_sub(LABEL_WIDTH + 2)
+        .max(MIN_PLOT_WIDTH);
+    let plot_height = options.height.max(MIN_PLOT_HEIGHT);
+
+    let transform = |v: f64| if options.log_scale { v.ln() } else { v };
+    let (t_min, t_max) = points
+        .iter()
+        .fold((i64::MAX, i64::MIN), |(lo, hi), &(t, _)| {
+            (lo.min(t), hi.max(t))
+        });
+    let (v_min, v_max) = points
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &(_, v)| {
+            (lo.min(v), hi.max(v))
+        });
+
+    #[allow(clippy::cast_precision_loss)]
+    let dot = |(t, v): (i64, f64)| {
+        (
+            scale(t as f64, t_min as f64, t_max as f64, plot_width * 2),
+            scale(
+                transform(v),
+                transform(v_min),
+                transform(v_max),
+                plot_height * 4,
+            ),
+        )
+    };
+    let mut canvas = Canvas::new(plot_width, plot_height);
+    let mut previous = dot(points[0]);
+    canvas.set(previous.0, previous.1);
+    for &point in &points[1..] {
+        let next = dot(point);
+        canvas.line(previous, next);
+        previous = next;
+    }
+
+    let v_mid = if options.log_scale {
+        (v_min.ln().midpoint(v_max.ln())).exp()
+    } else {
+        v_min.midpoint(v_max)
+    };
+    let mut out = String::new();
+    for row in 0..plot_height {
+        let label = match row {
+            0 => format!("{v_max:.3}"),
+            r if r == plot_height / 2 => format!("{v_mid:.3}"),
+            r if r == plot_height - 1 => format!("{v_min:.3}"),
+            _ => String::new(),
+        };
+        let tick = if label.is_empty() { '│' } else { '┤' };
+        out.push_str(&format!(
+            "{label:>LABEL_WIDTH$} {tick}{}\n",
+            canvas.row(row)
+        ));
+    }
+    out.push_str(&format!(
+        "{:>LABEL_WIDTH$} └{}\n",
+        "",
+        "─".repeat(plot_width)
+    ));
+    let (start, end) = (format_time(t_min), format_time(t_max));
+    let gap = (plot_width + 1)
+        .saturating_sub(start.len() + end.len())
+        .max(1);
+    out.push_str(&format!(
+        "{:>LABEL_WIDTH$} {start}{}{end}\n",
+        "",
+        " ".repeat(gap)
+    ));
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(width: usize, height: usize) -> ChartOptions {
+        ChartOptions {
+            width,
+            height,
+            log_scale: false,
+        }
+    }
+
+    #[test]
+    fn render_labels_axes() {
+        let chart = render(&[(0, 0.2), (3600, 0.8)], options(40, 4)).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert!(lines[0].trim_start().starts_with("0.800 ┤"));
+        assert!(lines[3].trim_start().starts_with("0.200 ┤"));
+        assert!(lines[4].contains('└'));
+        assert!(lines[5].contains("1970-01-01 00:00") && lines[5].contains("1970-01-01 01:00"));
+    }
+
+    #[test]
+    fn rising_series_goes_bottom_left_to_top_right() {
+        let chart = render(&[(0, 0.0), (10, 1.0)], options(22, 4)).unwrap();
+        let rows: Vec<Vec<char>> = chart
+            .lines()
+            .take(4)
+            .map(|l| l.split(['┤', '│']).nth(1).unwrap().chars().collect())
+            .collect();
+        assert_ne!(rows[3][0], '\u{2800}');
+        assert_ne!(rows[0][rows[0].len() - 1], '\u{2800}');
+        assert_eq!(rows[0][0], '\u{2800}');
+    }
+
+    #[test]
+    fn log_scale_rejects_non_positive_values() {
+        let opts = ChartOptions {
+            log_scale: true,
+            ..options(40, 4)
+        };
+        assert!(render(&[(0, 0.0), (1, 0.5)], opts).is_err());
+        assert!(render(&[(0, 0.1), (1, 0.5)], opts).is_ok());
+    }
+
+    #[test]
+    fn empty_series_is_an_error() {
+        assert!(render(&[], options(40, 4)).is_err());
+    }
+
+    #[test]
+    fn flat_single_point_renders() {
+        assert!(render(&[(0, 0.5)], options(40, 4)).is_ok());
+    }
+}
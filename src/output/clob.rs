@@ -1,30 +1,41 @@
 #![allow(clippy::items_after_statements)]
 
-use polymarket_client_sdk::auth::Credentials;
+use std::path::Path;
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use polymarket_client_sdk::auth::{ApiKey, Credentials};
 use polymarket_client_sdk::clob::types::response::{
     ApiKeysResponse, BalanceAllowanceResponse, BanStatusResponse, CancelOrdersResponse,
     CurrentRewardResponse, FeeRateResponse, GeoblockResponse, LastTradePriceResponse,
     LastTradesPricesResponse, MarketResponse, MarketRewardResponse, MidpointResponse,
     MidpointsResponse, NegRiskResponse, NotificationResponse, OpenOrderResponse,
-    OrderBookSummaryResponse, OrderScoringResponse, OrdersScoringResponse, Page, PostOrderResponse,
-    PriceHistoryResponse, PriceResponse, PricesResponse, RewardsPercentagesResponse,
-    SimplifiedMarketResponse, SpreadResponse, SpreadsResponse, TickSizeResponse,
-    TotalUserEarningResponse, TradeResponse, UserEarningResponse, UserRewardsEarningResponse,
+    OrderBookSummaryResponse, OrderScoringResponse, OrderSummary, OrdersScoringResponse, Page,
+    PostOrderResponse, PriceHistoryResponse, PriceResponse, PricesResponse,
+    RewardsPercentagesResponse, SimplifiedMarketResponse, SpreadResponse, SpreadsResponse,
+    TickSizeResponse, TotalUserEarningResponse, TradeResponse, UserEarningResponse,
+    UserRewardsEarningResponse,
+};
+use polymarket_client_sdk::clob::types::{OrderType, Side};
+use polymarket_client_sdk::clob::ws::types::response::{
+    OrderMessage, OrderMessageType, TradeMessage, TradeMessageStatus,
 };
-use polymarket_client_sdk::types::Decimal;
+use polymarket_client_sdk::types::{B256, Decimal, U256};
+use rust_decimal::prelude::ToPrimitive;
 use serde_json::json;
-use tabled::settings::Style;
-use tabled::{Table, Tabled};
+use tabled::Tabled;
 
-use super::{OutputFormat, format_decimal, truncate};
+use super::dry_run::{net_of_fee, taker_fee};
+use super::{OutputFormat, color, format_decimal, truncate};
+use crate::order_file::OrderSpec;
 
 /// Base64-encoded empty cursor returned by the CLOB API when there are no more pages.
-const END_CURSOR: &str = "LTE=";
+pub const END_CURSOR: &str = "LTE=";
 
 pub fn print_ok(result: &str, output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => println!("CLOB API: {result}"),
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(&json!({"status": result}))?;
         }
     }
@@ -32,9 +43,12 @@ pub fn print_ok(result: &str, output: &OutputFormat) -> anyhow::Result<()> {
 }
 
 pub fn print_price(result: &PriceResponse, output: &OutputFormat) -> anyhow::Result<()> {
+    if super::print_raw(result.price) {
+        return Ok(());
+    }
     match output {
         OutputFormat::Table => println!("Price: {}", result.price),
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(&json!({"price": result.price.to_string()}))?;
         }
     }
@@ -66,15 +80,15 @@ pub fn print_batch_prices(result: &PricesResponse, output: &OutputFormat) -> any
                 for (side, price) in sides {
                     rows.push(Row {
                         token_id: truncate(&token_id.to_string(), 20),
-                        side: side.to_string(),
+                        side: color::side(side),
                         price: price.to_string(),
                     });
                 }
             }
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = super::render_table(rows)?;
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data = result.prices.as_ref().map(|prices| {
                 prices
                     .iter()
@@ -94,9 +108,12 @@ pub fn print_batch_prices(result: &PricesResponse, output: &OutputFormat) -> any
 }
 
 pub fn print_midpoint(result: &MidpointResponse, output: &OutputFormat) -> anyhow::Result<()> {
+    if super::print_raw(result.mid) {
+        return Ok(());
+    }
     match output {
         OutputFormat::Table => println!("Midpoint: {}", result.mid),
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(&json!({"midpoint": result.mid.to_string()}))?;
         }
     }
@@ -125,10 +142,10 @@ pub fn print_midpoints(result: &MidpointsResponse, output: &OutputFormat) -> any
                     midpoint: mid.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = super::render_table(rows)?;
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: serde_json::Map<String, serde_json::Value> = result
                 .midpoints
                 .iter()
@@ -143,7 +160,7 @@ pub fn print_midpoints(result: &MidpointsResponse, output: &OutputFormat) -> any
 pub fn print_spread(result: &SpreadResponse, output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => println!("Spread: {}", result.spread),
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(&json!({"spread": result.spread.to_string()}))?;
         }
     }
@@ -175,10 +192,10 @@ pub fn print_spreads(result: &SpreadsResponse, output: &OutputFormat) -> anyhow:
                     spread: spread.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = super::render_table(rows)?;
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data = result.spreads.as_ref().map(|spreads| {
                 spreads
                     .iter()
@@ -215,14 +232,54 @@ fn order_book_to_json(book: &OrderBookSummaryResponse) -> serde_json::Value {
     })
 }
 
+#[derive(Tabled)]
+struct DepthRow {
+    #[tabled(rename = "Price")]
+    price: String,
+    #[tabled(rename = "Size")]
+    size: String,
+    #[tabled(rename = "Total")]
+    total: String,
+    #[tabled(rename = "Notional")]
+    notional: String,
+}
+
+/// Book levels best price first, with running totals of size and USDC.
+fn depth_rows(levels: &[OrderSummary], bids: bool) -> Vec<DepthRow> {
+    let mut levels = levels.to_vec();
+    if bids {
+        levels.sort_by_key(|l| std::cmp::Reverse(l.price));
+    } else {
+        levels.sort_by_key(|l| l.price);
+    }
+    let (mut total, mut notional) = (Decimal::ZERO, Decimal::ZERO);
+    levels
+        .iter()
+        .map(|l| {
+            total += l.size;
+            notional += l.size * l.price;
+            DepthRow {
+                price: if bids {
+                    color::bid(l.price)
+                } else {
+                    color::ask(l.price)
+                },
+                size: l.size.to_string(),
+                total: total.to_string(),
+                notional: format!("${}", notional.round_dp(2)),
+            }
+        })
+        .collect()
+}
+
 pub fn print_order_book(
     result: &OrderBookSummaryResponse,
     output: &OutputFormat,
 ) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => {
-            println!("Market: {}", result.market);
-            println!("Asset: {}", result.asset_id);
+            println!("{}", color::dim(format!("Market: {}", result.market)));
+            println!("{}", color::dim(format!("Asset: {}", result.asset_id)));
             println!(
                 "Last Trade: {}",
                 result
@@ -231,27 +288,11 @@ pub fn print_order_book(
             );
             println!();
 
-            #[derive(Tabled)]
-            struct Row {
-                #[tabled(rename = "Price")]
-                price: String,
-                #[tabled(rename = "Size")]
-                size: String,
-            }
-
             if result.bids.is_empty() {
                 println!("No bids.");
             } else {
                 println!("Bids:");
-                let rows: Vec<Row> = result
-                    .bids
-                    .iter()
-                    .map(|o| Row {
-                        price: o.price.to_string(),
-                        size: o.size.to_string(),
-                    })
-                    .collect();
-                let table = Table::new(rows).with(Style::rounded()).to_string();
+                let table = super::render_table(depth_rows(&result.bids, true))?;
                 println!("{table}");
             }
 
@@ -261,19 +302,11 @@ pub fn print_order_book(
                 println!("No asks.");
             } else {
                 println!("Asks:");
-                let rows: Vec<Row> = result
-                    .asks
-                    .iter()
-                    .map(|o| Row {
-                        price: o.price.to_string(),
-                        size: o.size.to_string(),
-                    })
-                    .collect();
-                let table = Table::new(rows).with(Style::rounded()).to_string();
+                let table = super::render_table(depth_rows(&result.asks, false))?;
                 println!("{table}");
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(&order_book_to_json(result))?;
         }
     }
@@ -297,7 +330,7 @@ pub fn print_order_books(
                 print_order_book(book, output)?;
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = result.iter().map(order_book_to_json).collect();
             super::print_json(&data)?;
         }
@@ -310,8 +343,14 @@ pub fn print_last_trade(
     output: &OutputFormat,
 ) -> anyhow::Result<()> {
     match output {
-        OutputFormat::Table => println!("Last Trade: {} ({})", result.price, result.side),
-        OutputFormat::Json => {
+        OutputFormat::Table => {
+            println!(
+                "Last Trade: {} ({})",
+                result.price,
+                color::side(result.side)
+            );
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(&json!({
                 "price": result.price.to_string(),
                 "side": result.side.to_string(),
@@ -345,13 +384,13 @@ pub fn print_last_trades_prices(
                 .map(|t| Row {
                     token_id: truncate(&t.token_id.to_string(), 20),
                     price: t.price.to_string(),
-                    side: t.side.to_string(),
+                    side: color::side(t.side),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = super::render_table(rows)?;
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .iter()
                 .map(|t| {
@@ -407,13 +446,67 @@ pub fn print_clob_market(result: &MarketResponse, output: &OutputFormat) -> anyh
             }
             super::print_detail_table(rows);
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(result)?;
         }
     }
     Ok(())
 }
 
+/// Outcome → token ID mapping for `clob tokens`; IDs are never truncated
+/// since they're meant to be copied.
+pub fn print_clob_tokens(result: &MarketResponse, output: &OutputFormat) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            if result.tokens.is_empty() {
+                println!("No tokens found.");
+                return Ok(());
+            }
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Outcome")]
+                outcome: String,
+                #[tabled(rename = "Price")]
+                price: String,
+                #[tabled(rename = "Token ID")]
+                token_id: String,
+            }
+            let rows: Vec<Row> = result
+                .tokens
+                .iter()
+                .map(|t| Row {
+                    outcome: if t.winner {
+                        format!("{} (winner)", t.outcome)
+                    } else {
+                        t.outcome.clone()
+                    },
+                    price: t.price.to_string(),
+                    token_id: t.token_id.to_string(),
+                })
+                .collect();
+            println!("{}", color::dim(&result.question));
+            let table = super::render_table(rows)?;
+            println!("{table}");
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let data: Vec<_> = result
+                .tokens
+                .iter()
+                .map(|t| {
+                    json!({
+                        "outcome": t.outcome,
+                        "token_id": t.token_id.to_string(),
+                        "price": t.price.to_string(),
+                        "winner": t.winner,
+                    })
+                })
+                .collect();
+            super::print_json(&data)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn print_clob_markets(
     result: &Page<MarketResponse>,
     output: &OutputFormat,
@@ -445,13 +538,16 @@ pub fn print_clob_markets(
                     min_tick: m.minimum_tick_size.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = super::render_table(rows)?;
             println!("{table}");
             if result.next_cursor != END_CURSOR {
-                println!("Next cursor: {}", result.next_cursor);
+                println!(
+                    "{}",
+                    color::dim(format!("Next cursor: {}", result.next_cursor))
+                );
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(result)?;
         }
     }
@@ -494,13 +590,16 @@ pub fn print_simplified_markets(
                     accepting_orders: if m.accepting_orders { "Yes" } else { "No" }.into(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = super::render_table(rows)?;
             println!("{table}");
             if result.next_cursor != END_CURSOR {
-                println!("Next cursor: {}", result.next_cursor);
+                println!(
+                    "{}",
+                    color::dim(format!("Next cursor: {}", result.next_cursor))
+                );
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(result)?;
         }
     }
@@ -512,7 +611,7 @@ pub fn print_tick_size(result: &TickSizeResponse, output: &OutputFormat) -> anyh
         OutputFormat::Table => {
             println!("Tick size: {}", result.minimum_tick_size.as_decimal());
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(&json!({
                 "minimum_tick_size": result.minimum_tick_size.as_decimal().to_string(),
             }))?;
@@ -526,7 +625,7 @@ pub fn print_fee_rate(result: &FeeRateResponse, output: &OutputFormat) -> anyhow
         OutputFormat::Table => {
             println!("Fee rate: {} bps", result.base_fee);
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(&json!({
                 "base_fee_bps": result.base_fee,
             }))?;
@@ -535,10 +634,67 @@ pub fn print_fee_rate(result: &FeeRateResponse, output: &OutputFormat) -> anyhow
     Ok(())
 }
 
+/// The cost of an order before it is placed, assuming it all fills as taker.
+pub struct FeePreview {
+    pub token_id: U256,
+    pub side: Side,
+    pub price: Decimal,
+    pub size: Decimal,
+    pub fee_rate_bps: u32,
+}
+
+impl FeePreview {
+    fn notional(&self) -> Decimal {
+        self.price * self.size
+    }
+
+    fn fee(&self) -> Decimal {
+        taker_fee(Decimal::from(self.fee_rate_bps), self.price, self.size)
+    }
+
+    fn net(&self) -> Decimal {
+        net_of_fee(matches!(self.side, Side::Buy), self.notional(), self.fee())
+    }
+}
+
+pub fn print_fee_preview(preview: &FeePreview, output: &OutputFormat) -> anyhow::Result<()> {
+    let net_label = match preview.side {
+        Side::Buy => "Total Cost",
+        _ => "Net Proceeds",
+    };
+    match output {
+        OutputFormat::Table => {
+            super::print_detail_table(vec![
+                ["Token ID".into(), preview.token_id.to_string()],
+                ["Side".into(), color::side(preview.side)],
+                ["Price".into(), preview.price.to_string()],
+                ["Size".into(), preview.size.to_string()],
+                ["Notional".into(), format!("${}", preview.notional())],
+                ["Fee Rate".into(), format!("{} bps", preview.fee_rate_bps)],
+                ["Taker Fee (max)".into(), format!("${}", preview.fee())],
+                [net_label.into(), format!("${}", preview.net())],
+            ]);
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            super::print_json(&json!({
+                "token_id": preview.token_id.to_string(),
+                "side": preview.side.to_string(),
+                "price": preview.price.to_string(),
+                "size": preview.size.to_string(),
+                "notional": preview.notional().to_string(),
+                "fee_rate_bps": preview.fee_rate_bps,
+                "taker_fee": preview.fee().to_string(),
+                "net": preview.net().to_string(),
+            }))?;
+        }
+    }
+    Ok(())
+}
+
 pub fn print_neg_risk(result: &NegRiskResponse, output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => println!("Neg risk: {}", result.neg_risk),
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(&json!({"neg_risk": result.neg_risk}))?;
         }
     }
@@ -573,10 +729,12 @@ pub fn print_price_history(
                     price: p.p.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = super::render_table(rows)?;
             println!("{table}");
+            let prices: Vec<Decimal> = result.history.iter().map(|p| p.p).collect();
+            super::print_detail_table(history_summary(&prices));
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .history
                 .iter()
@@ -588,7 +746,81 @@ pub fn print_price_history(
     Ok(())
 }
 
+/// Line chart of the series sized to the terminal, followed by the summary rows.
+pub fn print_price_chart(result: &PriceHistoryResponse, log_scale: bool) -> anyhow::Result<()> {
+    if result.history.is_empty() {
+        println!("No price history found.");
+        return Ok(());
+    }
+    let points: Vec<(i64, f64)> = result
+        .history
+        .iter()
+        .filter_map(|p| Some((p.t, p.p.to_f64()?)))
+        .collect();
+    print!(
+        "{}",
+        super::chart::render(&points, super::chart::ChartOptions::for_terminal(log_scale))?
+    );
+    let prices: Vec<Decimal> = result.history.iter().map(|p| p.p).collect();
+    super::print_detail_table(history_summary(&prices));
+    Ok(())
+}
+
+const SPARK_BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const SPARK_WIDTH: usize = 60;
+
+/// Unicode sparkline of `values`, sampled down to at most `width` bars.
+fn sparkline(values: &[Decimal], width: usize) -> String {
+    let (Some(min), Some(max)) = (values.iter().min(), values.iter().max()) else {
+        return String::new();
+    };
+    let range = max - min;
+    let step = values.len().div_ceil(width.max(1));
+    values
+        .iter()
+        .step_by(step.max(1))
+        .map(|v| {
+            if range.is_zero() {
+                return SPARK_BARS[0];
+            }
+            let level = ((v - min) / range * Decimal::from(SPARK_BARS.len() - 1))
+                .round()
+                .to_usize()
+                .unwrap_or(0);
+            SPARK_BARS[level.min(SPARK_BARS.len() - 1)]
+        })
+        .collect()
+}
+
+/// Percent change from the first to the last value, if the first is non-zero.
+fn percent_change(values: &[Decimal]) -> Option<Decimal> {
+    let (first, last) = (values.first()?, values.last()?);
+    (!first.is_zero()).then(|| (last - first) / first * Decimal::ONE_HUNDRED)
+}
+
+fn history_summary(prices: &[Decimal]) -> Vec<[String; 2]> {
+    let show = |v: Option<&Decimal>| v.map_or("—".into(), |p| p.normalize().to_string());
+    let change = percent_change(prices).map_or("—".into(), |pct| {
+        let text = format!("{:+.2}%", pct.round_dp(2));
+        if pct.is_sign_negative() {
+            color::ask(text)
+        } else {
+            color::bid(text)
+        }
+    });
+    vec![
+        ["Trend".into(), sparkline(prices, SPARK_WIDTH)],
+        ["Min".into(), show(prices.iter().min())],
+        ["Max".into(), show(prices.iter().max())],
+        ["Last".into(), show(prices.last())],
+        ["Change".into(), change],
+    ]
+}
+
 pub fn print_server_time(timestamp: i64, output: &OutputFormat) -> anyhow::Result<()> {
+    if super::print_raw(timestamp) {
+        return Ok(());
+    }
     match output {
         OutputFormat::Table => {
             let dt = chrono::DateTime::from_timestamp(timestamp, 0);
@@ -602,7 +834,7 @@ pub fn print_server_time(timestamp: i64, output: &OutputFormat) -> anyhow::Resul
                 None => println!("Server time: {timestamp}"),
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(&json!({"timestamp": timestamp}))?;
         }
     }
@@ -617,7 +849,7 @@ pub fn print_geoblock(result: &GeoblockResponse, output: &OutputFormat) -> anyho
             println!("Country: {}", result.country);
             println!("Region: {}", result.region);
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(&json!({
                 "blocked": result.blocked,
                 "ip": result.ip,
@@ -658,7 +890,7 @@ pub fn print_orders(result: &Page<OpenOrderResponse>, output: &OutputFormat) ->
                 .iter()
                 .map(|o| Row {
                     id: truncate(&o.id, 12),
-                    side: o.side.to_string(),
+                    side: color::side(o.side),
                     price: o.price.to_string(),
                     original_size: o.original_size.to_string(),
                     size_matched: o.size_matched.to_string(),
@@ -666,13 +898,16 @@ pub fn print_orders(result: &Page<OpenOrderResponse>, output: &OutputFormat) ->
                     order_type: o.order_type.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = super::render_table(rows)?;
             println!("{table}");
             if result.next_cursor != END_CURSOR {
-                println!("Next cursor: {}", result.next_cursor);
+                println!(
+                    "{}",
+                    color::dim(format!("Next cursor: {}", result.next_cursor))
+                );
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .data
                 .iter()
@@ -720,7 +955,7 @@ pub fn print_order_detail(result: &OpenOrderResponse, output: &OutputFormat) ->
             ];
             super::print_detail_table(rows);
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data = json!({
                 "id": result.id,
                 "status": result.status.to_string(),
@@ -779,13 +1014,35 @@ pub fn print_post_order_result(
             println!("Making: {}", result.making_amount);
             println!("Taking: {}", result.taking_amount);
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(&post_order_to_json(result))?;
         }
     }
     Ok(())
 }
 
+pub fn print_replace_result(
+    canceled_id: &str,
+    replacement: &PostOrderResponse,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            println!("Canceled: {canceled_id}");
+            println!("---");
+            print_post_order_result(replacement, output)?;
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let data = json!({
+                "canceled": canceled_id,
+                "replacement": post_order_to_json(replacement),
+            });
+            super::print_json(&data)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn print_post_orders_result(
     results: &[PostOrderResponse],
     output: &OutputFormat,
@@ -799,7 +1056,7 @@ pub fn print_post_orders_result(
                 print_post_order_result(r, output)?;
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = results.iter().map(post_order_to_json).collect();
             super::print_json(&data)?;
         }
@@ -807,6 +1064,69 @@ pub fn print_post_orders_result(
     Ok(())
 }
 
+/// Per-order report for `clob post-orders --file`, lined up with the file.
+pub fn print_order_file_results(
+    specs: &[OrderSpec],
+    results: &[PostOrderResponse],
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "#")]
+                index: usize,
+                #[tabled(rename = "Side")]
+                side: String,
+                #[tabled(rename = "Price")]
+                price: String,
+                #[tabled(rename = "Size")]
+                size: String,
+                #[tabled(rename = "Type")]
+                order_type: String,
+                #[tabled(rename = "Status")]
+                status: String,
+                #[tabled(rename = "Order ID / Error")]
+                detail: String,
+            }
+            let rows: Vec<Row> = specs
+                .iter()
+                .zip(results)
+                .enumerate()
+                .map(|(i, (spec, r))| Row {
+                    index: i + 1,
+                    side: color::side(Side::from(spec.side)),
+                    price: spec.price.to_string(),
+                    size: spec.size.to_string(),
+                    order_type: OrderType::from(spec.order_type).to_string(),
+                    status: r.status.to_string(),
+                    detail: match &r.error_msg {
+                        Some(err) if !r.success && !err.is_empty() => err.clone(),
+                        _ => truncate(&r.order_id, 20),
+                    },
+                })
+                .collect();
+            let table = super::render_table(rows)?;
+            println!("{table}");
+            let placed = results.iter().filter(|r| r.success).count();
+            println!("Placed {placed} of {} orders.", specs.len());
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let data: Vec<_> = specs
+                .iter()
+                .zip(results)
+                .map(|(spec, r)| {
+                    let mut value = post_order_to_json(r);
+                    value["order"] = serde_json::to_value(spec)?;
+                    Ok(value)
+                })
+                .collect::<anyhow::Result<_>>()?;
+            super::print_json(&data)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn print_cancel_result(
     result: &CancelOrdersResponse,
     output: &OutputFormat,
@@ -826,7 +1146,7 @@ pub fn print_cancel_result(
                 println!("No orders to cancel.");
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data = json!({
                 "canceled": result.canceled,
                 "not_canceled": result.not_canceled,
@@ -864,20 +1184,23 @@ pub fn print_trades(result: &Page<TradeResponse>, output: &OutputFormat) -> anyh
                 .iter()
                 .map(|t| Row {
                     id: truncate(&t.id, 12),
-                    side: t.side.to_string(),
+                    side: color::side(t.side),
                     price: t.price.to_string(),
                     size: t.size.to_string(),
                     status: t.status.to_string(),
                     match_time: t.match_time.format("%Y-%m-%d %H:%M").to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = super::render_table(rows)?;
             println!("{table}");
             if result.next_cursor != END_CURSOR {
-                println!("Next cursor: {}", result.next_cursor);
+                println!(
+                    "{}",
+                    color::dim(format!("Next cursor: {}", result.next_cursor))
+                );
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .data
                 .iter()
@@ -907,7 +1230,7 @@ pub fn print_trades(result: &Page<TradeResponse>, output: &OutputFormat) -> anyh
 }
 
 /// USDC uses 6 decimal places on-chain.
-const USDC_DECIMALS: u32 = 6;
+pub const USDC_DECIMALS: u32 = 6;
 
 pub fn print_balance(
     result: &BalanceAllowanceResponse,
@@ -930,7 +1253,7 @@ pub fn print_balance(
                 }
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let allowances: serde_json::Map<String, serde_json::Value> = result
                 .allowances
                 .iter()
@@ -946,6 +1269,101 @@ pub fn print_balance(
     Ok(())
 }
 
+/// Converts a 6-decimal on-chain amount, as the balance endpoint reports
+/// it, to USDC or shares.
+pub fn from_raw_decimal(raw: Decimal) -> Decimal {
+    (raw / Decimal::from(10u64.pow(USDC_DECIMALS))).normalize()
+}
+
+/// Allowances come back as raw integers, and approvals are usually the
+/// maximum, which reads better as "unlimited".
+fn format_allowance(raw: &str) -> String {
+    match U256::from_str(raw) {
+        Ok(amount) if amount >= U256::MAX >> 1 => "unlimited".into(),
+        Ok(_) => match Decimal::from_str(raw) {
+            Ok(amount) => format_decimal(from_raw_decimal(amount)),
+            Err(_) => raw.into(),
+        },
+        Err(_) => raw.into(),
+    }
+}
+
+/// A conditional token balance in `clob balances`.
+pub struct TokenBalance {
+    pub token_id: U256,
+    /// Outcome and market question, if Gamma knows the token
+    pub label: Option<String>,
+    pub shares: Decimal,
+}
+
+pub fn print_balances(
+    collateral: &BalanceAllowanceResponse,
+    tokens: &[TokenBalance],
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    let usdc = from_raw_decimal(collateral.balance);
+    match output {
+        OutputFormat::Table => {
+            println!("USDC: {}", format_decimal(usdc));
+            let mut allowances: Vec<_> = collateral.allowances.iter().collect();
+            allowances.sort();
+            for (spender, raw) in allowances {
+                println!(
+                    "  allowance for {}: {}",
+                    truncate(&spender.to_string(), 14),
+                    format_allowance(raw)
+                );
+            }
+            if tokens.is_empty() {
+                println!("No open orders or positions.");
+                return Ok(());
+            }
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Market")]
+                market: String,
+                #[tabled(rename = "Token ID")]
+                token_id: String,
+                #[tabled(rename = "Shares")]
+                shares: String,
+            }
+            let rows: Vec<Row> = tokens
+                .iter()
+                .map(|t| Row {
+                    market: truncate(t.label.as_deref().unwrap_or("—"), 50),
+                    token_id: truncate(&t.token_id.to_string(), 14),
+                    shares: t.shares.to_string(),
+                })
+                .collect();
+            println!();
+            println!("{}", super::render_table(rows)?);
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let allowances: serde_json::Map<String, serde_json::Value> = collateral
+                .allowances
+                .iter()
+                .map(|(spender, raw)| (spender.to_string(), json!(raw)))
+                .collect();
+            let tokens: Vec<_> = tokens
+                .iter()
+                .map(|t| {
+                    json!({
+                        "token_id": t.token_id.to_string(),
+                        "label": t.label,
+                        "shares": t.shares.to_string(),
+                    })
+                })
+                .collect();
+            super::print_json(&json!({
+                "usdc": usdc.to_string(),
+                "allowances": allowances,
+                "tokens": tokens,
+            }))?;
+        }
+    }
+    Ok(())
+}
+
 pub fn print_notifications(
     result: &[NotificationResponse],
     output: &OutputFormat,
@@ -974,15 +1392,15 @@ pub fn print_notifications(
                 .map(|n| Row {
                     notif_type: n.r#type.to_string(),
                     question: truncate(&n.payload.question, 40),
-                    side: n.payload.side.to_string(),
+                    side: color::side(n.payload.side),
                     price: n.payload.price.to_string(),
                     size: n.payload.matched_size.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = super::render_table(rows)?;
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .iter()
                 .map(|n| {
@@ -1037,13 +1455,16 @@ pub fn print_rewards(
                     rate: e.asset_rate.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = super::render_table(rows)?;
             println!("{table}");
             if result.next_cursor != END_CURSOR {
-                println!("Next cursor: {}", result.next_cursor);
+                println!(
+                    "{}",
+                    color::dim(format!("Next cursor: {}", result.next_cursor))
+                );
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .data
                 .iter()
@@ -1085,7 +1506,7 @@ pub fn print_earnings(
                 println!("Maker: {}", e.maker_address);
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .iter()
                 .map(|e| {
@@ -1104,6 +1525,129 @@ pub fn print_earnings(
     Ok(())
 }
 
+/// Reward earnings summed over `--from`/`--to`.
+pub struct EarningsRange {
+    /// Total per day, oldest first, including days that earned nothing
+    pub daily: Vec<(NaiveDate, Decimal)>,
+    /// Total per market, largest first; empty when only day totals are known
+    pub markets: Vec<(B256, Decimal)>,
+    /// Each market's earnings per day, oldest first
+    pub entries: Vec<(NaiveDate, B256, Decimal)>,
+}
+
+impl EarningsRange {
+    fn total(&self) -> Decimal {
+        self.daily.iter().map(|(_, e)| *e).sum()
+    }
+}
+
+pub fn print_earnings_range(range: &EarningsRange, output: &OutputFormat) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            #[derive(Tabled)]
+            struct DayRow {
+                #[tabled(rename = "Date")]
+                date: String,
+                #[tabled(rename = "Earnings")]
+                earnings: String,
+            }
+            let days: Vec<DayRow> = range
+                .daily
+                .iter()
+                .map(|(date, earnings)| DayRow {
+                    date: date.to_string(),
+                    earnings: format_decimal(*earnings),
+                })
+                .collect();
+            println!("{}", super::render_table(days)?);
+            if !range.markets.is_empty() {
+                #[derive(Tabled)]
+                struct MarketRow {
+                    #[tabled(rename = "Condition ID")]
+                    condition_id: String,
+                    #[tabled(rename = "Earnings")]
+                    earnings: String,
+                }
+                let markets: Vec<MarketRow> = range
+                    .markets
+                    .iter()
+                    .map(|(condition_id, earnings)| MarketRow {
+                        condition_id: truncate(&condition_id.to_string(), 14),
+                        earnings: format_decimal(*earnings),
+                    })
+                    .collect();
+                println!("{}", super::render_table(markets)?);
+            }
+            println!("Total: {}", format_decimal(range.total()));
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let daily: Vec<_> = range
+                .daily
+                .iter()
+                .map(|(date, earnings)| {
+                    json!({"date": date.to_string(), "earnings": earnings.to_string()})
+                })
+                .collect();
+            let mut data = json!({
+                "total": range.total().to_string(),
+                "daily": daily,
+            });
+            if !range.markets.is_empty() {
+                data["markets"] = range
+                    .markets
+                    .iter()
+                    .map(|(condition_id, earnings)| {
+                        json!({
+                            "condition_id": condition_id.to_string(),
+                            "earnings": earnings.to_string(),
+                        })
+                    })
+                    .collect();
+            }
+            super::print_json(&data)?;
+        }
+    }
+    Ok(())
+}
+
+/// CSV of `date,condition_id,earnings`: one row per day and market, then a
+/// subtotal per day (blank market), per market (blank date), and the total
+/// (both blank).
+pub fn earnings_csv(range: &EarningsRange) -> String {
+    let mut csv = String::from("date,condition_id,earnings\n");
+    for (date, market, earnings) in &range.entries {
+        csv.push_str(&format!("{date},{market},{earnings}\n"));
+    }
+    for (date, earnings) in &range.daily {
+        csv.push_str(&format!("{date},,{earnings}\n"));
+    }
+    for (market, earnings) in &range.markets {
+        csv.push_str(&format!(",{market},{earnings}\n"));
+    }
+    csv.push_str(&format!(",,{}\n", range.total()));
+    csv
+}
+
+pub fn print_earnings_summary(
+    month: &str,
+    range: &EarningsRange,
+    csv: Option<&Path>,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    if matches!(output, OutputFormat::Table) {
+        println!("Earnings for {month}");
+        print_earnings_range(range, output)?;
+        if let Some(path) = csv {
+            println!(
+                "{}",
+                color::dim(format!("CSV written to {}", path.display()))
+            );
+        }
+        return Ok(());
+    }
+    print_earnings_range(range, output)
+}
+
 pub fn print_user_earnings_markets(
     result: &[UserRewardsEarningResponse],
     output: &OutputFormat,
@@ -1137,10 +1681,10 @@ pub fn print_user_earnings_markets(
                     min_size: e.rewards_min_size.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = super::render_table(rows)?;
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .iter()
                 .map(|e| {
@@ -1205,10 +1749,10 @@ pub fn print_reward_percentages(
                     percentage: format!("{pct}%"),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = super::render_table(rows)?;
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: serde_json::Map<String, serde_json::Value> = result
                 .iter()
                 .map(|(k, v)| (k.clone(), json!(v.to_string())))
@@ -1250,13 +1794,16 @@ pub fn print_current_rewards(
                     configs: r.rewards_config.len().to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = super::render_table(rows)?;
             println!("{table}");
             if result.next_cursor != END_CURSOR {
-                println!("Next cursor: {}", result.next_cursor);
+                println!(
+                    "{}",
+                    color::dim(format!("Next cursor: {}", result.next_cursor))
+                );
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .data
                 .iter()
@@ -1282,6 +1829,118 @@ pub fn print_current_rewards(
     Ok(())
 }
 
+/// One quote suggested by `clob rewards-suggest`.
+pub struct RewardQuote {
+    pub side: Side,
+    pub price: Decimal,
+    pub size: Decimal,
+    /// Distance from the midpoint
+    pub distance: Decimal,
+    /// Score per share relative to an order at the midpoint, from 0 to 1
+    pub score: Decimal,
+}
+
+/// Suggested quotes for one outcome token of a rewarded market.
+pub struct RewardSuggestion {
+    pub outcome: String,
+    pub token_id: U256,
+    pub midpoint: Decimal,
+    pub quotes: Vec<RewardQuote>,
+}
+
+/// Rewards only pay single-sided quotes near the middle of the range, and
+/// at a reduced rate.
+fn two_sided_note(midpoint: Decimal) -> &'static str {
+    if midpoint < Decimal::new(10, 2) || midpoint > Decimal::new(90, 2) {
+        "Only two-sided quotes score at this midpoint; post both sides."
+    } else {
+        "Single-sided quotes score at a third of the rate; post both sides for full rewards."
+    }
+}
+
+pub fn print_reward_suggestions(
+    market: &MarketRewardResponse,
+    suggestions: &[RewardSuggestion],
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    let daily: Decimal = market.rewards_config.iter().map(|c| c.rate_per_day).sum();
+    match output {
+        OutputFormat::Table => {
+            println!("{}", market.question);
+            println!(
+                "Max spread: {}¢ · Min size: {} · Rewards: {}/day",
+                market.rewards_max_spread,
+                market.rewards_min_size,
+                format_decimal(daily)
+            );
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Outcome")]
+                outcome: String,
+                #[tabled(rename = "Midpoint")]
+                midpoint: String,
+                #[tabled(rename = "Side")]
+                side: String,
+                #[tabled(rename = "Price")]
+                price: String,
+                #[tabled(rename = "Size")]
+                size: String,
+                #[tabled(rename = "From Mid")]
+                distance: String,
+                #[tabled(rename = "Score")]
+                score: String,
+            }
+            let rows: Vec<Row> = suggestions
+                .iter()
+                .flat_map(|s| {
+                    s.quotes.iter().map(|q| Row {
+                        outcome: s.outcome.clone(),
+                        midpoint: s.midpoint.normalize().to_string(),
+                        side: color::side(q.side),
+                        price: q.price.to_string(),
+                        size: q.size.to_string(),
+                        distance: q.distance.normalize().to_string(),
+                        score: format!("{}%", (q.score * Decimal::ONE_HUNDRED).round_dp(1)),
+                    })
+                })
+                .collect();
+            println!("{}", super::render_table(rows)?);
+            if let Some(first) = suggestions.first() {
+                println!("{}", color::dim(two_sided_note(first.midpoint)));
+            }
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let data: Vec<_> = suggestions
+                .iter()
+                .map(|s| {
+                    json!({
+                        "outcome": s.outcome,
+                        "token_id": s.token_id.to_string(),
+                        "midpoint": s.midpoint.to_string(),
+                        "quotes": s.quotes.iter().map(|q| json!({
+                            "side": q.side.to_string(),
+                            "price": q.price.to_string(),
+                            "size": q.size.to_string(),
+                            "distance": q.distance.to_string(),
+                            "score": q.score.to_string(),
+                        })).collect::<Vec<_>>(),
+                    })
+                })
+                .collect();
+            super::print_json(&json!({
+                "condition_id": market.condition_id.to_string(),
+                "question": market.question,
+                "rewards_max_spread": market.rewards_max_spread.to_string(),
+                "rewards_min_size": market.rewards_min_size.to_string(),
+                "rewards_per_day": daily.to_string(),
+                "note": suggestions.first().map(|s| two_sided_note(s.midpoint)),
+                "suggestions": data,
+            }))?;
+        }
+    }
+    Ok(())
+}
+
 pub fn print_market_reward(
     result: &Page<MarketRewardResponse>,
     output: &OutputFormat,
@@ -1310,10 +1969,13 @@ pub fn print_market_reward(
                 }
             }
             if result.next_cursor != END_CURSOR {
-                println!("Next cursor: {}", result.next_cursor);
+                println!(
+                    "{}",
+                    color::dim(format!("Next cursor: {}", result.next_cursor))
+                );
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .data
                 .iter()
@@ -1357,7 +2019,7 @@ pub fn print_order_scoring(
 ) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => println!("Scoring: {}", result.scoring),
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(&json!({"scoring": result.scoring}))?;
         }
     }
@@ -1388,10 +2050,10 @@ pub fn print_orders_scoring(
                     scoring: scoring.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = super::render_table(rows)?;
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(result)?;
         }
     }
@@ -1406,7 +2068,7 @@ pub fn print_api_keys(result: &ApiKeysResponse, output: &OutputFormat) -> anyhow
         OutputFormat::Table => {
             println!("API Keys: {debug}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(&json!({"api_keys": debug}))?;
         }
     }
@@ -1419,7 +2081,7 @@ pub fn print_delete_api_key(
 ) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => println!("API key deleted: {result}"),
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(result)?;
         }
     }
@@ -1433,7 +2095,7 @@ pub fn print_create_api_key(result: &Credentials, output: &OutputFormat) -> anyh
             println!("Secret: [redacted]");
             println!("Passphrase: [redacted]");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(&json!({
                 "api_key": result.key().to_string(),
                 "secret": "[redacted]",
@@ -1444,6 +2106,31 @@ pub fn print_create_api_key(result: &Credentials, output: &OutputFormat) -> anyh
     Ok(())
 }
 
+pub fn print_rotate_api_key(
+    old_key: ApiKey,
+    new: &Credentials,
+    old_deleted: bool,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            println!("New API key: {}", new.key());
+            println!(
+                "Old API key: {old_key}{}",
+                if old_deleted { " (deleted)" } else { "" }
+            );
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            super::print_json(&json!({
+                "api_key": new.key().to_string(),
+                "old_api_key": old_key.to_string(),
+                "old_deleted": old_deleted,
+            }))?;
+        }
+    }
+    Ok(())
+}
+
 pub fn print_account_status(
     result: &BanStatusResponse,
     output: &OutputFormat,
@@ -1459,9 +2146,304 @@ pub fn print_account_status(
                 }
             );
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(&json!({"closed_only": result.closed_only}))?;
         }
     }
     Ok(())
 }
+
+/// WebSocket timestamps arrive in seconds or milliseconds depending on the event.
+fn format_ws_timestamp(ts: Option<i64>) -> String {
+    let dt = match ts {
+        Some(t) if t > 1_000_000_000_000 => chrono::DateTime::from_timestamp_millis(t),
+        Some(t) => chrono::DateTime::from_timestamp(t, 0),
+        None => None,
+    };
+    dt.unwrap_or_else(chrono::Utc::now)
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string()
+}
+
+fn format_order_message_type(t: Option<&OrderMessageType>) -> String {
+    match t {
+        Some(OrderMessageType::Placement) => "PLACEMENT".into(),
+        Some(OrderMessageType::Cancellation) => "CANCELLATION".into(),
+        Some(OrderMessageType::Unknown(s)) => s.to_uppercase(),
+        _ => "UPDATE".into(),
+    }
+}
+
+fn format_trade_message_status(s: &TradeMessageStatus) -> String {
+    match s {
+        TradeMessageStatus::Matched => "MATCHED".into(),
+        TradeMessageStatus::Mined => "MINED".into(),
+        TradeMessageStatus::Confirmed => "CONFIRMED".into(),
+        TradeMessageStatus::Unknown(s) => s.to_uppercase(),
+        _ => "UNKNOWN".into(),
+    }
+}
+
+pub fn print_order_update(order: &OrderMessage, output: &OutputFormat) -> anyhow::Result<()> {
+    let kind = format_order_message_type(order.msg_type.as_ref());
+    match output {
+        OutputFormat::Table => {
+            println!(
+                "{}  ORDER  {kind:<12} {} {} @ {}  matched {}  {}",
+                color::dim(format_ws_timestamp(order.timestamp)),
+                color::side(format!("{:<4}", order.side)),
+                order.original_size.map_or("—".into(), |s| s.to_string()),
+                order.price,
+                order.size_matched.map_or("—".into(), |s| s.to_string()),
+                truncate(&order.id, 14),
+            );
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            super::print_json(&json!({
+                "event": "order",
+                "type": kind,
+                "id": order.id,
+                "market": order.market.to_string(),
+                "asset_id": order.asset_id.to_string(),
+                "side": order.side.to_string(),
+                "price": order.price.to_string(),
+                "original_size": order.original_size.map(|s| s.to_string()),
+                "size_matched": order.size_matched.map(|s| s.to_string()),
+                "outcome": order.outcome,
+                "status": order.status.as_ref().map(ToString::to_string),
+                "timestamp": order.timestamp,
+            }))?;
+        }
+    }
+    Ok(())
+}
+
+pub fn print_trade_update(trade: &TradeMessage, output: &OutputFormat) -> anyhow::Result<()> {
+    let status = format_trade_message_status(&trade.status);
+    match output {
+        OutputFormat::Table => {
+            println!(
+                "{}  FILL   {status:<12} {} {} @ {}  {}",
+                color::dim(format_ws_timestamp(trade.matchtime.or(trade.timestamp))),
+                color::side(format!("{:<4}", trade.side)),
+                trade.size,
+                trade.price,
+                truncate(&trade.id, 14),
+            );
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            super::print_json(&json!({
+                "event": "trade",
+                "status": status,
+                "id": trade.id,
+                "market": trade.market.to_string(),
+                "asset_id": trade.asset_id.to_string(),
+                "side": trade.side.to_string(),
+                "size": trade.size.to_string(),
+                "price": trade.price.to_string(),
+                "outcome": trade.outcome,
+                "taker_order_id": trade.taker_order_id,
+                "fee_rate_bps": trade.fee_rate_bps.map(|f| f.to_string()),
+                "transaction_hash": trade.transaction_hash.map(|h| h.to_string()),
+                "match_time": trade.matchtime,
+            }))?;
+        }
+    }
+    Ok(())
+}
+
+/// Latest top-of-book quote for a token in the live price ticker.
+#[derive(Debug, Clone)]
+pub struct TickerQuote {
+    pub token_id: U256,
+    pub best_bid: Option<Decimal>,
+    pub best_ask: Option<Decimal>,
+}
+
+impl TickerQuote {
+    pub fn midpoint(&self) -> Option<Decimal> {
+        match (self.best_bid, self.best_ask) {
+            (Some(bid), Some(ask)) => Some((bid + ask) / Decimal::TWO),
+            _ => None,
+        }
+    }
+}
+
+fn format_quote_price(price: Option<Decimal>) -> String {
+    price.map_or_else(|| "—".into(), |p| p.normalize().to_string())
+}
+
+pub fn print_price_ticker(quotes: &[TickerQuote], output: &OutputFormat) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Token ID")]
+                token_id: String,
+                #[tabled(rename = "Bid")]
+                bid: String,
+                #[tabled(rename = "Ask")]
+                ask: String,
+                #[tabled(rename = "Mid")]
+                mid: String,
+            }
+            let rows: Vec<Row> = quotes
+                .iter()
+                .map(|q| Row {
+                    token_id: truncate(&q.token_id.to_string(), 20),
+                    bid: color::bid(format_quote_price(q.best_bid)),
+                    ask: color::ask(format_quote_price(q.best_ask)),
+                    mid: format_quote_price(q.midpoint()),
+                })
+                .collect();
+            let table = super::render_table(rows)?;
+            // Redraw in place so the ticker updates continuously.
+            print!("\x1b[2J\x1b[H");
+            println!("{}  Press Ctrl+C to stop.", format_ws_timestamp(None));
+            println!("{table}");
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            for q in quotes {
+                super::print_json(&json!({
+                    "event": "price",
+                    "token_id": q.token_id.to_string(),
+                    "best_bid": q.best_bid.map(|p| p.to_string()),
+                    "best_ask": q.best_ask.map(|p| p.to_string()),
+                    "midpoint": q.midpoint().map(|p| p.to_string()),
+                }))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn quote(bid: Option<Decimal>, ask: Option<Decimal>) -> TickerQuote {
+        TickerQuote {
+            token_id: U256::from(1u64),
+            best_bid: bid,
+            best_ask: ask,
+        }
+    }
+
+    #[test]
+    fn ticker_midpoint_averages_bid_and_ask() {
+        assert_eq!(
+            quote(Some(dec!(0.48)), Some(dec!(0.52))).midpoint(),
+            Some(dec!(0.50))
+        );
+    }
+
+    #[test]
+    fn ticker_midpoint_requires_both_sides() {
+        assert_eq!(quote(Some(dec!(0.48)), None).midpoint(), None);
+        assert_eq!(quote(None, None).midpoint(), None);
+    }
+
+    #[test]
+    fn sparkline_spans_lowest_to_highest_bar() {
+        assert_eq!(sparkline(&[dec!(0.1), dec!(0.5), dec!(0.9)], 10), "▁▅█");
+    }
+
+    #[test]
+    fn sparkline_flat_series_and_sampling() {
+        assert_eq!(sparkline(&[dec!(0.5); 4], 10), "▁▁▁▁");
+        assert_eq!(sparkline(&[dec!(0.5); 100], 10).chars().count(), 10);
+        assert_eq!(sparkline(&[], 10), "");
+    }
+
+    #[test]
+    fn percent_change_first_to_last() {
+        assert_eq!(percent_change(&[dec!(0.40), dec!(0.50)]), Some(dec!(25)));
+        assert_eq!(percent_change(&[dec!(0), dec!(0.50)]), None);
+        assert_eq!(percent_change(&[]), None);
+    }
+
+    #[test]
+    fn format_quote_price_missing_is_dash() {
+        assert_eq!(format_quote_price(None), "—");
+        assert_eq!(format_quote_price(Some(dec!(0.500))), "0.5");
+    }
+
+    #[test]
+    fn format_order_message_type_known_variants() {
+        assert_eq!(
+            format_order_message_type(Some(&OrderMessageType::Placement)),
+            "PLACEMENT"
+        );
+        assert_eq!(
+            format_order_message_type(Some(&OrderMessageType::Update)),
+            "UPDATE"
+        );
+        assert_eq!(
+            format_order_message_type(Some(&OrderMessageType::Cancellation)),
+            "CANCELLATION"
+        );
+    }
+
+    #[test]
+    fn format_order_message_type_missing_is_update() {
+        assert_eq!(format_order_message_type(None), "UPDATE");
+    }
+
+    #[test]
+    fn format_order_message_type_unknown_passes_through() {
+        let t = OrderMessageType::Unknown("expired".into());
+        assert_eq!(format_order_message_type(Some(&t)), "EXPIRED");
+    }
+
+    #[test]
+    fn format_trade_message_status_variants() {
+        assert_eq!(
+            format_trade_message_status(&TradeMessageStatus::Matched),
+            "MATCHED"
+        );
+        assert_eq!(
+            format_trade_message_status(&TradeMessageStatus::Confirmed),
+            "CONFIRMED"
+        );
+        assert_eq!(
+            format_trade_message_status(&TradeMessageStatus::Unknown("failed".into())),
+            "FAILED"
+        );
+    }
+
+    #[test]
+    fn format_ws_timestamp_seconds_and_millis_agree() {
+        assert_eq!(
+            format_ws_timestamp(Some(1_700_000_000)),
+            format_ws_timestamp(Some(1_700_000_000_000))
+        );
+        assert_eq!(format_ws_timestamp(Some(0)), "1970-01-01 00:00:00");
+    }
+
+    #[test]
+    fn allowances_read_in_usdc_or_unlimited() {
+        assert_eq!(format_allowance("2500000"), "$2.50");
+        assert_eq!(format_allowance(&U256::MAX.to_string()), "unlimited");
+        assert_eq!(format_allowance("n/a"), "n/a");
+        assert_eq!(from_raw_decimal(dec!(12345678)), dec!(12.345678));
+    }
+
+    #[test]
+    fn earnings_csv_lists_entries_then_subtotals() {
+        let day = NaiveDate::from_ymd_opt(2025, 1, 2).unwrap();
+        let market = B256::repeat_byte(0xa);
+        let range = EarningsRange {
+            daily: vec![(day, dec!(1.5))],
+            markets: vec![(market, dec!(1.5))],
+            entries: vec![(day, market, dec!(1.5))],
+        };
+        let csv = earnings_csv(&range);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "date,condition_id,earnings");
+        assert_eq!(lines[1], format!("2025-01-02,{market},1.5"));
+        assert_eq!(lines[2], "2025-01-02,,1.5");
+        assert_eq!(lines[3], format!(",{market},1.5"));
+        assert_eq!(lines[4], ",,1.5");
+    }
+}
@@ -0,0 +1,108 @@
+//! ANSI colors for table output: green for bids/buys, red for asks/sells,
+//! dim for metadata. Structured formats are never colored.
+
+use std::fmt::Display;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorChoice {
+    /// Color when stdout is a terminal and `NO_COLOR` is not set
+    Auto,
+    Always,
+    Never,
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+const DIM: &str = "\x1b[2m";
+const REVERSE: &str = "\x1b[7m";
+const RESET: &str = "\x1b[0m";
+
+/// Decides whether to color. Priority: `--color` flag > config file >
+/// `NO_COLOR` > whether stdout is a terminal.
+pub fn configure(flag: Option<ColorChoice>, config: Option<ColorChoice>) {
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    let enabled = resolve(flag.or(config), no_color, std::io::stdout().is_terminal());
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+fn resolve(choice: Option<ColorChoice>, no_color: bool, is_terminal: bool) -> bool {
+    match choice {
+        Some(ColorChoice::Always) => true,
+        Some(ColorChoice::Never) => false,
+        Some(ColorChoice::Auto) | None => !no_color && is_terminal,
+    }
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+fn paint(code: &str, text: impl Display) -> String {
+    if enabled() {
+        format!("{code}{text}{RESET}")
+    } else {
+        text.to_string()
+    }
+}
+
+/// Bid/buy styling.
+pub fn bid(text: impl Display) -> String {
+    paint(GREEN, text)
+}
+
+/// Ask/sell styling.
+pub fn ask(text: impl Display) -> String {
+    paint(RED, text)
+}
+
+/// De-emphasized metadata such as cursors, IDs, and timestamps.
+pub fn dim(text: impl Display) -> String {
+    paint(DIM, text)
+}
+
+/// Values that changed since the previous `--watch` refresh.
+pub fn changed(text: impl Display) -> String {
+    paint(REVERSE, text)
+}
+
+/// Colors an order or trade side: `BUY` as a bid, `SELL` as an ask.
+pub fn side(side: impl Display) -> String {
+    let text = side.to_string();
+    match text.trim().to_ascii_uppercase().as_str() {
+        "BUY" => bid(text),
+        "SELL" => ask(text),
+        _ => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explicit_choice_overrides_environment() {
+        assert!(resolve(Some(ColorChoice::Always), true, false));
+        assert!(!resolve(Some(ColorChoice::Never), false, true));
+    }
+
+    #[test]
+    fn auto_respects_no_color_and_terminal() {
+        assert!(resolve(None, false, true));
+        assert!(!resolve(None, true, true));
+        assert!(!resolve(Some(ColorChoice::Auto), false, false));
+    }
+
+    #[test]
+    fn plain_text_when_disabled() {
+        ENABLED.store(false, Ordering::Relaxed);
+        assert_eq!(side("BUY"), "BUY");
+        assert_eq!(dim("cursor"), "cursor");
+    }
+}
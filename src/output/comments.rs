@@ -2,7 +2,7 @@ use polymarket_client_sdk::gamma::types::response::Comment;
 use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
-use super::{detail_field, print_detail_table, truncate};
+use super::{color, detail_field, print_detail_table, truncate};
 
 #[derive(Tabled)]
 struct CommentRow {
@@ -51,6 +51,49 @@ pub fn print_comments_table(comments: &[Comment]) {
     println!("{table}");
 }
 
+/// Top-level comments in the given order, each followed by its replies
+/// (oldest first). Replies whose parent isn't on this page stand alone.
+fn thread_order(comments: &[Comment]) -> Vec<(&Comment, bool)> {
+    let on_page = |id: &str| comments.iter().any(|c| c.id == id);
+    let mut out = Vec::new();
+    for root in comments
+        .iter()
+        .filter(|c| !c.parent_comment_id.as_deref().is_some_and(on_page))
+    {
+        out.push((root, false));
+        let mut replies: Vec<&Comment> = comments
+            .iter()
+            .filter(|c| c.parent_comment_id.as_deref() == Some(root.id.as_str()))
+            .collect();
+        replies.sort_by_key(|c| c.created_at);
+        out.extend(replies.into_iter().map(|c| (c, true)));
+    }
+    out
+}
+
+pub fn print_comment_thread(comments: &[Comment]) {
+    if comments.is_empty() {
+        println!("No comments found.");
+        return;
+    }
+    for (c, is_reply) in thread_order(comments) {
+        let indent = if is_reply { "    ↳ " } else { "" };
+        let header = format!(
+            "{} · {} · {} reactions",
+            comment_author(c),
+            c.created_at
+                .map_or_else(|| "—".into(), |d| d.format("%Y-%m-%d %H:%M").to_string()),
+            c.reaction_count.unwrap_or(0),
+        );
+        println!("{indent}{}", color::dim(header));
+        let body_indent = if is_reply { "      " } else { "" };
+        for line in c.body.as_deref().unwrap_or_default().lines() {
+            println!("{body_indent}{line}");
+        }
+        println!();
+    }
+}
+
 pub fn print_comment_detail(c: &Comment) {
     let mut rows: Vec<[String; 2]> = Vec::new();
 
@@ -111,3 +154,35 @@ pub fn print_comment_detail(c: &Comment) {
 
     print_detail_table(rows);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn thread_order_nests_replies_under_their_parent() {
+        let comments: Vec<Comment> = serde_json::from_value(json!([
+            {"id": "b", "createdAt": "2025-01-02T00:00:00Z"},
+            {"id": "r2", "parentCommentID": "a", "createdAt": "2025-01-03T00:00:00Z"},
+            {"id": "a", "createdAt": "2025-01-01T00:00:00Z"},
+            {"id": "r1", "parentCommentID": "a", "createdAt": "2025-01-02T00:00:00Z"},
+            {"id": "orphan", "parentCommentID": "gone"}
+        ]))
+        .unwrap();
+        let order: Vec<(&str, bool)> = thread_order(&comments)
+            .into_iter()
+            .map(|(c, reply)| (c.id.as_str(), reply))
+            .collect();
+        assert_eq!(
+            order,
+            [
+                ("b", false),
+                ("a", false),
+                ("r1", true),
+                ("r2", true),
+                ("orphan", false)
+            ]
+        );
+    }
+}
@@ -1,30 +1,35 @@
-use alloy::primitives::{B256, U256};
+use alloy::primitives::{Address, B256, U256};
 use anyhow::Result;
+use rust_decimal::Decimal;
+use tabled::Tabled;
 
-use super::{OutputFormat, print_detail_table};
+use super::{OutputFormat, color, print_detail_table, truncate};
 
 pub fn print_tx_result(
     operation: &str,
     tx_hash: B256,
-    block_number: u64,
+    block_number: Option<u64>,
     output: &OutputFormat,
 ) -> Result<()> {
     match output {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let json = serde_json::json!({
                 "operation": operation,
                 "transaction_hash": format!("{tx_hash}"),
                 "block_number": block_number,
                 "polygonscan": format!("https://polygonscan.com/tx/{tx_hash}"),
             });
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            super::print_json(&json)?;
             Ok(())
         }
         OutputFormat::Table => {
             let rows = vec![
                 ["Operation".into(), operation.to_string()],
                 ["Tx Hash".into(), format!("{tx_hash}")],
-                ["Block".into(), block_number.to_string()],
+                [
+                    "Block".into(),
+                    block_number.map_or_else(|| "not yet mined".into(), |b| b.to_string()),
+                ],
                 [
                     "Polygonscan".into(),
                     format!("https://polygonscan.com/tx/{tx_hash}"),
@@ -37,12 +42,15 @@ pub fn print_tx_result(
 }
 
 pub fn print_condition_id(condition_id: B256, output: &OutputFormat) -> Result<()> {
+    if super::print_raw(condition_id) {
+        return Ok(());
+    }
     match output {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let json = serde_json::json!({
                 "condition_id": format!("{condition_id}"),
             });
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            super::print_json(&json)?;
             Ok(())
         }
         OutputFormat::Table => {
@@ -53,12 +61,15 @@ pub fn print_condition_id(condition_id: B256, output: &OutputFormat) -> Result<(
 }
 
 pub fn print_collection_id(collection_id: B256, output: &OutputFormat) -> Result<()> {
+    if super::print_raw(collection_id) {
+        return Ok(());
+    }
     match output {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let json = serde_json::json!({
                 "collection_id": format!("{collection_id}"),
             });
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            super::print_json(&json)?;
             Ok(())
         }
         OutputFormat::Table => {
@@ -69,12 +80,15 @@ pub fn print_collection_id(collection_id: B256, output: &OutputFormat) -> Result
 }
 
 pub fn print_position_id(position_id: U256, output: &OutputFormat) -> Result<()> {
+    if super::print_raw(position_id) {
+        return Ok(());
+    }
     match output {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let json = serde_json::json!({
                 "position_id": position_id.to_string(),
             });
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            super::print_json(&json)?;
             Ok(())
         }
         OutputFormat::Table => {
@@ -83,3 +97,352 @@ pub fn print_position_id(position_id: U256, output: &OutputFormat) -> Result<()>
         }
     }
 }
+
+/// An outcome token balance read from the Conditional Tokens contract.
+pub struct PositionBalance {
+    pub token_id: U256,
+    /// Outcome and market question, when known
+    pub label: Option<String>,
+    pub shares: Decimal,
+}
+
+pub fn print_balances(
+    owner: Address,
+    balances: &[PositionBalance],
+    output: &OutputFormat,
+) -> Result<()> {
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let positions: Vec<_> = balances
+                .iter()
+                .map(|b| {
+                    serde_json::json!({
+                        "token_id": b.token_id.to_string(),
+                        "label": b.label,
+                        "shares": b.shares.to_string(),
+                    })
+                })
+                .collect();
+            super::print_json(&serde_json::json!({
+                "address": format!("{owner}"),
+                "positions": positions,
+            }))?;
+            Ok(())
+        }
+        OutputFormat::Table => {
+            if balances.is_empty() {
+                println!("No positions found for {owner}.");
+                return Ok(());
+            }
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Outcome")]
+                outcome: String,
+                #[tabled(rename = "Token ID")]
+                token_id: String,
+                #[tabled(rename = "Shares")]
+                shares: String,
+            }
+            let rows: Vec<Row> = balances
+                .iter()
+                .map(|b| Row {
+                    outcome: truncate(b.label.as_deref().unwrap_or("—"), 50),
+                    token_id: truncate(&b.token_id.to_string(), 14),
+                    shares: b.shares.to_string(),
+                })
+                .collect();
+            println!("{}", super::render_table(rows)?);
+            Ok(())
+        }
+    }
+}
+
+/// Whether an operator may move a wallet's outcome tokens, and the
+/// transaction that changed it, if any.
+pub struct OperatorApproval {
+    pub name: String,
+    pub operator: Address,
+    pub approved: bool,
+    pub tx_hash: Option<B256>,
+}
+
+pub fn print_operator_approvals(
+    owner: Address,
+    approvals: &[OperatorApproval],
+    output: &OutputFormat,
+) -> Result<()> {
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let operators: Vec<_> = approvals
+                .iter()
+                .map(|a| {
+                    serde_json::json!({
+                        "name": a.name,
+                        "operator": format!("{}", a.operator),
+                        "approved": a.approved,
+                        "transaction_hash": a.tx_hash.map(|h| format!("{h}")),
+                    })
+                })
+                .collect();
+            super::print_json(&serde_json::json!({
+                "address": format!("{owner}"),
+                "operators": operators,
+            }))?;
+            Ok(())
+        }
+        OutputFormat::Table => {
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Operator")]
+                name: String,
+                #[tabled(rename = "Address")]
+                operator: String,
+                #[tabled(rename = "Approved")]
+                approved: String,
+                #[tabled(rename = "Tx Hash")]
+                tx_hash: String,
+            }
+            let rows: Vec<Row> = approvals
+                .iter()
+                .map(|a| Row {
+                    name: a.name.clone(),
+                    operator: format!("{}", a.operator),
+                    approved: if a.approved {
+                        "\u{2713} Yes"
+                    } else {
+                        "\u{2717} No"
+                    }
+                    .into(),
+                    tx_hash: a
+                        .tx_hash
+                        .map_or_else(|| "—".into(), |h| truncate(&format!("{h}"), 14)),
+                })
+                .collect();
+            println!("{}", super::render_table(rows)?);
+            Ok(())
+        }
+    }
+}
+
+/// Every ID of one market, as derived by `ctf ids`.
+pub struct MarketIdChain {
+    pub question: String,
+    pub question_id: B256,
+    /// The address that prepared the condition, when known
+    pub oracle: Option<Address>,
+    /// The condition ID Gamma lists
+    pub condition_id: B256,
+    /// The condition ID derived from the oracle, question ID, and outcome count
+    pub derived_condition_id: Option<B256>,
+    pub neg_risk: bool,
+    pub collateral: Address,
+    pub outcomes: Vec<OutcomeIds>,
+}
+
+pub struct OutcomeIds {
+    pub outcome: String,
+    pub index_set: U256,
+    pub collection_id: B256,
+    pub position_id: U256,
+    /// The token ID Gamma lists for this outcome
+    pub clob_token_id: Option<U256>,
+}
+
+impl OutcomeIds {
+    fn matches_clob(&self) -> Option<bool> {
+        self.clob_token_id.map(|token| token == self.position_id)
+    }
+}
+
+pub fn print_market_ids(ids: &MarketIdChain, output: &OutputFormat) -> Result<()> {
+    let condition_matches = ids.derived_condition_id.map(|d| d == ids.condition_id);
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let outcomes: Vec<_> = ids
+                .outcomes
+                .iter()
+                .map(|o| {
+                    serde_json::json!({
+                        "outcome": o.outcome,
+                        "index_set": o.index_set.to_string(),
+                        "collection_id": o.collection_id.to_string(),
+                        "position_id": o.position_id.to_string(),
+                        "matches_clob_token": o.matches_clob(),
+                    })
+                })
+                .collect();
+            super::print_json(&serde_json::json!({
+                "question": ids.question,
+                "question_id": ids.question_id.to_string(),
+                "oracle": ids.oracle.map(|a| a.to_string()),
+                "condition_id": ids.condition_id.to_string(),
+                "condition_id_matches": condition_matches,
+                "neg_risk": ids.neg_risk,
+                "collateral": ids.collateral.to_string(),
+                "outcomes": outcomes,
+            }))
+        }
+        OutputFormat::Table => {
+            let check = |matches: Option<bool>| match matches {
+                Some(true) => " \u{2713}".to_string(),
+                Some(false) => format!(" {}", color::ask("\u{2717} differs from Gamma")),
+                None => String::new(),
+            };
+            print_detail_table(vec![
+                ["Question".into(), ids.question.clone()],
+                ["Question ID".into(), ids.question_id.to_string()],
+                [
+                    "Oracle".into(),
+                    ids.oracle
+                        .map_or_else(|| "unknown".into(), |a| a.to_string()),
+                ],
+                [
+                    "Condition ID".into(),
+                    format!("{}{}", ids.condition_id, check(condition_matches)),
+                ],
+                [
+                    "Collateral".into(),
+                    format!(
+                        "{}{}",
+                        ids.collateral,
+                        if ids.neg_risk {
+                            " (wrapped USDC, neg-risk)"
+                        } else {
+                            " (USDC)"
+                        }
+                    ),
+                ],
+            ]);
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Outcome")]
+                outcome: String,
+                #[tabled(rename = "Index Set")]
+                index_set: String,
+                #[tabled(rename = "Collection ID")]
+                collection_id: String,
+                #[tabled(rename = "Position ID")]
+                position_id: String,
+            }
+            let rows: Vec<Row> = ids
+                .outcomes
+                .iter()
+                .map(|o| Row {
+                    outcome: o.outcome.clone(),
+                    index_set: o.index_set.to_string(),
+                    collection_id: o.collection_id.to_string(),
+                    position_id: format!("{}{}", o.position_id, check(o.matches_clob())),
+                })
+                .collect();
+            println!("{}", super::render_table(rows)?);
+            Ok(())
+        }
+    }
+}
+
+/// A resolved market `ctf redeem-all` will redeem.
+pub struct RedeemTarget {
+    pub condition_id: B256,
+    pub title: String,
+    pub neg_risk: bool,
+    /// Raw shares held of each outcome, by outcome index
+    pub amounts: Vec<U256>,
+    /// USDC the Data API expects the redemption to pay
+    pub payout: Decimal,
+}
+
+pub struct RedeemResult {
+    pub title: String,
+    pub payout: Decimal,
+    pub tx_hash: Result<B256, String>,
+}
+
+/// Lists the markets about to be redeemed. Table output only; JSON gets the
+/// results in the summary.
+pub fn print_redeem_targets(
+    owner: Address,
+    targets: &[RedeemTarget],
+    output: &OutputFormat,
+) -> Result<()> {
+    if !matches!(output, OutputFormat::Table) {
+        return Ok(());
+    }
+    if targets.is_empty() {
+        println!("Nothing to redeem for {owner}.");
+        return Ok(());
+    }
+    #[derive(Tabled)]
+    struct Row {
+        #[tabled(rename = "Market")]
+        title: String,
+        #[tabled(rename = "Type")]
+        kind: &'static str,
+        #[tabled(rename = "Payout")]
+        payout: String,
+    }
+    let rows: Vec<Row> = targets
+        .iter()
+        .map(|t| Row {
+            title: truncate(&t.title, 60),
+            kind: if t.neg_risk { "neg-risk" } else { "standard" },
+            payout: format!("${}", t.payout.round_dp(2)),
+        })
+        .collect();
+    println!("{}", super::render_table(rows)?);
+    Ok(())
+}
+
+pub fn print_redeem_result(result: &RedeemResult, output: &OutputFormat) {
+    if !matches!(output, OutputFormat::Table) {
+        return;
+    }
+    match &result.tx_hash {
+        Ok(hash) => println!(
+            "  \u{2713} {} {}",
+            truncate(&result.title, 60),
+            color::dim(truncate(&format!("{hash}"), 14))
+        ),
+        Err(e) => println!("  \u{2717} {}: {e}", truncate(&result.title, 60)),
+    }
+}
+
+pub fn print_redeem_summary(
+    results: &[RedeemResult],
+    recovered: Option<Decimal>,
+    output: &OutputFormat,
+) -> Result<()> {
+    let redeemed = results.iter().filter(|r| r.tx_hash.is_ok()).count();
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let markets: Vec<_> = results
+                .iter()
+                .map(|r| {
+                    serde_json::json!({
+                        "title": r.title,
+                        "expected_payout": r.payout.to_string(),
+                        "transaction_hash": r.tx_hash.as_ref().ok().map(|h| format!("{h}")),
+                        "error": r.tx_hash.as_ref().err(),
+                    })
+                })
+                .collect();
+            super::print_json(&serde_json::json!({
+                "redeemed": redeemed,
+                "failed": results.len() - redeemed,
+                "usdc_recovered": recovered.map(|r| r.to_string()),
+                "markets": markets,
+            }))?;
+        }
+        OutputFormat::Table => {
+            println!();
+            let recovered = recovered.map_or_else(
+                || "USDC balance unavailable".to_string(),
+                |r| format!("${} USDC recovered", r.round_dp(2)),
+            );
+            println!(
+                "Redeemed {redeemed} of {} markets: {recovered}.",
+                results.len()
+            );
+        }
+    }
+    Ok(())
+}
@@ -4,11 +4,13 @@ use polymarket_client_sdk::data::types::response::{
     Activity, BuilderLeaderboardEntry, BuilderVolumeEntry, ClosedPosition, LiveVolume, Market,
     MetaHolder, OpenInterest, Position, Trade, Traded, TraderLeaderboardEntry, Value,
 };
+use polymarket_client_sdk::types::{Address, B256, Decimal};
 use serde_json::json;
 use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
-use super::{OutputFormat, format_decimal, truncate};
+use super::{OutputFormat, color, format_decimal, print_detail_table, truncate};
+use crate::auth::WalletKind;
 
 fn format_market(m: &Market) -> String {
     match m {
@@ -18,6 +20,50 @@ fn format_market(m: &Market) -> String {
     }
 }
 
+/// Lines describing a looked-up wallet; an EOA also gets the wallets that
+/// would hold its Polymarket positions.
+fn wallet_kind_lines(
+    address: Address,
+    kind: Option<WalletKind>,
+    derived: (Option<Address>, Option<Address>),
+) -> Vec<String> {
+    match kind {
+        Some(WalletKind::Proxy) => vec![format!("Wallet {address} (Polymarket proxy wallet)")],
+        Some(WalletKind::Eoa) => {
+            let mut lines = vec![format!("Wallet {address} (EOA)")];
+            let hints: Vec<String> = [(derived.0, "email login"), (derived.1, "browser wallet")]
+                .into_iter()
+                .filter_map(|(a, how)| a.map(|a| format!("{a} ({how})")))
+                .collect();
+            if !hints.is_empty() {
+                lines.push(format!(
+                    "Positions are usually held by its proxy wallet: {}",
+                    hints.join(" or ")
+                ));
+            }
+            lines
+        }
+        None => vec![format!(
+            "Wallet {address} (type unknown: could not reach Polygon RPC)"
+        )],
+    }
+}
+
+pub fn print_wallet_kind(
+    address: Address,
+    kind: Option<WalletKind>,
+    derived: (Option<Address>, Option<Address>),
+) {
+    for line in wallet_kind_lines(address, kind, derived) {
+        println!("{}", color::dim(line));
+    }
+}
+
+fn format_time(ts: i64) -> String {
+    chrono::DateTime::from_timestamp(ts, 0)
+        .map_or(ts.to_string(), |dt| dt.format("%Y-%m-%d %H:%M").to_string())
+}
+
 pub fn print_positions(positions: &[Position], output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => {
@@ -54,7 +100,7 @@ pub fn print_positions(positions: &[Position], output: &OutputFormat) -> anyhow:
             let table = Table::new(rows).with(Style::rounded()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = positions
                 .iter()
                 .map(|p| {
@@ -117,7 +163,7 @@ pub fn print_closed_positions(
             let table = Table::new(rows).with(Style::rounded()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = positions
                 .iter()
                 .map(|p| {
@@ -166,7 +212,7 @@ pub fn print_value(values: &[Value], output: &OutputFormat) -> anyhow::Result<()
             let table = Table::new(rows).with(Style::rounded()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = values
                 .iter()
                 .map(|v| json!({"user": v.user.to_string(), "value": v.value.to_string()}))
@@ -180,7 +226,7 @@ pub fn print_value(values: &[Value], output: &OutputFormat) -> anyhow::Result<()
 pub fn print_traded(t: &Traded, output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => println!("{}: {} markets traded", t.user, t.traded),
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             super::print_json(&json!({
                 "user": t.user.to_string(),
                 "traded": t.traded,
@@ -199,6 +245,8 @@ pub fn print_trades(trades: &[Trade], output: &OutputFormat) -> anyhow::Result<(
             }
             #[derive(Tabled)]
             struct Row {
+                #[tabled(rename = "Time")]
+                time: String,
                 #[tabled(rename = "Market")]
                 title: String,
                 #[tabled(rename = "Side")]
@@ -213,8 +261,9 @@ pub fn print_trades(trades: &[Trade], output: &OutputFormat) -> anyhow::Result<(
             let rows: Vec<Row> = trades
                 .iter()
                 .map(|t| Row {
+                    time: color::dim(format_time(t.timestamp)),
                     title: truncate(&t.title, 40),
-                    side: t.side.to_string(),
+                    side: color::side(&t.side),
                     outcome: t.outcome.clone(),
                     size: format!("{:.2}", t.size),
                     price: format!("{:.4}", t.price),
@@ -223,31 +272,51 @@ pub fn print_trades(trades: &[Trade], output: &OutputFormat) -> anyhow::Result<(
             let table = Table::new(rows).with(Style::rounded()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
-            let data: Vec<_> = trades
-                .iter()
-                .map(|t| {
-                    json!({
-                        "title": t.title,
-                        "slug": t.slug,
-                        "side": t.side.to_string(),
-                        "outcome": t.outcome,
-                        "outcome_index": t.outcome_index,
-                        "size": t.size.to_string(),
-                        "price": t.price.to_string(),
-                        "timestamp": t.timestamp,
-                        "condition_id": t.condition_id.to_string(),
-                        "proxy_wallet": t.proxy_wallet.to_string(),
-                        "transaction_hash": t.transaction_hash.to_string(),
-                    })
-                })
-                .collect();
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let data: Vec<_> = trades.iter().map(trade_json).collect();
             super::print_json(&data)?;
         }
     }
     Ok(())
 }
 
+fn trade_json(t: &Trade) -> serde_json::Value {
+    json!({
+        "title": t.title,
+        "slug": t.slug,
+        "side": t.side.to_string(),
+        "outcome": t.outcome,
+        "outcome_index": t.outcome_index,
+        "size": t.size.to_string(),
+        "price": t.price.to_string(),
+        "timestamp": t.timestamp,
+        "condition_id": t.condition_id.to_string(),
+        "proxy_wallet": t.proxy_wallet.to_string(),
+        "transaction_hash": t.transaction_hash.to_string(),
+    })
+}
+
+/// One line per trade for `data trades --follow`.
+pub fn print_trade_print(trade: &Trade, output: &OutputFormat) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            println!(
+                "{}  {} {:>10.2} @ {:.4}  {:<8} {}",
+                color::dim(format_time(trade.timestamp)),
+                color::side(format!("{:<4}", trade.side)),
+                trade.size,
+                trade.price,
+                truncate(&trade.outcome, 8),
+                truncate(&trade.proxy_wallet.to_string(), 14),
+            );
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            super::print_json(&trade_json(trade))?;
+        }
+    }
+    Ok(())
+}
+
 pub fn print_activity(activity: &[Activity], output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => {
@@ -257,8 +326,12 @@ pub fn print_activity(activity: &[Activity], output: &OutputFormat) -> anyhow::R
             }
             #[derive(Tabled)]
             struct Row {
+                #[tabled(rename = "Time")]
+                time: String,
                 #[tabled(rename = "Type")]
                 activity_type: String,
+                #[tabled(rename = "Side")]
+                side: String,
                 #[tabled(rename = "Market")]
                 title: String,
                 #[tabled(rename = "Size")]
@@ -271,7 +344,9 @@ pub fn print_activity(activity: &[Activity], output: &OutputFormat) -> anyhow::R
             let rows: Vec<Row> = activity
                 .iter()
                 .map(|a| Row {
+                    time: color::dim(format_time(a.timestamp)),
                     activity_type: a.activity_type.to_string(),
+                    side: a.side.as_ref().map_or_else(String::new, color::side),
                     title: truncate(a.title.as_deref().unwrap_or("—"), 35),
                     size: format!("{:.2}", a.size),
                     usdc_size: format_decimal(a.usdc_size),
@@ -281,15 +356,20 @@ pub fn print_activity(activity: &[Activity], output: &OutputFormat) -> anyhow::R
             let table = Table::new(rows).with(Style::rounded()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = activity
                 .iter()
                 .map(|a| {
                     json!({
                         "activity_type": a.activity_type.to_string(),
                         "title": a.title,
+                        "outcome": a.outcome,
+                        "side": a.side.as_ref().map(ToString::to_string),
+                        "price": a.price.map(|p| p.to_string()),
                         "size": a.size.to_string(),
                         "usdc_size": a.usdc_size.to_string(),
+                        "condition_id": a.condition_id.map(|c| c.to_string()),
+                        "asset": a.asset.map(|a| a.to_string()),
                         "timestamp": a.timestamp,
                         "transaction_hash": a.transaction_hash.to_string(),
                         "proxy_wallet": a.proxy_wallet.to_string(),
@@ -302,10 +382,21 @@ pub fn print_activity(activity: &[Activity], output: &OutputFormat) -> anyhow::R
     Ok(())
 }
 
-pub fn print_holders(meta_holders: &[MetaHolder], output: &OutputFormat) -> anyhow::Result<()> {
+/// A holder's share of an outcome token's supply, as a percentage.
+fn supply_share(amount: Decimal, supply: Option<Decimal>) -> Option<Decimal> {
+    supply
+        .filter(|s| s.is_sign_positive() && !s.is_zero())
+        .map(|s| (amount / s * Decimal::ONE_HUNDRED).round_dp(2))
+}
+
+pub fn print_holders(
+    meta_holders: &[MetaHolder],
+    supply: Option<Decimal>,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => {
-            if meta_holders.is_empty() {
+            if meta_holders.iter().all(|mh| mh.holders.is_empty()) {
                 println!("No holders found.");
                 return Ok(());
             }
@@ -315,15 +406,27 @@ pub fn print_holders(meta_holders: &[MetaHolder], output: &OutputFormat) -> anyh
                 wallet: String,
                 #[tabled(rename = "Name")]
                 name: String,
-                #[tabled(rename = "Amount")]
+                #[tabled(rename = "Size")]
                 amount: String,
-                #[tabled(rename = "Outcome")]
-                outcome_index: String,
+                #[tabled(rename = "Share")]
+                share: String,
             }
-            let rows: Vec<Row> = meta_holders
-                .iter()
-                .flat_map(|mh| {
-                    mh.holders.iter().map(|h| Row {
+            for (i, mh) in meta_holders.iter().enumerate() {
+                if mh.holders.is_empty() {
+                    continue;
+                }
+                if i > 0 {
+                    println!();
+                }
+                let outcome = mh
+                    .holders
+                    .first()
+                    .map_or_else(String::new, |h| format!("Outcome {} — ", h.outcome_index));
+                println!("{}", color::dim(format!("{outcome}token {}", mh.token)));
+                let rows: Vec<Row> = mh
+                    .holders
+                    .iter()
+                    .map(|h| Row {
                         wallet: truncate(&h.proxy_wallet.to_string(), 14),
                         name: h
                             .name
@@ -332,14 +435,21 @@ pub fn print_holders(meta_holders: &[MetaHolder], output: &OutputFormat) -> anyh
                             .unwrap_or("—")
                             .into(),
                         amount: format_decimal(h.amount),
-                        outcome_index: h.outcome_index.to_string(),
+                        share: supply_share(h.amount, supply)
+                            .map_or_else(|| "—".into(), |p| format!("{p}%")),
                     })
-                })
-                .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
-            println!("{table}");
+                    .collect();
+                let table = Table::new(rows).with(Style::rounded()).to_string();
+                println!("{table}");
+            }
+            if supply.is_none() {
+                println!(
+                    "{}",
+                    color::dim("Share unavailable: could not fetch open interest for this market.")
+                );
+            }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = meta_holders
                 .iter()
                 .map(|mh| {
@@ -352,11 +462,16 @@ pub fn print_holders(meta_holders: &[MetaHolder], output: &OutputFormat) -> anyh
                                 "name": h.name,
                                 "pseudonym": h.pseudonym,
                                 "amount": h.amount.to_string(),
+                                "share_pct": supply_share(h.amount, supply).map(|p| p.to_string()),
                                 "outcome_index": h.outcome_index,
                             })
                         })
                         .collect();
-                    json!({"token": mh.token.to_string(), "holders": holders})
+                    json!({
+                        "token": mh.token.to_string(),
+                        "supply": supply.map(|s| s.to_string()),
+                        "holders": holders,
+                    })
                 })
                 .collect();
             super::print_json(&data)?;
@@ -365,6 +480,50 @@ pub fn print_holders(meta_holders: &[MetaHolder], output: &OutputFormat) -> anyh
     Ok(())
 }
 
+/// Headline figures for `data stats`, merged from Gamma and the Data API.
+pub struct MarketStats {
+    pub condition_id: B256,
+    pub question: Option<String>,
+    pub volume_24hr: Option<Decimal>,
+    pub volume: Option<Decimal>,
+    pub open_interest: Option<Decimal>,
+    pub liquidity: Option<Decimal>,
+}
+
+pub fn print_market_stats(stats: &MarketStats, output: &OutputFormat) -> anyhow::Result<()> {
+    let dollars = |d: Option<Decimal>| d.map_or_else(|| "—".into(), format_decimal);
+    match output {
+        OutputFormat::Table => {
+            print_detail_table(vec![
+                [
+                    "Market".into(),
+                    stats.question.clone().unwrap_or_else(|| "—".into()),
+                ],
+                [
+                    "Condition ID".into(),
+                    color::dim(stats.condition_id.to_string()),
+                ],
+                ["24h Volume".into(), dollars(stats.volume_24hr)],
+                ["Total Volume".into(), dollars(stats.volume)],
+                ["Open Interest".into(), dollars(stats.open_interest)],
+                ["Liquidity".into(), dollars(stats.liquidity)],
+            ]);
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let string = |d: Option<Decimal>| d.map(|d| d.to_string());
+            super::print_json(&json!({
+                "condition_id": stats.condition_id.to_string(),
+                "question": stats.question,
+                "volume_24hr": string(stats.volume_24hr),
+                "volume": string(stats.volume),
+                "open_interest": string(stats.open_interest),
+                "liquidity": string(stats.liquidity),
+            }))?;
+        }
+    }
+    Ok(())
+}
+
 pub fn print_open_interest(oi: &[OpenInterest], output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => {
@@ -389,7 +548,7 @@ pub fn print_open_interest(oi: &[OpenInterest], output: &OutputFormat) -> anyhow
             let table = Table::new(rows).with(Style::rounded()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = oi
                 .iter()
                 .map(|o| json!({"market": format_market(&o.market), "value": o.value.to_string()}))
@@ -428,7 +587,7 @@ pub fn print_live_volume(volume: &[LiveVolume], output: &OutputFormat) -> anyhow
                 println!("{table}");
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = volume
                 .iter()
                 .map(|v| {
@@ -479,7 +638,7 @@ pub fn print_leaderboard(
             let table = Table::new(rows).with(Style::rounded()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = entries
                 .iter()
                 .map(|e| {
@@ -531,7 +690,7 @@ pub fn print_builder_leaderboard(
             let table = Table::new(rows).with(Style::rounded()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = entries
                 .iter()
                 .map(|e| {
@@ -586,7 +745,7 @@ pub fn print_builder_volume(
             let table = Table::new(rows).with(Style::rounded()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
             let data: Vec<_> = entries
                 .iter()
                 .map(|e| {
@@ -605,3 +764,46 @@ pub fn print_builder_volume(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn supply_share_is_a_rounded_percentage() {
+        assert_eq!(
+            supply_share(dec!(1234), Some(dec!(10000))),
+            Some(dec!(12.34))
+        );
+        assert_eq!(supply_share(dec!(1), Some(dec!(3))), Some(dec!(33.33)));
+    }
+
+    #[test]
+    fn eoa_lines_point_at_derived_wallets() {
+        let eoa = Address::repeat_byte(1);
+        let proxy = Address::repeat_byte(2);
+        let lines = wallet_kind_lines(eoa, Some(WalletKind::Eoa), (Some(proxy), None));
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with("(EOA)"));
+        assert!(lines[1].contains(&format!("{proxy} (email login)")));
+        assert!(!lines[1].contains("browser wallet"));
+    }
+
+    #[test]
+    fn proxy_lines_have_no_hint() {
+        let lines = wallet_kind_lines(
+            Address::repeat_byte(1),
+            Some(WalletKind::Proxy),
+            (None, None),
+        );
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].contains("proxy wallet"));
+    }
+
+    #[test]
+    fn supply_share_needs_a_positive_supply() {
+        assert_eq!(supply_share(dec!(5), None), None);
+        assert_eq!(supply_share(dec!(5), Some(Decimal::ZERO)), None);
+    }
+}
@@ -0,0 +1,246 @@
+//! `--dry-run` and `--estimate-only` reports: the signed orders or
+//! transactions a command would have submitted. Nothing shown here has been
+//! sent.
+
+use polymarket_client_sdk::types::{Address, B256, Decimal, U256};
+use serde_json::json;
+use tabled::Tabled;
+
+use super::{OutputFormat, color, print_detail_table, print_json};
+use crate::tx::{GasEstimate, format_gwei, format_pol};
+
+const NOTHING_SENT: &str = "Dry run: nothing was submitted.";
+const ESTIMATE_ONLY: &str = "Estimate only: nothing was submitted.";
+
+/// A signed order that was not posted.
+pub struct DryRunOrder {
+    /// EIP-712 hash the exchange identifies the order by
+    pub hash: B256,
+    pub token_id: U256,
+    pub side: String,
+    pub order_type: String,
+    pub price: Decimal,
+    pub size: Decimal,
+    /// USDC paid (buy) or received (sell) at the order's price
+    pub notional: Decimal,
+    pub fee_rate_bps: U256,
+    /// Fee charged if the whole order fills as taker
+    pub taker_fee: Decimal,
+    /// Unix timestamp; 0 means no expiration
+    pub expiration: U256,
+    pub maker: Address,
+    pub signer: Address,
+    /// The exact JSON body that would be posted
+    pub payload: serde_json::Value,
+}
+
+/// A contract call, as shown before it is sent or instead of sending it.
+#[derive(Clone)]
+pub struct PlannedTx {
+    pub action: String,
+    pub from: Option<Address>,
+    pub to: Address,
+    pub calldata: Vec<u8>,
+    /// POL sent along with the call, in wei.
+    pub value: U256,
+}
+
+/// Polymarket charges `rate × min(price, 1 − price) × size` on taker fills.
+pub fn taker_fee(fee_rate_bps: Decimal, price: Decimal, size: Decimal) -> Decimal {
+    (fee_rate_bps / Decimal::from(10_000) * price.min(Decimal::ONE - price) * size).round_dp(6)
+}
+
+/// USDC that changes hands once the taker fee is counted: the most a buy
+/// costs, or the least a sell brings in.
+pub fn net_of_fee(buy: bool, notional: Decimal, fee: Decimal) -> Decimal {
+    if buy { notional + fee } else { notional - fee }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    use std::fmt::Write as _;
+    bytes.iter().fold(String::from("0x"), |mut out, b| {
+        let _ = write!(out, "{b:02x}");
+        out
+    })
+}
+
+pub fn print_dry_run_orders(orders: &[DryRunOrder], output: &OutputFormat) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            println!("{}", color::dim(NOTHING_SENT));
+            for o in orders {
+                println!();
+                print_detail_table(vec![
+                    ["Order Hash".into(), o.hash.to_string()],
+                    ["Token ID".into(), o.token_id.to_string()],
+                    ["Side".into(), color::side(&o.side)],
+                    ["Type".into(), o.order_type.clone()],
+                    ["Price".into(), o.price.to_string()],
+                    ["Size".into(), o.size.to_string()],
+                    ["Notional".into(), format!("${}", o.notional)],
+                    ["Fee Rate".into(), format!("{} bps", o.fee_rate_bps)],
+                    ["Taker Fee (max)".into(), format!("${}", o.taker_fee)],
+                    [
+                        "Expiration".into(),
+                        if o.expiration.is_zero() {
+                            "none".into()
+                        } else {
+                            o.expiration.to_string()
+                        },
+                    ],
+                    ["Maker".into(), o.maker.to_string()],
+                    ["Signer".into(), o.signer.to_string()],
+                ]);
+            }
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let data: Vec<_> = orders
+                .iter()
+                .map(|o| {
+                    json!({
+                        "dry_run": true,
+                        "order_hash": o.hash.to_string(),
+                        "token_id": o.token_id.to_string(),
+                        "side": o.side,
+                        "order_type": o.order_type,
+                        "price": o.price.to_string(),
+                        "size": o.size.to_string(),
+                        "notional": o.notional.to_string(),
+                        "fee_rate_bps": o.fee_rate_bps.to_string(),
+                        "taker_fee": o.taker_fee.to_string(),
+                        "payload": o.payload,
+                    })
+                })
+                .collect();
+            print_json(&data)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn print_planned_txs(txs: &[PlannedTx], output: &OutputFormat) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            println!("{}", color::dim(NOTHING_SENT));
+            for tx in txs {
+                println!();
+                let mut rows = vec![
+                    ["Action".into(), tx.action.clone()],
+                    [
+                        "From".into(),
+                        tx.from.map_or_else(|| "—".into(), |a| a.to_string()),
+                    ],
+                    ["To".into(), tx.to.to_string()],
+                ];
+                if !tx.value.is_zero() {
+                    rows.push(["Value".into(), format!("{} POL", format_pol(tx.value))]);
+                }
+                rows.push(["Calldata".into(), hex(&tx.calldata)]);
+                print_detail_table(rows);
+            }
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let data: Vec<_> = txs
+                .iter()
+                .map(|tx| {
+                    json!({
+                        "dry_run": true,
+                        "action": tx.action,
+                        "from": tx.from.map(|a| a.to_string()),
+                        "to": tx.to.to_string(),
+                        "value": tx.value.to_string(),
+                        "calldata": hex(&tx.calldata),
+                    })
+                })
+                .collect();
+            print_json(&data)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn print_gas_estimates(
+    txs: &[PlannedTx],
+    estimates: &[GasEstimate],
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Action")]
+                action: String,
+                #[tabled(rename = "Gas Limit")]
+                gas_limit: u64,
+                #[tabled(rename = "Max Fee (gwei)")]
+                max_fee: String,
+                #[tabled(rename = "Tip (gwei)")]
+                priority_fee: String,
+                #[tabled(rename = "Max Cost (POL)")]
+                max_cost: String,
+            }
+            let rows: Vec<Row> = txs
+                .iter()
+                .zip(estimates)
+                .map(|(tx, e)| Row {
+                    action: tx.action.clone(),
+                    gas_limit: e.gas_limit,
+                    max_fee: format_gwei(e.max_fee_per_gas),
+                    priority_fee: format_gwei(e.max_priority_fee_per_gas),
+                    max_cost: format_pol(e.max_cost()),
+                })
+                .collect();
+            println!("{}", color::dim(ESTIMATE_ONLY));
+            println!("{}", super::render_table(rows)?);
+            if estimates.len() > 1 {
+                let total = estimates
+                    .iter()
+                    .fold(U256::ZERO, |total, e| total + e.max_cost());
+                println!("Up to {} POL in total.", format_pol(total));
+            }
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let data: Vec<_> = txs
+                .iter()
+                .zip(estimates)
+                .map(|(tx, e)| {
+                    json!({
+                        "action": tx.action,
+                        "to": tx.to.to_string(),
+                        "gas_limit": e.gas_limit,
+                        "max_fee_per_gas": e.max_fee_per_gas.to_string(),
+                        "max_priority_fee_per_gas": e.max_priority_fee_per_gas.to_string(),
+                        "max_cost_wei": e.max_cost().to_string(),
+                    })
+                })
+                .collect();
+            print_json(&data)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn taker_fee_uses_the_cheaper_side_of_the_price() {
+        assert_eq!(taker_fee(dec!(100), dec!(0.30), dec!(100)), dec!(0.3));
+        assert_eq!(taker_fee(dec!(100), dec!(0.70), dec!(100)), dec!(0.3));
+        assert_eq!(taker_fee(dec!(0), dec!(0.5), dec!(100)), dec!(0));
+    }
+
+    #[test]
+    fn fees_add_to_buys_and_come_out_of_sells() {
+        assert_eq!(net_of_fee(true, dec!(30), dec!(0.3)), dec!(30.3));
+        assert_eq!(net_of_fee(false, dec!(30), dec!(0.3)), dec!(29.7));
+    }
+
+    #[test]
+    fn hex_prefixes_and_pads() {
+        assert_eq!(hex(&[0x0a, 0xff]), "0x0aff");
+        assert_eq!(hex(&[]), "0x");
+    }
+}
@@ -1,9 +1,13 @@
-use polymarket_client_sdk::gamma::types::response::Market;
-use polymarket_client_sdk::types::Decimal;
+use polymarket_client_sdk::clob::types::response::MarketResponse;
+use polymarket_client_sdk::gamma::types::response::{Market, Tag};
+use polymarket_client_sdk::types::{Decimal, U256};
+use serde_json::json;
 use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
-use super::{detail_field, format_decimal, print_detail_table, truncate};
+use super::{
+    OutputFormat, color, detail_field, format_decimal, print_detail_table, print_json, truncate,
+};
 
 #[derive(Tabled)]
 struct MarketRow {
@@ -56,6 +60,227 @@ pub fn print_markets_table(markets: &[Market]) {
     println!("{table}");
 }
 
+#[derive(Tabled)]
+struct TrendingRow {
+    #[tabled(rename = "#")]
+    rank: usize,
+    #[tabled(rename = "Question")]
+    question: String,
+    #[tabled(rename = "24h Volume")]
+    volume_24hr: String,
+    #[tabled(rename = "vs 7d Avg")]
+    surge: String,
+    #[tabled(rename = "24h Move")]
+    price_move: String,
+    #[tabled(rename = "Liquidity")]
+    liquidity: String,
+}
+
+fn trending_row(rank: usize, m: &Market) -> TrendingRow {
+    let surge = match (m.volume_24hr, m.volume_1wk.filter(|v| !v.is_zero())) {
+        (Some(day), Some(week)) => format!("{:.1}×", day * Decimal::from(7) / week),
+        _ => "—".into(),
+    };
+    TrendingRow {
+        rank,
+        question: truncate(m.question.as_deref().unwrap_or("—"), 50),
+        volume_24hr: m.volume_24hr.map_or_else(|| "—".into(), format_decimal),
+        surge,
+        price_move: m.one_day_price_change.map_or_else(
+            || "—".into(),
+            |c| format!("{:+.1}¢", c * Decimal::from(100)),
+        ),
+        liquidity: m.liquidity_num.map_or_else(|| "—".into(), format_decimal),
+    }
+}
+
+/// Markets ranked by `markets trending`, each paired with its score.
+pub fn print_trending(ranked: &[(Market, Decimal)], output: &OutputFormat) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            if ranked.is_empty() {
+                println!("No markets found.");
+                return Ok(());
+            }
+            let rows: Vec<TrendingRow> = ranked
+                .iter()
+                .enumerate()
+                .map(|(i, (m, _))| trending_row(i + 1, m))
+                .collect();
+            let table = Table::new(rows).with(Style::rounded()).to_string();
+            println!("{table}");
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let data: Vec<_> = ranked
+                .iter()
+                .map(|(m, score)| {
+                    let mut value = serde_json::to_value(m)?;
+                    value["trend_score"] = serde_json::Value::String(score.to_string());
+                    Ok(value)
+                })
+                .collect::<anyhow::Result<_>>()?;
+            print_json(&data)?;
+        }
+    }
+    Ok(())
+}
+
+/// One line per market for `markets watch-new`.
+pub fn print_new_market(m: &Market, output: &OutputFormat) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            let created = m
+                .created_at
+                .map_or_else(|| "—".into(), |t| t.format("%Y-%m-%d %H:%M").to_string());
+            println!(
+                "{}  {}  {}",
+                color::dim(created),
+                truncate(m.question.as_deref().unwrap_or("—"), 70),
+                color::dim(m.slug.as_deref().unwrap_or_default()),
+            );
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => print_json(m)?,
+    }
+    Ok(())
+}
+
+/// Everything `markets info` shows about one market.
+pub struct MarketInfo {
+    pub market: Market,
+    pub tags: Vec<Tag>,
+    pub clob: MarketResponse,
+    pub quotes: Vec<OutcomeQuote>,
+}
+
+pub struct OutcomeQuote {
+    pub outcome: String,
+    pub token_id: U256,
+    pub price: Decimal,
+    pub best_bid: Option<Decimal>,
+    pub best_ask: Option<Decimal>,
+}
+
+#[derive(Tabled)]
+struct OutcomeQuoteRow {
+    #[tabled(rename = "Outcome")]
+    outcome: String,
+    #[tabled(rename = "Price")]
+    price: String,
+    #[tabled(rename = "Best Bid")]
+    best_bid: String,
+    #[tabled(rename = "Best Ask")]
+    best_ask: String,
+    #[tabled(rename = "Token ID")]
+    token_id: String,
+}
+
+fn format_quote(price: Option<Decimal>) -> String {
+    price.map_or_else(|| "—".into(), |p| format!("{p:.4}"))
+}
+
+fn tag_labels(tags: &[Tag]) -> String {
+    tags.iter()
+        .filter_map(|t| t.label.as_deref().or(t.slug.as_deref()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn print_market_info(info: &MarketInfo, output: &OutputFormat) -> anyhow::Result<()> {
+    let m = &info.market;
+    let c = &info.clob;
+    match output {
+        OutputFormat::Table => {
+            print_detail_table(vec![
+                ["Question".into(), m.question.clone().unwrap_or_default()],
+                ["Slug".into(), m.slug.clone().unwrap_or_default()],
+                [
+                    "Condition ID".into(),
+                    c.condition_id.map(|c| c.to_string()).unwrap_or_default(),
+                ],
+                ["Tags".into(), tag_labels(&info.tags)],
+                [
+                    "End Date".into(),
+                    m.end_date.map(|d| d.to_string()).unwrap_or_default(),
+                ],
+                ["Status".into(), market_status(m).into()],
+                [
+                    "Accepting Orders".into(),
+                    if c.accepting_orders { "Yes" } else { "No" }.into(),
+                ],
+                ["Tick Size".into(), c.minimum_tick_size.to_string()],
+                ["Min Order Size".into(), c.minimum_order_size.to_string()],
+                [
+                    "Neg Risk".into(),
+                    if c.neg_risk { "Yes" } else { "No" }.into(),
+                ],
+                ["Maker Fee".into(), format!("{} bps", c.maker_base_fee)],
+                ["Taker Fee".into(), format!("{} bps", c.taker_base_fee)],
+                [
+                    "Volume".into(),
+                    m.volume_num.map(format_decimal).unwrap_or_default(),
+                ],
+                [
+                    "Liquidity".into(),
+                    m.liquidity_num.map(format_decimal).unwrap_or_default(),
+                ],
+                [
+                    "Description".into(),
+                    m.description.clone().unwrap_or_default(),
+                ],
+            ]);
+            if !info.quotes.is_empty() {
+                let rows: Vec<OutcomeQuoteRow> = info
+                    .quotes
+                    .iter()
+                    .map(|q| OutcomeQuoteRow {
+                        outcome: q.outcome.clone(),
+                        price: format!("{:.4}", q.price),
+                        best_bid: format_quote(q.best_bid),
+                        best_ask: format_quote(q.best_ask),
+                        token_id: q.token_id.to_string(),
+                    })
+                    .collect();
+                println!("{}", Table::new(rows).with(Style::rounded()));
+            }
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let outcomes: Vec<_> = info
+                .quotes
+                .iter()
+                .map(|q| {
+                    json!({
+                        "outcome": q.outcome,
+                        "token_id": q.token_id.to_string(),
+                        "price": q.price.to_string(),
+                        "best_bid": q.best_bid.map(|p| p.to_string()),
+                        "best_ask": q.best_ask.map(|p| p.to_string()),
+                    })
+                })
+                .collect();
+            print_json(&json!({
+                "id": m.id,
+                "question": m.question,
+                "slug": m.slug,
+                "description": m.description,
+                "condition_id": c.condition_id.map(|c| c.to_string()),
+                "end_date": m.end_date,
+                "tags": info.tags.iter().filter_map(|t| t.slug.clone()).collect::<Vec<_>>(),
+                "status": market_status(m),
+                "accepting_orders": c.accepting_orders,
+                "tick_size": c.minimum_tick_size.to_string(),
+                "min_order_size": c.minimum_order_size.to_string(),
+                "neg_risk": c.neg_risk,
+                "maker_fee_bps": c.maker_base_fee.to_string(),
+                "taker_fee_bps": c.taker_base_fee.to_string(),
+                "volume": m.volume_num.map(|v| v.to_string()),
+                "liquidity": m.liquidity_num.map(|v| v.to_string()),
+                "outcomes": outcomes,
+            }))?;
+        }
+    }
+    Ok(())
+}
+
 pub fn print_market_detail(m: &Market) {
     let mut rows: Vec<[String; 2]> = Vec::new();
 
@@ -234,4 +459,31 @@ mod tests {
         let m = make_market(json!({"id": "1", "active": true}));
         assert_eq!(market_to_row(&m).status, "Active");
     }
+
+    #[test]
+    fn tag_labels_prefer_label_over_slug() {
+        let tags: Vec<Tag> = serde_json::from_value(json!([
+            {"id": "1", "label": "Politics", "slug": "politics"},
+            {"id": "2", "slug": "fed-rates"},
+            {"id": "3"}
+        ]))
+        .unwrap();
+        assert_eq!(tag_labels(&tags), "Politics, fed-rates");
+    }
+
+    #[test]
+    fn trending_row_shows_surge_and_signed_move() {
+        let row = trending_row(
+            1,
+            &make_market(json!({
+                "id": "1",
+                "volume24hr": 3000,
+                "volume1wk": 7000,
+                "oneDayPriceChange": -0.042
+            })),
+        );
+        assert_eq!(row.surge, "3.0×");
+        assert_eq!(row.price_move, "-4.2¢");
+        assert_eq!(row.liquidity, "—");
+    }
 }
@@ -1,26 +1,115 @@
+pub mod algo;
 pub mod approve;
 pub mod bridge;
+pub mod chart;
 pub mod clob;
+pub mod color;
 pub mod comments;
 pub mod ctf;
 pub mod data;
+pub mod dry_run;
 pub mod events;
 pub mod markets;
+pub mod pager;
+pub mod pnl;
+pub mod portfolio;
 pub mod profiles;
+pub mod query;
+pub mod safe;
 pub mod series;
 pub mod sports;
 pub mod tags;
+pub mod template;
+pub mod tx;
+
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError, RwLock};
+
+use anyhow::Context;
 
 use polymarket_client_sdk::types::Decimal;
 use rust_decimal::prelude::ToPrimitive;
-use tabled::Table;
+use tabled::builder::Builder;
 use tabled::settings::object::Columns;
 use tabled::settings::{Modify, Style, Width};
+use tabled::{Table, Tabled};
 
-#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+#[derive(Clone, Copy, Debug, clap::ValueEnum, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum OutputFormat {
     Table,
     Json,
+    /// Newline-delimited JSON: one compact object per line
+    Ndjson,
+    Yaml,
+}
+
+/// Process-wide output settings, applied once per command invocation.
+#[derive(Clone, Debug)]
+pub struct OutputOptions {
+    pub format: OutputFormat,
+    /// Structured output is collected and written here when the command finishes.
+    pub output_file: Option<PathBuf>,
+    /// Single-value commands print just the bare value.
+    pub raw: bool,
+    /// JMESPath expression applied to structured output before printing.
+    pub query: Option<String>,
+    /// Table columns to keep, in display order, from `--columns`.
+    pub columns: Option<Vec<String>>,
+    /// minijinja template source that replaces the normal structured rendering.
+    pub template: Option<String>,
+}
+
+static OPTIONS: RwLock<OutputOptions> = RwLock::new(OutputOptions {
+    format: OutputFormat::Table,
+    output_file: None,
+    raw: false,
+    query: None,
+    columns: None,
+    template: None,
+});
+
+/// Structured output captured for `--output-file`.
+static CAPTURED: Mutex<String> = Mutex::new(String::new());
+
+pub fn configure(options: OutputOptions) {
+    CAPTURED
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clear();
+    *OPTIONS.write().unwrap_or_else(PoisonError::into_inner) = options;
+}
+
+/// Writes captured output to `--output-file`, if one was given.
+pub fn finish() -> anyhow::Result<()> {
+    let Some(path) = options().output_file else {
+        return Ok(());
+    };
+    let contents = std::mem::take(&mut *CAPTURED.lock().unwrap_or_else(PoisonError::into_inner));
+    write_atomic(&path, &contents)
+        .with_context(|| format!("Failed to write output file {}", path.display()))
+}
+
+/// Writes via a temporary sibling file and a rename, so readers never see a partial file.
+pub(crate) fn write_atomic(path: &Path, contents: &str) -> std::io::Result<()> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| std::io::Error::other("output path has no file name"))?;
+    let mut tmp_name = std::ffi::OsString::from(".");
+    tmp_name.push(file_name);
+    tmp_name.push(format!(".{}.tmp", std::process::id()));
+    let tmp = path.with_file_name(tmp_name);
+    std::fs::write(&tmp, contents)?;
+    std::fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&tmp);
+    })
+}
+
+fn options() -> OutputOptions {
+    OPTIONS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
 }
 
 pub fn truncate(s: &str, max: usize) -> String {
@@ -43,11 +132,132 @@ pub fn format_decimal(n: Decimal) -> String {
     }
 }
 
+/// In `--raw` mode, prints the bare value and returns true so the caller
+/// skips its labelled or structured output.
+pub fn print_raw(value: impl std::fmt::Display) -> bool {
+    let raw = options().raw;
+    if raw {
+        println!("{value}");
+    }
+    raw
+}
+
+/// Prints structured data in the active format: pretty JSON, NDJSON, or YAML,
+/// after applying any `--query` expression. A `--template` takes precedence
+/// over the format.
 pub fn print_json(data: &impl serde::Serialize) -> anyhow::Result<()> {
-    println!("{}", serde_json::to_string_pretty(data)?);
+    let options = options();
+    let rendered = match (&options.query, &options.template) {
+        (Some(expr), Some(source)) => template::render(source, &query::apply(expr, data)?)?,
+        (None, Some(source)) => template::render(source, data)?,
+        (Some(expr), None) => {
+            let result = query::apply(expr, data)?;
+            match options.format {
+                OutputFormat::Table => query::render_table(&result) + "\n",
+                format => render_structured(&result, format)?,
+            }
+        }
+        (None, None) => render_structured(data, options.format)?,
+    };
+    if options.output_file.is_some() {
+        CAPTURED
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push_str(&rendered);
+    } else {
+        print!("{rendered}");
+    }
     Ok(())
 }
 
+/// Reports a failed command: structured formats print `{"error": ...}` to
+/// stdout, table mode prints `Error: ...` to stderr.
+pub fn print_error(err: &anyhow::Error, format: OutputFormat) {
+    let body = serde_json::json!({"error": err.to_string()});
+    match format {
+        OutputFormat::Json | OutputFormat::Ndjson => println!("{body}"),
+        OutputFormat::Yaml => print!("{}", serde_yaml::to_string(&body).unwrap_or_default()),
+        OutputFormat::Table => eprintln!("Error: {err}"),
+    }
+}
+
+fn render_structured(data: &impl serde::Serialize, format: OutputFormat) -> anyhow::Result<String> {
+    Ok(match format {
+        OutputFormat::Ndjson => json_lines(data)?
+            .into_iter()
+            .map(|line| line + "\n")
+            .collect(),
+        OutputFormat::Yaml => serde_yaml::to_string(data)?,
+        OutputFormat::Json | OutputFormat::Table => serde_json::to_string_pretty(data)? + "\n",
+    })
+}
+
+/// Splits a value into NDJSON lines: arrays, and the `data` array of a
+/// paginated `{"data": [...], "next_cursor": ...}` response, yield one line
+/// per element.
+fn json_lines(data: &impl serde::Serialize) -> anyhow::Result<Vec<String>> {
+    use serde_json::value::RawValue;
+
+    let compact = serde_json::to_string(data)?;
+    let array = if compact.starts_with('[') {
+        compact.as_str()
+    } else if compact.starts_with('{') {
+        let fields: std::collections::HashMap<String, &RawValue> = serde_json::from_str(&compact)?;
+        match fields.get("data") {
+            Some(items) if fields.contains_key("next_cursor") && items.get().starts_with('[') => {
+                items.get()
+            }
+            _ => return Ok(vec![compact]),
+        }
+    } else {
+        return Ok(vec![compact]);
+    };
+    let items: Vec<&RawValue> = serde_json::from_str(array)?;
+    Ok(items.iter().map(|item| item.get().to_string()).collect())
+}
+
+/// Folds a column name for matching: `token-id`, `Token ID`, and `token_id` are equal.
+fn column_key(name: &str) -> String {
+    name.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Indices of the requested columns within `headers`, in requested order.
+fn select_columns(headers: &[String], wanted: &[String]) -> anyhow::Result<Vec<usize>> {
+    wanted
+        .iter()
+        .map(|name| {
+            headers
+                .iter()
+                .position(|h| column_key(h) == column_key(name))
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Unknown column '{name}'. Valid columns: {}",
+                        headers.join(", ")
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Renders rows as a rounded table, keeping only the `--columns` selection if one was given.
+pub fn render_table<T: Tabled>(rows: Vec<T>) -> anyhow::Result<String> {
+    let Some(wanted) = options().columns else {
+        return Ok(Table::new(rows).with(Style::rounded()).to_string());
+    };
+    let headers: Vec<String> = T::headers().into_iter().map(|h| h.into_owned()).collect();
+    let indices = select_columns(&headers, &wanted)?;
+    let mut builder = Builder::default();
+    builder.push_record(indices.iter().map(|&i| headers[i].clone()));
+    for row in &rows {
+        let fields = row.fields();
+        builder.push_record(indices.iter().map(|&i| fields[i].to_string()));
+    }
+    Ok(builder.build().with(Style::rounded()).to_string())
+}
+
 pub fn print_detail_table(rows: Vec<[String; 2]>) {
     let table = Table::from_iter(rows)
         .with(Style::rounded())
@@ -70,6 +280,90 @@ mod tests {
     use super::*;
     use rust_decimal_macros::dec;
 
+    #[test]
+    fn select_columns_matches_loosely_in_requested_order() {
+        let headers = vec!["ID".to_string(), "Token ID".to_string(), "Side".to_string()];
+        let wanted = vec!["side".to_string(), "token-id".to_string()];
+        assert_eq!(select_columns(&headers, &wanted).unwrap(), vec![2, 1]);
+    }
+
+    #[test]
+    fn select_columns_unknown_lists_valid_names() {
+        let headers = vec!["ID".to_string(), "Side".to_string()];
+        let err = select_columns(&headers, &["sidee".to_string()]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown column 'sidee'. Valid columns: ID, Side"
+        );
+    }
+
+    #[test]
+    fn json_lines_splits_arrays() {
+        let lines = json_lines(&serde_json::json!([{"a": 1}, {"a": 2}])).unwrap();
+        assert_eq!(lines, vec![r#"{"a":1}"#, r#"{"a":2}"#]);
+    }
+
+    #[test]
+    fn json_lines_object_is_single_compact_line() {
+        let lines = json_lines(&serde_json::json!({"a": [1, 2]})).unwrap();
+        assert_eq!(lines, vec![r#"{"a":[1,2]}"#]);
+    }
+
+    #[test]
+    fn json_lines_splits_paginated_data() {
+        let page = serde_json::json!({
+            "data": [{"id": "a"}, {"id": "b"}],
+            "next_cursor": "LTE=",
+            "count": 2,
+        });
+        let lines = json_lines(&page).unwrap();
+        assert_eq!(lines, vec![r#"{"id":"a"}"#, r#"{"id":"b"}"#]);
+        // A `data` field alone doesn't make a page.
+        let lines = json_lines(&serde_json::json!({"data": [1, 2]})).unwrap();
+        assert_eq!(lines, vec![r#"{"data":[1,2]}"#]);
+    }
+
+    #[test]
+    fn json_lines_empty_array_prints_nothing() {
+        assert!(json_lines(&serde_json::json!([])).unwrap().is_empty());
+    }
+
+    #[test]
+    fn json_lines_keeps_struct_field_order() {
+        #[derive(serde::Serialize)]
+        struct Item {
+            z: u8,
+            a: u8,
+        }
+        let lines = json_lines(&[Item { z: 1, a: 2 }]).unwrap();
+        assert_eq!(lines, vec![r#"{"z":1,"a":2}"#]);
+    }
+
+    #[test]
+    fn write_atomic_replaces_file_contents() {
+        let dir = std::env::temp_dir().join(format!("polymarket-out-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("snapshot.json");
+        write_atomic(&path, "first").unwrap();
+        write_atomic(&path, "second").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "second");
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn render_structured_yaml() {
+        let out =
+            render_structured(&serde_json::json!({"price": "0.5"}), OutputFormat::Yaml).unwrap();
+        assert_eq!(out, "price: '0.5'\n");
+    }
+
+    #[test]
+    fn render_structured_json_is_pretty() {
+        let out = render_structured(&serde_json::json!({"a": 1}), OutputFormat::Json).unwrap();
+        assert_eq!(out, "{\n  \"a\": 1\n}\n");
+    }
+
     #[test]
     fn truncate_shorter_than_max_unchanged() {
         assert_eq!(truncate("hello", 10), "hello");
@@ -0,0 +1,85 @@
+//! Sends table output through `$PAGER` (default `less`) when stdout is a terminal.
+//!
+//! Stdout is redirected into the pager for the lifetime of the command, so
+//! every printer is paged without knowing about it. `less` gets `LESS=FRX`
+//! unless the user set `LESS`, which makes it exit immediately when the output
+//! fits on one screen.
+
+use std::io::{IsTerminal, Write};
+
+#[cfg(unix)]
+pub struct Pager {
+    child: std::process::Child,
+    saved_stdout: std::os::fd::OwnedFd,
+}
+
+#[cfg(not(unix))]
+pub struct Pager;
+
+fn pager_command() -> Option<String> {
+    let command = std::env::var("PAGER").unwrap_or_else(|_| "less".into());
+    let command = command.trim();
+    (!command.is_empty() && command != "cat").then(|| command.to_string())
+}
+
+/// Starts the pager if stdout is a terminal. Returns `None` when paging is
+/// unavailable, in which case output goes straight to the terminal.
+#[cfg(unix)]
+pub fn start() -> Option<Pager> {
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+    use std::process::{Command, Stdio};
+
+    if !std::io::stdout().is_terminal() || std::env::var("TERM").is_ok_and(|t| t == "dumb") {
+        return None;
+    }
+    let mut command = Command::new("sh");
+    command
+        .arg("-c")
+        .arg(pager_command()?)
+        .stdin(Stdio::piped());
+    if std::env::var_os("LESS").is_none() {
+        command.env("LESS", "FRX");
+    }
+    let mut child = command.spawn().ok()?;
+    let pipe = OwnedFd::from(child.stdin.take()?);
+
+    std::io::stdout().flush().ok()?;
+    // SAFETY: dup/dup2 only operate on file descriptors owned by this process;
+    // the saved descriptor is immediately wrapped so it is closed exactly once.
+    let saved_stdout = unsafe {
+        let saved = libc::dup(libc::STDOUT_FILENO);
+        if saved < 0 {
+            return None;
+        }
+        let saved = OwnedFd::from_raw_fd(saved);
+        if libc::dup2(pipe.as_raw_fd(), libc::STDOUT_FILENO) < 0 {
+            return None;
+        }
+        saved
+    };
+    Some(Pager {
+        child,
+        saved_stdout,
+    })
+}
+
+#[cfg(not(unix))]
+pub fn start() -> Option<Pager> {
+    None
+}
+
+impl Pager {
+    /// Restores stdout, which closes the pager's input, and waits for the user to quit it.
+    #[cfg(unix)]
+    pub fn finish(mut self) {
+        use std::os::fd::AsRawFd;
+
+        let _ = std::io::stdout().flush();
+        // SAFETY: restores the original stdout saved in `start`.
+        unsafe { libc::dup2(self.saved_stdout.as_raw_fd(), libc::STDOUT_FILENO) };
+        let _ = self.child.wait();
+    }
+
+    #[cfg(not(unix))]
+    pub fn finish(self) {}
+}
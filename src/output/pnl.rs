@@ -0,0 +1,102 @@
+use polymarket_client_sdk::types::Decimal;
+use serde_json::json;
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+
+use super::{OutputFormat, color, truncate};
+
+/// Realized and unrealized PnL for one outcome token.
+pub struct PnlRow {
+    pub market: String,
+    pub outcome: String,
+    /// Tokens still held; zero for closed positions.
+    pub size: Decimal,
+    pub realized: Decimal,
+    pub unrealized: Decimal,
+}
+
+impl PnlRow {
+    pub fn total(&self) -> Decimal {
+        self.realized + self.unrealized
+    }
+}
+
+fn format_pnl(pnl: Decimal) -> String {
+    let text = format!("{:+.2}", pnl.round_dp(2));
+    if pnl.is_sign_negative() {
+        color::ask(text)
+    } else {
+        color::bid(text)
+    }
+}
+
+pub fn print_pnl(rows: &[PnlRow], output: &OutputFormat) -> anyhow::Result<()> {
+    let realized: Decimal = rows.iter().map(|r| r.realized).sum();
+    let unrealized: Decimal = rows.iter().map(|r| r.unrealized).sum();
+    match output {
+        OutputFormat::Table => {
+            if rows.is_empty() {
+                println!("No fills or positions found.");
+                return Ok(());
+            }
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Market")]
+                market: String,
+                #[tabled(rename = "Outcome")]
+                outcome: String,
+                #[tabled(rename = "Size")]
+                size: String,
+                #[tabled(rename = "Realized")]
+                realized: String,
+                #[tabled(rename = "Unrealized")]
+                unrealized: String,
+                #[tabled(rename = "Total")]
+                total: String,
+            }
+            let mut table_rows: Vec<Row> = rows
+                .iter()
+                .map(|r| Row {
+                    market: truncate(&r.market, 40),
+                    outcome: truncate(&r.outcome, 10),
+                    size: format!("{:.2}", r.size),
+                    realized: format_pnl(r.realized),
+                    unrealized: format_pnl(r.unrealized),
+                    total: format_pnl(r.total()),
+                })
+                .collect();
+            table_rows.push(Row {
+                market: "Total".into(),
+                outcome: String::new(),
+                size: String::new(),
+                realized: format_pnl(realized),
+                unrealized: format_pnl(unrealized),
+                total: format_pnl(realized + unrealized),
+            });
+            let table = Table::new(table_rows).with(Style::rounded()).to_string();
+            println!("{table}");
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let markets: Vec<_> = rows
+                .iter()
+                .map(|r| {
+                    json!({
+                        "market": r.market,
+                        "outcome": r.outcome,
+                        "size": r.size.to_string(),
+                        "realized_pnl": r.realized.to_string(),
+                        "unrealized_pnl": r.unrealized.to_string(),
+                        "total_pnl": r.total().to_string(),
+                    })
+                })
+                .collect();
+            super::print_json(&json!({
+                "markets": markets,
+                "realized_pnl": realized.to_string(),
+                "unrealized_pnl": unrealized.to_string(),
+                "total_pnl": (realized + unrealized).to_string(),
+            }))?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use polymarket_client_sdk::types::Decimal;
+use serde_json::json;
+
+use super::{OutputFormat, color, detail_field, print_detail_table};
+
+/// Net worth breakdown for `portfolio summary`.
+pub struct PortfolioSummary {
+    /// Collateral balance in USDC.
+    pub usdc_balance: Decimal,
+    /// USDC committed to the unfilled part of open buy orders.
+    pub open_buy_notional: Decimal,
+    pub open_buy_orders: usize,
+    /// Shares offered by the unfilled part of open sell orders.
+    pub open_sell_shares: Decimal,
+    pub open_sell_orders: usize,
+    /// Positions marked to the CLOB midpoint.
+    pub positions_value: Decimal,
+    pub positions: usize,
+}
+
+impl PortfolioSummary {
+    /// Balance not already committed to open buy orders.
+    pub fn available_usdc(&self) -> Decimal {
+        self.usdc_balance - self.open_buy_notional
+    }
+
+    /// Open orders don't move funds until they fill, so they are part of the
+    /// balance and positions already counted here.
+    pub fn net_worth(&self) -> Decimal {
+        self.usdc_balance + self.positions_value
+    }
+}
+
+/// Exact dollar amounts; a net-worth breakdown shouldn't abbreviate to `$1.5K`.
+fn usd(amount: Decimal) -> String {
+    format!("${:.2}", amount.round_dp(2))
+}
+
+pub fn print_portfolio_summary(
+    summary: &PortfolioSummary,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            let mut rows = Vec::new();
+            detail_field!(rows, "USDC Balance", usd(summary.usdc_balance));
+            detail_field!(
+                rows,
+                "  Reserved (buys)",
+                color::dim(format!(
+                    "{} across {} order(s)",
+                    usd(summary.open_buy_notional),
+                    summary.open_buy_orders
+                ))
+            );
+            detail_field!(rows, "  Available", usd(summary.available_usdc()));
+            detail_field!(
+                rows,
+                "Positions Value",
+                format!(
+                    "{} across {} position(s)",
+                    usd(summary.positions_value),
+                    summary.positions
+                )
+            );
+            detail_field!(
+                rows,
+                "  Offered (sells)",
+                color::dim(format!(
+                    "{:.2} shares across {} order(s)",
+                    summary.open_sell_shares, summary.open_sell_orders
+                ))
+            );
+            detail_field!(rows, "Net Worth", usd(summary.net_worth()));
+            print_detail_table(rows);
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            super::print_json(&json!({
+                "usdc_balance": summary.usdc_balance.to_string(),
+                "open_buy_notional": summary.open_buy_notional.to_string(),
+                "open_buy_orders": summary.open_buy_orders,
+                "available_usdc": summary.available_usdc().to_string(),
+                "open_sell_shares": summary.open_sell_shares.to_string(),
+                "open_sell_orders": summary.open_sell_orders,
+                "positions_value": summary.positions_value.to_string(),
+                "positions": summary.positions,
+                "net_worth": summary.net_worth().to_string(),
+            }))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn net_worth_counts_balance_and_positions_once() {
+        let summary = PortfolioSummary {
+            usdc_balance: dec!(100),
+            open_buy_notional: dec!(30),
+            open_buy_orders: 2,
+            open_sell_shares: dec!(10),
+            open_sell_orders: 1,
+            positions_value: dec!(45.5),
+            positions: 3,
+        };
+        assert_eq!(summary.available_usdc(), dec!(70));
+        assert_eq!(summary.net_worth(), dec!(145.5));
+    }
+}
@@ -0,0 +1,113 @@
+use anyhow::{Context, Result};
+use serde_json::Value;
+use tabled::Table;
+use tabled::builder::Builder;
+use tabled::settings::Style;
+
+/// Checks that a `--query` expression parses before any command runs.
+pub fn validate(expr: &str) -> Result<()> {
+    jmespath::compile(expr)
+        .map(|_| ())
+        .with_context(|| format!("Invalid --query expression: {expr}"))
+}
+
+/// Evaluates a JMESPath expression against serialized command output.
+pub fn apply(expr: &str, data: &impl serde::Serialize) -> Result<Value> {
+    let compiled =
+        jmespath::compile(expr).with_context(|| format!("Invalid --query expression: {expr}"))?;
+    let input = serde_json::to_value(data)?;
+    let result = compiled
+        .search(&input)
+        .with_context(|| format!("--query failed: {expr}"))?;
+    Ok(serde_json::to_value(&*result)?)
+}
+
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Renders a query result for table mode: scalars bare, lists one per line,
+/// objects as key/value tables, and lists of objects as a column table.
+pub fn render_table(value: &Value) -> String {
+    match value {
+        Value::Array(items) if !items.is_empty() && items.iter().all(Value::is_object) => {
+            let mut columns: Vec<&str> = Vec::new();
+            for item in items.iter().filter_map(Value::as_object) {
+                for key in item.keys() {
+                    if !columns.contains(&key.as_str()) {
+                        columns.push(key);
+                    }
+                }
+            }
+            let mut builder = Builder::default();
+            builder.push_record(columns.iter().copied());
+            for item in items {
+                builder.push_record(
+                    columns
+                        .iter()
+                        .map(|c| item.get(*c).map(cell_text).unwrap_or_default()),
+                );
+            }
+            builder.build().with(Style::rounded()).to_string()
+        }
+        Value::Array(items) => items.iter().map(cell_text).collect::<Vec<_>>().join("\n"),
+        Value::Object(map) => Table::from_iter(map.iter().map(|(k, v)| [k.clone(), cell_text(v)]))
+            .with(Style::rounded())
+            .to_string(),
+        other => cell_text(other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn apply_projects_list_field() {
+        let data = json!([{"question": "A", "id": 1}, {"question": "B", "id": 2}]);
+        assert_eq!(apply("[].question", &data).unwrap(), json!(["A", "B"]));
+    }
+
+    #[test]
+    fn apply_filters_and_selects() {
+        let data =
+            json!({"data": [{"side": "BUY", "price": "0.4"}, {"side": "SELL", "price": "0.6"}]});
+        assert_eq!(
+            apply("data[?side=='SELL'].price | [0]", &data).unwrap(),
+            json!("0.6")
+        );
+    }
+
+    #[test]
+    fn apply_missing_field_is_null() {
+        assert_eq!(apply("nope", &json!({"a": 1})).unwrap(), Value::Null);
+    }
+
+    #[test]
+    fn validate_rejects_bad_syntax() {
+        assert!(validate("[?").is_err());
+        assert!(validate("data[0].price").is_ok());
+    }
+
+    #[test]
+    fn render_table_scalar_is_bare() {
+        assert_eq!(render_table(&json!("0.55")), "0.55");
+        assert_eq!(render_table(&json!(42)), "42");
+    }
+
+    #[test]
+    fn render_table_scalar_list_one_per_line() {
+        assert_eq!(render_table(&json!(["a", "b"])), "a\nb");
+    }
+
+    #[test]
+    fn render_table_objects_use_union_of_keys() {
+        let out = render_table(&json!([{"a": 1}, {"b": "x"}]));
+        assert!(out.contains('a') && out.contains('b') && out.contains('x'));
+    }
+}
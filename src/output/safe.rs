@@ -0,0 +1,118 @@
+use std::path::Path;
+
+use alloy::primitives::{B256, U256};
+use anyhow::Result;
+use polymarket_client_sdk::types::Address;
+
+use super::{OutputFormat, print_detail_table, print_json};
+use crate::commands::safe::Proposal;
+
+pub fn print_safe_info(
+    safe: Address,
+    owners: &[Address],
+    threshold: U256,
+    nonce: U256,
+    version: Option<&str>,
+    output: &OutputFormat,
+) -> Result<()> {
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({
+                "safe": safe.to_string(),
+                "owners": owners.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                "threshold": threshold.to_string(),
+                "nonce": nonce.to_string(),
+                "version": version,
+            }))
+        }
+        OutputFormat::Table => {
+            let mut rows = vec![
+                ["Safe".into(), safe.to_string()],
+                [
+                    "Threshold".into(),
+                    format!("{threshold} of {}", owners.len()),
+                ],
+                ["Next Nonce".into(), nonce.to_string()],
+            ];
+            if let Some(version) = version {
+                rows.push(["Version".into(), version.to_string()]);
+            }
+            for (i, owner) in owners.iter().enumerate() {
+                let label = if i == 0 { "Owners" } else { "" };
+                rows.push([label.into(), owner.to_string()]);
+            }
+            print_detail_table(rows);
+            Ok(())
+        }
+    }
+}
+
+/// A proposal after `safe propose` or `safe sign` wrote it to `path`.
+/// `signed` counts the signatures that check out.
+pub fn print_proposal(
+    proposal: &Proposal,
+    path: &Path,
+    signed: usize,
+    output: &OutputFormat,
+) -> Result<()> {
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({
+                "file": path.display().to_string(),
+                "safe": proposal.safe.to_string(),
+                "nonce": proposal.nonce.to_string(),
+                "safe_tx_hash": proposal.safe_tx_hash.to_string(),
+                "actions": proposal.actions,
+                "signers": proposal.signatures.keys().map(ToString::to_string).collect::<Vec<_>>(),
+                "valid_signatures": signed,
+            }))
+        }
+        OutputFormat::Table => {
+            let mut rows = vec![
+                ["Safe".into(), proposal.safe.to_string()],
+                ["Nonce".into(), proposal.nonce.to_string()],
+                ["Safe Tx Hash".into(), proposal.safe_tx_hash.to_string()],
+            ];
+            for (i, action) in proposal.actions.iter().enumerate() {
+                let label = if i == 0 { "Calls" } else { "" };
+                rows.push([label.into(), action.clone()]);
+            }
+            for (i, signer) in proposal.signatures.keys().enumerate() {
+                let label = if i == 0 { "Signed By" } else { "" };
+                rows.push([label.into(), signer.to_string()]);
+            }
+            print_detail_table(rows);
+            println!(
+                "Saved to {} with {signed} signature{}. Other owners add theirs with",
+                path.display(),
+                if signed == 1 { "" } else { "s" }
+            );
+            println!(
+                "`polymarket safe sign {0}`, then `polymarket safe execute {0}`.",
+                path.display()
+            );
+            Ok(())
+        }
+    }
+}
+
+pub fn print_executed(proposal: &Proposal, tx_hash: B256, output: &OutputFormat) -> Result<()> {
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            print_json(&serde_json::json!({
+                "safe": proposal.safe.to_string(),
+                "nonce": proposal.nonce.to_string(),
+                "safe_tx_hash": proposal.safe_tx_hash.to_string(),
+                "tx_hash": tx_hash.to_string(),
+            }))
+        }
+        OutputFormat::Table => {
+            println!(
+                "Executed Safe transaction {} from {}.",
+                proposal.nonce, proposal.safe
+            );
+            println!("Tx: {tx_hash}");
+            Ok(())
+        }
+    }
+}
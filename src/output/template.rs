@@ -0,0 +1,88 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use minijinja::Environment;
+use serde_json::{Map, Value};
+
+/// Reads a `--template` argument: a path to a template file, or the template text itself.
+pub fn load(arg: &str) -> Result<String> {
+    let path = Path::new(arg);
+    if path.is_file() {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read template {}", path.display()))
+    } else {
+        Ok(arg.to_string())
+    }
+}
+
+/// Checks template syntax before any command runs.
+pub fn validate(source: &str) -> Result<()> {
+    Environment::new()
+        .template_from_str(source)
+        .map(|_| ())
+        .context("Invalid --template")
+}
+
+/// Renders a result through a minijinja template. The whole result is
+/// available as `result`; fields of an object result are also top-level names.
+pub fn render(source: &str, data: &impl serde::Serialize) -> Result<String> {
+    let value = serde_json::to_value(data)?;
+    let mut context = match &value {
+        Value::Object(map) => map.clone(),
+        _ => Map::new(),
+    };
+    context.insert("result".into(), value);
+
+    let env = Environment::new();
+    let template = env
+        .template_from_str(source)
+        .context("Invalid --template")?;
+    let mut rendered = template
+        .render(&context)
+        .context("Failed to render --template")?;
+    if !rendered.ends_with('\n') {
+        rendered.push('\n');
+    }
+    Ok(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn render_list_through_result() {
+        let data = json!([{"side": "BUY", "price": "0.4"}, {"side": "SELL", "price": "0.6"}]);
+        let out = render(
+            "{% for t in result %}{{ t.side }} @ {{ t.price }}\n{% endfor %}",
+            &data,
+        )
+        .unwrap();
+        assert_eq!(out, "BUY @ 0.4\nSELL @ 0.6\n");
+    }
+
+    #[test]
+    fn render_object_fields_are_top_level() {
+        let out = render("{{ mid }} / {{ result.mid }}", &json!({"mid": "0.5"})).unwrap();
+        assert_eq!(out, "0.5 / 0.5\n");
+    }
+
+    #[test]
+    fn render_paged_result_iterates_data_field() {
+        let page = json!({"data": [{"id": "a"}, {"id": "b"}], "next_cursor": "LTE="});
+        let out = render("{% for t in data %}{{ t.id }}{% endfor %}", &page).unwrap();
+        assert_eq!(out, "ab\n");
+    }
+
+    #[test]
+    fn validate_rejects_unclosed_block() {
+        assert!(validate("{% for x in result %}").is_err());
+        assert!(validate("{{ result | length }} trades").is_ok());
+    }
+
+    #[test]
+    fn load_inline_when_not_a_file() {
+        assert_eq!(load("{{ result }}").unwrap(), "{{ result }}");
+    }
+}
@@ -0,0 +1,96 @@
+use alloy::primitives::{Address, B256};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use tabled::Tabled;
+
+use super::{OutputFormat, color, truncate};
+use crate::tx::format_gwei;
+
+/// A transaction sent by this CLI that the node still has in its pool.
+pub struct PendingTx {
+    pub hash: B256,
+    pub nonce: u64,
+    pub action: String,
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+    pub sent_at: DateTime<Utc>,
+}
+
+/// `unknown` counts pending nonces the node reports that this CLI didn't send.
+pub fn print_pending(
+    owner: Address,
+    confirmed_nonce: u64,
+    pending: &[PendingTx],
+    unknown: u64,
+    output: &OutputFormat,
+) -> Result<()> {
+    match output {
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let transactions: Vec<_> = pending
+                .iter()
+                .map(|p| {
+                    serde_json::json!({
+                        "hash": p.hash.to_string(),
+                        "nonce": p.nonce,
+                        "action": p.action,
+                        "max_fee_per_gas": p.max_fee_per_gas.to_string(),
+                        "max_priority_fee_per_gas": p.max_priority_fee_per_gas.to_string(),
+                        "sent_at": p.sent_at.to_rfc3339(),
+                    })
+                })
+                .collect();
+            super::print_json(&serde_json::json!({
+                "address": owner.to_string(),
+                "confirmed_nonce": confirmed_nonce,
+                "transactions": transactions,
+                "other_pending": unknown,
+            }))
+        }
+        OutputFormat::Table => {
+            if pending.is_empty() {
+                println!("No pending transactions sent by this CLI from {owner}.");
+            } else {
+                #[derive(Tabled)]
+                struct Row {
+                    #[tabled(rename = "Nonce")]
+                    nonce: u64,
+                    #[tabled(rename = "Hash")]
+                    hash: String,
+                    #[tabled(rename = "Action")]
+                    action: String,
+                    #[tabled(rename = "Max Fee (gwei)")]
+                    max_fee: String,
+                    #[tabled(rename = "Tip (gwei)")]
+                    tip: String,
+                    #[tabled(rename = "Sent")]
+                    sent: String,
+                }
+                let rows: Vec<Row> = pending
+                    .iter()
+                    .map(|p| Row {
+                        nonce: p.nonce,
+                        hash: p.hash.to_string(),
+                        action: truncate(&p.action, 40),
+                        max_fee: format_gwei(p.max_fee_per_gas),
+                        tip: format_gwei(p.max_priority_fee_per_gas),
+                        sent: p.sent_at.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+                    })
+                    .collect();
+                println!("{}", super::render_table(rows)?);
+                println!(
+                    "{}",
+                    color::dim(
+                        "Use `tx speedup <hash>` to raise the fees or `tx cancel <hash>` to replace it."
+                    )
+                );
+            }
+            if unknown > 0 {
+                println!(
+                    "The node has {unknown} more pending transaction{} from {owner} that this CLI didn't send.",
+                    if unknown == 1 { "" } else { "s" }
+                );
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Fixed-interval loops for long-running commands (`--watch`, `--follow`,
+//! `follow`) that stop cleanly on Ctrl+C or SIGTERM.
+//!
+//! A signal never interrupts a refresh half-way: it is remembered, and the
+//! loop ends at the next tick, so a file being written is always complete.
+
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::time::{Instant, sleep_until};
+
+pub struct Poller {
+    interval: Duration,
+    next: Option<Instant>,
+    shutdown: watch::Receiver<bool>,
+}
+
+impl Poller {
+    pub fn new(interval: Duration) -> Self {
+        let (tx, rx) = watch::channel(false);
+        tokio::spawn(async move {
+            wait_for_shutdown_signal().await;
+            let _ = tx.send(true);
+        });
+        Self::with_shutdown(interval, rx)
+    }
+
+    fn with_shutdown(interval: Duration, shutdown: watch::Receiver<bool>) -> Self {
+        Self {
+            interval: interval.max(Duration::from_millis(1)),
+            next: None,
+            shutdown,
+        }
+    }
+
+    /// Returns immediately the first time, then waits one interval from the
+    /// previous tick. Returns false once shutdown has been requested.
+    pub async fn tick(&mut self) -> bool {
+        if *self.shutdown.borrow() {
+            return false;
+        }
+        let now = Instant::now();
+        let due = self.next.unwrap_or(now);
+        self.next = Some(due.max(now) + self.interval);
+        tokio::select! {
+            () = sleep_until(due) => true,
+            Ok(_) = self.shutdown.wait_for(|stop| *stop) => false,
+        }
+    }
+}
+
+#[cfg(unix)]
+async fn wait_for_shutdown_signal() {
+    use tokio::signal::unix::{SignalKind, signal};
+    match signal(SignalKind::terminate()) {
+        Ok(mut term) => {
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {}
+                _ = term.recv() => {}
+            }
+        }
+        Err(_) => {
+            let _ = tokio::signal::ctrl_c().await;
+        }
+    }
+}
+
+#[cfg(not(unix))]
+async fn wait_for_shutdown_signal() {
+    let _ = tokio::signal::ctrl_c().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn first_tick_is_immediate_then_waits_an_interval() {
+        let (_tx, rx) = watch::channel(false);
+        let mut poller = Poller::with_shutdown(Duration::from_secs(5), rx);
+        let start = Instant::now();
+        assert!(poller.tick().await);
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        assert!(poller.tick().await);
+        assert_eq!(start.elapsed(), Duration::from_secs(5));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn shutdown_ends_the_loop_without_waiting() {
+        let (tx, rx) = watch::channel(false);
+        let mut poller = Poller::with_shutdown(Duration::from_secs(60), rx);
+        assert!(poller.tick().await);
+        tx.send(true).unwrap();
+        let start = Instant::now();
+        assert!(!poller.tick().await);
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+}
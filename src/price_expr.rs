@@ -0,0 +1,226 @@
+//! Limit prices given relative to the live book, e.g. `mid`, `bid+0.01`, or
+//! `ask-2t` (two ticks), as accepted by `clob create-order --price`.
+
+use std::str::FromStr;
+
+use anyhow::{Result, bail};
+use polymarket_client_sdk::clob::types::Side;
+use polymarket_client_sdk::types::Decimal;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base {
+    Mid,
+    Bid,
+    Ask,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Offset {
+    Price(Decimal),
+    Ticks(i64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriceExpr {
+    Fixed(Decimal),
+    Relative { base: Base, offset: Option<Offset> },
+}
+
+/// The parts of an order book a relative price is resolved against.
+#[derive(Clone, Copy, Debug)]
+pub struct BookQuote {
+    pub best_bid: Option<Decimal>,
+    pub best_ask: Option<Decimal>,
+    pub tick_size: Decimal,
+}
+
+impl FromStr for PriceExpr {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim().to_ascii_lowercase();
+        if let Ok(price) = Decimal::from_str(&s) {
+            return Ok(Self::Fixed(price));
+        }
+        let invalid = || {
+            anyhow::anyhow!(
+                "Invalid price: {s}. Use a decimal (0.50) or mid, bid, or ask with an optional \
+                 offset in price or ticks (mid-0.01, ask+1t)"
+            )
+        };
+        let split = s.find(['+', '-']).unwrap_or(s.len());
+        let (base, rest) = s.split_at(split);
+        let base = match base {
+            "mid" => Base::Mid,
+            "bid" => Base::Bid,
+            "ask" => Base::Ask,
+            _ => return Err(invalid()),
+        };
+        if rest.is_empty() {
+            return Ok(Self::Relative { base, offset: None });
+        }
+        let negative = rest.starts_with('-');
+        let amount = &rest[1..];
+        let offset = if let Some(ticks) = amount.strip_suffix('t') {
+            let ticks: i64 = ticks.parse().map_err(|_| invalid())?;
+            Offset::Ticks(if negative { -ticks } else { ticks })
+        } else {
+            let price = Decimal::from_str(amount).map_err(|_| invalid())?;
+            Offset::Price(if negative { -price } else { price })
+        };
+        Ok(Self::Relative {
+            base,
+            offset: Some(offset),
+        })
+    }
+}
+
+impl PriceExpr {
+    /// The limit price for an order on `side`. Prices between ticks round
+    /// away from the spread (down for buys, up for sells) so a resolved price
+    /// never crosses further than asked.
+    pub fn resolve(&self, side: Side, quote: &BookQuote) -> Result<Decimal> {
+        let (base, offset) = match *self {
+            Self::Fixed(price) => return Ok(price),
+            Self::Relative { base, offset } => (base, offset),
+        };
+        let (bid, ask) = (quote.best_bid, quote.best_ask);
+        let anchor = match base {
+            Base::Bid => bid.ok_or_else(|| anyhow::anyhow!("The book has no bids"))?,
+            Base::Ask => ask.ok_or_else(|| anyhow::anyhow!("The book has no asks"))?,
+            Base::Mid => match (bid, ask) {
+                (Some(bid), Some(ask)) => (bid + ask) / Decimal::TWO,
+                _ => bail!("The book needs both bids and asks to price at mid"),
+            },
+        };
+        let tick = quote.tick_size;
+        let price = anchor
+            + match offset {
+                None => Decimal::ZERO,
+                Some(Offset::Price(delta)) => delta,
+                Some(Offset::Ticks(n)) => tick * Decimal::from(n),
+            };
+        let price = round_to_tick(price, tick, side);
+        if price < tick || price > Decimal::ONE - tick {
+            bail!(
+                "Price resolves to {price}, outside the tradable range {tick} to {}",
+                Decimal::ONE - tick
+            );
+        }
+        Ok(price)
+    }
+}
+
+/// Rounds a price onto the tick grid away from the spread: down for buys,
+/// up for sells.
+pub fn round_to_tick(price: Decimal, tick: Decimal, side: Side) -> Decimal {
+    let ticks = price / tick;
+    let ticks = if matches!(side, Side::Buy) {
+        ticks.floor()
+    } else {
+        ticks.ceil()
+    };
+    (ticks * tick).normalize()
+}
+
+/// Checks a fixed limit price against the market's tick size before it is
+/// signed, since the CLOB's own rejection doesn't say what went wrong. With
+/// `round`, a price between ticks rounds away from the spread instead.
+pub fn fit_to_tick(price: Decimal, tick: Decimal, side: Side, round: bool) -> Result<Decimal> {
+    let fitted = round_to_tick(price, tick, side);
+    if fitted != price && !round {
+        let below = round_to_tick(price, tick, Side::Buy);
+        let above = round_to_tick(price, tick, Side::Sell);
+        bail!(
+            "Price {price} is not a multiple of the tick size {tick}; use {below} or {above}, \
+             or pass --round-to-tick"
+        );
+    }
+    if fitted < tick || fitted > Decimal::ONE - tick {
+        bail!(
+            "Price {fitted} is outside the tradable range {tick} to {}",
+            Decimal::ONE - tick
+        );
+    }
+    Ok(fitted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn quote() -> BookQuote {
+        BookQuote {
+            best_bid: Some(dec!(0.44)),
+            best_ask: Some(dec!(0.47)),
+            tick_size: dec!(0.01),
+        }
+    }
+
+    fn resolve(expr: &str, side: Side) -> Result<Decimal> {
+        expr.parse::<PriceExpr>()?.resolve(side, &quote())
+    }
+
+    #[test]
+    fn plain_decimals_stay_fixed() {
+        let expr: PriceExpr = "0.50".parse().unwrap();
+        assert_eq!(expr, PriceExpr::Fixed(dec!(0.50)));
+        assert_eq!(expr.resolve(Side::Buy, &quote()).unwrap(), dec!(0.50));
+    }
+
+    #[test]
+    fn offsets_apply_in_price_or_ticks() {
+        assert_eq!(resolve("bid", Side::Buy).unwrap(), dec!(0.44));
+        assert_eq!(resolve("ask+1t", Side::Sell).unwrap(), dec!(0.48));
+        assert_eq!(resolve("ASK-2t", Side::Buy).unwrap(), dec!(0.45));
+        assert_eq!(resolve("bid+0.02", Side::Buy).unwrap(), dec!(0.46));
+    }
+
+    #[test]
+    fn mid_between_ticks_rounds_away_from_the_spread() {
+        assert_eq!(resolve("mid", Side::Buy).unwrap(), dec!(0.45));
+        assert_eq!(resolve("mid", Side::Sell).unwrap(), dec!(0.46));
+        assert_eq!(resolve("mid-0.01", Side::Buy).unwrap(), dec!(0.44));
+    }
+
+    #[test]
+    fn rejects_bad_expressions_and_out_of_range_prices() {
+        assert!("last".parse::<PriceExpr>().is_err());
+        assert!("mid+".parse::<PriceExpr>().is_err());
+        assert!("bid+1.5t".parse::<PriceExpr>().is_err());
+        let err = resolve("ask+60t", Side::Sell).unwrap_err().to_string();
+        assert!(err.contains("outside the tradable range"), "got: {err}");
+    }
+
+    #[test]
+    fn missing_side_of_the_book_is_an_error() {
+        let one_sided = BookQuote {
+            best_ask: None,
+            ..quote()
+        };
+        let mid: PriceExpr = "mid".parse().unwrap();
+        assert!(mid.resolve(Side::Buy, &one_sided).is_err());
+        let bid: PriceExpr = "bid".parse().unwrap();
+        assert_eq!(bid.resolve(Side::Buy, &one_sided).unwrap(), dec!(0.44));
+    }
+
+    #[test]
+    fn off_tick_prices_are_rejected_unless_rounding() {
+        let err = fit_to_tick(dec!(0.455), dec!(0.01), Side::Buy, false).unwrap_err();
+        assert!(err.to_string().contains("use 0.45 or 0.46"));
+        assert_eq!(
+            fit_to_tick(dec!(0.455), dec!(0.01), Side::Buy, true).unwrap(),
+            dec!(0.45)
+        );
+        assert_eq!(
+            fit_to_tick(dec!(0.455), dec!(0.01), Side::Sell, true).unwrap(),
+            dec!(0.46)
+        );
+        assert_eq!(
+            fit_to_tick(dec!(0.45), dec!(0.01), Side::Buy, false).unwrap(),
+            dec!(0.45)
+        );
+        assert!(fit_to_tick(dec!(0.995), dec!(0.01), Side::Sell, true).is_err());
+    }
+}
@@ -0,0 +1,385 @@
+//! QR codes for short text such as wallet addresses, drawn in the terminal.
+//! Only what an address needs is supported: byte mode, versions 1 to 3, and
+//! error correction level M, which keeps every code in a single block.
+
+/// Data and error correction codewords for versions 1 to 3 at level M.
+const VERSIONS: [(usize, usize); 3] = [(16, 10), (28, 16), (44, 26)];
+
+/// Format bits for level M.
+const LEVEL_M: u32 = 0b00;
+
+/// Light modules around the code, which scanners need to find its edges.
+const QUIET_ZONE: usize = 4;
+
+/// A QR code's modules, `true` for dark.
+pub struct QrCode {
+    size: usize,
+    modules: Vec<Vec<bool>>,
+    /// Finder, timing, alignment, and format modules, which masks skip.
+    reserved: Vec<Vec<bool>>,
+}
+
+impl QrCode {
+    /// Encodes `text` in the smallest version it fits, or `None` when it is
+    /// longer than 42 bytes.
+    pub fn encode(text: &str) -> Option<Self> {
+        let bytes = text.as_bytes();
+        let version = VERSIONS
+            .iter()
+            .position(|(data, _)| bytes.len() + 2 <= *data)?
+            + 1;
+        let (data_len, ec_len) = VERSIONS[version - 1];
+
+        let mut codewords = data_codewords(bytes, data_len);
+        let ec = reed_solomon(&codewords, ec_len);
+        codewords.extend(ec);
+
+        let size = 17 + 4 * version;
+        let mut qr = Self {
+            size,
+            modules: vec![vec![false; size]; size],
+            reserved: vec![vec![false; size]; size],
+        };
+        qr.draw_function_patterns(version);
+        qr.draw_codewords(&codewords);
+
+        let best = (0..8)
+            .min_by_key(|&mask| {
+                qr.apply_mask(mask);
+                qr.draw_format_bits(mask);
+                let penalty = qr.penalty();
+                qr.apply_mask(mask);
+                penalty
+            })
+            .unwrap_or(0);
+        qr.apply_mask(best);
+        qr.draw_format_bits(best);
+        Some(qr)
+    }
+
+    /// Whether the module at column `x`, row `y` is dark.
+    pub fn module(&self, x: usize, y: usize) -> bool {
+        self.modules[y][x]
+    }
+
+    /// The code as text, two rows per line using half blocks. Blocks are
+    /// light modules, so the code reads right on a dark terminal; with
+    /// `color`, the colors are set so it reads on any background.
+    pub fn render(&self, color: bool) -> String {
+        let span = self.size + 2 * QUIET_ZONE;
+        let light = |x: usize, y: usize| {
+            let inside = |i: usize| (QUIET_ZONE..QUIET_ZONE + self.size).contains(&i);
+            !(inside(x) && inside(y) && self.module(x - QUIET_ZONE, y - QUIET_ZONE))
+        };
+        let mut out = String::new();
+        for y in (0..span).step_by(2) {
+            if color {
+                out.push_str("\x1b[97;40m");
+            }
+            for x in 0..span {
+                let top = light(x, y);
+                let bottom = y + 1 < span && light(x, y + 1);
+                out.push(match (top, bottom) {
+                    (true, true) => '\u{2588}',
+                    (true, false) => '\u{2580}',
+                    (false, true) => '\u{2584}',
+                    (false, false) => ' ',
+                });
+            }
+            if color {
+                out.push_str("\x1b[0m");
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        self.modules[y][x] = dark;
+        self.reserved[y][x] = true;
+    }
+
+    fn draw_function_patterns(&mut self, version: usize) {
+        let size = self.size;
+        for i in 0..size {
+            self.set_function(6, i, i.is_multiple_of(2));
+            self.set_function(i, 6, i.is_multiple_of(2));
+        }
+        self.draw_finder(3, 3);
+        self.draw_finder(size - 4, 3);
+        self.draw_finder(3, size - 4);
+        // Versions 2 and 3 have one alignment pattern, near the bottom right.
+        if version > 1 {
+            let at = size - 7;
+            for dy in -2i32..=2 {
+                for dx in -2i32..=2 {
+                    let x = (at as i32 + dx) as usize;
+                    let y = (at as i32 + dy) as usize;
+                    self.set_function(x, y, dx.abs().max(dy.abs()) != 1);
+                }
+            }
+        }
+        // Reserve the format areas; the real bits go in once the mask is picked.
+        self.draw_format_bits(0);
+    }
+
+    /// A finder pattern centered on (`cx`, `cy`) with its light separator.
+    fn draw_finder(&mut self, cx: usize, cy: usize) {
+        for dy in -4i32..=4 {
+            for dx in -4i32..=4 {
+                let x = cx as i32 + dx;
+                let y = cy as i32 + dy;
+                if (0..self.size as i32).contains(&x) && (0..self.size as i32).contains(&y) {
+                    let distance = dx.abs().max(dy.abs());
+                    self.set_function(x as usize, y as usize, distance != 2 && distance != 4);
+                }
+            }
+        }
+    }
+
+    fn draw_format_bits(&mut self, mask: u32) {
+        let bits = format_bits(mask);
+        let bit = |i: u32| (bits >> i) & 1 == 1;
+        let size = self.size;
+        for i in 0..6 {
+            self.set_function(8, i, bit(i as u32));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i as u32));
+        }
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i as u32));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i as u32));
+        }
+        // The module that is always dark.
+        self.set_function(8, size - 8, true);
+    }
+
+    /// Places codewords in the zigzag order: two columns at a time from the
+    /// right, alternating up and down, skipping the vertical timing pattern.
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        let size = self.size;
+        let total = codewords.len() * 8;
+        let mut i = 0;
+        let mut right = size - 1;
+        loop {
+            if right == 6 {
+                right = 5;
+            }
+            for vert in 0..size {
+                for j in 0..2 {
+                    let x = right - j;
+                    let upward = (right + 1) & 2 == 0;
+                    let y = if upward { size - 1 - vert } else { vert };
+                    if !self.reserved[y][x] && i < total {
+                        self.modules[y][x] = (codewords[i / 8] >> (7 - i % 8)) & 1 == 1;
+                        i += 1;
+                    }
+                }
+            }
+            if right < 2 {
+                break;
+            }
+            right -= 2;
+        }
+    }
+
+    /// Flips the data modules `mask` selects; applying it twice undoes it.
+    fn apply_mask(&mut self, mask: u32) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                if !self.reserved[y][x] && masked(mask, x, y) {
+                    self.modules[y][x] = !self.modules[y][x];
+                }
+            }
+        }
+    }
+
+    /// How hard the code is to scan, from runs of one color, 2x2 blocks, and
+    /// imbalance between dark and light. Lower is better.
+    fn penalty(&self) -> usize {
+        let size = self.size;
+        let mut penalty = 0;
+        let run_penalty = |line: &mut dyn Iterator<Item = bool>| {
+            let mut total = 0;
+            let mut last = None;
+            let mut run = 0;
+            for dark in line {
+                if Some(dark) == last {
+                    run += 1;
+                } else {
+                    if run >= 5 {
+                        total += run - 2;
+                    }
+                    last = Some(dark);
+                    run = 1;
+                }
+            }
+            if run >= 5 {
+                total += run - 2;
+            }
+            total
+        };
+        for i in 0..size {
+            penalty += run_penalty(&mut (0..size).map(|x| self.modules[i][x]));
+            penalty += run_penalty(&mut (0..size).map(|y| self.modules[y][i]));
+        }
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let dark = self.modules[y][x];
+                if self.modules[y][x + 1] == dark
+                    && self.modules[y + 1][x] == dark
+                    && self.modules[y + 1][x + 1] == dark
+                {
+                    penalty += 3;
+                }
+            }
+        }
+        let dark = self.modules.iter().flatten().filter(|m| **m).count();
+        let percent = dark * 100 / (size * size);
+        penalty + percent.abs_diff(50) / 5 * 10
+    }
+}
+
+fn masked(mask: u32, x: usize, y: usize) -> bool {
+    match mask {
+        0 => (x + y).is_multiple_of(2),
+        1 => y.is_multiple_of(2),
+        2 => x.is_multiple_of(3),
+        3 => (x + y).is_multiple_of(3),
+        4 => (x / 3 + y / 2).is_multiple_of(2),
+        5 => x * y % 2 + x * y % 3 == 0,
+        6 => (x * y % 2 + x * y % 3).is_multiple_of(2),
+        _ => ((x + y) % 2 + x * y % 3).is_multiple_of(2),
+    }
+}
+
+/// The 15 format bits for level M and `mask`: five data bits, ten BCH
+/// check bits, and the fixed XOR pattern.
+fn format_bits(mask: u32) -> u32 {
+    let data = (LEVEL_M << 3) | mask;
+    let mut rem = data;
+    for _ in 0..10 {
+        rem = (rem << 1) ^ ((rem >> 9) * 0x537);
+    }
+    ((data << 10) | rem) ^ 0x5412
+}
+
+/// Byte mode header, the bytes, a terminator, and pad codewords up to
+/// `len`.
+fn data_codewords(bytes: &[u8], len: usize) -> Vec<u8> {
+    let mut bits: Vec<bool> = Vec::with_capacity(len * 8);
+    let mut push = |value: u32, count: u32| {
+        for i in (0..count).rev() {
+            bits.push((value >> i) & 1 == 1);
+        }
+    };
+    push(0b0100, 4);
+    push(bytes.len() as u32, 8);
+    for byte in bytes {
+        push(u32::from(*byte), 8);
+    }
+    let terminator = (len * 8 - bits.len()).min(4);
+    bits.extend(std::iter::repeat_n(false, terminator));
+    while !bits.len().is_multiple_of(8) {
+        bits.push(false);
+    }
+    let mut codewords: Vec<u8> = bits
+        .chunks(8)
+        .map(|byte| byte.iter().fold(0, |acc, bit| (acc << 1) | u8::from(*bit)))
+        .collect();
+    for pad in [0xEC, 0x11].into_iter().cycle() {
+        if codewords.len() >= len {
+            break;
+        }
+        codewords.push(pad);
+    }
+    codewords
+}
+
+/// Multiplies in GF(2^8) modulo the QR polynomial x^8 + x^4 + x^3 + x^2 + 1.
+fn gf_mul(x: u8, y: u8) -> u8 {
+    let mut z: u32 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11D);
+        z ^= u32::from((y >> i) & 1) * u32::from(x);
+    }
+    z as u8
+}
+
+/// The `len` Reed-Solomon error correction codewords for `data`.
+fn reed_solomon(data: &[u8], len: usize) -> Vec<u8> {
+    let mut divisor = vec![0u8; len];
+    divisor[len - 1] = 1;
+    let mut root = 1u8;
+    for _ in 0..len {
+        for j in 0..len {
+            divisor[j] = gf_mul(divisor[j], root);
+            if j + 1 < len {
+                divisor[j] ^= divisor[j + 1];
+            }
+        }
+        root = gf_mul(root, 0x02);
+    }
+
+    let mut remainder = vec![0u8; len];
+    for byte in data {
+        let factor = byte ^ remainder.remove(0);
+        remainder.push(0);
+        for (r, d) in remainder.iter_mut().zip(&divisor) {
+            *r ^= gf_mul(*d, factor);
+        }
+    }
+    remainder
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_correction_matches_the_standard_example() {
+        // "HELLO WORLD" as version 1-M, from the QR specification's example.
+        let data = [
+            32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17,
+        ];
+        assert_eq!(
+            reed_solomon(&data, 10),
+            [196, 35, 39, 119, 235, 215, 231, 226, 93, 23]
+        );
+    }
+
+    #[test]
+    fn format_bits_match_the_specification_table() {
+        assert_eq!(format_bits(0), 0b101_0100_0001_0010);
+        assert_eq!(format_bits(5), 0b100_0000_1100_1110);
+        assert_eq!(format_bits(7), 0b100_1010_1010_0000);
+    }
+
+    #[test]
+    fn addresses_fit_in_version_3() {
+        let address = "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23";
+        let qr = QrCode::encode(address).unwrap();
+        assert_eq!(qr.size, 29);
+        // Finder corners are dark and their separators light.
+        for (x, y) in [(0, 0), (28, 0), (0, 28)] {
+            assert!(qr.module(x, y));
+        }
+        assert!(!qr.module(7, 7));
+        assert!(qr.module(8, 29 - 8));
+        assert!(QrCode::encode(&"x".repeat(43)).is_none());
+        assert_eq!(QrCode::encode("hi").unwrap().size, 21);
+    }
+
+    #[test]
+    fn pads_alternate_after_the_data() {
+        let codewords = data_codewords(b"A", 16);
+        assert_eq!(&codewords[..3], [0x40, 0x14, 0x10]);
+        assert_eq!(&codewords[3..7], [0xEC, 0x11, 0xEC, 0x11]);
+    }
+}
@@ -0,0 +1,189 @@
+//! Private keys encrypted at rest, and the passphrases that unlock them.
+//! PBKDF2-HMAC-SHA256 stretches the passphrase into an AES-256-GCM key; the
+//! wallet address is authenticated alongside the key so it can be shown
+//! without asking for the passphrase.
+
+use std::io::{self, IsTerminal as _, Write as _};
+use std::num::NonZeroU32;
+
+use alloy::hex;
+use anyhow::{Context, Result, bail};
+use aws_lc_rs::aead::{AES_256_GCM, Aad, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use aws_lc_rs::{pbkdf2, rand};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal;
+use polymarket_client_sdk::types::Address;
+use serde::{Deserialize, Serialize};
+
+const PASSPHRASE_ENV_VAR: &str = "POLYMARKET_PASSPHRASE";
+const KDF: &str = "pbkdf2-sha256";
+/// OWASP's recommendation for PBKDF2-HMAC-SHA256.
+const ITERATIONS: u32 = 600_000;
+const SALT_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct EncryptedKey {
+    pub address: Address,
+    pub kdf: String,
+    pub iterations: u32,
+    pub salt: String,
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
+fn cipher(passphrase: &str, salt: &[u8], iterations: u32) -> Result<LessSafeKey> {
+    let iterations = NonZeroU32::new(iterations).context("Invalid key file: zero iterations")?;
+    let mut key = [0u8; KEY_LEN];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        salt,
+        passphrase.as_bytes(),
+        &mut key,
+    );
+    let key = UnboundKey::new(&AES_256_GCM, &key)
+        .map_err(|_| anyhow::anyhow!("Failed to set up encryption"))?;
+    Ok(LessSafeKey::new(key))
+}
+
+pub fn encrypt(private_key: &str, address: Address, passphrase: &str) -> Result<EncryptedKey> {
+    encrypt_with(private_key, address, passphrase, ITERATIONS)
+}
+
+fn encrypt_with(
+    private_key: &str,
+    address: Address,
+    passphrase: &str,
+    iterations: u32,
+) -> Result<EncryptedKey> {
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    rand::fill(&mut salt)
+        .and_then(|()| rand::fill(&mut nonce))
+        .map_err(|_| anyhow::anyhow!("Failed to generate random bytes"))?;
+    let mut data = private_key.as_bytes().to_vec();
+    cipher(passphrase, &salt, iterations)?
+        .seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(address.as_slice()),
+            &mut data,
+        )
+        .map_err(|_| anyhow::anyhow!("Failed to encrypt the private key"))?;
+    Ok(EncryptedKey {
+        address,
+        kdf: KDF.to_string(),
+        iterations,
+        salt: hex::encode(salt),
+        nonce: hex::encode(nonce),
+        ciphertext: hex::encode(data),
+    })
+}
+
+pub fn decrypt(encrypted: &EncryptedKey, passphrase: &str) -> Result<String> {
+    if encrypted.kdf != KDF {
+        bail!("Unsupported key encryption '{}'", encrypted.kdf);
+    }
+    let salt = hex::decode(&encrypted.salt).context("Invalid key file: bad salt")?;
+    let nonce = Nonce::try_assume_unique_for_key(
+        &hex::decode(&encrypted.nonce).context("Invalid key file: bad nonce")?,
+    )
+    .map_err(|_| anyhow::anyhow!("Invalid key file: bad nonce"))?;
+    let mut data = hex::decode(&encrypted.ciphertext).context("Invalid key file: bad data")?;
+    let key = cipher(passphrase, &salt, encrypted.iterations)?
+        .open_in_place(nonce, Aad::from(encrypted.address.as_slice()), &mut data)
+        .map_err(|_| anyhow::anyhow!("Wrong passphrase"))?;
+    String::from_utf8(key.to_vec()).context("Invalid key file: key is not text")
+}
+
+/// Reads a line from the terminal without echoing it.
+fn read_hidden(prompt: &str) -> Result<String> {
+    let mut stderr = io::stderr();
+    write!(stderr, "{prompt}")?;
+    stderr.flush()?;
+    terminal::enable_raw_mode()?;
+    let read = (|| {
+        let mut line = String::new();
+        loop {
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Enter => return Ok(line),
+                KeyCode::Backspace => {
+                    line.pop();
+                }
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    bail!("Cancelled")
+                }
+                KeyCode::Char(c) => line.push(c),
+                _ => {}
+            }
+        }
+    })();
+    terminal::disable_raw_mode()?;
+    writeln!(stderr)?;
+    read
+}
+
+/// The passphrase from `POLYMARKET_PASSPHRASE`, or asked for on the terminal.
+pub fn passphrase(prompt: &str) -> Result<String> {
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV_VAR)
+        && !passphrase.is_empty()
+    {
+        return Ok(passphrase);
+    }
+    if !io::stdin().is_terminal() {
+        bail!("The private key is encrypted: set {PASSPHRASE_ENV_VAR} to unlock it");
+    }
+    read_hidden(prompt)
+}
+
+/// Like [`passphrase`], but asks twice on the terminal so a typo doesn't
+/// lock the key away.
+pub fn new_passphrase() -> Result<String> {
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV_VAR)
+        && !passphrase.is_empty()
+    {
+        return Ok(passphrase);
+    }
+    if !io::stdin().is_terminal() {
+        bail!("Set {PASSPHRASE_ENV_VAR} to choose a passphrase without a terminal");
+    }
+    let passphrase = read_hidden("New passphrase: ")?;
+    if passphrase.is_empty() {
+        bail!("The passphrase can't be empty");
+    }
+    if read_hidden("Repeat passphrase: ")? != passphrase {
+        bail!("Passphrases don't match");
+    }
+    Ok(passphrase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    #[test]
+    fn round_trips_with_the_right_passphrase() {
+        let encrypted = encrypt_with(KEY, Address::ZERO, "hunter2", 1000).unwrap();
+        assert!(!encrypted.ciphertext.contains(&KEY[2..]));
+        assert_eq!(decrypt(&encrypted, "hunter2").unwrap(), KEY);
+    }
+
+    #[test]
+    fn rejects_a_wrong_passphrase_or_swapped_address() {
+        let encrypted = encrypt_with(KEY, Address::ZERO, "hunter2", 1000).unwrap();
+        assert!(decrypt(&encrypted, "hunter3").is_err());
+        let swapped = EncryptedKey {
+            address: Address::repeat_byte(1),
+            ..encrypted
+        };
+        assert!(decrypt(&swapped, "hunter2").is_err());
+    }
+}
@@ -0,0 +1,110 @@
+//! Shell aliases: `alias b = clob book` makes `b <token>` run
+//! `clob book <token>`. An alias may stand for several commands separated
+//! by `;`, which run in turn.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Result, bail, ensure};
+
+/// Words the shell handles itself, which an alias can't replace.
+const RESERVED: &[&str] = &["alias", "unalias", "use", "watch", "split", "exit", "quit"];
+
+/// Parses `NAME = COMMAND`, the argument of the `alias` directive.
+pub fn parse_definition(definition: &str) -> Result<(String, String)> {
+    let Some((name, command)) = definition.split_once('=') else {
+        bail!("Usage: alias NAME = COMMAND (e.g. alias b = clob book)");
+    };
+    let (name, command) = (name.trim(), command.trim());
+    ensure!(
+        !name.is_empty() && !name.contains(char::is_whitespace),
+        "Alias names are a single word"
+    );
+    ensure!(!RESERVED.contains(&name), "'{name}' is a shell command");
+    ensure!(!command.is_empty(), "Alias '{name}' needs a command");
+    Ok((name.to_string(), command.to_string()))
+}
+
+/// Splits a line into its commands at `;` outside quotes.
+pub fn split_commands(line: &str) -> Vec<String> {
+    let mut commands = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    for c in line.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                current.push(c);
+            }
+            ';' if !in_quotes => commands.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    commands.push(current);
+    commands
+        .into_iter()
+        .map(|command| command.trim().to_string())
+        .filter(|command| !command.is_empty())
+        .collect()
+}
+
+/// The commands a line runs: split at `;`, with the first word of each
+/// replaced by its alias, if it has one. Arguments after an alias go to
+/// the last of its commands. Aliases aren't expanded again inside an alias.
+pub fn expand(line: &str, aliases: &BTreeMap<String, String>) -> Vec<String> {
+    split_commands(line)
+        .into_iter()
+        .flat_map(|command| {
+            let (first, rest) = command
+                .split_once(char::is_whitespace)
+                .unwrap_or((&command, ""));
+            match aliases.get(first) {
+                Some(alias) if rest.is_empty() => split_commands(alias),
+                Some(alias) => split_commands(&format!("{alias} {rest}")),
+                None => vec![command.clone()],
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_definitions() {
+        assert_eq!(
+            parse_definition("b = clob book").unwrap(),
+            ("b".to_string(), "clob book".to_string())
+        );
+        assert!(parse_definition("b clob book").is_err());
+        assert!(parse_definition("my book = clob book").is_err());
+        assert!(parse_definition("exit = markets list").is_err());
+        assert!(parse_definition("b =  ").is_err());
+    }
+
+    #[test]
+    fn expands_the_first_word_and_appends_arguments() {
+        let aliases = BTreeMap::from([
+            ("b".to_string(), "clob book".to_string()),
+            ("am".to_string(), "clob balance; clob orders".to_string()),
+        ]);
+        assert_eq!(expand("b 123", &aliases), ["clob book 123"]);
+        assert_eq!(expand("am", &aliases), ["clob balance", "clob orders"]);
+        assert_eq!(
+            expand("am --market 0xabc", &aliases),
+            ["clob balance", "clob orders --market 0xabc"]
+        );
+        assert_eq!(expand("markets list", &aliases), ["markets list"]);
+        assert_eq!(expand("b 1; b 2", &aliases), ["clob book 1", "clob book 2"]);
+        // Only the first word is an alias.
+        assert_eq!(expand("clob b", &aliases), ["clob b"]);
+    }
+
+    #[test]
+    fn keeps_semicolons_inside_quotes() {
+        assert_eq!(
+            split_commands(r#"markets search "a;b"; clob ok"#),
+            [r#"markets search "a;b""#, "clob ok"]
+        );
+    }
+}
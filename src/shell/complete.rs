@@ -0,0 +1,214 @@
+//! Tab completion for the shell: subcommands and flags from the clap
+//! definition, and market slugs, condition IDs, and token IDs from the
+//! market index.
+
+use std::collections::BTreeMap;
+
+use clap::CommandFactory as _;
+use rustyline::completion::{Completer, Pair};
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::{Context, Helper};
+
+use super::index::{MarketIndex, SharedIndex};
+
+/// More candidates than this aren't useful on one screen.
+const MAX_CANDIDATES: usize = 40;
+
+pub struct ShellHelper {
+    cli: clap::Command,
+    index: SharedIndex,
+}
+
+impl ShellHelper {
+    pub fn new(index: SharedIndex) -> Self {
+        let mut cli = crate::Cli::command();
+        // Propagates global flags to every subcommand.
+        cli.build();
+        Self { cli, index }
+    }
+
+    /// Candidates for the word being typed, given the words before it.
+    fn candidates(
+        &self,
+        previous: &[&str],
+        word: &str,
+        aliases: &BTreeMap<String, String>,
+    ) -> Vec<Pair> {
+        let command = super::find_command(&self.cli, previous);
+
+        if word.starts_with('-') {
+            let flags = command
+                .get_arguments()
+                .filter(|arg| !arg.is_hide_set())
+                .filter_map(|arg| arg.get_long())
+                .map(|long| format!("--{long}"));
+            return plain(flags.filter(|flag| flag.starts_with(word)));
+        }
+        if command.has_subcommands() && previous.first() != Some(&"use") {
+            let mut names: Vec<_> = command
+                .get_subcommands()
+                .filter(|sub| !sub.is_hide_set())
+                .map(|sub| sub.get_name().to_string())
+                .collect();
+            if previous.is_empty() {
+                names.extend(
+                    ["alias", "unalias", "use", "watch", "split", "exit", "quit"].map(String::from),
+                );
+                names.extend(aliases.keys().cloned());
+            }
+            return plain(names.into_iter().filter(|name| name.starts_with(word)));
+        }
+        match self.index.read() {
+            Ok(index) => market_candidates(&index, word),
+            Err(_) => Vec::new(),
+        }
+    }
+}
+
+fn plain(words: impl Iterator<Item = String>) -> Vec<Pair> {
+    words
+        .map(|word| Pair {
+            display: word.clone(),
+            replacement: word,
+        })
+        .collect()
+}
+
+/// Token IDs for a word of digits, condition IDs for `0x...`, and slugs
+/// (by prefix, then anywhere in the slug) for anything else.
+fn market_candidates(index: &MarketIndex, word: &str) -> Vec<Pair> {
+    if word.is_empty() {
+        return Vec::new();
+    }
+    let markets = index.markets.iter();
+    if crate::commands::is_numeric_id(word) {
+        markets
+            .flat_map(|m| {
+                m.outcomes
+                    .iter()
+                    .map(move |(outcome, token)| (m, outcome, token))
+            })
+            .map(|(m, outcome, token)| (m, outcome, token.to_string()))
+            .filter(|(_, _, token)| token.starts_with(word))
+            .map(|(m, outcome, token)| Pair {
+                display: format!("{token}  {outcome} · {}", m.slug),
+                replacement: token,
+            })
+            .take(MAX_CANDIDATES)
+            .collect()
+    } else if word.starts_with("0x") {
+        let word = word.to_ascii_lowercase();
+        markets
+            .filter_map(|m| Some((m, m.condition_id?.to_string())))
+            .filter(|(_, id)| id.starts_with(&word))
+            .map(|(m, id)| Pair {
+                display: format!("{id}  {}", m.slug),
+                replacement: id,
+            })
+            .take(MAX_CANDIDATES)
+            .collect()
+    } else {
+        let word = word.to_ascii_lowercase();
+        let prefixed = markets.clone().filter(|m| m.slug.starts_with(&word));
+        let inside = markets.filter(|m| !m.slug.starts_with(&word) && m.slug.contains(&word));
+        plain(prefixed.chain(inside).map(|m| m.slug.clone()))
+            .into_iter()
+            .take(MAX_CANDIDATES)
+            .collect()
+    }
+}
+
+impl Completer for ShellHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let before = &line[..pos];
+        let start = before.rfind(char::is_whitespace).map_or(0, |i| i + 1);
+        let mut previous: Vec<&str> = before[..start].split_whitespace().collect();
+        // After an alias, complete as if its (last) command had been typed.
+        let aliases = crate::config::aliases();
+        let alias = previous.first().and_then(|first| aliases.get(*first));
+        if let Some(command) = alias.and_then(|command| command.rsplit(';').next()) {
+            previous.splice(0..1, command.split_whitespace());
+        }
+        Ok((
+            start,
+            self.candidates(&previous, &before[start..], &aliases),
+        ))
+    }
+}
+
+impl Hinter for ShellHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ShellHelper {}
+
+impl Validator for ShellHelper {}
+
+impl Helper for ShellHelper {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, RwLock};
+
+    use super::*;
+    use crate::shell::index::IndexedMarket;
+
+    fn helper() -> ShellHelper {
+        let market = |slug: &str, condition: u8, tokens: [u64; 2]| IndexedMarket {
+            slug: slug.to_string(),
+            question: String::new(),
+            condition_id: Some([condition; 32].into()),
+            outcomes: vec![
+                ("Yes".to_string(), tokens[0].try_into().unwrap()),
+                ("No".to_string(), tokens[1].try_into().unwrap()),
+            ],
+        };
+        let index = MarketIndex {
+            updated_at: None,
+            markets: vec![
+                market("will-it-rain", 0xab, [1234, 5678]),
+                market("rain-or-shine", 0xcd, [1299, 9999]),
+            ],
+        };
+        ShellHelper::new(Arc::new(RwLock::new(index)))
+    }
+
+    fn replacements(previous: &[&str], word: &str) -> Vec<String> {
+        helper()
+            .candidates(previous, word, &BTreeMap::new())
+            .into_iter()
+            .map(|pair| pair.replacement)
+            .collect()
+    }
+
+    #[test]
+    fn completes_subcommands_and_flags() {
+        assert_eq!(replacements(&[], "mark"), ["markets"]);
+        assert_eq!(replacements(&["clob"], "boo"), ["book", "books"]);
+        assert!(replacements(&["clob", "book"], "--out").contains(&"--output".to_string()));
+    }
+
+    #[test]
+    fn completes_market_ids_from_the_index() {
+        assert_eq!(replacements(&["clob", "book"], "12"), ["1234", "1299"]);
+        assert_eq!(
+            replacements(&["clob", "book"], "0xCD"),
+            [format!("0x{}", "cd".repeat(32))]
+        );
+        // Prefix matches come before matches inside the slug.
+        assert_eq!(replacements(&["use"], "will"), ["will-it-rain"]);
+        assert_eq!(
+            replacements(&["clob", "book"], "rain"),
+            ["rain-or-shine", "will-it-rain"]
+        );
+    }
+}
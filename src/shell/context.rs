@@ -0,0 +1,266 @@
+//! The market picked with `use`, for the rest of a shell session. Commands
+//! that need a token or condition ID and weren't given one get it from
+//! here.
+
+use anyhow::{Result, bail, ensure};
+
+use super::index::{IndexedMarket, SharedIndex};
+use crate::commands::{is_numeric_id, resolve_condition_arg, resolve_token_arg};
+
+/// Arguments filled with the session's token ID.
+const TOKEN_ARGS: &[&str] = &["token", "token_id", "token_ids"];
+
+/// Arguments filled with the session's condition ID.
+const CONDITION_ARGS: &[&str] = &["condition_id"];
+
+#[derive(Debug, PartialEq)]
+pub struct Session {
+    /// The market's slug, or the ID given when the market isn't known.
+    label: String,
+    outcome: Option<String>,
+    token: Option<String>,
+    condition: Option<String>,
+}
+
+impl Session {
+    /// Picks a market by slug, URL, condition ID, or token ID, and
+    /// optionally the outcome whose token commands should use. Markets in
+    /// the shell's index are resolved without a network call.
+    pub async fn resolve(arg: &str, outcome: Option<&str>, index: &SharedIndex) -> Result<Self> {
+        let indexed = index.read().ok().and_then(|index| index.find(arg).cloned());
+        if let Some(market) = indexed {
+            return Self::from_market(&market, arg, outcome);
+        }
+
+        if is_numeric_id(arg) {
+            ensure!(outcome.is_none(), "A token ID already picks the outcome");
+            return Ok(Self {
+                label: arg.to_string(),
+                outcome: None,
+                token: Some(arg.to_string()),
+                condition: None,
+            });
+        }
+        if arg.starts_with("0x") {
+            ensure!(
+                outcome.is_none(),
+                "Market {arg} isn't in the shell's market index; use its slug to pick an outcome"
+            );
+            return Ok(Self {
+                label: arg.to_string(),
+                outcome: None,
+                token: None,
+                condition: Some(arg.to_string()),
+            });
+        }
+        let condition = resolve_condition_arg(arg).await?;
+        let token = match outcome {
+            Some(outcome) => Some(resolve_token_arg(arg, Some(outcome)).await?),
+            None => None,
+        };
+        Ok(Self {
+            label: arg.to_string(),
+            outcome: outcome.map(str::to_string),
+            token,
+            condition: Some(condition),
+        })
+    }
+
+    fn from_market(market: &IndexedMarket, arg: &str, outcome: Option<&str>) -> Result<Self> {
+        let picked = if is_numeric_id(arg) {
+            ensure!(outcome.is_none(), "A token ID already picks the outcome");
+            market
+                .outcomes
+                .iter()
+                .find(|(_, token)| token.to_string() == arg)
+        } else if let Some(outcome) = outcome {
+            let found = market
+                .outcomes
+                .iter()
+                .find(|(label, _)| label.eq_ignore_ascii_case(outcome.trim()));
+            if found.is_none() {
+                let labels: Vec<_> = market.outcomes.iter().map(|(l, _)| l.as_str()).collect();
+                bail!(
+                    "Market '{}' has no outcome '{outcome}'. Valid outcomes: {}",
+                    market.slug,
+                    labels.join(", ")
+                );
+            }
+            found
+        } else {
+            None
+        };
+        Ok(Self {
+            label: market.slug.clone(),
+            outcome: picked.map(|(label, _)| label.clone()),
+            token: picked.map(|(_, token)| token.to_string()),
+            condition: market.condition_id.map(|id| id.to_string()),
+        })
+    }
+
+    pub fn token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+
+    pub fn condition(&self) -> Option<&str> {
+        self.condition.as_deref()
+    }
+
+    /// The market and outcome, as the prompt shows them.
+    pub fn title(&self) -> String {
+        match &self.outcome {
+            Some(outcome) => format!("{} · {outcome}", self.label),
+            None => self.label.clone(),
+        }
+    }
+
+    pub fn prompt(&self) -> String {
+        format!("polymarket [{}]> ", self.title())
+    }
+
+    /// What `use` prints after picking the market.
+    pub fn describe(&self) -> String {
+        let mut lines = vec![format!("Using {}", self.label)];
+        if let Some(token) = &self.token {
+            let outcome = self.outcome.as_deref().unwrap_or("Token");
+            lines.push(format!("  {outcome}: {token}"));
+        }
+        if let Some(condition) = &self.condition {
+            lines.push(format!("  Condition: {condition}"));
+        }
+        if self.token.is_none() {
+            lines.push("  Add an outcome (use <market> yes) to fill in token IDs too".into());
+        }
+        lines.join("\n")
+    }
+
+    /// `args` with the session's IDs added for each token or condition
+    /// argument of `command` that isn't given as a flag, or `None` when
+    /// there is nothing to add. Positional arguments are appended, so this
+    /// is only worth trying once parsing has failed for a missing argument.
+    pub fn fill(&self, command: &clap::Command, args: &[String]) -> Option<Vec<String>> {
+        let mut filled = args.to_vec();
+        for arg in command.get_arguments() {
+            let id = arg.get_id().as_str();
+            let value = if TOKEN_ARGS.contains(&id) {
+                self.token.as_ref()
+            } else if CONDITION_ARGS.contains(&id) {
+                self.condition.as_ref()
+            } else {
+                None
+            };
+            let Some(value) = value else {
+                continue;
+            };
+            match arg.get_long() {
+                Some(long) => {
+                    let flag = format!("--{long}");
+                    let given = args
+                        .iter()
+                        .any(|a| *a == flag || a.starts_with(&format!("{flag}=")));
+                    if !given {
+                        filled.extend([flag, value.clone()]);
+                    }
+                }
+                None => filled.push(value.clone()),
+            }
+        }
+        (filled.len() > args.len()).then_some(filled)
+    }
+}
+
+/// The `use` directive: no argument shows the market in use, `--clear`
+/// forgets it, and anything else picks a new one.
+pub async fn cmd_use(
+    args: &[String],
+    session: &mut Option<Session>,
+    index: &SharedIndex,
+) -> Result<()> {
+    match args {
+        [] => match session {
+            Some(session) => println!("{}", session.describe()),
+            None => println!("No market in use. Pick one with: use <market-or-token> [outcome]"),
+        },
+        [flag] if flag == "--clear" => {
+            *session = None;
+            println!("Cleared the market in use");
+        }
+        [market] | [market, _] => {
+            let outcome = args.get(1).map(String::as_str);
+            let picked = Session::resolve(market, outcome, index).await?;
+            println!("{}", picked.describe());
+            *session = Some(picked);
+        }
+        _ => bail!("Usage: use <market-or-token> [outcome], or use --clear"),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory as _;
+
+    use super::*;
+
+    fn market() -> IndexedMarket {
+        IndexedMarket {
+            slug: "will-it-rain".to_string(),
+            question: String::new(),
+            condition_id: Some([0xab; 32].into()),
+            outcomes: vec![
+                ("Yes".to_string(), 1234u64.try_into().unwrap()),
+                ("No".to_string(), 5678u64.try_into().unwrap()),
+            ],
+        }
+    }
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    fn leaf(words: &[&str]) -> clap::Command {
+        let mut cli = crate::Cli::command();
+        cli.build();
+        super::super::find_command(&cli, words).clone()
+    }
+
+    #[test]
+    fn picks_the_outcome_token_of_an_indexed_market() {
+        let session = Session::from_market(&market(), "will-it-rain", Some("no")).unwrap();
+        assert_eq!(session.token.as_deref(), Some("5678"));
+        assert_eq!(session.outcome.as_deref(), Some("No"));
+        assert_eq!(session.prompt(), "polymarket [will-it-rain · No]> ");
+
+        let session = Session::from_market(&market(), "1234", None).unwrap();
+        assert_eq!(session.outcome.as_deref(), Some("Yes"));
+        assert!(Session::from_market(&market(), "will-it-rain", Some("maybe")).is_err());
+    }
+
+    #[test]
+    fn fills_missing_token_and_condition_arguments() {
+        let session = Session::from_market(&market(), "will-it-rain", Some("yes")).unwrap();
+        let book = leaf(&["clob", "book"]);
+        assert_eq!(
+            session.fill(&book, &args("polymarket clob book --depth 5")),
+            Some(args("polymarket clob book --depth 5 1234"))
+        );
+        let order = leaf(&["clob", "create-order"]);
+        assert_eq!(
+            session.fill(&order, &args("polymarket clob create-order --side buy")),
+            Some(args("polymarket clob create-order --side buy --token 1234"))
+        );
+        // A token given as a flag is left alone.
+        assert_eq!(
+            session.fill(&order, &args("polymarket clob create-order --token 9")),
+            None
+        );
+        let tokens = leaf(&["clob", "tokens"]);
+        assert_eq!(
+            session.fill(&tokens, &args("polymarket clob tokens")),
+            Some(args(&format!(
+                "polymarket clob tokens 0x{}",
+                "ab".repeat(32)
+            )))
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! A local index of active markets, so the shell can complete slugs,
+//! condition IDs, and token IDs without a network call per keystroke.
+
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, Duration, Utc};
+use polymarket_client_sdk::gamma::types::request::MarketsRequest;
+use polymarket_client_sdk::gamma::types::response::Market;
+use polymarket_client_sdk::types::{B256, U256};
+use serde::{Deserialize, Serialize};
+
+use crate::{config, endpoints};
+
+/// How many markets are indexed, by volume.
+const INDEX_SIZE: i32 = 500;
+
+/// How old the index may get before the shell refreshes it.
+const MAX_AGE: Duration = Duration::hours(1);
+
+/// The index, shared between the completer and the refresh task.
+pub type SharedIndex = Arc<RwLock<MarketIndex>>;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct MarketIndex {
+    pub updated_at: Option<DateTime<Utc>>,
+    pub markets: Vec<IndexedMarket>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexedMarket {
+    pub slug: String,
+    pub question: String,
+    pub condition_id: Option<B256>,
+    /// Outcome labels and their token IDs, in Gamma's order.
+    pub outcomes: Vec<(String, U256)>,
+}
+
+impl IndexedMarket {
+    fn from_market(market: Market) -> Option<Self> {
+        let labels = market.outcomes.unwrap_or_default();
+        let tokens = market.clob_token_ids.unwrap_or_default();
+        Some(Self {
+            slug: market.slug?,
+            question: market.question.unwrap_or_default(),
+            condition_id: market.condition_id,
+            outcomes: labels.into_iter().zip(tokens).collect(),
+        })
+    }
+}
+
+impl MarketIndex {
+    /// The index saved by an earlier session, or an empty one.
+    pub fn load() -> Self {
+        config::market_index_path()
+            .ok()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// Best effort: a failed write only means the next shell refreshes again.
+    fn save(&self) {
+        let Ok(path) = config::market_index_path() else {
+            return;
+        };
+        if let (Some(dir), Ok(json)) = (path.parent(), serde_json::to_string(self)) {
+            let _ = std::fs::create_dir_all(dir);
+            let _ = crate::output::write_atomic(&path, &json);
+        }
+    }
+
+    /// The market with this slug, condition ID, or token ID.
+    pub fn find(&self, id: &str) -> Option<&IndexedMarket> {
+        self.markets.iter().find(|m| {
+            m.slug == id
+                || m.condition_id
+                    .is_some_and(|c| c.to_string().eq_ignore_ascii_case(id))
+                || m.outcomes.iter().any(|(_, token)| token.to_string() == id)
+        })
+    }
+
+    pub fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        self.updated_at.is_none_or(|at| now - at > MAX_AGE)
+    }
+
+    /// The most traded open markets, fresh from Gamma.
+    async fn fetch() -> anyhow::Result<Self> {
+        let request = MarketsRequest::builder()
+            .limit(INDEX_SIZE)
+            .closed(false)
+            .order("volume_num".into())
+            .ascending(false)
+            .build();
+        let markets = endpoints::gamma().markets(&request).await?;
+        Ok(Self {
+            updated_at: Some(Utc::now()),
+            markets: markets
+                .into_iter()
+                .filter_map(IndexedMarket::from_market)
+                .collect(),
+        })
+    }
+}
+
+/// Loads the saved index and, if it is stale, refreshes it in the
+/// background. Failures are silent so they don't land in the middle of
+/// whatever the user is typing; completion just uses the older index.
+pub fn load_and_refresh() -> SharedIndex {
+    let index = MarketIndex::load();
+    let stale = index.is_stale(Utc::now());
+    let shared = Arc::new(RwLock::new(index));
+    if stale {
+        let shared = Arc::clone(&shared);
+        tokio::spawn(async move {
+            if let Ok(fresh) = MarketIndex::fetch().await {
+                fresh.save();
+                if let Ok(mut index) = shared.write() {
+                    *index = fresh;
+                }
+            }
+        });
+    }
+    shared
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_goes_stale_after_an_hour() {
+        let now = Utc::now();
+        assert!(MarketIndex::default().is_stale(now));
+        let index = MarketIndex {
+            updated_at: Some(now - Duration::minutes(59)),
+            markets: Vec::new(),
+        };
+        assert!(!index.is_stale(now));
+        assert!(index.is_stale(now + Duration::minutes(2)));
+    }
+}
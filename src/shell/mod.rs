@@ -0,0 +1,253 @@
+mod aliases;
+mod complete;
+mod context;
+mod index;
+mod panes;
+
+use clap::error::ErrorKind;
+use clap::{CommandFactory as _, Parser};
+use rustyline::config::{CompletionType, Config};
+use rustyline::history::DefaultHistory;
+
+/// Lines of history kept across sessions.
+const HISTORY_SIZE: usize = 1000;
+
+pub async fn run_shell() {
+    println!();
+    println!("  Polymarket CLI · Interactive Shell");
+    println!("  Type 'help' for commands, 'exit' to quit. Ctrl+R searches history.");
+    println!();
+
+    let config = Config::builder()
+        .completion_type(CompletionType::List)
+        .max_history_size(HISTORY_SIZE)
+        .map(|builder| builder.build())
+        .unwrap_or_default();
+    let mut rl = match rustyline::Editor::<_, DefaultHistory>::with_config(config) {
+        Ok(rl) => rl,
+        Err(e) => {
+            eprintln!("Failed to initialize shell: {e}");
+            return;
+        }
+    };
+    let index = index::load_and_refresh();
+    rl.set_helper(Some(complete::ShellHelper::new(index.clone())));
+    let mut shell = Shell::new(index);
+
+    let history = crate::config::shell_history_path().ok();
+    if let Some(path) = &history {
+        let _ = rl.load_history(path);
+    }
+
+    loop {
+        let prompt = shell
+            .session
+            .as_ref()
+            .map_or_else(|| "polymarket> ".to_string(), context::Session::prompt);
+        match rl.readline(&prompt) {
+            Ok(line) => {
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+
+                // Saved as it's entered, so history survives a crash.
+                if rl.add_history_entry(line).unwrap_or(false)
+                    && let Some(path) = &history
+                {
+                    if let Some(dir) = path.parent() {
+                        let _ = std::fs::create_dir_all(dir);
+                    }
+                    let _ = rl.append_history(path);
+                }
+
+                if !shell.run_line(line).await {
+                    break;
+                }
+            }
+            Err(rustyline::error::ReadlineError::Interrupted) => continue,
+            Err(rustyline::error::ReadlineError::Eof) => break,
+            Err(e) => {
+                eprintln!("Error: {e}");
+                break;
+            }
+        }
+    }
+
+    println!("Goodbye!");
+}
+
+/// What a shell session keeps between lines.
+struct Shell {
+    /// The CLI definition, to find the arguments a command takes.
+    cli: clap::Command,
+    index: index::SharedIndex,
+    /// The market picked with `use`.
+    session: Option<context::Session>,
+    /// Views added with `watch`, for `split`.
+    panes: Vec<crate::tui::split::Pane>,
+}
+
+impl Shell {
+    fn new(index: index::SharedIndex) -> Self {
+        let mut cli = crate::Cli::command();
+        cli.build();
+        Self {
+            cli,
+            index,
+            session: None,
+            panes: Vec::new(),
+        }
+    }
+
+    /// Runs a line from the prompt; `false` when it asks to leave the shell.
+    async fn run_line(&mut self, line: &str) -> bool {
+        // A definition keeps its `;`s: they separate the alias's commands.
+        if let Some(definition) = directive(line, "alias") {
+            if let Err(e) = cmd_alias(definition) {
+                eprintln!("Error: {e}");
+            }
+            return true;
+        }
+        for command in aliases::expand(line, &crate::config::aliases()) {
+            if command == "exit" || command == "quit" {
+                return false;
+            }
+            let directive_result = if let Some(definition) = directive(&command, "alias") {
+                cmd_alias(definition)
+            } else if let Some(name) = directive(&command, "unalias") {
+                cmd_unalias(name)
+            } else if let Some(market) = directive(&command, "use") {
+                context::cmd_use(&split_args(market), &mut self.session, &self.index).await
+            } else if let Some(view) = directive(&command, "watch") {
+                let args = split_args(view);
+                panes::cmd_watch(&args, &mut self.panes, self.session.as_ref()).await
+            } else if let Some(flags) = directive(&command, "split") {
+                panes::cmd_split(&split_args(flags), &self.panes).await
+            } else {
+                self.run_command(&command).await;
+                continue;
+            };
+            if let Err(e) = directive_result {
+                eprintln!("Error: {e}");
+            }
+        }
+        true
+    }
+
+    async fn run_command(&self, command: &str) {
+        let args = split_args(command);
+        let mut full_args = vec!["polymarket".to_string()];
+        full_args.extend(args);
+
+        if let Some(cmd) = full_args.get(1) {
+            if cmd == "shell" {
+                println!("Already in shell mode.");
+                return;
+            }
+            if cmd == "setup" {
+                println!("Run 'polymarket setup' outside the shell.");
+                return;
+            }
+        }
+
+        let parsed = match crate::Cli::try_parse_from(&full_args) {
+            Err(e) if e.kind() == ErrorKind::MissingRequiredArgument => {
+                // Try again with the IDs of the market in use, keeping the
+                // original error if that doesn't help.
+                let words: Vec<&str> = full_args[1..].iter().map(String::as_str).collect();
+                let command = find_command(&self.cli, &words);
+                self.session
+                    .as_ref()
+                    .and_then(|session| session.fill(command, &full_args))
+                    .and_then(|filled| Some((crate::Cli::try_parse_from(&filled).ok()?, filled)))
+                    .ok_or(e)
+            }
+            parsed => parsed.map(|cli| (cli, full_args)),
+        };
+        match parsed {
+            Ok((cli, full_args)) => {
+                let output = cli.output;
+                if let Err(e) = crate::run(cli, &full_args).await {
+                    crate::output::print_error(&e, output);
+                }
+            }
+            Err(e) => {
+                let _ = e.print();
+            }
+        }
+    }
+}
+
+/// The subcommand `words` name, going as deep as they match.
+fn find_command<'a>(cli: &'a clap::Command, words: &[&str]) -> &'a clap::Command {
+    let mut command = cli;
+    for word in words {
+        if let Some(sub) = command.find_subcommand(word) {
+            command = sub;
+        }
+    }
+    command
+}
+
+/// The rest of `line` if it starts with the word `name`.
+fn directive<'a>(line: &'a str, name: &str) -> Option<&'a str> {
+    let rest = line.strip_prefix(name)?;
+    (rest.is_empty() || rest.starts_with(char::is_whitespace)).then(|| rest.trim())
+}
+
+/// `alias` lists the aliases; `alias NAME = COMMAND` saves one.
+fn cmd_alias(definition: &str) -> anyhow::Result<()> {
+    if definition.is_empty() {
+        let aliases = crate::config::aliases();
+        if aliases.is_empty() {
+            println!("No aliases. Add one with: alias b = clob book");
+        }
+        for (name, command) in aliases {
+            println!("{name} = {command}");
+        }
+        return Ok(());
+    }
+    let (name, command) = aliases::parse_definition(definition)?;
+    crate::config::update_config(|config, _| {
+        config.aliases.insert(name.clone(), command.clone());
+        Ok(())
+    })?;
+    println!("Saved alias {name} = {command}");
+    Ok(())
+}
+
+fn cmd_unalias(name: &str) -> anyhow::Result<()> {
+    anyhow::ensure!(!name.is_empty(), "Usage: unalias NAME");
+    crate::config::update_config(|config, _| {
+        config
+            .aliases
+            .remove(name)
+            .map(drop)
+            .ok_or_else(|| anyhow::anyhow!("No alias named '{name}'"))
+    })?;
+    println!("Removed alias {name}");
+    Ok(())
+}
+
+fn split_args(input: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    for c in input.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ' ' if !in_quotes => {
+                if !current.is_empty() {
+                    args.push(std::mem::take(&mut current));
+                }
+            }
+            _ => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        args.push(current);
+    }
+    args
+}
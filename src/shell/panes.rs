@@ -0,0 +1,158 @@
+//! `watch` and `split`: live views added one at a time, then tiled in one
+//! screen with the TUI.
+
+use std::time::Duration;
+
+use anyhow::{Context as _, Result, bail};
+
+use super::context::Session;
+use crate::commands::clob::parse_token_id;
+use crate::commands::{parse_condition_id, resolve_condition_arg, resolve_token_arg};
+use crate::tui::split::{Pane, SplitConfig, View};
+
+/// Seconds between refreshes when `split` isn't given `--interval`.
+const DEFAULT_INTERVAL: u64 = 2;
+
+const USAGE: &str = "Usage: watch book|orders|trades [market-or-token] [outcome], or watch --clear";
+
+/// `watch VIEW [market-or-token] [outcome]`: a pane showing a book, the
+/// wallet's open orders, or a market's trades. The market defaults to the
+/// one picked with `use`.
+pub async fn parse_pane(args: &[String], session: Option<&Session>) -> Result<Pane> {
+    let (view, market, outcome) = match args {
+        [view] => (view, None, None),
+        [view, market] => (view, Some(market), None),
+        [view, market, outcome] => (view, Some(market), Some(outcome.as_str())),
+        _ => bail!(USAGE),
+    };
+    let title = |label: &str| match (market, outcome) {
+        (Some(market), Some(outcome)) => format!("{label} · {market} · {outcome}"),
+        (Some(market), None) => format!("{label} · {market}"),
+        _ => session.map_or_else(|| label.to_string(), |s| format!("{label} · {}", s.title())),
+    };
+    match view.as_str() {
+        "book" => {
+            let token = match (market, session.and_then(Session::token)) {
+                (Some(market), _) => resolve_token_arg(market, outcome).await?,
+                (None, Some(token)) => token.to_string(),
+                (None, None) => {
+                    bail!("watch book needs a token, or an outcome picked with `use <market> yes`")
+                }
+            };
+            Ok(Pane {
+                view: View::Book(parse_token_id(&token)?),
+                title: title("Book"),
+            })
+        }
+        "trades" => {
+            let condition = match (market, session.and_then(Session::condition)) {
+                (Some(market), _) => resolve_condition_arg(market).await?,
+                (None, Some(condition)) => condition.to_string(),
+                (None, None) => bail!("watch trades needs a market, or one picked with `use`"),
+            };
+            Ok(Pane {
+                view: View::Trades(parse_condition_id(&condition)?),
+                title: title("Trades"),
+            })
+        }
+        "orders" => Ok(Pane {
+            view: View::Orders,
+            title: "Open Orders".into(),
+        }),
+        _ => bail!(USAGE),
+    }
+}
+
+/// The `watch` directive: no argument lists the panes, `--clear` removes
+/// them, and anything else adds one.
+pub async fn cmd_watch(
+    args: &[String],
+    panes: &mut Vec<Pane>,
+    session: Option<&Session>,
+) -> Result<()> {
+    match args {
+        [] if panes.is_empty() => println!("No panes. Add one with: watch book|orders|trades"),
+        [] => {
+            for (i, pane) in panes.iter().enumerate() {
+                println!("{}. {}", i + 1, pane.title);
+            }
+        }
+        [flag] if flag == "--clear" => {
+            panes.clear();
+            println!("Removed every pane");
+        }
+        _ => {
+            let pane = parse_pane(args, session).await?;
+            println!(
+                "Added pane {}: {}. Run `split` to show them.",
+                panes.len() + 1,
+                pane.title
+            );
+            panes.push(pane);
+        }
+    }
+    Ok(())
+}
+
+/// The `split [--interval SECONDS]` directive: tiles the panes until `q`.
+pub async fn cmd_split(args: &[String], panes: &[Pane]) -> Result<()> {
+    let interval = match args {
+        [] => DEFAULT_INTERVAL,
+        [flag, seconds] if flag == "--interval" => seconds
+            .parse()
+            .with_context(|| format!("Invalid interval: {seconds}"))?,
+        _ => bail!("Usage: split [--interval SECONDS]"),
+    };
+    if panes.is_empty() {
+        bail!("Nothing to show. Add panes first, e.g. watch book; watch orders; watch trades");
+    }
+    let client = if panes.iter().any(|p| p.view == View::Orders) {
+        crate::auth::authenticated_clob_client(None, None).await
+    } else {
+        Err(anyhow::anyhow!("No orders pane"))
+    };
+    crate::tui::split::run(
+        SplitConfig {
+            panes: panes.to_vec(),
+            interval: Duration::from_secs(interval.max(1)),
+        },
+        client,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use polymarket_client_sdk::types::U256;
+
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[tokio::test]
+    async fn panes_take_ids_without_a_network_call() {
+        let pane = parse_pane(&args("book 1234"), None).await.unwrap();
+        assert_eq!(pane.view, View::Book(U256::from(1234)));
+        assert_eq!(pane.title, "Book · 1234");
+
+        let condition = format!("0x{}", "ab".repeat(32));
+        let pane = parse_pane(&args(&format!("trades {condition}")), None)
+            .await
+            .unwrap();
+        assert_eq!(pane.view, View::Trades([0xab; 32].into()));
+
+        assert_eq!(
+            parse_pane(&args("orders"), None).await.unwrap().view,
+            View::Orders
+        );
+    }
+
+    #[tokio::test]
+    async fn panes_need_a_market_without_one_in_use() {
+        assert!(parse_pane(&args("book"), None).await.is_err());
+        assert!(parse_pane(&args("trades"), None).await.is_err());
+        assert!(parse_pane(&args("chart 1234"), None).await.is_err());
+    }
+}
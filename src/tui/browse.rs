@@ -0,0 +1,480 @@
+use std::io::Write as _;
+
+use anyhow::Result;
+use base64::Engine as _;
+use crossterm::event::{Event, EventStream, KeyCode, KeyEvent, KeyEventKind};
+use futures::StreamExt as _;
+use polymarket_client_sdk::clob;
+use polymarket_client_sdk::clob::types::request::OrderBookSummaryRequest;
+use polymarket_client_sdk::clob::types::response::OrderBookSummaryResponse;
+use polymarket_client_sdk::gamma::types::response::Market;
+use polymarket_client_sdk::types::{Decimal, U256};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph, Row, Table, TableState};
+use ratatui::{DefaultTerminal, Frame};
+
+use super::{book_table, format_price, is_quit_key};
+use crate::endpoints;
+use crate::output::{format_decimal, truncate};
+
+const BOOK_DEPTH: usize = 15;
+
+/// What the user chose when leaving the browser.
+pub enum BrowseExit {
+    Quit,
+    Ladder(U256),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SortKey {
+    Volume,
+    Liquidity,
+    Volume24h,
+}
+
+impl SortKey {
+    fn next(self) -> Self {
+        match self {
+            Self::Volume => Self::Liquidity,
+            Self::Liquidity => Self::Volume24h,
+            Self::Volume24h => Self::Volume,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Volume => "volume",
+            Self::Liquidity => "liquidity",
+            Self::Volume24h => "24h volume",
+        }
+    }
+
+    fn value(self, m: &Market) -> Decimal {
+        match self {
+            Self::Volume => m.volume_num,
+            Self::Liquidity => m.liquidity_num,
+            Self::Volume24h => m.volume_24hr,
+        }
+        .unwrap_or_default()
+    }
+}
+
+/// Indices of markets matching `query` (case-insensitive, question or slug),
+/// ordered by `sort` descending.
+fn filter_and_sort(markets: &[Market], query: &str, sort: SortKey) -> Vec<usize> {
+    let query = query.to_lowercase();
+    let mut indices: Vec<usize> = markets
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| {
+            query.is_empty()
+                || [m.question.as_deref(), m.slug.as_deref()]
+                    .into_iter()
+                    .flatten()
+                    .any(|text| text.to_lowercase().contains(&query))
+        })
+        .map(|(i, _)| i)
+        .collect();
+    indices.sort_by_key(|&i| std::cmp::Reverse(sort.value(&markets[i])));
+    indices
+}
+
+/// Copies text to the system clipboard via the OSC 52 terminal escape.
+fn copy_to_clipboard(text: &str) -> std::io::Result<()> {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    let mut stdout = std::io::stdout();
+    write!(stdout, "\x1b]52;c;{encoded}\x07")?;
+    stdout.flush()
+}
+
+struct MarketView {
+    market: usize,
+    outcome: TableState,
+    book: Option<Result<OrderBookSummaryResponse, String>>,
+}
+
+struct Browser {
+    markets: Vec<Market>,
+    visible: Vec<usize>,
+    list: TableState,
+    query: String,
+    searching: bool,
+    sort: SortKey,
+    view: Option<MarketView>,
+    status: String,
+}
+
+impl Browser {
+    fn new(markets: Vec<Market>) -> Self {
+        let mut browser = Self {
+            markets,
+            visible: Vec::new(),
+            list: TableState::default(),
+            query: String::new(),
+            searching: false,
+            sort: SortKey::Volume,
+            view: None,
+            status: String::new(),
+        };
+        browser.refilter();
+        browser
+    }
+
+    fn refilter(&mut self) {
+        self.visible = filter_and_sort(&self.markets, &self.query, self.sort);
+        self.list.select((!self.visible.is_empty()).then_some(0));
+    }
+
+    fn selected_market(&self) -> Option<usize> {
+        self.list
+            .selected()
+            .and_then(|i| self.visible.get(i))
+            .copied()
+    }
+
+    fn token_ids(&self, market: usize) -> Vec<U256> {
+        self.markets[market]
+            .clob_token_ids
+            .clone()
+            .unwrap_or_default()
+    }
+
+    fn selected_token(&self) -> Option<U256> {
+        let view = self.view.as_ref()?;
+        let tokens = self.token_ids(view.market);
+        view.outcome.selected().and_then(|i| tokens.get(i)).copied()
+    }
+}
+
+pub async fn run(markets: Vec<Market>) -> Result<BrowseExit> {
+    let mut browser = Browser::new(markets);
+    let client = endpoints::clob();
+
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &mut browser, &client).await;
+    ratatui::restore();
+    result
+}
+
+async fn event_loop(
+    terminal: &mut DefaultTerminal,
+    browser: &mut Browser,
+    client: &clob::Client,
+) -> Result<BrowseExit> {
+    let mut events = EventStream::new();
+    loop {
+        terminal.draw(|frame| render(frame, browser))?;
+        match events.next().await {
+            Some(Ok(Event::Key(key))) if key.kind == KeyEventKind::Press => {
+                let exit = if browser.view.is_some() {
+                    handle_market_key(browser, key, client).await
+                } else {
+                    handle_list_key(browser, key, client).await
+                };
+                if let Some(exit) = exit {
+                    return Ok(exit);
+                }
+            }
+            Some(Ok(_)) => {}
+            Some(Err(e)) => return Err(e.into()),
+            None => return Ok(BrowseExit::Quit),
+        }
+    }
+}
+
+async fn handle_list_key(
+    browser: &mut Browser,
+    key: KeyEvent,
+    client: &clob::Client,
+) -> Option<BrowseExit> {
+    if browser.searching {
+        match key.code {
+            KeyCode::Enter => browser.searching = false,
+            KeyCode::Esc => {
+                browser.searching = false;
+                browser.query.clear();
+                browser.refilter();
+            }
+            KeyCode::Backspace => {
+                browser.query.pop();
+                browser.refilter();
+            }
+            KeyCode::Char(c) => {
+                browser.query.push(c);
+                browser.refilter();
+            }
+            _ => {}
+        }
+        return None;
+    }
+
+    if is_quit_key(&key) {
+        return Some(BrowseExit::Quit);
+    }
+    match key.code {
+        KeyCode::Down | KeyCode::Char('j') => browser.list.select_next(),
+        KeyCode::Up | KeyCode::Char('k') => browser.list.select_previous(),
+        KeyCode::Char('/') => browser.searching = true,
+        KeyCode::Char('o') => {
+            browser.sort = browser.sort.next();
+            browser.refilter();
+        }
+        KeyCode::Enter => {
+            if let Some(market) = browser.selected_market() {
+                browser.view = Some(MarketView {
+                    market,
+                    outcome: TableState::default().with_selected(Some(0)),
+                    book: None,
+                });
+                load_book(browser, client).await;
+            }
+        }
+        _ => {}
+    }
+    None
+}
+
+async fn handle_market_key(
+    browser: &mut Browser,
+    key: KeyEvent,
+    client: &clob::Client,
+) -> Option<BrowseExit> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Backspace | KeyCode::Left => {
+            browser.view = None;
+            browser.status.clear();
+        }
+        KeyCode::Char('q') => return Some(BrowseExit::Quit),
+        KeyCode::Down | KeyCode::Char('j') => {
+            if let Some(view) = browser.view.as_mut() {
+                view.outcome.select_next();
+            }
+            load_book(browser, client).await;
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            if let Some(view) = browser.view.as_mut() {
+                view.outcome.select_previous();
+            }
+            load_book(browser, client).await;
+        }
+        KeyCode::Char('r') => load_book(browser, client).await,
+        KeyCode::Char('y') => {
+            if let Some(token) = browser.selected_token() {
+                browser.status = match copy_to_clipboard(&token.to_string()) {
+                    Ok(()) => format!("Copied token ID {token}"),
+                    Err(e) => format!("Copy failed: {e}"),
+                };
+            }
+        }
+        KeyCode::Char('l') | KeyCode::Enter => {
+            if let Some(token) = browser.selected_token() {
+                return Some(BrowseExit::Ladder(token));
+            }
+        }
+        _ => {}
+    }
+    None
+}
+
+async fn load_book(browser: &mut Browser, client: &clob::Client) {
+    let Some(token) = browser.selected_token() else {
+        return;
+    };
+    let request = OrderBookSummaryRequest::builder().token_id(token).build();
+    let book = client.order_book(&request).await.map_err(|e| e.to_string());
+    if let Some(view) = browser.view.as_mut() {
+        view.book = Some(book);
+    }
+}
+
+fn render(frame: &mut Frame, browser: &mut Browser) {
+    let [body, footer] =
+        Layout::vertical([Constraint::Min(5), Constraint::Length(1)]).areas(frame.area());
+
+    let footer_line = if browser.searching {
+        Line::from(format!("/{}▏", browser.query))
+    } else if !browser.status.is_empty() {
+        Line::from(browser.status.as_str())
+    } else if browser.view.is_some() {
+        Line::from("↑/↓ outcome  y copy token ID  l/enter ladder  r refresh  esc back  q quit")
+            .style(Style::default().fg(Color::DarkGray))
+    } else {
+        Line::from("↑/↓ move  enter open  / search  o sort  q quit")
+            .style(Style::default().fg(Color::DarkGray))
+    };
+    frame.render_widget(Paragraph::new(footer_line), footer);
+
+    if browser.view.is_some() {
+        render_market(frame, body, browser);
+    } else {
+        render_list(frame, body, browser);
+    }
+}
+
+fn render_list(frame: &mut Frame, area: Rect, browser: &mut Browser) {
+    let rows: Vec<Row> = browser
+        .visible
+        .iter()
+        .map(|&i| {
+            let m = &browser.markets[i];
+            let price_yes = m
+                .outcome_prices
+                .as_ref()
+                .and_then(|p| p.first())
+                .map_or_else(
+                    || "—".into(),
+                    |p| format!("{:.1}¢", p * Decimal::ONE_HUNDRED),
+                );
+            let money = |v: Option<Decimal>| v.map_or_else(|| "—".into(), format_decimal);
+            Row::new(vec![
+                m.question.clone().unwrap_or_default(),
+                price_yes,
+                money(m.volume_num),
+                money(m.liquidity_num),
+                money(m.volume_24hr),
+            ])
+        })
+        .collect();
+
+    let mut title = format!(
+        " Markets ({}/{}) by {} ",
+        browser.visible.len(),
+        browser.markets.len(),
+        browser.sort.label()
+    );
+    if !browser.query.is_empty() {
+        title.push_str(&format!("matching \"{}\" ", browser.query));
+    }
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(30),
+            Constraint::Length(8),
+            Constraint::Length(9),
+            Constraint::Length(9),
+            Constraint::Length(9),
+        ],
+    )
+    .header(
+        Row::new(["Question", "Yes", "Volume", "Liquidity", "24h Vol"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+    .block(Block::bordered().title(title));
+    frame.render_stateful_widget(table, area, &mut browser.list);
+}
+
+fn render_market(frame: &mut Frame, area: Rect, browser: &mut Browser) {
+    let Some(view) = browser.view.as_mut() else {
+        return;
+    };
+    let market = &browser.markets[view.market];
+    let [info, main] = Layout::vertical([Constraint::Length(5), Constraint::Min(5)]).areas(area);
+    let [outcomes_area, book_area] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(main);
+
+    let details = vec![
+        Line::from(market.question.clone().unwrap_or_default())
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+        Line::from(format!("Slug: {}", market.slug.as_deref().unwrap_or("—"))),
+        Line::from(format!(
+            "Condition: {}",
+            market
+                .condition_id
+                .map_or_else(|| "—".into(), |c| c.to_string())
+        )),
+    ];
+    frame.render_widget(Paragraph::new(details).block(Block::bordered()), info);
+
+    let names = market.outcomes.clone().unwrap_or_default();
+    let prices = market.outcome_prices.clone().unwrap_or_default();
+    let tokens = market.clob_token_ids.clone().unwrap_or_default();
+    let rows: Vec<Row> = tokens
+        .iter()
+        .enumerate()
+        .map(|(i, token)| {
+            Row::new(vec![
+                names.get(i).cloned().unwrap_or_else(|| format!("#{i}")),
+                format_price(prices.get(i).copied()),
+                truncate(&token.to_string(), 24),
+            ])
+        })
+        .collect();
+    let outcomes = Table::new(
+        rows,
+        [
+            Constraint::Length(12),
+            Constraint::Length(8),
+            Constraint::Min(10),
+        ],
+    )
+    .header(
+        Row::new(["Outcome", "Price", "Token ID"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+    .block(Block::bordered().title(" Outcomes "));
+    frame.render_stateful_widget(outcomes, outcomes_area, &mut view.outcome);
+
+    let block = Block::bordered().title(" Order Book ");
+    match &view.book {
+        Some(Ok(book)) => frame.render_widget(book_table(book, BOOK_DEPTH, block), book_area),
+        Some(Err(e)) => frame.render_widget(Paragraph::new(e.as_str()).block(block), book_area),
+        None => frame.render_widget(Paragraph::new("No order book.").block(block), book_area),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn market(question: &str, slug: &str, volume: u32, liquidity: u32) -> Market {
+        serde_json::from_value(serde_json::json!({
+            "id": question,
+            "question": question,
+            "slug": slug,
+            "volumeNum": volume,
+            "liquidityNum": liquidity,
+        }))
+        .unwrap()
+    }
+
+    fn sample() -> Vec<Market> {
+        vec![
+            market("Will BTC hit 100k?", "btc-100k", 50, 900),
+            market("Will ETH flip BTC?", "eth-flip", 300, 10),
+            market("Election winner", "election", 100, 500),
+        ]
+    }
+
+    #[test]
+    fn filter_and_sort_by_volume_descending() {
+        assert_eq!(filter_and_sort(&sample(), "", SortKey::Volume), [1, 2, 0]);
+    }
+
+    #[test]
+    fn filter_and_sort_by_liquidity_descending() {
+        assert_eq!(
+            filter_and_sort(&sample(), "", SortKey::Liquidity),
+            [0, 2, 1]
+        );
+    }
+
+    #[test]
+    fn filter_matches_question_case_insensitively() {
+        assert_eq!(filter_and_sort(&sample(), "btc", SortKey::Volume), [1, 0]);
+    }
+
+    #[test]
+    fn filter_matches_slug() {
+        assert_eq!(filter_and_sort(&sample(), "election", SortKey::Volume), [2]);
+    }
+
+    #[test]
+    fn sort_key_cycles() {
+        assert_eq!(SortKey::Volume.next(), SortKey::Liquidity);
+        assert_eq!(SortKey::Volume24h.next(), SortKey::Volume);
+    }
+}
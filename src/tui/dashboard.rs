@@ -0,0 +1,402 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use crossterm::event::{Event, EventStream, KeyCode, KeyEventKind};
+use futures::StreamExt as _;
+use polymarket_client_sdk::auth::Normal;
+use polymarket_client_sdk::auth::state::Authenticated;
+use polymarket_client_sdk::clob::types::AssetType;
+use polymarket_client_sdk::clob::types::request::{
+    BalanceAllowanceRequest, OrderBookSummaryRequest, OrdersRequest,
+};
+use polymarket_client_sdk::clob::types::response::{OpenOrderResponse, OrderBookSummaryResponse};
+use polymarket_client_sdk::data::types::request::PositionsRequest;
+use polymarket_client_sdk::data::types::response::Position;
+use polymarket_client_sdk::types::{Address, Decimal, U256};
+use polymarket_client_sdk::{clob, data};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph, Row, Table};
+use ratatui::{DefaultTerminal, Frame};
+use tokio::sync::{Notify, mpsc};
+
+use super::{ask_style, bid_style, book_table, is_quit_key};
+use crate::endpoints;
+use crate::output::truncate;
+
+const BOOK_DEPTH: usize = 10;
+const USDC_DECIMALS: u32 = 6;
+
+pub struct DashboardConfig {
+    pub watchlist: Vec<U256>,
+    pub interval: Duration,
+}
+
+/// Everything fetched in one background refresh.
+struct Snapshot {
+    orders: Vec<OpenOrderResponse>,
+    positions: Vec<Position>,
+    collateral: Option<Decimal>,
+    books: Vec<OrderBookSummaryResponse>,
+    errors: Vec<String>,
+    updated_at: DateTime<Local>,
+}
+
+struct Dashboard {
+    funder: Address,
+    watchlist: Vec<U256>,
+    snapshot: Option<Snapshot>,
+    selected_book: usize,
+}
+
+impl Dashboard {
+    fn next_book(&mut self) {
+        if !self.watchlist.is_empty() {
+            self.selected_book = (self.selected_book + 1) % self.watchlist.len();
+        }
+    }
+
+    fn prev_book(&mut self) {
+        if !self.watchlist.is_empty() {
+            self.selected_book =
+                (self.selected_book + self.watchlist.len() - 1) % self.watchlist.len();
+        }
+    }
+}
+
+pub async fn run(
+    client: clob::Client<Authenticated<Normal>>,
+    funder: Address,
+    config: DashboardConfig,
+) -> Result<()> {
+    let (tx, mut rx) = mpsc::channel(1);
+    let refresh = Arc::new(Notify::new());
+    let refresher = tokio::spawn(refresh_loop(
+        client,
+        funder,
+        config.watchlist.clone(),
+        config.interval,
+        Arc::clone(&refresh),
+        tx,
+    ));
+
+    let mut app = Dashboard {
+        funder,
+        watchlist: config.watchlist,
+        snapshot: None,
+        selected_book: 0,
+    };
+
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &mut app, &mut rx, &refresh).await;
+    ratatui::restore();
+    refresher.abort();
+    result
+}
+
+async fn event_loop(
+    terminal: &mut DefaultTerminal,
+    app: &mut Dashboard,
+    rx: &mut mpsc::Receiver<Snapshot>,
+    refresh: &Notify,
+) -> Result<()> {
+    let mut events = EventStream::new();
+    loop {
+        terminal.draw(|frame| render(frame, app))?;
+        tokio::select! {
+            Some(snapshot) = rx.recv() => app.snapshot = Some(snapshot),
+            event = events.next() => match event {
+                Some(Ok(Event::Key(key))) if key.kind == KeyEventKind::Press => {
+                    if is_quit_key(&key) {
+                        break;
+                    }
+                    match key.code {
+                        KeyCode::Char('r') => refresh.notify_one(),
+                        KeyCode::Right | KeyCode::Tab => app.next_book(),
+                        KeyCode::Left | KeyCode::BackTab => app.prev_book(),
+                        _ => {}
+                    }
+                }
+                Some(Ok(_)) => {}
+                Some(Err(e)) => return Err(e.into()),
+                None => break,
+            },
+        }
+    }
+    Ok(())
+}
+
+async fn refresh_loop(
+    client: clob::Client<Authenticated<Normal>>,
+    funder: Address,
+    watchlist: Vec<U256>,
+    interval: Duration,
+    refresh: Arc<Notify>,
+    tx: mpsc::Sender<Snapshot>,
+) {
+    let data_client = endpoints::data();
+    loop {
+        let snapshot = fetch_snapshot(&client, &data_client, funder, &watchlist).await;
+        if tx.send(snapshot).await.is_err() {
+            break;
+        }
+        tokio::select! {
+            () = tokio::time::sleep(interval) => {}
+            () = refresh.notified() => {}
+        }
+    }
+}
+
+async fn fetch_snapshot(
+    client: &clob::Client<Authenticated<Normal>>,
+    data_client: &data::Client,
+    funder: Address,
+    watchlist: &[U256],
+) -> Snapshot {
+    let orders_request = OrdersRequest::builder().build();
+    let balance_request = BalanceAllowanceRequest::builder()
+        .asset_type(AssetType::Collateral)
+        .build();
+    let positions_request = PositionsRequest::builder().user(funder).build();
+    let book_requests: Vec<_> = watchlist
+        .iter()
+        .map(|id| OrderBookSummaryRequest::builder().token_id(*id).build())
+        .collect();
+
+    let (orders, balance, positions, books) = tokio::join!(
+        client.orders(&orders_request, None),
+        client.balance_allowance(balance_request),
+        data_client.positions(&positions_request),
+        async {
+            if book_requests.is_empty() {
+                Ok(Vec::new())
+            } else {
+                client.order_books(&book_requests).await
+            }
+        },
+    );
+
+    let mut errors = Vec::new();
+    let mut take = |label: &str, e: &dyn std::fmt::Display| errors.push(format!("{label}: {e}"));
+    let orders = orders.map(|p| p.data).unwrap_or_else(|e| {
+        take("orders", &e);
+        Vec::new()
+    });
+    let collateral = balance
+        .map(|b| b.balance / Decimal::from(10u64.pow(USDC_DECIMALS)))
+        .map_err(|e| take("balance", &e))
+        .ok();
+    let positions = positions.unwrap_or_else(|e| {
+        take("positions", &e);
+        Vec::new()
+    });
+    let books = books.unwrap_or_else(|e| {
+        take("books", &e);
+        Vec::new()
+    });
+
+    Snapshot {
+        orders,
+        positions,
+        collateral,
+        books,
+        errors,
+        updated_at: Local::now(),
+    }
+}
+
+fn render(frame: &mut Frame, app: &Dashboard) {
+    let [header, body, book, status] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Percentage(50),
+        Constraint::Min(6),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let [orders, positions] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(body);
+
+    render_header(frame, header, app);
+    match &app.snapshot {
+        Some(snapshot) => {
+            render_orders(frame, orders, &snapshot.orders);
+            render_positions(frame, positions, &snapshot.positions);
+            render_book(frame, book, app, snapshot);
+        }
+        None => {
+            frame.render_widget(Paragraph::new("Loading…").block(Block::bordered()), body);
+        }
+    }
+    render_status(frame, status, app);
+}
+
+fn render_header(frame: &mut Frame, area: Rect, app: &Dashboard) {
+    let balance = app
+        .snapshot
+        .as_ref()
+        .and_then(|s| s.collateral)
+        .map_or_else(|| "—".into(), |b| format!("${b:.2}"));
+    let updated = app.snapshot.as_ref().map_or_else(
+        || "—".into(),
+        |s| s.updated_at.format("%H:%M:%S").to_string(),
+    );
+    let line = Line::from(vec![
+        Span::styled("Polymarket", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(
+            "  Wallet {}",
+            truncate(&app.funder.to_string(), 14)
+        )),
+        Span::raw(format!("  USDC {balance}")),
+        Span::raw(format!("  Updated {updated}")),
+    ]);
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+pub(super) fn render_orders(frame: &mut Frame, area: Rect, orders: &[OpenOrderResponse]) {
+    let rows = orders.iter().map(|o| {
+        let style = if o.side.to_string() == "BUY" {
+            bid_style()
+        } else {
+            ask_style()
+        };
+        Row::new(vec![
+            o.side.to_string(),
+            o.price.normalize().to_string(),
+            o.original_size.normalize().to_string(),
+            o.size_matched.normalize().to_string(),
+            truncate(&o.outcome, 12),
+            truncate(&o.id, 12),
+        ])
+        .style(style)
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(5),
+            Constraint::Length(7),
+            Constraint::Length(10),
+            Constraint::Length(10),
+            Constraint::Length(12),
+            Constraint::Min(12),
+        ],
+    )
+    .header(
+        Row::new(["Side", "Price", "Size", "Matched", "Outcome", "ID"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .block(Block::bordered().title(format!(" Open Orders ({}) ", orders.len())));
+    frame.render_widget(table, area);
+}
+
+fn render_positions(frame: &mut Frame, area: Rect, positions: &[Position]) {
+    let rows = positions.iter().map(|p| {
+        let style = if p.cash_pnl.is_sign_negative() {
+            ask_style()
+        } else {
+            bid_style()
+        };
+        Row::new(vec![
+            truncate(&p.title, 30),
+            truncate(&p.outcome, 8),
+            format!("{:.2}", p.size),
+            format!("{:.3}", p.avg_price),
+            format!("{:.3}", p.cur_price),
+            format!("{:.2}", p.cash_pnl),
+        ])
+        .style(style)
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(20),
+            Constraint::Length(8),
+            Constraint::Length(10),
+            Constraint::Length(7),
+            Constraint::Length(7),
+            Constraint::Length(10),
+        ],
+    )
+    .header(
+        Row::new(["Market", "Outcome", "Size", "Avg", "Mark", "PnL"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .block(Block::bordered().title(format!(" Positions ({}) ", positions.len())));
+    frame.render_widget(table, area);
+}
+
+fn render_book(frame: &mut Frame, area: Rect, app: &Dashboard, snapshot: &Snapshot) {
+    let Some(token_id) = app.watchlist.get(app.selected_book) else {
+        frame.render_widget(
+            Paragraph::new("No watchlist. Pass --tokens to show order books here.")
+                .block(Block::bordered().title(" Watchlist ")),
+            area,
+        );
+        return;
+    };
+    let title = format!(
+        " Book {}/{}: {} ",
+        app.selected_book + 1,
+        app.watchlist.len(),
+        truncate(&token_id.to_string(), 20)
+    );
+    let Some(book) = snapshot.books.iter().find(|b| b.asset_id == *token_id) else {
+        frame.render_widget(
+            Paragraph::new("No order book for this token.").block(Block::bordered().title(title)),
+            area,
+        );
+        return;
+    };
+
+    frame.render_widget(
+        book_table(book, BOOK_DEPTH, Block::bordered().title(title)),
+        area,
+    );
+}
+
+fn render_status(frame: &mut Frame, area: Rect, app: &Dashboard) {
+    let errors = app
+        .snapshot
+        .as_ref()
+        .map(|s| s.errors.join(" | "))
+        .unwrap_or_default();
+    let line = if errors.is_empty() {
+        Line::from("q quit  r refresh  ←/→ switch book")
+    } else {
+        Line::from(Span::styled(errors, ask_style()))
+    };
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dashboard(watchlist: usize) -> Dashboard {
+        Dashboard {
+            funder: Address::ZERO,
+            watchlist: (0..watchlist).map(|i| U256::from(i)).collect(),
+            snapshot: None,
+            selected_book: 0,
+        }
+    }
+
+    #[test]
+    fn book_selection_wraps_both_ways() {
+        let mut app = dashboard(3);
+        app.prev_book();
+        assert_eq!(app.selected_book, 2);
+        app.next_book();
+        assert_eq!(app.selected_book, 0);
+    }
+
+    #[test]
+    fn book_selection_empty_watchlist_is_noop() {
+        let mut app = dashboard(0);
+        app.next_book();
+        app.prev_book();
+        assert_eq!(app.selected_book, 0);
+    }
+}
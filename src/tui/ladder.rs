@@ -0,0 +1,546 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use crossterm::event::{Event, EventStream, KeyCode, KeyEventKind};
+use futures::StreamExt as _;
+use polymarket_client_sdk::auth::Normal;
+use polymarket_client_sdk::auth::state::Authenticated;
+use polymarket_client_sdk::clob;
+use polymarket_client_sdk::clob::types::request::{
+    CancelMarketOrderRequest, OrderBookSummaryRequest, OrdersRequest,
+};
+use polymarket_client_sdk::clob::types::response::{OpenOrderResponse, OrderBookSummaryResponse};
+use polymarket_client_sdk::clob::types::{OrderType, Side};
+use polymarket_client_sdk::types::{Decimal, U256};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph, Row, Table};
+use ratatui::{DefaultTerminal, Frame};
+use tokio::sync::{Notify, mpsc};
+
+use super::{ask_style, bid_style, format_price, is_quit_key};
+use crate::auth::WalletSigner;
+use crate::commands::clob::place_limit_order;
+use crate::output::truncate;
+
+const PAGE_TICKS: i64 = 10;
+
+pub struct LadderConfig {
+    pub token_id: U256,
+    pub size: Decimal,
+    pub interval: Duration,
+}
+
+struct Snapshot {
+    book: Option<OrderBookSummaryResponse>,
+    orders: Vec<OpenOrderResponse>,
+    error: Option<String>,
+}
+
+struct Ladder {
+    token_id: U256,
+    tick: Decimal,
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+    last_trade: Option<Decimal>,
+    orders: Vec<OpenOrderResponse>,
+    cursor: Option<Decimal>,
+    /// Level whose orders are being moved with `space`.
+    picked: Option<Decimal>,
+    size: Decimal,
+    status: String,
+}
+
+impl Ladder {
+    fn apply(&mut self, snapshot: Snapshot) {
+        if let Some(book) = snapshot.book {
+            self.tick = book.tick_size.as_decimal();
+            self.bids = book.bids.iter().map(|l| (l.price, l.size)).collect();
+            self.asks = book.asks.iter().map(|l| (l.price, l.size)).collect();
+            self.last_trade = book.last_trade_price;
+        }
+        self.orders = snapshot.orders;
+        if let Some(e) = snapshot.error {
+            self.status = e;
+        }
+        if self.cursor.is_none() {
+            self.center();
+        }
+    }
+
+    fn best_bid(&self) -> Option<Decimal> {
+        self.bids.keys().next_back().copied()
+    }
+
+    fn best_ask(&self) -> Option<Decimal> {
+        self.asks.keys().next().copied()
+    }
+
+    fn center(&mut self) {
+        let mid = match (self.best_bid(), self.best_ask()) {
+            (Some(bid), Some(ask)) => Some((bid + ask) / Decimal::TWO),
+            (bid, ask) => bid.or(ask).or(self.last_trade),
+        };
+        if let Some(mid) = mid {
+            self.cursor = Some(clamp_price(round_to_tick(mid, self.tick), self.tick));
+        }
+    }
+
+    fn move_cursor(&mut self, ticks: i64) {
+        if let Some(cursor) = self.cursor {
+            let moved = cursor + self.tick * Decimal::from(ticks);
+            self.cursor = Some(clamp_price(moved, self.tick));
+        }
+    }
+
+    fn adjust_size(&mut self, delta: Decimal) {
+        self.size = (self.size + delta).max(Decimal::ONE);
+    }
+
+    fn orders_at(&self, price: Decimal) -> impl Iterator<Item = &OpenOrderResponse> {
+        self.orders.iter().filter(move |o| o.price == price)
+    }
+
+    fn my_size_at(&self, price: Decimal, side: Side) -> Decimal {
+        self.orders_at(price)
+            .filter(|o| o.side == side)
+            .map(|o| o.original_size - o.size_matched)
+            .sum()
+    }
+}
+
+/// Snaps a price to the nearest multiple of `tick`.
+fn round_to_tick(price: Decimal, tick: Decimal) -> Decimal {
+    (price / tick).round() * tick
+}
+
+/// Keeps a price inside the tradable range `[tick, 1 - tick]`.
+fn clamp_price(price: Decimal, tick: Decimal) -> Decimal {
+    price.max(tick).min(Decimal::ONE - tick)
+}
+
+/// Price levels for a window of `rows` centred on `center`, highest first.
+fn ladder_prices(center: Decimal, tick: Decimal, rows: usize) -> Vec<Decimal> {
+    let levels = ((Decimal::ONE - tick) / tick)
+        .round()
+        .try_into()
+        .unwrap_or(0usize);
+    let rows = rows.min(levels);
+    let max = Decimal::ONE - tick;
+    let min_top = tick * Decimal::from(rows);
+    let top = (center + tick * Decimal::from(rows / 2))
+        .min(max)
+        .max(min_top);
+    (0..rows).map(|i| top - tick * Decimal::from(i)).collect()
+}
+
+pub async fn run(
+    client: clob::Client<Authenticated<Normal>>,
+    signer: WalletSigner,
+    config: LadderConfig,
+) -> Result<()> {
+    let (tx, mut rx) = mpsc::channel(1);
+    let refresh = Arc::new(Notify::new());
+    let refresher = tokio::spawn(refresh_loop(
+        client.clone(),
+        config.token_id,
+        config.interval,
+        Arc::clone(&refresh),
+        tx,
+    ));
+
+    let mut ladder = Ladder {
+        token_id: config.token_id,
+        tick: Decimal::new(1, 2),
+        bids: BTreeMap::new(),
+        asks: BTreeMap::new(),
+        last_trade: None,
+        orders: Vec::new(),
+        cursor: None,
+        picked: None,
+        size: config.size,
+        status: "Loading order book…".into(),
+    };
+
+    let mut terminal = ratatui::init();
+    let result = event_loop(
+        &mut terminal,
+        &mut ladder,
+        &client,
+        &signer,
+        &mut rx,
+        &refresh,
+    )
+    .await;
+    ratatui::restore();
+    refresher.abort();
+    result
+}
+
+async fn event_loop(
+    terminal: &mut DefaultTerminal,
+    ladder: &mut Ladder,
+    client: &clob::Client<Authenticated<Normal>>,
+    signer: &WalletSigner,
+    rx: &mut mpsc::Receiver<Snapshot>,
+    refresh: &Notify,
+) -> Result<()> {
+    let mut events = EventStream::new();
+    loop {
+        terminal.draw(|frame| render(frame, ladder))?;
+        tokio::select! {
+            Some(snapshot) = rx.recv() => ladder.apply(snapshot),
+            event = events.next() => match event {
+                Some(Ok(Event::Key(key))) if key.kind == KeyEventKind::Press => {
+                    if is_quit_key(&key) {
+                        break;
+                    }
+                    let acted = handle_key(ladder, key.code, client, signer).await;
+                    if acted {
+                        refresh.notify_one();
+                    }
+                }
+                Some(Ok(_)) => {}
+                Some(Err(e)) => return Err(e.into()),
+                None => break,
+            },
+        }
+    }
+    Ok(())
+}
+
+/// Applies a key press; returns true when the book and orders should be refetched.
+async fn handle_key(
+    ladder: &mut Ladder,
+    code: KeyCode,
+    client: &clob::Client<Authenticated<Normal>>,
+    signer: &WalletSigner,
+) -> bool {
+    match code {
+        KeyCode::Up | KeyCode::Char('k') => ladder.move_cursor(1),
+        KeyCode::Down | KeyCode::Char('j') => ladder.move_cursor(-1),
+        KeyCode::PageUp => ladder.move_cursor(PAGE_TICKS),
+        KeyCode::PageDown => ladder.move_cursor(-PAGE_TICKS),
+        KeyCode::Char('c') => ladder.center(),
+        KeyCode::Char('+' | '=') => ladder.adjust_size(Decimal::ONE),
+        KeyCode::Char('-') => ladder.adjust_size(-Decimal::ONE),
+        KeyCode::Char('b') => {
+            place(ladder, client, signer, Side::Buy).await;
+            return true;
+        }
+        KeyCode::Char('s') => {
+            place(ladder, client, signer, Side::Sell).await;
+            return true;
+        }
+        KeyCode::Char('x') => {
+            cancel_level(ladder, client).await;
+            return true;
+        }
+        KeyCode::Char('X') => {
+            cancel_token(ladder, client).await;
+            return true;
+        }
+        KeyCode::Char(' ') => return move_orders(ladder, client, signer).await,
+        KeyCode::Char('r') => return true,
+        _ => {}
+    }
+    false
+}
+
+async fn place(
+    ladder: &mut Ladder,
+    client: &clob::Client<Authenticated<Normal>>,
+    signer: &WalletSigner,
+    side: Side,
+) {
+    let Some(price) = ladder.cursor else {
+        return;
+    };
+    let result = place_limit_order(
+        client,
+        signer,
+        ladder.token_id,
+        side,
+        price,
+        ladder.size,
+        OrderType::GTC,
+        false,
+        None,
+    )
+    .await;
+    ladder.status = match result {
+        Ok(r) if r.success => format!(
+            "Placed {side} {} @ {price} ({})",
+            ladder.size,
+            truncate(&r.order_id, 14)
+        ),
+        Ok(r) => format!(
+            "Order rejected: {}",
+            r.error_msg.unwrap_or_else(|| r.status.to_string())
+        ),
+        Err(e) => format!("Order failed: {e}"),
+    };
+}
+
+async fn cancel_level(ladder: &mut Ladder, client: &clob::Client<Authenticated<Normal>>) {
+    let Some(price) = ladder.cursor else {
+        return;
+    };
+    let ids: Vec<&str> = ladder.orders_at(price).map(|o| o.id.as_str()).collect();
+    if ids.is_empty() {
+        ladder.status = format!("No orders at {price}");
+        return;
+    }
+    ladder.status = match client.cancel_orders(&ids).await {
+        Ok(r) => format!("Canceled {} order(s) at {price}", r.canceled.len()),
+        Err(e) => format!("Cancel failed: {e}"),
+    };
+}
+
+async fn cancel_token(ladder: &mut Ladder, client: &clob::Client<Authenticated<Normal>>) {
+    let request = CancelMarketOrderRequest::builder()
+        .asset_id(ladder.token_id)
+        .build();
+    ladder.status = match client.cancel_market_orders(&request).await {
+        Ok(r) => format!("Canceled {} order(s) on this token", r.canceled.len()),
+        Err(e) => format!("Cancel failed: {e}"),
+    };
+}
+
+/// First `space` picks up the orders at the cursor; the second moves them
+/// to the new cursor level by canceling and re-placing the remaining size.
+async fn move_orders(
+    ladder: &mut Ladder,
+    client: &clob::Client<Authenticated<Normal>>,
+    signer: &WalletSigner,
+) -> bool {
+    let Some(cursor) = ladder.cursor else {
+        return false;
+    };
+    let Some(from) = ladder.picked.take() else {
+        let count = ladder.orders_at(cursor).count();
+        if count == 0 {
+            ladder.status = format!("No orders at {cursor} to move");
+        } else {
+            ladder.picked = Some(cursor);
+            ladder.status =
+                format!("Moving {count} order(s) from {cursor}: select a level and press space");
+        }
+        return false;
+    };
+    if from == cursor {
+        ladder.status = "Move canceled".into();
+        return false;
+    }
+
+    let orders: Vec<OpenOrderResponse> = ladder.orders_at(from).cloned().collect();
+    let ids: Vec<&str> = orders.iter().map(|o| o.id.as_str()).collect();
+    if let Err(e) = client.cancel_orders(&ids).await {
+        ladder.status = format!("Move failed while canceling: {e}");
+        return true;
+    }
+    let mut moved = 0;
+    for order in &orders {
+        let remaining = order.original_size - order.size_matched;
+        match place_limit_order(
+            client,
+            signer,
+            ladder.token_id,
+            order.side,
+            cursor,
+            remaining,
+            OrderType::GTC,
+            false,
+            None,
+        )
+        .await
+        {
+            Ok(r) if r.success => moved += 1,
+            Ok(r) => {
+                ladder.status = format!(
+                    "Re-placing at {cursor} rejected: {}",
+                    r.error_msg.unwrap_or_else(|| r.status.to_string())
+                );
+                return true;
+            }
+            Err(e) => {
+                ladder.status = format!("Re-placing at {cursor} failed: {e}");
+                return true;
+            }
+        }
+    }
+    ladder.status = format!("Moved {moved} order(s) from {from} to {cursor}");
+    true
+}
+
+async fn refresh_loop(
+    client: clob::Client<Authenticated<Normal>>,
+    token_id: U256,
+    interval: Duration,
+    refresh: Arc<Notify>,
+    tx: mpsc::Sender<Snapshot>,
+) {
+    let book_request = OrderBookSummaryRequest::builder()
+        .token_id(token_id)
+        .build();
+    let orders_request = OrdersRequest::builder().asset_id(token_id).build();
+    loop {
+        let (book, orders) = tokio::join!(
+            client.order_book(&book_request),
+            client.orders(&orders_request, None),
+        );
+        let mut errors = Vec::new();
+        let book = book.map_err(|e| errors.push(format!("book: {e}"))).ok();
+        let orders = orders
+            .map(|p| p.data)
+            .map_err(|e| errors.push(format!("orders: {e}")))
+            .unwrap_or_default();
+        let snapshot = Snapshot {
+            book,
+            orders,
+            error: (!errors.is_empty()).then(|| errors.join(" | ")),
+        };
+        if tx.send(snapshot).await.is_err() {
+            break;
+        }
+        tokio::select! {
+            () = tokio::time::sleep(interval) => {}
+            () = refresh.notified() => {}
+        }
+    }
+}
+
+fn render(frame: &mut Frame, ladder: &Ladder) {
+    let [header, body, status, help] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(5),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let header_line = format!(
+        "Token {}  Tick {}  Size {}  Bid {}  Ask {}  Last {}",
+        truncate(&ladder.token_id.to_string(), 20),
+        ladder.tick.normalize(),
+        ladder.size.normalize(),
+        format_price(ladder.best_bid()),
+        format_price(ladder.best_ask()),
+        format_price(ladder.last_trade),
+    );
+    frame.render_widget(
+        Paragraph::new(header_line).style(Style::default().add_modifier(Modifier::BOLD)),
+        header,
+    );
+    render_ladder(frame, body, ladder);
+    frame.render_widget(Paragraph::new(ladder.status.as_str()), status);
+    frame.render_widget(
+        Paragraph::new(Line::from(
+            "↑/↓ move  PgUp/PgDn page  c center  b buy  s sell  +/- size  x cancel level  X cancel all  space move  q quit",
+        ))
+        .style(Style::default().fg(Color::DarkGray)),
+        help,
+    );
+}
+
+fn render_ladder(frame: &mut Frame, area: Rect, ladder: &Ladder) {
+    let block = Block::bordered().title(" Ladder ");
+    let Some(cursor) = ladder.cursor else {
+        frame.render_widget(Paragraph::new("Waiting for order book…").block(block), area);
+        return;
+    };
+    let rows = usize::from(area.height.saturating_sub(3));
+    let best_bid = ladder.best_bid();
+    let best_ask = ladder.best_ask();
+
+    let table_rows = ladder_prices(cursor, ladder.tick, rows)
+        .into_iter()
+        .map(|price| {
+            let size = |value: Decimal| {
+                if value.is_zero() {
+                    String::new()
+                } else {
+                    value.normalize().to_string()
+                }
+            };
+            let bid_size = ladder.bids.get(&price).copied().unwrap_or_default();
+            let ask_size = ladder.asks.get(&price).copied().unwrap_or_default();
+            let style = if Some(price) == ladder.picked {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else if best_bid.is_some_and(|b| price <= b) {
+                bid_style()
+            } else if best_ask.is_some_and(|a| price >= a) {
+                ask_style()
+            } else {
+                Style::default()
+            };
+            let style = if price == cursor {
+                style.add_modifier(Modifier::REVERSED)
+            } else {
+                style
+            };
+            Row::new(vec![
+                size(ladder.my_size_at(price, Side::Buy)),
+                size(bid_size),
+                price.normalize().to_string(),
+                size(ask_size),
+                size(ladder.my_size_at(price, Side::Sell)),
+            ])
+            .style(style)
+        });
+
+    let table = Table::new(table_rows, [Constraint::Ratio(1, 5); 5])
+        .header(
+            Row::new(["My Bids", "Bid Size", "Price", "Ask Size", "My Asks"])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .block(block);
+    frame.render_widget(table, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn round_to_tick_snaps_to_nearest_level() {
+        assert_eq!(round_to_tick(dec!(0.456), dec!(0.01)), dec!(0.46));
+        assert_eq!(round_to_tick(dec!(0.4549), dec!(0.01)), dec!(0.45));
+        assert_eq!(round_to_tick(dec!(0.4567), dec!(0.001)), dec!(0.457));
+    }
+
+    #[test]
+    fn clamp_price_stays_inside_tradable_range() {
+        assert_eq!(clamp_price(dec!(0), dec!(0.01)), dec!(0.01));
+        assert_eq!(clamp_price(dec!(1), dec!(0.01)), dec!(0.99));
+        assert_eq!(clamp_price(dec!(0.5), dec!(0.01)), dec!(0.5));
+    }
+
+    #[test]
+    fn ladder_prices_centred_and_descending() {
+        let prices = ladder_prices(dec!(0.50), dec!(0.01), 5);
+        assert_eq!(
+            prices,
+            [dec!(0.52), dec!(0.51), dec!(0.50), dec!(0.49), dec!(0.48)]
+        );
+    }
+
+    #[test]
+    fn ladder_prices_pinned_at_edges() {
+        let top = ladder_prices(dec!(0.99), dec!(0.01), 3);
+        assert_eq!(top, [dec!(0.99), dec!(0.98), dec!(0.97)]);
+        let bottom = ladder_prices(dec!(0.01), dec!(0.01), 3);
+        assert_eq!(bottom, [dec!(0.03), dec!(0.02), dec!(0.01)]);
+    }
+
+    #[test]
+    fn ladder_prices_capped_at_available_levels() {
+        assert_eq!(ladder_prices(dec!(0.5), dec!(0.1), 50).len(), 9);
+    }
+}
@@ -0,0 +1,123 @@
+pub mod browse;
+pub mod dashboard;
+pub mod ladder;
+pub mod portfolio;
+pub mod split;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use polymarket_client_sdk::clob::types::response::{OrderBookSummaryResponse, OrderSummary};
+use polymarket_client_sdk::types::Decimal;
+use ratatui::layout::Constraint;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Span;
+use ratatui::widgets::{Block, Row, Table};
+
+/// Keys that close any TUI screen: `q`, `Esc`, or `Ctrl+C`.
+pub fn is_quit_key(key: &KeyEvent) -> bool {
+    matches!(key.code, KeyCode::Char('q') | KeyCode::Esc)
+        || (key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL))
+}
+
+pub fn format_price(price: Option<Decimal>) -> String {
+    price.map_or_else(|| "—".into(), |p| p.normalize().to_string())
+}
+
+/// Best `depth` levels of each side, bids descending and asks ascending.
+pub fn top_levels(
+    bids: &[OrderSummary],
+    asks: &[OrderSummary],
+    depth: usize,
+) -> (Vec<OrderSummary>, Vec<OrderSummary>) {
+    let mut bids = bids.to_vec();
+    let mut asks = asks.to_vec();
+    bids.sort_by_key(|l| std::cmp::Reverse(l.price));
+    asks.sort_by_key(|l| l.price);
+    bids.truncate(depth);
+    asks.truncate(depth);
+    (bids, asks)
+}
+
+/// Side-by-side bids and asks, best levels on the first row.
+pub fn book_table<'a>(
+    book: &OrderBookSummaryResponse,
+    depth: usize,
+    block: Block<'a>,
+) -> Table<'a> {
+    let (bids, asks) = top_levels(&book.bids, &book.asks, depth);
+    let cell = |level: Option<&OrderSummary>, value: fn(&OrderSummary) -> Decimal, style| {
+        Span::styled(
+            level
+                .map(|l| value(l).normalize().to_string())
+                .unwrap_or_default(),
+            style,
+        )
+    };
+    let rows: Vec<Row> = (0..bids.len().max(asks.len()))
+        .map(|i| {
+            let bid = bids.get(i);
+            let ask = asks.get(i);
+            Row::new(vec![
+                cell(bid, |l| l.size, bid_style()),
+                cell(bid, |l| l.price, bid_style()),
+                cell(ask, |l| l.price, ask_style()),
+                cell(ask, |l| l.size, ask_style()),
+            ])
+        })
+        .collect();
+    Table::new(rows, [Constraint::Ratio(1, 4); 4])
+        .header(
+            Row::new(["Bid Size", "Bid", "Ask", "Ask Size"])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .block(block.title_bottom(format!(" Last {} ", format_price(book.last_trade_price))))
+}
+
+pub fn bid_style() -> Style {
+    Style::default().fg(Color::Green)
+}
+
+pub fn ask_style() -> Style {
+    Style::default().fg(Color::Red)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn level(price: Decimal) -> OrderSummary {
+        OrderSummary::builder().price(price).size(dec!(10)).build()
+    }
+
+    #[test]
+    fn quit_keys() {
+        assert!(is_quit_key(&KeyEvent::from(KeyCode::Char('q'))));
+        assert!(is_quit_key(&KeyEvent::from(KeyCode::Esc)));
+        assert!(is_quit_key(&KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL
+        )));
+        assert!(!is_quit_key(&KeyEvent::from(KeyCode::Char('c'))));
+    }
+
+    #[test]
+    fn top_levels_sorts_best_first_and_truncates() {
+        let bids = [level(dec!(0.40)), level(dec!(0.45)), level(dec!(0.42))];
+        let asks = [level(dec!(0.60)), level(dec!(0.55)), level(dec!(0.58))];
+        let (bids, asks) = top_levels(&bids, &asks, 2);
+        assert_eq!(
+            bids.iter().map(|l| l.price).collect::<Vec<_>>(),
+            [dec!(0.45), dec!(0.42)]
+        );
+        assert_eq!(
+            asks.iter().map(|l| l.price).collect::<Vec<_>>(),
+            [dec!(0.55), dec!(0.58)]
+        );
+    }
+
+    #[test]
+    fn format_price_missing_is_dash() {
+        assert_eq!(format_price(None), "—");
+        assert_eq!(format_price(Some(dec!(0.500))), "0.5");
+    }
+}
@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use crossterm::event::{Event, EventStream, KeyCode, KeyEventKind};
+use futures::StreamExt as _;
+use polymarket_client_sdk::clob::types::request::MidpointRequest;
+use polymarket_client_sdk::data::types::request::PositionsRequest;
+use polymarket_client_sdk::data::types::response::Position;
+use polymarket_client_sdk::types::{Address, Decimal, U256};
+use polymarket_client_sdk::{clob, data};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph, Row, Table, TableState};
+use ratatui::{DefaultTerminal, Frame};
+use tokio::sync::{Notify, mpsc};
+
+use super::{ask_style, bid_style, is_quit_key};
+use crate::endpoints;
+use crate::output::truncate;
+
+/// A position marked to the current midpoint.
+struct Holding {
+    title: String,
+    outcome: String,
+    size: Decimal,
+    avg_price: Decimal,
+    mark: Decimal,
+}
+
+impl Holding {
+    fn from_position(p: &Position, midpoints: &HashMap<U256, Decimal>) -> Self {
+        Self {
+            title: p.title.clone(),
+            outcome: p.outcome.clone(),
+            size: p.size,
+            avg_price: p.avg_price,
+            mark: midpoints.get(&p.asset).copied().unwrap_or(p.cur_price),
+        }
+    }
+
+    fn cost(&self) -> Decimal {
+        self.size * self.avg_price
+    }
+
+    fn value(&self) -> Decimal {
+        self.size * self.mark
+    }
+
+    fn unrealized_pnl(&self) -> Decimal {
+        self.value() - self.cost()
+    }
+
+    fn unrealized_pct(&self) -> Option<Decimal> {
+        let cost = self.cost();
+        (!cost.is_zero()).then(|| self.unrealized_pnl() / cost * Decimal::ONE_HUNDRED)
+    }
+}
+
+struct Snapshot {
+    holdings: Vec<Holding>,
+    error: Option<String>,
+    updated_at: DateTime<Local>,
+}
+
+struct Portfolio {
+    address: Address,
+    snapshot: Option<Snapshot>,
+    table: TableState,
+}
+
+pub async fn run(address: Address, interval: Duration) -> Result<()> {
+    let (tx, mut rx) = mpsc::channel(1);
+    let refresh = Arc::new(Notify::new());
+    let refresher = tokio::spawn(refresh_loop(address, interval, Arc::clone(&refresh), tx));
+
+    let mut app = Portfolio {
+        address,
+        snapshot: None,
+        table: TableState::default(),
+    };
+
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &mut app, &mut rx, &refresh).await;
+    ratatui::restore();
+    refresher.abort();
+    result
+}
+
+async fn event_loop(
+    terminal: &mut DefaultTerminal,
+    app: &mut Portfolio,
+    rx: &mut mpsc::Receiver<Snapshot>,
+    refresh: &Notify,
+) -> Result<()> {
+    let mut events = EventStream::new();
+    loop {
+        terminal.draw(|frame| render(frame, app))?;
+        tokio::select! {
+            Some(snapshot) = rx.recv() => app.snapshot = Some(snapshot),
+            event = events.next() => match event {
+                Some(Ok(Event::Key(key))) if key.kind == KeyEventKind::Press => {
+                    if is_quit_key(&key) {
+                        break;
+                    }
+                    match key.code {
+                        KeyCode::Char('r') => refresh.notify_one(),
+                        KeyCode::Down | KeyCode::Char('j') => app.table.select_next(),
+                        KeyCode::Up | KeyCode::Char('k') => app.table.select_previous(),
+                        _ => {}
+                    }
+                }
+                Some(Ok(_)) => {}
+                Some(Err(e)) => return Err(e.into()),
+                None => break,
+            },
+        }
+    }
+    Ok(())
+}
+
+async fn refresh_loop(
+    address: Address,
+    interval: Duration,
+    refresh: Arc<Notify>,
+    tx: mpsc::Sender<Snapshot>,
+) {
+    let data_client = endpoints::data();
+    let clob_client = endpoints::clob();
+    loop {
+        let snapshot = fetch_snapshot(&data_client, &clob_client, address).await;
+        if tx.send(snapshot).await.is_err() {
+            break;
+        }
+        tokio::select! {
+            () = tokio::time::sleep(interval) => {}
+            () = refresh.notified() => {}
+        }
+    }
+}
+
+async fn fetch_snapshot(
+    data_client: &data::Client,
+    clob_client: &clob::Client,
+    address: Address,
+) -> Snapshot {
+    let request = PositionsRequest::builder().user(address).build();
+    let positions = match data_client.positions(&request).await {
+        Ok(positions) => positions,
+        Err(e) => {
+            return Snapshot {
+                holdings: Vec::new(),
+                error: Some(format!("positions: {e}")),
+                updated_at: Local::now(),
+            };
+        }
+    };
+
+    let mut error = None;
+    let midpoints = if positions.is_empty() {
+        HashMap::new()
+    } else {
+        let requests: Vec<_> = positions
+            .iter()
+            .map(|p| MidpointRequest::builder().token_id(p.asset).build())
+            .collect();
+        match clob_client.midpoints(&requests).await {
+            Ok(r) => r.midpoints,
+            Err(e) => {
+                error = Some(format!("midpoints (using last price instead): {e}"));
+                HashMap::new()
+            }
+        }
+    };
+
+    Snapshot {
+        holdings: positions
+            .iter()
+            .map(|p| Holding::from_position(p, &midpoints))
+            .collect(),
+        error,
+        updated_at: Local::now(),
+    }
+}
+
+fn format_pnl(pnl: Decimal) -> String {
+    format!("{:+.2}", pnl.round_dp(2))
+}
+
+fn pnl_style(pnl: Decimal) -> Style {
+    if pnl.is_sign_negative() {
+        ask_style()
+    } else {
+        bid_style()
+    }
+}
+
+fn render(frame: &mut Frame, app: &mut Portfolio) {
+    let [header, body, totals, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(5),
+        Constraint::Length(3),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let updated = app.snapshot.as_ref().map_or_else(
+        || "—".into(),
+        |s| s.updated_at.format("%H:%M:%S").to_string(),
+    );
+    frame.render_widget(
+        Paragraph::new(format!("Portfolio {}  Updated {updated}", app.address))
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+        header,
+    );
+
+    let Some(snapshot) = &app.snapshot else {
+        frame.render_widget(Paragraph::new("Loading…").block(Block::bordered()), body);
+        return;
+    };
+
+    let rows: Vec<Row> = snapshot
+        .holdings
+        .iter()
+        .map(|h| {
+            let pnl = h.unrealized_pnl();
+            Row::new(vec![
+                truncate(&h.title, 40),
+                truncate(&h.outcome, 10),
+                format!("{:.2}", h.size),
+                format!("{:.3}", h.avg_price),
+                format!("{:.3}", h.mark),
+                format!("${:.2}", h.value()),
+                format_pnl(pnl),
+                h.unrealized_pct()
+                    .map_or_else(|| "—".into(), |p| format!("{p:+.1}%")),
+            ])
+            .style(pnl_style(pnl))
+        })
+        .collect();
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(20),
+            Constraint::Length(10),
+            Constraint::Length(10),
+            Constraint::Length(7),
+            Constraint::Length(7),
+            Constraint::Length(11),
+            Constraint::Length(10),
+            Constraint::Length(8),
+        ],
+    )
+    .header(
+        Row::new([
+            "Market", "Outcome", "Size", "Avg", "Mark", "Value", "PnL", "PnL %",
+        ])
+        .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+    .block(Block::bordered().title(format!(" Positions ({}) ", snapshot.holdings.len())));
+    frame.render_stateful_widget(table, body, &mut app.table);
+
+    let cost: Decimal = snapshot.holdings.iter().map(Holding::cost).sum();
+    let value: Decimal = snapshot.holdings.iter().map(Holding::value).sum();
+    let pnl = value - cost;
+    let totals_line = Line::from(vec![
+        Span::raw(format!("Cost ${cost:.2}   Value ${value:.2}   Unrealized ")),
+        Span::styled(format_pnl(pnl), pnl_style(pnl)),
+    ]);
+    frame.render_widget(
+        Paragraph::new(totals_line).block(Block::bordered().title(" Totals ")),
+        totals,
+    );
+
+    let footer_line = match &snapshot.error {
+        Some(e) => Line::from(Span::styled(e.as_str(), ask_style())),
+        None => {
+            Line::from("↑/↓ scroll  r refresh  q quit").style(Style::default().fg(Color::DarkGray))
+        }
+    };
+    frame.render_widget(Paragraph::new(footer_line), footer);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn holding(size: Decimal, avg: Decimal, mark: Decimal) -> Holding {
+        Holding {
+            title: "Market".into(),
+            outcome: "Yes".into(),
+            size,
+            avg_price: avg,
+            mark,
+        }
+    }
+
+    #[test]
+    fn unrealized_pnl_gain() {
+        let h = holding(dec!(100), dec!(0.40), dec!(0.55));
+        assert_eq!(h.cost(), dec!(40));
+        assert_eq!(h.value(), dec!(55));
+        assert_eq!(h.unrealized_pnl(), dec!(15));
+        assert_eq!(h.unrealized_pct(), Some(dec!(37.5)));
+    }
+
+    #[test]
+    fn unrealized_pnl_loss() {
+        let h = holding(dec!(10), dec!(0.50), dec!(0.25));
+        assert_eq!(h.unrealized_pnl(), dec!(-2.5));
+        assert_eq!(h.unrealized_pct(), Some(dec!(-50)));
+    }
+
+    #[test]
+    fn format_pnl_is_signed() {
+        assert_eq!(format_pnl(dec!(1.5)), "+1.50");
+        assert_eq!(format_pnl(dec!(-2.346)), "-2.35");
+    }
+
+    #[test]
+    fn unrealized_pct_zero_cost_is_none() {
+        assert_eq!(holding(dec!(10), dec!(0), dec!(0.5)).unrealized_pct(), None);
+    }
+}
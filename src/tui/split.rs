@@ -0,0 +1,304 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use crossterm::event::{Event, EventStream, KeyCode, KeyEventKind};
+use futures::StreamExt as _;
+use polymarket_client_sdk::auth::Normal;
+use polymarket_client_sdk::auth::state::Authenticated;
+use polymarket_client_sdk::clob::types::request::{OrderBookSummaryRequest, OrdersRequest};
+use polymarket_client_sdk::clob::types::response::{OpenOrderResponse, OrderBookSummaryResponse};
+use polymarket_client_sdk::data::types::MarketFilter;
+use polymarket_client_sdk::data::types::request::TradesRequest;
+use polymarket_client_sdk::data::types::response::Trade;
+use polymarket_client_sdk::types::{B256, U256};
+use polymarket_client_sdk::{clob, data};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph, Row, Table};
+use ratatui::{DefaultTerminal, Frame};
+use tokio::sync::{Notify, mpsc};
+
+use super::dashboard::render_orders;
+use super::{ask_style, bid_style, book_table, is_quit_key};
+use crate::endpoints;
+use crate::output::truncate;
+
+/// Trades fetched for a trades pane, more than fit in most panes.
+const TRADES_LIMIT: i32 = 50;
+
+/// What a pane shows.
+#[derive(Clone, Debug, PartialEq)]
+pub enum View {
+    /// The order book of a token.
+    Book(U256),
+    /// The wallet's open orders.
+    Orders,
+    /// The latest trades in a market, by condition ID.
+    Trades(B256),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pane {
+    pub view: View,
+    /// Shown in the pane's border, e.g. the market's slug.
+    pub title: String,
+}
+
+pub struct SplitConfig {
+    pub panes: Vec<Pane>,
+    pub interval: Duration,
+}
+
+/// A pane's latest data, or why it couldn't be fetched.
+enum PaneData {
+    Book(OrderBookSummaryResponse),
+    Orders(Vec<OpenOrderResponse>),
+    Trades(Vec<Trade>),
+    Error(String),
+}
+
+/// Everything fetched in one background refresh, one entry per pane.
+struct Snapshot {
+    panes: Vec<PaneData>,
+    updated_at: DateTime<Local>,
+}
+
+/// Tiles the panes in one screen until a quit key. `client` lists the
+/// orders for an orders pane, which shows the error instead when there is
+/// no client, so the other panes still work without a wallet.
+pub async fn run(
+    config: SplitConfig,
+    client: Result<clob::Client<Authenticated<Normal>>>,
+) -> Result<()> {
+    let (tx, mut rx) = mpsc::channel(1);
+    let refresh = Arc::new(Notify::new());
+    let views = config.panes.iter().map(|p| p.view.clone()).collect();
+    let refresher = tokio::spawn(refresh_loop(
+        client.map_err(|e| e.to_string()),
+        views,
+        config.interval,
+        Arc::clone(&refresh),
+        tx,
+    ));
+
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &config.panes, &mut rx, &refresh).await;
+    ratatui::restore();
+    refresher.abort();
+    result
+}
+
+async fn event_loop(
+    terminal: &mut DefaultTerminal,
+    panes: &[Pane],
+    rx: &mut mpsc::Receiver<Snapshot>,
+    refresh: &Notify,
+) -> Result<()> {
+    let mut events = EventStream::new();
+    let mut snapshot = None;
+    loop {
+        terminal.draw(|frame| render(frame, panes, snapshot.as_ref()))?;
+        tokio::select! {
+            Some(fresh) = rx.recv() => snapshot = Some(fresh),
+            event = events.next() => match event {
+                Some(Ok(Event::Key(key))) if key.kind == KeyEventKind::Press => {
+                    if is_quit_key(&key) {
+                        break;
+                    }
+                    if key.code == KeyCode::Char('r') {
+                        refresh.notify_one();
+                    }
+                }
+                Some(Ok(_)) => {}
+                Some(Err(e)) => return Err(e.into()),
+                None => break,
+            },
+        }
+    }
+    Ok(())
+}
+
+async fn refresh_loop(
+    client: std::result::Result<clob::Client<Authenticated<Normal>>, String>,
+    views: Vec<View>,
+    interval: Duration,
+    refresh: Arc<Notify>,
+    tx: mpsc::Sender<Snapshot>,
+) {
+    let clob_client = endpoints::clob();
+    let data_client = endpoints::data();
+    loop {
+        let panes = futures::future::join_all(
+            views
+                .iter()
+                .map(|view| fetch_pane(view, &clob_client, client.as_ref(), &data_client)),
+        )
+        .await;
+        let snapshot = Snapshot {
+            panes,
+            updated_at: Local::now(),
+        };
+        if tx.send(snapshot).await.is_err() {
+            break;
+        }
+        tokio::select! {
+            () = tokio::time::sleep(interval) => {}
+            () = refresh.notified() => {}
+        }
+    }
+}
+
+async fn fetch_pane(
+    view: &View,
+    clob_client: &clob::Client,
+    client: std::result::Result<&clob::Client<Authenticated<Normal>>, &String>,
+    data_client: &data::Client,
+) -> PaneData {
+    let result = match view {
+        View::Book(token_id) => {
+            let request = OrderBookSummaryRequest::builder()
+                .token_id(*token_id)
+                .build();
+            clob_client
+                .order_book(&request)
+                .await
+                .map(PaneData::Book)
+                .map_err(anyhow::Error::from)
+        }
+        View::Orders => match client {
+            Ok(client) => client
+                .orders(&OrdersRequest::builder().build(), None)
+                .await
+                .map(|page| PaneData::Orders(page.data))
+                .map_err(anyhow::Error::from),
+            Err(e) => Err(anyhow::anyhow!("{e}")),
+        },
+        View::Trades(condition_id) => match TradesRequest::builder().limit(TRADES_LIMIT) {
+            Ok(builder) => {
+                let request = builder
+                    .filter(MarketFilter::markets([*condition_id]))
+                    .build();
+                data_client
+                    .trades(&request)
+                    .await
+                    .map(PaneData::Trades)
+                    .map_err(anyhow::Error::from)
+            }
+            Err(e) => Err(e.into()),
+        },
+    };
+    result.unwrap_or_else(|e| PaneData::Error(e.to_string()))
+}
+
+/// Columns and rows for `n` panes: side by side up to two, then a grid
+/// about as wide as it is tall.
+fn grid(n: usize) -> (usize, usize) {
+    let columns = match n {
+        0 | 1 => 1,
+        2..=4 => 2,
+        _ => 3,
+    };
+    (columns, n.div_ceil(columns).max(1))
+}
+
+fn render(frame: &mut Frame, panes: &[Pane], snapshot: Option<&Snapshot>) {
+    let [body, status] =
+        Layout::vertical([Constraint::Min(3), Constraint::Length(1)]).areas(frame.area());
+
+    let (columns, rows) = grid(panes.len());
+    let row_areas = Layout::vertical(vec![Constraint::Ratio(1, rows as u32); rows]).split(body);
+    let areas = row_areas.iter().flat_map(|row| {
+        Layout::horizontal(vec![Constraint::Ratio(1, columns as u32); columns])
+            .split(*row)
+            .to_vec()
+    });
+    for (i, (pane, area)) in panes.iter().zip(areas).enumerate() {
+        let data = snapshot.and_then(|s| s.panes.get(i));
+        render_pane(frame, area, pane, data);
+    }
+
+    let updated = snapshot.map_or_else(
+        || "—".into(),
+        |s| s.updated_at.format("%H:%M:%S").to_string(),
+    );
+    let line = Line::from(vec![
+        Span::styled("q", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(" back to shell  "),
+        Span::styled("r", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(" refresh  Updated {updated}")),
+    ]);
+    frame.render_widget(Paragraph::new(line), status);
+}
+
+fn render_pane(frame: &mut Frame, area: Rect, pane: &Pane, data: Option<&PaneData>) {
+    let block = Block::bordered().title(format!(" {} ", pane.title));
+    match data {
+        None => frame.render_widget(Paragraph::new("Loading…").block(block), area),
+        Some(PaneData::Error(e)) => frame.render_widget(
+            Paragraph::new(Span::styled(e.clone(), ask_style())).block(block),
+            area,
+        ),
+        Some(PaneData::Book(book)) => {
+            // Borders and the header row take three lines.
+            let depth = usize::from(area.height.saturating_sub(3)).max(1);
+            frame.render_widget(book_table(book, depth, block), area);
+        }
+        Some(PaneData::Orders(orders)) => render_orders(frame, area, orders),
+        Some(PaneData::Trades(trades)) => render_trades(frame, area, trades, block),
+    }
+}
+
+fn render_trades(frame: &mut Frame, area: Rect, trades: &[Trade], block: Block) {
+    let rows = trades.iter().map(|t| {
+        let style = if t.side.to_string() == "BUY" {
+            bid_style()
+        } else {
+            ask_style()
+        };
+        let time = DateTime::from_timestamp(t.timestamp, 0)
+            .map(|at| at.with_timezone(&Local).format("%H:%M:%S").to_string())
+            .unwrap_or_default();
+        Row::new(vec![
+            time,
+            t.side.to_string(),
+            format!("{:.2}", t.size),
+            t.price.normalize().to_string(),
+            truncate(&t.outcome, 10),
+        ])
+        .style(style)
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(8),
+            Constraint::Length(5),
+            Constraint::Length(10),
+            Constraint::Length(7),
+            Constraint::Min(6),
+        ],
+    )
+    .header(
+        Row::new(["Time", "Side", "Size", "Price", "Outcome"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .block(block);
+    frame.render_widget(table, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_fits_every_pane() {
+        assert_eq!(grid(1), (1, 1));
+        assert_eq!(grid(2), (2, 1));
+        assert_eq!(grid(3), (2, 2));
+        assert_eq!(grid(4), (2, 2));
+        assert_eq!(grid(5), (3, 2));
+        assert_eq!(grid(7), (3, 3));
+    }
+}
@@ -0,0 +1,691 @@
+//! Sending contract calls on Polygon with the user's gas settings.
+//!
+//! Commands build their calls as [`PlannedTx`]s. The same call can then be
+//! printed for `--dry-run`, priced for `--estimate-only` and the
+//! confirmation prompt, and sent.
+//!
+//! Calls are simulated first where the command asks for it, so a call that
+//! would revert fails here instead of on-chain.
+//!
+//! Every send is noted locally so `tx pending` can find it again.
+
+use std::io::{IsTerminal as _, Write as _};
+use std::time::Duration;
+
+use alloy::network::{Ethereum, Network, TransactionBuilder as _};
+use alloy::primitives::{B256, Bytes, U256};
+use alloy::providers::Provider;
+use alloy::sol;
+use alloy::sol_types::{SolCall as _, decode_revert_reason};
+use alloy::transports::TransportError;
+use anyhow::{Context as _, Result, bail};
+use chrono::{DateTime, Utc};
+use clap::Args;
+use polymarket_client_sdk::types::Address;
+use polymarket_client_sdk::wallet_contract_config;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive as _;
+use serde::{Deserialize, Serialize};
+
+use crate::output::OutputFormat;
+use crate::output::dry_run::{PlannedTx, print_gas_estimates, print_planned_txs};
+use crate::{auth, config};
+
+type TransactionRequest = <Ethereum as Network>::TransactionRequest;
+
+const WEI_PER_GWEI: Decimal = Decimal::from_parts(1_000_000_000, 0, 0, false, 0);
+
+/// Roughly one Polygon block.
+const BLOCK_POLL: Duration = Duration::from_secs(2);
+
+/// Sends kept in the local record; anything older is long mined or dropped.
+const MAX_SENT: usize = 100;
+
+/// `typeCode` of a plain call in a proxy wallet batch.
+const PROXY_CALL: u8 = 1;
+
+sol! {
+    /// Polymarket's factory runs calls through the caller's proxy wallet.
+    interface IProxyWalletFactory {
+        struct ProxyCall {
+            uint8 typeCode;
+            address to;
+            uint256 value;
+            bytes data;
+        }
+
+        function proxy(ProxyCall[] calls) external payable returns (bytes[] returnValues);
+    }
+}
+
+/// Gas settings for commands that send transactions.
+#[derive(Args, Clone, Debug, Default)]
+pub struct GasArgs {
+    /// Max fee per gas in gwei (default: estimated from recent blocks)
+    #[arg(long, global = true, value_name = "GWEI")]
+    pub max_fee: Option<Decimal>,
+
+    /// Max priority fee (tip) per gas in gwei (default: estimated)
+    #[arg(long, global = true, value_name = "GWEI")]
+    pub priority_fee: Option<Decimal>,
+
+    /// Gas limit for each transaction (default: estimated)
+    #[arg(long, global = true)]
+    pub gas_limit: Option<u64>,
+
+    /// Estimate the gas and cost of each transaction and send nothing
+    #[arg(long, global = true)]
+    pub estimate_only: bool,
+}
+
+/// How long to wait once a transaction is sent.
+#[derive(Args, Clone, Copy, Debug)]
+pub struct WaitArgs {
+    /// Blocks to wait for, counting the one the transaction is mined in,
+    /// before printing the result
+    #[arg(
+        long,
+        global = true,
+        value_name = "N",
+        default_value_t = 1,
+        value_parser = clap::value_parser!(u64).range(1..),
+        conflicts_with = "no_wait"
+    )]
+    pub confirmations: u64,
+
+    /// Print the transaction hash as soon as it is sent, without waiting for
+    /// it to be mined
+    #[arg(long, global = true)]
+    pub no_wait: bool,
+}
+
+impl Default for WaitArgs {
+    fn default() -> Self {
+        Self {
+            confirmations: 1,
+            no_wait: false,
+        }
+    }
+}
+
+/// Gas and fees a transaction will be sent with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GasEstimate {
+    pub gas_limit: u64,
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+}
+
+impl GasEstimate {
+    /// The most the transaction can cost, in wei: every unit of gas at the
+    /// max fee. It usually costs less.
+    pub fn max_cost(&self) -> U256 {
+        U256::from(self.gas_limit) * U256::from(self.max_fee_per_gas)
+    }
+}
+
+fn gwei_to_wei(gwei: Decimal, flag: &str) -> Result<u128> {
+    anyhow::ensure!(gwei > Decimal::ZERO, "{flag} must be positive");
+    (gwei * WEI_PER_GWEI)
+        .trunc()
+        .to_u128()
+        .with_context(|| format!("{flag} is out of range: {gwei}"))
+}
+
+impl GasArgs {
+    /// Fills in whatever the flags leave open from the node's estimate. A
+    /// tip above the max fee can never be paid, so it is capped there.
+    fn fees(&self, estimated: (u128, u128)) -> Result<(u128, u128)> {
+        let max_fee = self
+            .max_fee
+            .map(|gwei| gwei_to_wei(gwei, "--max-fee"))
+            .transpose()?;
+        let priority = self
+            .priority_fee
+            .map(|gwei| gwei_to_wei(gwei, "--priority-fee"))
+            .transpose()?;
+        let (estimated_max, estimated_priority) = estimated;
+        Ok(match (max_fee, priority) {
+            (Some(max), Some(priority)) => {
+                anyhow::ensure!(
+                    priority <= max,
+                    "--priority-fee can't be higher than --max-fee"
+                );
+                (max, priority)
+            }
+            (Some(max), None) => (max, estimated_priority.min(max)),
+            (None, Some(priority)) => (estimated_max.max(priority), priority),
+            (None, None) => (estimated_max, estimated_priority),
+        })
+    }
+
+    /// Fees for a transaction that replaces one still pending at the same
+    /// nonce. Nodes only accept the replacement when it raises both fees by
+    /// at least 10%, so anything lower is raised to 12.5% above the old fees.
+    pub fn replacement_fees(
+        &self,
+        old: (u128, u128),
+        estimated: (u128, u128),
+    ) -> Result<(u128, u128)> {
+        let bump = |fee: u128| fee + fee.div_ceil(8);
+        let (min_max_fee, min_priority) = (bump(old.0), bump(old.1));
+        let (max_fee, priority) = self.fees(estimated)?;
+        if let Some(gwei) = self.max_fee {
+            anyhow::ensure!(
+                max_fee >= min_max_fee,
+                "--max-fee {gwei} is too low to replace the transaction; it needs at least {} gwei",
+                format_gwei(min_max_fee)
+            );
+        }
+        if let Some(gwei) = self.priority_fee {
+            anyhow::ensure!(
+                priority >= min_priority,
+                "--priority-fee {gwei} is too low to replace the transaction; it needs at least {} gwei",
+                format_gwei(min_priority)
+            );
+        }
+        let priority = priority.max(min_priority);
+        Ok((max_fee.max(min_max_fee).max(priority), priority))
+    }
+
+    /// Validates the flags without touching the network, so bad values fail
+    /// before anything is signed.
+    pub fn validate(&self) -> Result<()> {
+        self.fees((1, 1)).map(|_| ())
+    }
+
+    async fn estimate<P: Provider>(&self, provider: &P, tx: &PlannedTx) -> Result<GasEstimate> {
+        let gas_limit = match self.gas_limit {
+            Some(limit) => limit,
+            None => provider.estimate_gas(request(tx)).await.with_context(|| {
+                format!(
+                    "Failed to estimate gas for {}; pass --gas-limit to set it yourself",
+                    tx.action
+                )
+            })?,
+        };
+        let fees = if self.max_fee.is_some() && self.priority_fee.is_some() {
+            (0, 0)
+        } else {
+            let estimate = provider
+                .estimate_eip1559_fees()
+                .await
+                .context("Failed to estimate gas fees")?;
+            (estimate.max_fee_per_gas, estimate.max_priority_fee_per_gas)
+        };
+        let (max_fee_per_gas, max_priority_fee_per_gas) = self.fees(fees)?;
+        Ok(GasEstimate {
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        })
+    }
+}
+
+fn request(tx: &PlannedTx) -> TransactionRequest {
+    let request = TransactionRequest::default()
+        .with_to(tx.to)
+        .with_value(tx.value)
+        .with_input(Bytes::from(tx.calldata.clone()));
+    match tx.from {
+        Some(from) => request.with_from(from),
+        None => request,
+    }
+}
+
+/// How a command's transactions are handled before any is sent.
+pub struct TxOptions<'a> {
+    pub private_key: Option<&'a str>,
+    pub dry_run: bool,
+    pub confirm: bool,
+    pub gas: &'a GasArgs,
+    pub wait: WaitArgs,
+    /// Run each call with `eth_call` before estimating or sending it
+    pub simulate: bool,
+}
+
+impl TxOptions<'_> {
+    /// Whether [`prepare`] will ask before sending.
+    pub fn prompts(&self) -> bool {
+        self.confirm && !self.dry_run && !self.gas.estimate_only
+    }
+}
+
+/// Handles `--dry-run`, `--estimate-only`, and the confirmation prompt, which
+/// also shows what the transactions may cost in gas. Returns the estimates to
+/// send with, or `None` when nothing should be sent.
+pub async fn prepare(
+    txs: &[PlannedTx],
+    summary: &[String],
+    options: &TxOptions<'_>,
+    output: &OutputFormat,
+) -> Result<Option<Vec<GasEstimate>>> {
+    if options.dry_run {
+        print_planned_txs(txs, output)?;
+        return Ok(None);
+    }
+    let provider = auth::create_readonly_provider().await?;
+    prepare_on(&provider, "POL", txs, summary, options, output).await
+}
+
+/// [`prepare`] for transactions on another chain, reached through
+/// `provider`, whose gas is paid in `gas_token`.
+pub async fn prepare_on<P: Provider>(
+    provider: &P,
+    gas_token: &str,
+    txs: &[PlannedTx],
+    summary: &[String],
+    options: &TxOptions<'_>,
+    output: &OutputFormat,
+) -> Result<Option<Vec<GasEstimate>>> {
+    if options.dry_run {
+        print_planned_txs(txs, output)?;
+        return Ok(None);
+    }
+    options.gas.validate()?;
+    if options.prompts() {
+        crate::commands::ensure_can_confirm()?;
+    }
+    if options.simulate {
+        for tx in txs {
+            simulate(provider, tx).await?;
+        }
+    }
+    let mut estimates = Vec::with_capacity(txs.len());
+    for tx in txs {
+        estimates.push(options.gas.estimate(provider, tx).await?);
+    }
+    if options.gas.estimate_only {
+        print_gas_estimates(txs, &estimates, output)?;
+        return Ok(None);
+    }
+    if options.confirm {
+        let max_cost = estimates
+            .iter()
+            .fold(U256::ZERO, |total, e| total + e.max_cost());
+        let mut lines = summary.to_vec();
+        lines.push(format!(
+            "Gas: up to {} {gas_token} across {} transaction{}",
+            format_pol(max_cost),
+            txs.len(),
+            if txs.len() == 1 { "" } else { "s" }
+        ));
+        crate::commands::confirm_submit(&lines)?;
+    }
+    Ok(Some(estimates))
+}
+
+/// Runs `tx` with `eth_call`, turning a revert into an error that says why
+/// in plain language.
+async fn simulate<P: Provider>(provider: &P, tx: &PlannedTx) -> Result<()> {
+    let Err(err) = provider.call(request(tx)).await else {
+        return Ok(());
+    };
+    let Some(reason) = revert_reason(&err) else {
+        return Err(err).with_context(|| format!("Failed to simulate {}", tx.action));
+    };
+    let why = match (explain_revert(&reason), reason.is_empty()) {
+        (Some(explanation), _) => {
+            format!("{explanation} (the contract reverted with \"{reason}\")")
+        }
+        (None, true) => "the contract reverted without a reason".to_string(),
+        (None, false) => format!("the contract reverted with \"{reason}\""),
+    };
+    bail!(
+        "{} would fail: {why}. Nothing was sent; pass --skip-simulation to send it anyway",
+        tx.action
+    )
+}
+
+/// The reason string of a reverted call, empty when it gave none; `None` when
+/// the call failed for some other reason.
+fn revert_reason(err: &TransportError) -> Option<String> {
+    let payload = err.as_error_resp()?;
+    if let Some(reason) = payload
+        .as_revert_data()
+        .and_then(|data| decode_revert_reason(&data))
+    {
+        return Some(reason);
+    }
+    let rest = payload.message.strip_prefix("execution reverted")?;
+    Some(rest.trim_start_matches(':').trim().to_string())
+}
+
+/// What the Conditional Tokens contract and its collateral mean by their
+/// revert messages.
+fn explain_revert(reason: &str) -> Option<&'static str> {
+    let reason = reason.to_lowercase();
+    let explanation = if reason.contains("result for condition not received yet") {
+        "the market hasn't resolved yet, so there is nothing to redeem"
+    } else if reason.contains("condition not prepared") {
+        "there is no such condition on the Conditional Tokens contract; check the condition ID"
+    } else if reason.contains("singleton partition") {
+        "the partition needs at least two index sets"
+    } else if reason.contains("partition not disjoint") {
+        "the partition's index sets overlap"
+    } else if reason.contains("invalid index set") {
+        "an index set doesn't fit this condition's outcomes"
+    } else if reason.contains("exceeds allowance") {
+        "the contract isn't approved to spend that much of your collateral"
+    } else if reason.contains("exceeds balance") || reason.contains("could not receive collateral")
+    {
+        "the wallet doesn't have enough collateral (USDC)"
+    } else if reason.contains("insufficient balance") || reason.contains("subtraction overflow") {
+        "the wallet doesn't hold enough of these outcome tokens"
+    } else if reason.contains("operator approval") {
+        "the contract isn't approved to move your outcome tokens; see `ctf approval set`"
+    } else {
+        return None;
+    };
+    Some(explanation)
+}
+
+/// `calldata` for `to`, sent from `funder`, the wallet holding the user's
+/// funds and positions. EOA funds move directly; a proxy wallet is driven
+/// through Polymarket's factory by its owner. A Safe's call is only planned,
+/// for `safe propose`.
+pub fn funder_call(
+    funder: Address,
+    eoa: Address,
+    signature_type: &str,
+    action: String,
+    to: Address,
+    calldata: Vec<u8>,
+) -> Result<PlannedTx> {
+    if signature_type != "proxy" || funder == eoa {
+        return Ok(PlannedTx {
+            action,
+            from: Some(funder),
+            to,
+            calldata,
+            value: U256::ZERO,
+        });
+    }
+    let factory = wallet_contract_config(config::chain_id())
+        .and_then(|c| c.proxy_factory)
+        .context("Polymarket has no proxy wallets on this chain")?;
+    Ok(PlannedTx {
+        action,
+        from: Some(eoa),
+        to: factory,
+        calldata: IProxyWalletFactory::proxyCall {
+            calls: vec![IProxyWalletFactory::ProxyCall {
+                typeCode: PROXY_CALL,
+                to,
+                value: U256::ZERO,
+                data: calldata.into(),
+            }],
+        }
+        .abi_encode(),
+        value: U256::ZERO,
+    })
+}
+
+/// Safe funds can't be moved by a plain transaction; its calls go through
+/// `safe propose` instead.
+pub fn ensure_sendable(signature_type: &str, dry_run: bool) -> Result<()> {
+    if signature_type == "gnosis-safe" && !dry_run {
+        bail!(
+            "Funds in a Safe move by Safe transaction: run this with --dry-run -o json \
+             and pass the output to `polymarket safe propose`"
+        );
+    }
+    Ok(())
+}
+
+/// A transaction that was sent, and the block it was mined in unless
+/// `--no-wait` returned before then.
+pub struct Sent {
+    pub hash: B256,
+    pub block: Option<u64>,
+}
+
+/// Sends a transaction with the given gas settings and waits for it as
+/// `wait` asks. A reverted transaction is an error.
+pub async fn send<P: Provider>(
+    provider: &P,
+    tx: &PlannedTx,
+    gas: &GasEstimate,
+    wait: WaitArgs,
+) -> Result<Sent> {
+    broadcast(provider, request(tx), tx, gas, wait).await
+}
+
+/// Sends `tx` in place of the pending transaction at `nonce`.
+pub async fn replace<P: Provider>(
+    provider: &P,
+    tx: &PlannedTx,
+    nonce: u64,
+    gas: &GasEstimate,
+    wait: WaitArgs,
+) -> Result<Sent> {
+    let request = request(tx).with_nonce(nonce);
+    broadcast(provider, request, tx, gas, wait).await
+}
+
+async fn broadcast<P: Provider>(
+    provider: &P,
+    request: TransactionRequest,
+    tx: &PlannedTx,
+    gas: &GasEstimate,
+    wait: WaitArgs,
+) -> Result<Sent> {
+    let request = request
+        .with_gas_limit(gas.gas_limit)
+        .with_max_fee_per_gas(gas.max_fee_per_gas)
+        .with_max_priority_fee_per_gas(gas.max_priority_fee_per_gas);
+    let pending = provider
+        .send_transaction(request)
+        .await
+        .with_context(|| format!("Failed to send {}", tx.action))?;
+    let hash = *pending.tx_hash();
+    record_sent(SentTx {
+        hash,
+        action: tx.action.clone(),
+        sent_at: Utc::now(),
+    });
+    if wait.no_wait {
+        return Ok(Sent { hash, block: None });
+    }
+
+    let progress = std::io::stderr().is_terminal();
+    if progress {
+        eprint!("Sent {hash}, waiting for it to be mined...");
+    }
+    let receipt = pending
+        .get_receipt()
+        .await
+        .with_context(|| format!("Failed to confirm {}", tx.action))?;
+    anyhow::ensure!(
+        receipt.status(),
+        "{} reverted in transaction {hash}",
+        tx.action
+    );
+    let block = receipt
+        .block_number
+        .with_context(|| format!("No block number in the receipt for {hash}"))?;
+    loop {
+        let latest = provider.get_block_number().await?;
+        let confirmations = (latest.saturating_sub(block) + 1).min(wait.confirmations);
+        if progress {
+            eprint!(
+                "\r\x1b[2KMined {hash} in block {block}: {confirmations}/{} confirmations",
+                wait.confirmations
+            );
+            let _ = std::io::stderr().flush();
+        }
+        if confirmations >= wait.confirmations {
+            break;
+        }
+        tokio::time::sleep(BLOCK_POLL).await;
+    }
+    if progress {
+        eprintln!();
+    }
+    // The block may have been reorganized away while we waited.
+    if wait.confirmations > 1 {
+        let receipt = provider.get_transaction_receipt(hash).await?;
+        anyhow::ensure!(
+            receipt.is_some_and(|r| r.block_number.is_some()),
+            "{hash} was dropped from block {block} by a reorg; check `tx pending` before retrying"
+        );
+    }
+    Ok(Sent {
+        hash,
+        block: Some(block),
+    })
+}
+
+/// A transaction this CLI broadcast. Whether it is still pending, and its
+/// nonce and fees, come from the node.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SentTx {
+    pub hash: B256,
+    pub action: String,
+    pub sent_at: DateTime<Utc>,
+}
+
+/// The transactions this CLI sent, oldest first.
+pub fn load_sent() -> Vec<SentTx> {
+    config::sent_txs_path()
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Best effort: a failed write only means `tx pending` can't list this send.
+fn record_sent(sent: SentTx) {
+    let Ok(path) = config::sent_txs_path() else {
+        return;
+    };
+    let mut all = load_sent();
+    all.push(sent);
+    let excess = all.len().saturating_sub(MAX_SENT);
+    all.drain(..excess);
+    if let (Some(dir), Ok(json)) = (path.parent(), serde_json::to_string_pretty(&all)) {
+        let _ = std::fs::create_dir_all(dir);
+        let _ = crate::output::write_atomic(&path, &json);
+    }
+}
+
+/// Formats wei as POL with up to 6 decimal places.
+pub fn format_pol(wei: U256) -> String {
+    let micro = wei / U256::from(1_000_000_000_000u64);
+    let micro = i128::try_from(micro).unwrap_or(i128::MAX);
+    Decimal::try_from_i128_with_scale(micro, 6)
+        .map_or_else(|_| "a lot of".to_string(), |d| d.normalize().to_string())
+}
+
+/// Formats wei per gas as gwei.
+pub fn format_gwei(wei: u128) -> String {
+    Decimal::from(wei / 1_000_000)
+        .checked_div(Decimal::from(1_000))
+        .map_or_else(|| wei.to_string(), |d| d.normalize().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    const GWEI: u128 = 1_000_000_000;
+
+    fn gas(max_fee: Option<Decimal>, priority_fee: Option<Decimal>) -> GasArgs {
+        GasArgs {
+            max_fee,
+            priority_fee,
+            ..GasArgs::default()
+        }
+    }
+
+    #[test]
+    fn flags_override_the_estimate_and_the_tip_is_capped_by_the_max_fee() {
+        let estimated = (100 * GWEI, 30 * GWEI);
+        assert_eq!(gas(None, None).fees(estimated).unwrap(), estimated);
+        assert_eq!(
+            gas(Some(dec!(50)), None).fees(estimated).unwrap(),
+            (50 * GWEI, 30 * GWEI)
+        );
+        assert_eq!(
+            gas(Some(dec!(20)), None).fees(estimated).unwrap(),
+            (20 * GWEI, 20 * GWEI)
+        );
+        assert_eq!(
+            gas(None, Some(dec!(150))).fees(estimated).unwrap(),
+            (150 * GWEI, 150 * GWEI)
+        );
+        assert_eq!(
+            gas(Some(dec!(80.5)), Some(dec!(35)))
+                .fees(estimated)
+                .unwrap(),
+            (80_500_000_000, 35 * GWEI)
+        );
+    }
+
+    #[test]
+    fn rejects_a_tip_above_the_max_fee_and_non_positive_fees() {
+        assert!(gas(Some(dec!(30)), Some(dec!(40))).validate().is_err());
+        assert!(gas(Some(dec!(0)), None).validate().is_err());
+        assert!(gas(None, Some(dec!(-1))).validate().is_err());
+    }
+
+    #[test]
+    fn replacements_raise_both_fees_enough_for_the_node_to_accept_them() {
+        let old = (100 * GWEI, 30 * GWEI);
+        // A calmer market still has to outbid the stuck transaction.
+        assert_eq!(
+            gas(None, None)
+                .replacement_fees(old, (80 * GWEI, 25 * GWEI))
+                .unwrap(),
+            (112_500_000_000, 33_750_000_000)
+        );
+        // A spike is followed.
+        assert_eq!(
+            gas(None, None)
+                .replacement_fees(old, (300 * GWEI, 50 * GWEI))
+                .unwrap(),
+            (300 * GWEI, 50 * GWEI)
+        );
+        assert_eq!(
+            gas(Some(dec!(150)), Some(dec!(40)))
+                .replacement_fees(old, (80 * GWEI, 25 * GWEI))
+                .unwrap(),
+            (150 * GWEI, 40 * GWEI)
+        );
+        assert!(
+            gas(Some(dec!(105)), None)
+                .replacement_fees(old, (80 * GWEI, 25 * GWEI))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn common_reverts_are_explained() {
+        assert_eq!(
+            explain_revert("result for condition not received yet"),
+            Some("the market hasn't resolved yet, so there is nothing to redeem")
+        );
+        assert_eq!(
+            explain_revert("SafeMath: subtraction overflow"),
+            Some("the wallet doesn't hold enough of these outcome tokens")
+        );
+        assert_eq!(
+            explain_revert("ERC20: transfer amount exceeds balance"),
+            Some("the wallet doesn't have enough collateral (USDC)")
+        );
+        assert_eq!(explain_revert("something else entirely"), None);
+    }
+
+    #[test]
+    fn costs_are_shown_in_pol_and_gwei() {
+        let estimate = GasEstimate {
+            gas_limit: 200_000,
+            max_fee_per_gas: 150 * GWEI,
+            max_priority_fee_per_gas: 30 * GWEI,
+        };
+        assert_eq!(format_pol(estimate.max_cost()), "0.03");
+        assert_eq!(format_gwei(30 * GWEI), "30");
+        assert_eq!(format_gwei(30_500_000_000), "30.5");
+    }
+}
@@ -0,0 +1,178 @@
+//! `--watch <seconds>`: re-runs a read-only command on an interval, redrawing
+//! the screen and highlighting characters that changed since the last refresh.
+//!
+//! Each refresh runs this binary again without `--watch` and captures its
+//! output, so every read command can be watched without changes to its printer.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::Local;
+
+use crate::output::color;
+use crate::poll::Poller;
+
+/// Global flags removed from the child invocation, and whether each takes a value.
+const STRIPPED_FLAGS: [(&str, bool); 3] =
+    [("--watch", true), ("--color", true), ("--no-pager", false)];
+
+/// Arguments for one refresh: the original command line without `--watch`,
+/// with paging and color turned off so the output can be diffed.
+fn child_args(args: &[String]) -> Vec<String> {
+    let mut out = vec!["--no-pager".to_string(), "--color".into(), "never".into()];
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        if arg == "--" {
+            out.push(arg.clone());
+            out.extend(iter.cloned());
+            break;
+        }
+        match STRIPPED_FLAGS
+            .iter()
+            .find(|(flag, _)| arg == flag || arg.starts_with(&format!("{flag}=")))
+        {
+            Some((flag, true)) if arg == flag => {
+                iter.next();
+            }
+            Some(_) => {}
+            None => out.push(arg.clone()),
+        }
+    }
+    out
+}
+
+/// Marks characters of `current` that differ from the same position in `previous`.
+fn highlight_changes(previous: &str, current: &str, mark: impl Fn(String) -> String) -> String {
+    let previous: Vec<&str> = previous.lines().collect();
+    let mut out = String::new();
+    for (i, line) in current.lines().enumerate() {
+        let old: Vec<char> = previous
+            .get(i)
+            .map_or_else(Vec::new, |l| l.chars().collect());
+        let mut run = String::new();
+        let mut run_changed = false;
+        for (j, c) in line.chars().enumerate() {
+            let changed = old.get(j) != Some(&c) && !c.is_whitespace();
+            if changed != run_changed && !run.is_empty() {
+                out.push_str(&flush(&mut run, run_changed, &mark));
+            }
+            run_changed = changed;
+            run.push(c);
+        }
+        out.push_str(&flush(&mut run, run_changed, &mark));
+        out.push('\n');
+    }
+    out
+}
+
+fn flush(run: &mut String, changed: bool, mark: impl Fn(String) -> String) -> String {
+    let text = std::mem::take(run);
+    if changed { mark(text) } else { text }
+}
+
+pub async fn run(args: &[String], interval_secs: u64) -> Result<()> {
+    let exe = std::env::current_exe().context("Could not locate the polymarket executable")?;
+    let child_args = child_args(args);
+    let interval = Duration::from_secs(interval_secs.max(1));
+    let command_line = args.iter().skip(1).cloned().collect::<Vec<_>>().join(" ");
+    let mut previous: Option<String> = None;
+
+    let mut poller = Poller::new(interval);
+    while poller.tick().await {
+        let output = tokio::process::Command::new(&exe)
+            .args(&child_args)
+            .output()
+            .await
+            .context("Failed to run command")?;
+        let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+        let body = match &previous {
+            Some(previous) => highlight_changes(previous, &stdout, color::changed),
+            None => stdout.clone(),
+        };
+
+        print!("\x1b[2J\x1b[H");
+        println!(
+            "{}",
+            color::dim(format!(
+                "Every {}s: polymarket {command_line}    {}  (Ctrl+C to stop)",
+                interval.as_secs(),
+                Local::now().format("%H:%M:%S")
+            ))
+        );
+        println!();
+        print!("{body}");
+        if !output.status.success() {
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        }
+        previous = Some(stdout);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn child_args_strip_watch_and_force_plain_output() {
+        let child = child_args(&args(&[
+            "polymarket",
+            "clob",
+            "book",
+            "123",
+            "--watch",
+            "5",
+            "--color=always",
+            "--no-pager",
+        ]));
+        assert_eq!(
+            child,
+            args(&["--no-pager", "--color", "never", "clob", "book", "123"])
+        );
+    }
+
+    #[test]
+    fn child_args_handle_equals_form_and_keep_values_after_double_dash() {
+        let child = child_args(&args(&[
+            "polymarket",
+            "--watch=2",
+            "clob",
+            "price",
+            "--",
+            "--watch",
+        ]));
+        assert_eq!(
+            child,
+            args(&[
+                "--no-pager",
+                "--color",
+                "never",
+                "clob",
+                "price",
+                "--",
+                "--watch"
+            ])
+        );
+    }
+
+    fn brackets(s: String) -> String {
+        format!("[{s}]")
+    }
+
+    #[test]
+    fn highlight_marks_changed_runs() {
+        assert_eq!(
+            highlight_changes("bid 0.41\nask 0.55", "bid 0.42\nask 0.55", brackets),
+            "bid 0.4[2]\nask 0.55\n"
+        );
+    }
+
+    #[test]
+    fn highlight_marks_new_lines_but_not_whitespace() {
+        assert_eq!(highlight_changes("a", "a\nb c", brackets), "a\n[b] [c]\n");
+    }
+}
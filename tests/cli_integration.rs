@@ -25,7 +25,13 @@ fn help_lists_all_top_level_commands() {
             .and(predicate::str::contains("approve"))
             .and(predicate::str::contains("clob"))
             .and(predicate::str::contains("ctf"))
+            .and(predicate::str::contains("safe"))
             .and(predicate::str::contains("data"))
+            .and(predicate::str::contains("browse"))
+            .and(predicate::str::contains("dashboard"))
+            .and(predicate::str::contains("portfolio"))
+            .and(predicate::str::contains("pnl"))
+            .and(predicate::str::contains("activity"))
             .and(predicate::str::contains("bridge"))
             .and(predicate::str::contains("wallet"))
             .and(predicate::str::contains("status")),
@@ -51,6 +57,8 @@ fn markets_help_lists_subcommands() {
             predicate::str::contains("list")
                 .and(predicate::str::contains("get"))
                 .and(predicate::str::contains("search"))
+                .and(predicate::str::contains("info"))
+                .and(predicate::str::contains("trending"))
                 .and(predicate::str::contains("tags")),
         );
 }
@@ -79,10 +87,708 @@ fn wallet_help_lists_subcommands() {
                 .and(predicate::str::contains("import"))
                 .and(predicate::str::contains("address"))
                 .and(predicate::str::contains("show"))
-                .and(predicate::str::contains("reset")),
+                .and(predicate::str::contains("reset"))
+                .and(predicate::str::contains("list"))
+                .and(predicate::str::contains("use"))
+                .and(predicate::str::contains("balance"))
+                .and(predicate::str::contains("send-pol"))
+                .and(predicate::str::contains("gas-check")),
         );
 }
 
+#[test]
+fn wallet_send_pol_previews_the_value_with_dry_run() {
+    polymarket()
+        .args([
+            "--dry-run",
+            "-o",
+            "json",
+            "--private-key",
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+        ])
+        .args(["wallet", "send-pol", "--amount", "0.5"])
+        .args(["--to", "0x000000000000000000000000000000000000dEaD"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("\"value\": \"500000000000000000\"")
+                .and(predicate::str::contains("\"calldata\": \"0x\"")),
+        );
+    // Other wallet commands still have nothing to preview.
+    polymarket()
+        .args(["--dry-run", "wallet", "gas-check"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn wallet_address_qr_draws_the_deposit_address() {
+    let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+    let qr = |signature_type: &str| {
+        polymarket()
+            .args(["--private-key", key, "--signature-type", signature_type])
+            .args(["wallet", "address", "--qr"])
+            .assert()
+            .success()
+    };
+    // The proxy wallet takes deposits, not the signing key's address...
+    qr("proxy").stdout(
+        predicate::str::contains("\u{2588}\u{2588}\u{2588}\u{2588}")
+            .and(predicate::str::contains(
+                "0x96a9892De6A11FE0B18Cf63373B9763055EcA8a6",
+            ))
+            .and(predicate::str::contains("0x2c7536E3605D9C16a7a3D7b1898e529396a65c23").not()),
+    );
+    // ...unless the key's address holds the funds itself.
+    qr("eoa").stdout(predicate::str::contains(
+        "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23",
+    ));
+}
+
+#[test]
+fn unknown_profile_is_rejected_before_running() {
+    polymarket()
+        .args(["--profile", "no-such-profile-xyz", "markets", "list"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "No profile named 'no-such-profile-xyz'",
+        ));
+}
+
+#[test]
+fn encrypted_wallet_needs_the_passphrase_to_sign() {
+    let home = std::env::temp_dir().join(format!("polymarket-encrypt-{}", std::process::id()));
+    let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+    polymarket()
+        .env("HOME", &home)
+        .env("POLYMARKET_PASSPHRASE", "hunter2")
+        .args(["wallet", "import", key, "--encrypt"])
+        .assert()
+        .success();
+    let config = std::fs::read_to_string(home.join(".config/polymarket/config.json")).unwrap();
+    assert!(!config.contains(&key[2..]));
+
+    // The address is stored beside the key, so showing it needs no passphrase.
+    polymarket()
+        .env("HOME", &home)
+        .env_remove("POLYMARKET_PASSPHRASE")
+        .args(["wallet", "address"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23",
+        ));
+    polymarket()
+        .env("HOME", &home)
+        .env("POLYMARKET_PASSPHRASE", "wrong")
+        .args(["clob", "create-api-key"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Wrong passphrase"));
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+#[test]
+fn config_settings_persist_and_set_defaults() {
+    let home = std::env::temp_dir().join(format!("polymarket-settings-{}", std::process::id()));
+    polymarket()
+        .env("HOME", &home)
+        .args(["config", "set", "output", "json"])
+        .assert()
+        .success();
+    // The saved format now applies without -o.
+    polymarket()
+        .env("HOME", &home)
+        .args(["config", "get", "output"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"value\": \"json\""));
+    polymarket()
+        .env("HOME", &home)
+        .args(["config", "set", "endpoints.gamma", "ftp://example.com"])
+        .assert()
+        .failure();
+    polymarket()
+        .env("HOME", &home)
+        .args(["config", "set", "no_such_setting", "1"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("{\"error\":\"Unknown setting"));
+    polymarket()
+        .env("HOME", &home)
+        .args(["config", "unset", "output"])
+        .assert()
+        .success();
+    polymarket()
+        .env("HOME", &home)
+        .args(["config", "get", "output"])
+        .assert()
+        .success()
+        .stdout("table (default)\n");
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+#[test]
+fn corrupt_config_is_left_untouched() {
+    let home = std::env::temp_dir().join(format!("polymarket-corrupt-{}", std::process::id()));
+    let path = home.join(".config/polymarket/config.json");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let corrupt = "{\"private_key\": \"0xabc\",";
+    std::fs::write(&path, corrupt).unwrap();
+    let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+    for args in [
+        &["config", "set", "output", "json"][..],
+        &["wallet", "import", key, "--force"],
+        &[
+            "wallet",
+            "watch",
+            "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23",
+            "--force",
+        ],
+    ] {
+        polymarket()
+            .env("HOME", &home)
+            .args(args)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("not a valid config file"));
+    }
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), corrupt);
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+/// Serves the CLOB auth endpoints, refusing every key but `valid`, and
+/// returns the address and the request lines it saw.
+fn fake_clob(valid: &'static str) -> (String, std::sync::mpsc::Receiver<String>) {
+    use std::io::{BufRead as _, BufReader, Write as _};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    let (seen, requests) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let mut reader = BufReader::new(&stream);
+            let mut request = String::new();
+            reader.read_line(&mut request).unwrap();
+            let mut api_key = String::new();
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                if let Some((name, value)) = line.split_once(':')
+                    && name.eq_ignore_ascii_case("poly_api_key")
+                {
+                    api_key = value.trim().to_string();
+                }
+                line.clear();
+            }
+            let (status, body) = match request.split_whitespace().take(2).collect::<Vec<_>>()[..] {
+                ["POST", "/auth/api-key"] => (
+                    "200 OK",
+                    format!(r#"{{"apiKey":"{valid}","secret":"c2VjcmV0","passphrase":"p"}}"#),
+                ),
+                ["GET", "/auth/api-keys"] if api_key == valid => {
+                    ("200 OK", format!(r#"{{"apiKeys":["{valid}"]}}"#))
+                }
+                ["GET", "/auth/api-keys"] => {
+                    ("401 Unauthorized", r#"{"error":"Unauthorized"}"#.into())
+                }
+                _ => ("404 Not Found", "{}".into()),
+            };
+            let _ = seen.send(request.trim().to_string());
+            let _ = write!(
+                &stream,
+                "HTTP/1.1 {status}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+        }
+    });
+    (url, requests)
+}
+
+#[test]
+fn rejected_cached_api_key_is_replaced() {
+    let home = std::env::temp_dir().join(format!("polymarket-stale-key-{}", std::process::id()));
+    let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+    let stale = "00000000-0000-4000-8000-000000000001";
+    let fresh = "00000000-0000-4000-8000-000000000002";
+    let (url, requests) = fake_clob(fresh);
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "import", key])
+        .assert()
+        .success();
+    polymarket()
+        .env("HOME", &home)
+        .args(["config", "set", "endpoints.clob", &url])
+        .assert()
+        .success();
+
+    let path = home.join(".config/polymarket/config.json");
+    let mut config: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    config["api_credentials"] = serde_json::json!({
+        "address": "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23",
+        "key": stale,
+        "secret": "c2VjcmV0",
+        "passphrase": "p",
+    });
+    std::fs::write(&path, config.to_string()).unwrap();
+
+    polymarket()
+        .env("HOME", &home)
+        .args(["--signature-type", "eoa", "clob", "api-keys"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(fresh));
+    let config: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(config["api_credentials"]["key"], fresh);
+    assert_eq!(
+        requests.try_iter().collect::<Vec<_>>(),
+        [
+            "GET /auth/api-keys HTTP/1.1",
+            "POST /auth/api-key HTTP/1.1",
+            "GET /auth/api-keys HTTP/1.1",
+        ]
+    );
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+#[test]
+fn shell_aliases_and_history_persist() {
+    let home = std::env::temp_dir().join(format!("polymarket-shell-{}", std::process::id()));
+    let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+    polymarket()
+        .env("HOME", &home)
+        .arg("shell")
+        .write_stdin("alias addr = wallet address; alias\nexit\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Saved alias addr"));
+    // A new session picks the alias up from the config file, and its `;`
+    // runs the commands in turn.
+    polymarket()
+        .env("HOME", &home)
+        .env("POLYMARKET_PRIVATE_KEY", key)
+        .write_stdin("addr\nunalias addr\nexit\n")
+        .arg("shell")
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("0x2c7536E3605D9C16a7a3D7b1898e529396a65c23")
+                .and(predicate::str::contains("addr = wallet address; alias"))
+                .and(predicate::str::contains("Removed alias addr")),
+        );
+    let history =
+        std::fs::read_to_string(home.join(".config/polymarket/shell-history.txt")).unwrap();
+    assert!(history.contains("unalias addr"));
+    let config = std::fs::read_to_string(home.join(".config/polymarket/config.json")).unwrap();
+    assert!(!config.contains("\"addr\""));
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+#[test]
+fn shell_use_sets_the_market_for_the_session() {
+    let home = std::env::temp_dir().join(format!("polymarket-use-{}", std::process::id()));
+    polymarket()
+        .env("HOME", &home)
+        .arg("shell")
+        .write_stdin("use 123 yes\nuse 123\nuse\nuse --clear\nuse\nexit\n")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "A token ID already picks the outcome",
+        ))
+        .stdout(
+            predicate::str::contains("Token: 123")
+                .and(predicate::str::contains("Cleared the market in use"))
+                .and(predicate::str::contains("No market in use")),
+        );
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+#[test]
+fn chain_setting_and_flag_pick_the_deployment() {
+    let home = std::env::temp_dir().join(format!("polymarket-chain-{}", std::process::id()));
+    let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "import", key])
+        .assert()
+        .success();
+    polymarket()
+        .env("HOME", &home)
+        .args(["config", "set", "chain", "amoy"])
+        .assert()
+        .success();
+    // Polymarket deploys no proxy wallets on Amoy.
+    polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "wallet", "show"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"proxy_address\": null"));
+    polymarket()
+        .env("HOME", &home)
+        .args(["--chain", "polygon", "-o", "json", "wallet", "show"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"proxy_address\": \"0x"));
+    polymarket()
+        .args(["--chain", "mainnet", "wallet", "show"])
+        .assert()
+        .failure();
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+#[test]
+fn watch_only_wallets_read_but_never_sign() {
+    let home = std::env::temp_dir().join(format!("polymarket-watch-{}", std::process::id()));
+    let address = "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23";
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "watch", address])
+        .assert()
+        .success();
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "address"])
+        .assert()
+        .success()
+        .stdout(format!("{address}\n"));
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "sign-message", "hi"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("watch-only wallet"));
+    // A key replaces the watched address only when asked to.
+    polymarket()
+        .env("HOME", &home)
+        .args([
+            "wallet",
+            "import",
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+        ])
+        .assert()
+        .failure();
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+#[test]
+fn safe_proposals_collect_signatures_and_reject_edits() {
+    let home = std::env::temp_dir().join(format!("polymarket-safe-{}", std::process::id()));
+    std::fs::create_dir_all(&home).unwrap();
+    let plan = home.join("plan.json");
+    let proposal = home.join("proposal.json");
+    std::fs::write(
+        &plan,
+        r#"[{"dry_run":true,"action":"split","to":"0x4D97DCd97eC945f40cF65F87097ACe5EA0476045","calldata":"0x1234"}]"#,
+    )
+    .unwrap();
+    for (profile, key) in [
+        (
+            "alice",
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+        ),
+        (
+            "bob",
+            "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+        ),
+    ] {
+        polymarket()
+            .env("HOME", &home)
+            .args(["--profile", profile, "wallet", "import", key])
+            .assert()
+            .success();
+    }
+    let safe = "0x5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a";
+    polymarket()
+        .env("HOME", &home)
+        .args([
+            "--profile",
+            "alice",
+            "safe",
+            "propose",
+            "--safe",
+            safe,
+            "--nonce",
+            "3",
+        ])
+        .arg(&plan)
+        .arg("--out")
+        .arg(&proposal)
+        .assert()
+        .success();
+    // Signing shows the calls and asks first, which needs a terminal.
+    polymarket()
+        .env("HOME", &home)
+        .args(["--profile", "bob", "safe", "sign"])
+        .arg(&proposal)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("pass --yes"));
+    polymarket()
+        .env("HOME", &home)
+        .args(["--profile", "bob", "-o", "json", "--yes", "safe", "sign"])
+        .arg(&proposal)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"valid_signatures\": 2"));
+
+    let edited = std::fs::read_to_string(&proposal)
+        .unwrap()
+        .replace("\"0x1234\"", "\"0x9999\"");
+    std::fs::write(&proposal, edited).unwrap();
+    polymarket()
+        .env("HOME", &home)
+        .args(["--profile", "bob", "safe", "sign"])
+        .arg(&proposal)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "was changed after it was proposed",
+        ));
+
+    // A call planned from another wallet would run with the wrong sender.
+    std::fs::write(
+        &plan,
+        r#"[{"action":"transfer","from":"0x2c7536E3605D9C16a7a3D7b1898e529396a65c23","to":"0x4D97DCd97eC945f40cF65F87097ACe5EA0476045","calldata":"0x1234"}]"#,
+    )
+    .unwrap();
+    polymarket()
+        .env("HOME", &home)
+        .args([
+            "--profile",
+            "alice",
+            "safe",
+            "propose",
+            "--force",
+            "--safe",
+            safe,
+            "--nonce",
+            "3",
+        ])
+        .arg(&plan)
+        .arg("--out")
+        .arg(&proposal)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not the Safe"));
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+#[test]
+fn bridge_withdraw_sends_from_the_funding_wallet() {
+    let home = std::env::temp_dir().join(format!("polymarket-withdraw-{}", std::process::id()));
+    polymarket()
+        .env("HOME", &home)
+        .args([
+            "wallet",
+            "import",
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+        ])
+        .assert()
+        .success();
+    let withdraw = |signature_type: &str| {
+        polymarket()
+            .env("HOME", &home)
+            .args([
+                "--dry-run",
+                "-o",
+                "json",
+                "--signature-type",
+                signature_type,
+            ])
+            .args(["bridge", "withdraw", "--amount", "5"])
+            .args(["--to", "0x000000000000000000000000000000000000dEaD"])
+            .assert()
+            .success()
+    };
+    // A proxy wallet's funds move through Polymarket's proxy factory...
+    withdraw("proxy").stdout(predicate::str::contains(
+        "\"to\": \"0xaB45c5A4B0c941a2F231C04C3f49182e1A254052\"",
+    ));
+    // ...and an EOA's straight from the USDC contract.
+    withdraw("eoa").stdout(predicate::str::contains(
+        "\"to\": \"0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174\"",
+    ));
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+#[test]
+fn wallet_keystore_export_round_trips_through_import() {
+    let home = std::env::temp_dir().join(format!("polymarket-keystore-{}", std::process::id()));
+    let file = home.join("wallet.json");
+    let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "import", key])
+        .assert()
+        .success();
+    polymarket()
+        .env("HOME", &home)
+        .env("POLYMARKET_PASSPHRASE", "hunter2")
+        .args(["wallet", "export", "--keystore"])
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(key).not());
+    let json = std::fs::read_to_string(&file).unwrap();
+    assert!(json.contains("\"version\": 3") && !json.contains(&key[2..]));
+
+    polymarket()
+        .env("HOME", &home)
+        .env("POLYMARKET_PASSPHRASE", "hunter2")
+        .args(["wallet", "export", "--keystore"])
+        .arg(&file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+    polymarket()
+        .env("HOME", &home)
+        .env("POLYMARKET_PASSPHRASE", "wrong")
+        .args(["--profile", "copy", "wallet", "import", "--keystore"])
+        .arg(&file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Wrong keystore password"));
+    polymarket()
+        .env("HOME", &home)
+        .env("POLYMARKET_PASSPHRASE", "hunter2")
+        .args(["--profile", "copy", "wallet", "import", "--keystore"])
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23",
+        ));
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+#[test]
+fn wallet_signatures_verify_against_the_signer_only() {
+    let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+    let out = polymarket()
+        .args(["--private-key", key, "-o", "json", "wallet", "sign-message"])
+        .arg("I own this account")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+    let signature = json["signature"].as_str().unwrap();
+
+    let verify = |address: &str, message: &str| {
+        polymarket()
+            .args(["wallet", "verify", "--address", address])
+            .args(["--signature", signature, "--message", message])
+            .assert()
+    };
+    verify(
+        "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23",
+        "I own this account",
+    )
+    .success()
+    .stdout(predicate::str::contains("Valid signature"));
+    verify(
+        "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23",
+        "I own that account",
+    )
+    .failure()
+    .stderr(predicate::str::contains("not from"));
+    verify(
+        "0x0000000000000000000000000000000000000001",
+        "I own this account",
+    )
+    .failure();
+}
+
+#[test]
+fn unknown_signer_is_rejected() {
+    polymarket()
+        .args(["--signer", "trezor", "clob", "orders"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown signer"));
+}
+
+#[cfg(not(feature = "ledger"))]
+#[test]
+fn ledger_signer_needs_the_ledger_feature() {
+    polymarket()
+        .args(["--signer", "ledger", "clob", "orders"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--features ledger"));
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+#[test]
+fn keyring_wallet_without_a_keyring_saves_nothing() {
+    let home = std::env::temp_dir().join(format!("polymarket-keyring-{}", std::process::id()));
+    std::fs::create_dir_all(&home).unwrap();
+
+    polymarket()
+        .env("HOME", &home)
+        .env("DBUS_SESSION_BUS_ADDRESS", "unix:path=/nonexistent")
+        .args([
+            "wallet",
+            "import",
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+            "--storage",
+            "keyring",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("OS keyring"));
+    assert!(!home.join(".config/polymarket/config.json").exists());
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+#[test]
+fn keyring_storage_rejects_encrypt() {
+    polymarket()
+        .args(["wallet", "create", "--storage", "keyring", "--encrypt"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--encrypt only applies"));
+}
+
+#[test]
+fn wallet_import_derives_keys_from_a_seed_phrase() {
+    let home = std::env::temp_dir().join(format!("polymarket-mnemonic-{}", std::process::id()));
+    let phrase = "test test test test test test test test test test test junk";
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "import", phrase, "--index", "1"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("0x70997970C51812dc3A010C7d01b50e0d17dc79C8")
+                .and(predicate::str::contains("m/44'/60'/0'/0/1")),
+        );
+    let _ = std::fs::remove_dir_all(&home);
+}
+
+#[test]
+fn wallet_import_path_requires_a_seed_phrase() {
+    polymarket()
+        .args(["wallet", "import", "0xabc", "--path", "m/44'/60'/0'/0/0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("only apply to seed phrases"));
+}
+
+#[test]
+fn wallet_use_rejects_unknown_profile() {
+    polymarket()
+        .args(["wallet", "use", "no-such-profile-xyz"])
+        .assert()
+        .failure();
+}
+
 #[test]
 fn no_args_shows_usage() {
     polymarket()
@@ -97,156 +803,1030 @@ fn unknown_command_fails() {
 }
 
 #[test]
-fn invalid_output_format_rejected() {
+fn invalid_output_format_rejected() {
+    polymarket()
+        .args(["--output", "xml", "status"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value"));
+}
+
+#[test]
+fn markets_search_requires_query() {
+    polymarket().args(["markets", "search"]).assert().failure();
+}
+
+#[test]
+fn markets_get_requires_id() {
+    polymarket().args(["markets", "get"]).assert().failure();
+}
+
+#[test]
+fn comments_list_requires_entity_args() {
+    polymarket().args(["comments", "list"]).assert().failure();
+}
+
+// Uses a guaranteed-to-fail command (nonexistent slug) to verify the error
+// output contract: JSON mode → structured error on stdout, table mode → stderr.
+
+#[test]
+fn json_mode_error_is_valid_json_with_error_key() {
+    let output = polymarket()
+        .args([
+            "--output",
+            "json",
+            "markets",
+            "get",
+            "nonexistent-slug-99999",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value = serde_json::from_str(stdout.trim())
+        .unwrap_or_else(|e| panic!("stdout not valid JSON: {e}\nstdout: {stdout}"));
+    assert!(
+        parsed.get("error").is_some(),
+        "missing 'error' key: {parsed}"
+    );
+}
+
+#[test]
+fn table_mode_error_goes_to_stderr() {
+    polymarket()
+        .args(["markets", "get", "nonexistent-slug-99999"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Error:"));
+}
+
+#[test]
+fn wallet_show_always_succeeds() {
+    polymarket().args(["wallet", "show"]).assert().success();
+}
+
+#[test]
+fn wallet_show_json_has_configured_field() {
+    let output = polymarket()
+        .args(["-o", "json", "wallet", "show"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value = serde_json::from_str(stdout.trim())
+        .unwrap_or_else(|e| panic!("stdout not valid JSON: {e}\nstdout: {stdout}"));
+    assert!(
+        parsed.get("configured").is_some(),
+        "missing 'configured' key: {parsed}"
+    );
+}
+
+#[test]
+fn output_file_writes_json_instead_of_stdout() {
+    let path = std::env::temp_dir().join(format!("polymarket-wallet-{}.json", std::process::id()));
+    let output = polymarket()
+        .args(["-o", "json", "wallet", "show", "--output-file"])
+        .arg(&path)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    let written = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&written).unwrap();
+    assert!(parsed.get("configured").is_some());
+}
+
+#[test]
+fn output_file_rejects_table_format() {
+    polymarket()
+        .args(["wallet", "show", "--output-file", "out.json"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--output-file"));
+}
+
+#[test]
+fn raw_flag_prints_bare_wallet_address() {
+    polymarket()
+        .args([
+            "--private-key",
+            "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "-o",
+            "json",
+            "wallet",
+            "address",
+            "--raw",
+        ])
+        .assert()
+        .success()
+        .stdout("0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf\n");
+}
+
+#[test]
+fn query_selects_field_from_json_output() {
+    polymarket()
+        .args([
+            "--private-key",
+            "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "-o",
+            "json",
+            "wallet",
+            "show",
+            "--query",
+            "address",
+        ])
+        .assert()
+        .success()
+        .stdout("\"0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf\"\n");
+}
+
+#[test]
+fn query_in_table_mode_prints_bare_scalar() {
+    polymarket()
+        .args([
+            "--private-key",
+            "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "wallet",
+            "show",
+            "--query",
+            "configured",
+        ])
+        .assert()
+        .success()
+        .stdout("true\n");
+}
+
+#[test]
+fn query_rejects_invalid_expression() {
+    polymarket()
+        .args(["wallet", "show", "--query", "[?"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid --query expression"));
+}
+
+#[test]
+fn template_renders_inline_string() {
+    polymarket()
+        .args([
+            "--private-key",
+            "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "wallet",
+            "show",
+            "--template",
+            "Wallet {{ address }} ({{ source }})",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "Wallet 0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf (",
+        ));
+}
+
+#[test]
+fn template_rejects_invalid_syntax() {
+    polymarket()
+        .args(["wallet", "show", "--template", "{% for x in data %}"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid --template"));
+}
+
+#[test]
+fn no_pager_flag_is_accepted_globally() {
+    polymarket()
+        .args(["wallet", "show", "--no-pager"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn color_flag_rejects_unknown_value() {
+    polymarket()
+        .args(["--color", "sometimes", "wallet", "show"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("auto").and(predicate::str::contains("never")));
+}
+
+#[test]
+fn price_history_log_scale_requires_chart() {
+    polymarket()
+        .args([
+            "clob",
+            "price-history",
+            "1",
+            "--interval",
+            "1d",
+            "--log-scale",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--chart"));
+}
+
+#[test]
+fn watch_rejects_non_read_commands() {
+    polymarket()
+        .args(["--watch", "5", "wallet", "show"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--watch only works"));
+}
+
+#[test]
+fn pnl_rejects_bad_since_date() {
+    polymarket()
+        .args(["pnl", "--since", "01/02/2024"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("YYYY-MM-DD"));
+}
+
+#[test]
+fn portfolio_help_lists_summary() {
+    polymarket()
+        .args(["portfolio", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("summary").and(predicate::str::contains("positions")));
+}
+
+#[test]
+fn portfolio_summary_rejects_other_address() {
+    polymarket()
+        .args([
+            "portfolio",
+            "summary",
+            "--address",
+            "0x0000000000000000000000000000000000000001",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("portfolio positions --address"));
+}
+
+#[test]
+fn follow_scale_requires_mirror() {
+    polymarket()
+        .args([
+            "follow",
+            "0x0000000000000000000000000000000000000001",
+            "--scale",
+            "0.5",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--mirror"));
+}
+
+#[test]
+fn activity_rejects_unknown_type() {
+    polymarket()
+        .args(["activity", "--type", "trade,teleport"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("teleport"));
+}
+
+#[test]
+fn upgrade_version_must_be_a_release_tag() {
+    polymarket()
+        .args(["upgrade", "--version", "latest"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid version: latest"));
+    polymarket()
+        .args(["upgrade", "--check", "--version", "v0.1.0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn upgrade_check_does_not_take_the_signature_skip() {
+    polymarket()
+        .args(["upgrade", "--check", "--insecure-skip-signature"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn tags_help_lists_subcommands() {
+    polymarket()
+        .args(["tags", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("list")
+                .and(predicate::str::contains("get"))
+                .and(predicate::str::contains("related")),
+        );
+}
+
+#[test]
+fn series_help_lists_subcommands() {
+    polymarket()
+        .args(["series", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("list").and(predicate::str::contains("get")));
+}
+
+#[test]
+fn comments_help_lists_subcommands() {
+    polymarket()
+        .args(["comments", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("list")
+                .and(predicate::str::contains("get"))
+                .and(predicate::str::contains("thread"))
+                .and(predicate::str::contains("by-user")),
+        );
+}
+
+#[test]
+fn profiles_help_lists_subcommands() {
+    polymarket()
+        .args(["profiles", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("get"));
+}
+
+#[test]
+fn sports_help_lists_subcommands() {
+    polymarket()
+        .args(["sports", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("list")
+                .and(predicate::str::contains("market-types"))
+                .and(predicate::str::contains("teams")),
+        );
+}
+
+#[test]
+fn clob_help_lists_subcommands() {
+    polymarket()
+        .args(["clob", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("book")
+                .and(predicate::str::contains("price"))
+                .and(predicate::str::contains("spread"))
+                .and(predicate::str::contains("midpoint"))
+                .and(predicate::str::contains("trades")),
+        );
+}
+
+#[test]
+fn clob_create_order_rejects_both_expiry_flags() {
+    polymarket()
+        .args([
+            "clob",
+            "create-order",
+            "--token",
+            "1",
+            "--side",
+            "buy",
+            "--price",
+            "0.5",
+            "--size",
+            "10",
+            "--order-type",
+            "GTD",
+            "--expires-at",
+            "1900000000",
+            "--expires-in",
+            "1h",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn clob_post_orders_requires_side_or_sides() {
+    polymarket()
+        .args([
+            "clob",
+            "post-orders",
+            "--tokens",
+            "1,2",
+            "--prices",
+            "0.4,0.6",
+            "--sizes",
+            "10,10",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--side"));
+}
+
+#[test]
+fn clob_post_orders_rejects_unknown_side_in_list() {
+    polymarket()
+        .args([
+            "clob",
+            "post-orders",
+            "--tokens",
+            "1,2",
+            "--sides",
+            "buy,hold",
+            "--prices",
+            "0.4,0.6",
+            "--sizes",
+            "10,10",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("hold"));
+}
+
+#[test]
+fn clob_post_orders_file_conflicts_with_flags() {
+    polymarket()
+        .args([
+            "clob",
+            "post-orders",
+            "--file",
+            "orders.json",
+            "--tokens",
+            "1",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn clob_post_orders_file_must_exist() {
+    polymarket()
+        .args(["clob", "post-orders", "--file", "/nonexistent/orders.json"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Order file not found"));
+}
+
+#[test]
+fn dry_run_refuses_commands_it_cannot_preview() {
+    polymarket()
+        .args(["--dry-run", "clob", "cancel-all"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--dry-run is not supported"));
+}
+
+#[test]
+fn dry_run_ctf_split_prints_calldata_without_sending() {
+    polymarket()
+        .args([
+            "-o",
+            "json",
+            "--dry-run",
+            "--private-key",
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+            "--signature-type",
+            "eoa",
+            "ctf",
+            "split",
+            "--condition",
+            "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "--amount",
+            "10",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("\"dry_run\": true")
+                .and(predicate::str::contains("\"calldata\": \"0x72ce4275")),
+        );
+}
+
+#[test]
+fn ctf_split_defaults_to_the_chains_collateral() {
+    polymarket()
+        .args([
+            "-o",
+            "json",
+            "--dry-run",
+            "--chain",
+            "amoy",
+            "--private-key",
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+            "--signature-type",
+            "eoa",
+            "ctf",
+            "split",
+            "--condition",
+            "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "--amount",
+            "10",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "0000000000000000000000009c4e1703476e875070ee25b56a58b008cfb8fa78",
+        ));
+}
+
+#[test]
+fn ctf_split_without_terminal_requires_yes() {
+    polymarket()
+        .args([
+            "--private-key",
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+            "ctf",
+            "split",
+            "--condition",
+            "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "--amount",
+            "10",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("pass --yes"));
+}
+
+#[test]
+fn ctf_split_rejects_priority_fee_above_max_fee() {
+    polymarket()
+        .args([
+            "--private-key",
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+            "ctf",
+            "split",
+            "--condition",
+            "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "--amount",
+            "10",
+            "--max-fee",
+            "30",
+            "--priority-fee",
+            "40",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--priority-fee can't be higher than --max-fee",
+        ));
+}
+
+#[test]
+fn ctf_transfer_dry_run_encodes_safe_transfer_from() {
+    polymarket()
+        .args([
+            "--private-key",
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+            "--signature-type",
+            "eoa",
+            "--dry-run",
+            "-o",
+            "json",
+            "ctf",
+            "transfer",
+            "--position",
+            "12",
+            "--to",
+            "0x0000000000000000000000000000000000000001",
+            "--amount",
+            "2.5",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"calldata\": \"0xf242432a"));
+}
+
+#[test]
+fn ctf_transfer_from_a_proxy_goes_through_the_factory() {
+    polymarket()
+        .args([
+            "--private-key",
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+            "--signature-type",
+            "proxy",
+            "--dry-run",
+            "-o",
+            "json",
+            "ctf",
+            "transfer",
+            "--position",
+            "12",
+            "--to",
+            "0x0000000000000000000000000000000000000001",
+            "--amount",
+            "2.5",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"calldata\": \"0x34ee9791"))
+        .stdout(predicate::str::contains(
+            "\"to\": \"0xaB45c5A4B0c941a2F231C04C3f49182e1A254052\"",
+        ));
+}
+
+#[test]
+fn ctf_transfer_from_a_safe_needs_dry_run() {
+    polymarket()
+        .args([
+            "--private-key",
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+            "--signature-type",
+            "gnosis-safe",
+            "ctf",
+            "transfer",
+            "--position",
+            "12",
+            "--to",
+            "0x0000000000000000000000000000000000000001",
+            "--amount",
+            "2.5",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("safe propose"));
+}
+
+#[test]
+fn ctf_transfer_batch_requires_one_amount_per_position() {
+    polymarket()
+        .args([
+            "ctf",
+            "transfer-batch",
+            "--positions",
+            "1,2",
+            "--amounts",
+            "5",
+            "--to",
+            "0x0000000000000000000000000000000000000001",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--positions has 2 entries but --amounts has 1",
+        ));
+}
+
+#[test]
+fn ctf_approval_revoke_dry_run_covers_default_operators() {
+    polymarket()
+        .args([
+            "--private-key",
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+            "--dry-run",
+            "ctf",
+            "approval",
+            "revoke",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("setApprovalForAll(false) \u{2192} CTF Exchange")
+                .and(predicate::str::contains("Neg Risk Exchange")),
+        );
+}
+
+#[test]
+fn ctf_no_wait_conflicts_with_confirmations() {
+    polymarket()
+        .args([
+            "ctf",
+            "split",
+            "--condition",
+            "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "--amount",
+            "10",
+            "--no-wait",
+            "--confirmations",
+            "3",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn ctf_confirmations_must_be_at_least_one() {
+    polymarket()
+        .args([
+            "ctf",
+            "redeem",
+            "--condition",
+            "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "--confirmations",
+            "0",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--confirmations"));
+}
+
+#[test]
+fn ctf_merge_max_conflicts_with_amount() {
+    polymarket()
+        .args([
+            "ctf",
+            "merge",
+            "--condition",
+            "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "--amount",
+            "10",
+            "--max",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn clob_create_order_rejects_unknown_price_expression() {
+    polymarket()
+        .args([
+            "--private-key",
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+            "clob",
+            "create-order",
+            "--token",
+            "1",
+            "--side",
+            "buy",
+            "--price",
+            "last-1t",
+            "--size",
+            "10",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid price: last-1t"));
+}
+
+#[test]
+fn clob_replace_requires_price() {
+    polymarket()
+        .args(["clob", "replace", "0xabc"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--price"));
+}
+
+#[test]
+fn clob_ladder_orders_rejects_unknown_skew() {
+    polymarket()
+        .args([
+            "clob",
+            "ladder-orders",
+            "--token",
+            "1",
+            "--side",
+            "buy",
+            "--from",
+            "0.40",
+            "--to",
+            "0.48",
+            "--total-size",
+            "500",
+            "--skew",
+            "exponential",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'exponential'"));
+}
+
+#[test]
+fn clob_twap_resume_reports_missing_state_file() {
+    polymarket()
+        .args([
+            "--yes",
+            "clob",
+            "twap",
+            "--resume",
+            "/nonexistent/twap.json",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to read state file"));
+}
+
+#[test]
+fn clob_iceberg_requires_display_size() {
+    polymarket()
+        .args([
+            "clob", "iceberg", "--token", "1", "--side", "sell", "--price", "0.62", "--total",
+            "100",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--display"));
+}
+
+#[test]
+fn clob_twap_cannot_dry_run() {
+    polymarket()
+        .args([
+            "--dry-run",
+            "clob",
+            "twap",
+            "--token",
+            "1",
+            "--side",
+            "buy",
+            "--total",
+            "10",
+            "--duration",
+            "1h",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--dry-run is not supported"));
+}
+
+#[test]
+fn clob_stop_requires_trigger() {
+    polymarket()
+        .args([
+            "clob", "stop", "--token", "1", "--side", "sell", "--size", "400",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--trigger"));
+}
+
+#[test]
+fn clob_fee_preview_rejects_price_outside_range() {
+    polymarket()
+        .args([
+            "clob",
+            "fee-preview",
+            "1",
+            "--side",
+            "buy",
+            "--price",
+            "1.2",
+            "--size",
+            "10",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--price must be between 0 and 1"));
+}
+
+#[test]
+fn clob_rewards_suggest_rejects_score_above_one() {
     polymarket()
-        .args(["--output", "xml", "status"])
+        .args([
+            "clob",
+            "rewards-suggest",
+            "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "--score",
+            "2",
+        ])
         .assert()
         .failure()
-        .stderr(predicate::str::contains("invalid value"));
+        .stderr(predicate::str::contains("--score must be above 0"));
 }
 
 #[test]
-fn markets_search_requires_query() {
-    polymarket().args(["markets", "search"]).assert().failure();
+fn clob_rewards_rejects_reversed_range() {
+    polymarket()
+        .args([
+            "clob",
+            "rewards",
+            "--from",
+            "2024-06-10",
+            "--to",
+            "2024-06-01",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is after --to"));
 }
 
 #[test]
-fn markets_get_requires_id() {
-    polymarket().args(["markets", "get"]).assert().failure();
+fn clob_earnings_summary_rejects_bad_month() {
+    polymarket()
+        .args(["clob", "earnings-summary", "--month", "2025-13"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid month: 2025-13"));
 }
 
 #[test]
-fn comments_list_requires_entity_args() {
-    polymarket().args(["comments", "list"]).assert().failure();
+fn clob_notifications_exec_requires_follow() {
+    polymarket()
+        .args(["clob", "notifications", "--exec", "true"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--follow"));
 }
 
-// Uses a guaranteed-to-fail command (nonexistent slug) to verify the error
-// output contract: JSON mode → structured error on stdout, table mode → stderr.
-
 #[test]
-fn json_mode_error_is_valid_json_with_error_key() {
-    let output = polymarket()
+fn clob_bracket_rejects_take_profit_below_stop_when_selling() {
+    polymarket()
         .args([
-            "--output",
-            "json",
-            "markets",
-            "get",
-            "nonexistent-slug-99999",
+            "--yes",
+            "clob",
+            "bracket",
+            "--token",
+            "1",
+            "--side",
+            "sell",
+            "--size",
+            "400",
+            "--take-profit",
+            "0.30",
+            "--stop",
+            "0.35",
         ])
-        .output()
-        .unwrap();
-
-    assert!(!output.status.success());
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let parsed: serde_json::Value = serde_json::from_str(stdout.trim())
-        .unwrap_or_else(|e| panic!("stdout not valid JSON: {e}\nstdout: {stdout}"));
-    assert!(
-        parsed.get("error").is_some(),
-        "missing 'error' key: {parsed}"
-    );
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("must be above --stop"));
 }
 
 #[test]
-fn table_mode_error_goes_to_stderr() {
+fn clob_trailing_stop_rejects_trail_outside_price_range() {
     polymarket()
-        .args(["markets", "get", "nonexistent-slug-99999"])
+        .args([
+            "--yes",
+            "clob",
+            "trailing-stop",
+            "--token",
+            "1",
+            "--side",
+            "sell",
+            "--trail",
+            "1.5",
+            "--size",
+            "300",
+        ])
         .assert()
         .failure()
-        .stderr(predicate::str::contains("Error:"));
-}
-
-#[test]
-fn wallet_show_always_succeeds() {
-    polymarket().args(["wallet", "show"]).assert().success();
+        .stderr(predicate::str::contains("--trail must be between 0 and 1"));
 }
 
 #[test]
-fn wallet_show_json_has_configured_field() {
-    let output = polymarket()
-        .args(["-o", "json", "wallet", "show"])
-        .output()
-        .unwrap();
-
-    assert!(output.status.success());
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let parsed: serde_json::Value = serde_json::from_str(stdout.trim())
-        .unwrap_or_else(|e| panic!("stdout not valid JSON: {e}\nstdout: {stdout}"));
-    assert!(
-        parsed.get("configured").is_some(),
-        "missing 'configured' key: {parsed}"
-    );
+fn clob_guard_rejects_invalid_ttl() {
+    polymarket()
+        .args(["clob", "guard", "--ttl", "soon"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid duration: soon"));
 }
 
 #[test]
-fn tags_help_lists_subcommands() {
+fn clob_trades_rejects_unparseable_time_filter() {
     polymarket()
-        .args(["tags", "--help"])
+        .args(["clob", "trades", "--after", "last week"])
         .assert()
-        .success()
-        .stdout(
-            predicate::str::contains("list")
-                .and(predicate::str::contains("get"))
-                .and(predicate::str::contains("related")),
-        );
+        .failure()
+        .stderr(predicate::str::contains("Invalid time: last week"));
 }
 
 #[test]
-fn series_help_lists_subcommands() {
+fn clob_orders_rejects_unknown_status() {
     polymarket()
-        .args(["series", "--help"])
+        .args(["clob", "orders", "--status", "pending"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("list").and(predicate::str::contains("get")));
+        .failure()
+        .stderr(predicate::str::contains("live"));
 }
 
 #[test]
-fn comments_help_lists_subcommands() {
+fn clob_book_rejects_zero_group() {
     polymarket()
-        .args(["comments", "--help"])
+        .args(["clob", "book", "1", "--group", "0"])
         .assert()
-        .success()
-        .stdout(
-            predicate::str::contains("list")
-                .and(predicate::str::contains("get"))
-                .and(predicate::str::contains("by-user")),
-        );
+        .failure()
+        .stderr(predicate::str::contains("--group"));
 }
 
 #[test]
-fn profiles_help_lists_subcommands() {
-    polymarket()
-        .args(["profiles", "--help"])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains("get"));
+fn clob_tokens_requires_condition_id() {
+    polymarket().args(["clob", "tokens"]).assert().failure();
 }
 
 #[test]
-fn sports_help_lists_subcommands() {
-    polymarket()
-        .args(["sports", "--help"])
-        .assert()
-        .success()
-        .stdout(
-            predicate::str::contains("list")
-                .and(predicate::str::contains("market-types"))
-                .and(predicate::str::contains("teams")),
-        );
+fn clob_ladder_requires_token() {
+    polymarket().args(["clob", "ladder"]).assert().failure();
 }
 
 #[test]
-fn clob_help_lists_subcommands() {
+fn clob_watch_prices_requires_tokens() {
     polymarket()
-        .args(["clob", "--help"])
+        .args(["clob", "watch-prices"])
         .assert()
-        .success()
-        .stdout(
-            predicate::str::contains("book")
-                .and(predicate::str::contains("price"))
-                .and(predicate::str::contains("spread"))
-                .and(predicate::str::contains("midpoint"))
-                .and(predicate::str::contains("trades")),
-        );
+        .failure()
+        .stderr(predicate::str::contains("--tokens"));
 }
 
 #[test]
@@ -258,6 +1838,7 @@ fn data_help_lists_subcommands() {
         .stdout(
             predicate::str::contains("positions")
                 .and(predicate::str::contains("trades"))
+                .and(predicate::str::contains("stats"))
                 .and(predicate::str::contains("leaderboard")),
         );
 }
@@ -275,11 +1856,81 @@ fn bridge_help_lists_subcommands() {
         );
 }
 
+#[test]
+fn bridge_deposit_amount_needs_a_source_chain() {
+    polymarket()
+        .args(["bridge", "deposit", "--amount", "100"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--from-chain"));
+    polymarket()
+        .args(["bridge", "deposit", "--from-chain", "1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--amount"));
+    polymarket()
+        .args(["bridge", "status", "0xabc", "--timeout", "60"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--wait"));
+}
+
 #[test]
 fn events_get_requires_id() {
     polymarket().args(["events", "get"]).assert().failure();
 }
 
+#[test]
+fn markets_list_order_conflicts_with_order_by() {
+    polymarket()
+        .args([
+            "markets",
+            "list",
+            "--order",
+            "volumeNum",
+            "--order-by",
+            "liquidity",
+        ])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn markets_list_rejects_unknown_order_by() {
+    polymarket()
+        .args(["markets", "list", "--order-by", "popularity"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("volume-24h"));
+}
+
+#[test]
+fn markets_trending_rejects_unknown_metric() {
+    polymarket()
+        .args(["markets", "trending", "--by", "hype"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("volume-surge"));
+}
+
+#[test]
+fn markets_watch_new_cannot_be_watched() {
+    polymarket()
+        .args(["--watch", "5", "markets", "watch-new"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--watch only works"));
+}
+
+#[test]
+fn events_list_rejects_bad_end_date() {
+    polymarket()
+        .args(["events", "list", "--end-before", "31/12/2025"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("YYYY-MM-DD"));
+}
+
 #[test]
 fn tags_get_requires_id() {
     polymarket().args(["tags", "get"]).assert().failure();
@@ -295,6 +1946,15 @@ fn comments_get_requires_id() {
     polymarket().args(["comments", "get"]).assert().failure();
 }
 
+#[test]
+fn comments_thread_rejects_unknown_sort() {
+    polymarket()
+        .args(["comments", "thread", "some-event", "--sort", "hot"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("top"));
+}
+
 #[test]
 fn comments_by_user_requires_address() {
     polymarket()
@@ -318,18 +1978,130 @@ fn clob_price_requires_token() {
     polymarket().args(["clob", "price"]).assert().failure();
 }
 
+#[test]
+fn clob_outcome_flag_is_accepted_after_subcommand() {
+    polymarket()
+        .args(["clob", "book", "--help", "--outcome", "yes"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--outcome"));
+}
+
 #[test]
 fn data_positions_requires_address() {
     polymarket().args(["data", "positions"]).assert().failure();
 }
 
+#[test]
+fn data_trades_rejects_invalid_target() {
+    polymarket()
+        .args(["data", "trades", "0x1234"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid address"));
+}
+
+#[test]
+fn data_trades_follow_conflicts_with_offset() {
+    polymarket()
+        .args([
+            "data",
+            "trades",
+            "0x0000000000000000000000000000000000000000",
+            "--follow",
+            "--offset",
+            "10",
+        ])
+        .assert()
+        .failure();
+}
+
 #[test]
 fn approve_help_lists_subcommands() {
     polymarket()
         .args(["approve", "--help"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("check").and(predicate::str::contains("set")));
+        .stdout(
+            predicate::str::contains("check")
+                .and(predicate::str::contains("set"))
+                .and(predicate::str::contains("revoke")),
+        );
+}
+
+#[test]
+fn approve_set_bounds_the_allowance_to_chosen_contracts() {
+    polymarket()
+        .args([
+            "--dry-run",
+            "-o",
+            "json",
+            "--private-key",
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+        ])
+        .args(["approve", "set", "--contract", "exchange", "--amount", "50"])
+        .assert()
+        .success()
+        .stdout(
+            // 50 USDC is 0x2faf080 raw units.
+            predicate::str::contains("0000002faf080\"")
+                .and(predicate::str::contains("USDC approve → CTF Exchange"))
+                .and(predicate::str::contains("Neg Risk").not()),
+        );
+}
+
+#[test]
+fn approve_revoke_zeroes_only_the_chosen_contracts() {
+    let revoke = |contract: &str| {
+        polymarket()
+            .args([
+                "--dry-run",
+                "-o",
+                "json",
+                "--private-key",
+                "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+            ])
+            .args(["approve", "revoke", "--contract", contract])
+            .assert()
+            .success()
+    };
+    revoke("exchange").stdout(
+        predicate::str::contains("USDC revoke → CTF Exchange")
+            .and(predicate::str::contains(
+                "setApprovalForAll(false) → CTF Exchange",
+            ))
+            .and(predicate::str::contains("Neg Risk").not()),
+    );
+    revoke("ctf").stdout(
+        predicate::str::contains("USDC revoke → Conditional Tokens")
+            .and(predicate::str::contains("setApprovalForAll").not()),
+    );
+    polymarket()
+        .args(["approve", "revoke", "--contract", "everything"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn tx_help_lists_subcommands() {
+    polymarket()
+        .args(["tx", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("pending")
+                .and(predicate::str::contains("speedup"))
+                .and(predicate::str::contains("cancel")),
+        );
+}
+
+#[test]
+fn tx_speedup_rejects_invalid_hash() {
+    polymarket()
+        .args(["tx", "speedup", "0x1234"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid transaction hash"));
 }
 
 #[test]
@@ -345,10 +2117,38 @@ fn ctf_help_lists_subcommands() {
                 .and(predicate::str::contains("redeem-neg-risk"))
                 .and(predicate::str::contains("condition-id"))
                 .and(predicate::str::contains("collection-id"))
-                .and(predicate::str::contains("position-id")),
+                .and(predicate::str::contains("position-id"))
+                .and(predicate::str::contains("balances"))
+                .and(predicate::str::contains("redeem-all"))
+                .and(predicate::str::contains("ids")),
         );
 }
 
+#[test]
+fn ctf_ids_requires_market() {
+    polymarket()
+        .args(["ctf", "ids"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("<MARKET>"));
+}
+
+#[test]
+fn ctf_balances_rejects_invalid_token_id() {
+    polymarket()
+        .args([
+            "ctf",
+            "balances",
+            "--tokens",
+            "1,abc",
+            "--address",
+            "0x0000000000000000000000000000000000000001",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid token ID: abc"));
+}
+
 #[test]
 fn ctf_collection_id_requires_condition_and_index_set() {
     polymarket()
@@ -381,10 +2181,20 @@ fn ctf_split_help_shows_all_flags() {
                 .and(predicate::str::contains("--amount"))
                 .and(predicate::str::contains("--collateral"))
                 .and(predicate::str::contains("--partition"))
-                .and(predicate::str::contains("--parent-collection")),
+                .and(predicate::str::contains("--parent-collection"))
+                .and(predicate::str::contains("--skip-simulation")),
         );
 }
 
+#[test]
+fn ctf_redeem_all_help_shows_include_losers_flag() {
+    polymarket()
+        .args(["ctf", "redeem-all", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--include-losers"));
+}
+
 #[test]
 fn ctf_redeem_help_shows_index_sets_flag() {
     polymarket()